@@ -0,0 +1,74 @@
+//! Throughput of the case conversions used while rendering model files, with and without the
+//! thread-local cache in `utils`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fmt::Write;
+use std::hint::black_box;
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/utils.rs"]
+mod utils;
+
+const FIELDS: [&str; 8] = [
+    "name", "email", "passwordHash", "displayName", "createdBy", "updatedBy", "isActive", "lastLoginAt",
+];
+
+/// Render a model file the way `ModelGenerator` does, converting the model and field names at each use
+fn render_model(name: &str, snake: fn(&str) -> String, pascal: fn(&str) -> String) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "//! {} Model", pascal(name)).unwrap();
+    writeln!(output, "#[tideorm::model(table = \"{}s\")]", snake(name)).unwrap();
+    writeln!(output, "pub struct {} {{", pascal(name)).unwrap();
+    for field in FIELDS {
+        writeln!(output, "    pub {}: String,", snake(field)).unwrap();
+    }
+    writeln!(output, "}}\n\nimpl {} {{", pascal(name)).unwrap();
+    for field in FIELDS {
+        writeln!(
+            output,
+            "    pub async fn find_by_{}(value: &str) -> tideorm::Result<Option<{}>> {{",
+            snake(field),
+            pascal(name)
+        )
+        .unwrap();
+        writeln!(output, "        Self::query().where_eq(\"{}\", value).first().await\n    }}", snake(field)).unwrap();
+    }
+    writeln!(output, "}}\n// use super::{}::{};", snake(name), pascal(name)).unwrap();
+
+    output
+}
+
+fn uncached_snake(s: &str) -> String {
+    heck::AsSnakeCase(s).to_string()
+}
+
+fn uncached_pascal(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+fn generate_models(c: &mut Criterion) {
+    let names: Vec<String> = (0..1000).map(|i| format!("BlogPostRevision{}", i)).collect();
+    let mut group = c.benchmark_group("generate 1000 model files");
+
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(render_model(black_box(name), uncached_snake, uncached_pascal));
+            }
+        })
+    });
+
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(render_model(black_box(name), utils::to_snake_case, utils::to_pascal_case));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, generate_models);
+criterion_main!(benches);
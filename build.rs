@@ -0,0 +1,41 @@
+//! Build script for TideORM CLI
+//!
+//! Exposes the resolved sqlx version as `TIDEORM_SQLX_VERSION` for `db version`.
+
+use std::path::Path;
+use std::process::Command;
+
+#[path = "src/sqlx_version.rs"]
+mod sqlx_version;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=src/sqlx_version.rs");
+    println!("cargo:rerun-if-env-changed=TIDEORM_SQLX_VERSION");
+
+    let metadata = cargo_metadata();
+    let version = sqlx_version::resolve(std::env::var("TIDEORM_SQLX_VERSION").ok(), metadata.as_deref());
+
+    println!("cargo:rustc-env=TIDEORM_SQLX_VERSION={}", version);
+}
+
+/// `cargo metadata` for this package, or `None` when it has no usable lockfile
+///
+/// `--locked --offline` keeps cargo from writing a lockfile or reaching the network,
+/// which matters when the crate is built from the registry without its own Cargo.lock.
+fn cargo_metadata() -> Option<String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = Path::new(&std::env::var("CARGO_MANIFEST_DIR").ok()?).join("Cargo.toml");
+
+    let output = Command::new(cargo)
+        .args(["metadata", "--format-version", "1", "--locked", "--offline", "--manifest-path"])
+        .arg(manifest)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout).ok()
+}
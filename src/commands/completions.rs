@@ -0,0 +1,97 @@
+//! Shell completion command for TideORM CLI
+
+use crate::utils::print_success;
+use clap_complete::Shell;
+use std::io::Write;
+use std::str::FromStr;
+
+/// Print or write the completion script for a shell
+pub fn run(mut command: clap::Command, shell: &str, output: Option<&str>) -> Result<(), String> {
+    let shell = parse_shell(shell)?;
+    let script = completion_script(&mut command, shell);
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, &script)
+                .map_err(|error| format!("Failed to write {}: {}", path, error))?;
+            print_success(&format!("Wrote {} completions to {}", shell, path));
+            println!("\n{}", install_instructions(shell));
+        }
+        None => {
+            std::io::stdout()
+                .write_all(&script)
+                .map_err(|error| format!("Failed to write completions: {}", error))?;
+            // Keep stdout to the script alone so it can be redirected or sourced
+            eprintln!("\n{}", install_instructions(shell));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_shell(shell: &str) -> Result<Shell, String> {
+    Shell::from_str(&shell.trim().to_lowercase()).map_err(|_| {
+        format!(
+            "Unsupported shell: {}. Expected one of: bash, zsh, fish, powershell, elvish",
+            shell
+        )
+    })
+}
+
+/// Render the completion script for the `tideorm` binary
+fn completion_script(command: &mut clap::Command, shell: Shell) -> Vec<u8> {
+    let mut script = Vec::new();
+    clap_complete::generate(shell, command, "tideorm", &mut script);
+    script
+}
+
+fn install_instructions(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Bash => {
+            "To install, save the script to your bash-completion directory:\n  \
+             tideorm completions bash > ~/.local/share/bash-completion/completions/tideorm"
+        }
+        Shell::Zsh => {
+            "To install, save the script in a directory on your $fpath:\n  \
+             tideorm completions zsh > ~/.zfunc/_tideorm\n\
+             and add `fpath+=~/.zfunc; autoload -Uz compinit && compinit` to ~/.zshrc"
+        }
+        Shell::Fish => {
+            "To install, save the script to your fish completions directory:\n  \
+             tideorm completions fish > ~/.config/fish/completions/tideorm.fish"
+        }
+        Shell::PowerShell => {
+            "To install, load the script from your PowerShell profile:\n  \
+             tideorm completions powershell >> $PROFILE"
+        }
+        Shell::Elvish => {
+            "To install, append the script to your Elvish rc file:\n  \
+             tideorm completions elvish >> ~/.config/elvish/rc.elv"
+        }
+        _ => "Load the script according to your shell's completion documentation",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{completion_script, parse_shell};
+    use crate::Cli;
+    use clap::CommandFactory;
+
+    #[test]
+    fn completion_scripts_mention_the_binary_for_every_shell() {
+        for name in ["bash", "zsh", "fish", "powershell", "elvish"] {
+            let shell = parse_shell(name).unwrap();
+            let script = String::from_utf8(completion_script(&mut Cli::command(), shell)).unwrap();
+
+            assert!(!script.is_empty(), "{} script is empty", name);
+            assert!(script.contains("tideorm"), "{} script does not mention tideorm", name);
+        }
+    }
+
+    #[test]
+    fn parse_shell_rejects_unknown_shells() {
+        assert!(parse_shell("PowerShell").is_ok());
+        assert!(parse_shell("cmd").unwrap_err().contains("Unsupported shell: cmd"));
+    }
+}
@@ -5,21 +5,74 @@ use crate::runtime_db;
 use crate::utils::{print_info, print_success, print_warning};
 use crate::DbCommands;
 use colored::Colorize;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 /// Handle database subcommands
 pub async fn handle(config_path: &str, cmd: DbCommands, verbose: bool) -> Result<(), String> {
     match cmd {
-        DbCommands::Seed { seeder, force } => seed(config_path, seeder, force, verbose).await,
+        DbCommands::Seed {
+            seeder,
+            only,
+            except,
+            force,
+            dry_run,
+        } => seed(config_path, seeder, only, except, force, dry_run, verbose).await,
         DbCommands::Fresh { force } => fresh(config_path, force, verbose).await,
         DbCommands::Status => status(config_path, verbose).await,
         DbCommands::Check => check(config_path, verbose).await,
         DbCommands::Create { name } => create_database(config_path, name, verbose).await,
         DbCommands::Drop { name, force } => drop_database(config_path, name, force, verbose).await,
-        DbCommands::Wipe { drop_types, force } => wipe(config_path, drop_types, force, verbose).await,
+        DbCommands::Wipe { cascade, force } => wipe(config_path, cascade, force, verbose).await,
         DbCommands::Table { name } => show_table(config_path, &name, verbose).await,
         DbCommands::Tables => list_tables(config_path, verbose).await,
+        DbCommands::Ping {
+            count,
+            interval_ms,
+            timeout,
+        } => ping(config_path, count, interval_ms, timeout, verbose).await,
+        DbCommands::Analyze { tables, vacuum } => analyze(config_path, tables, vacuum, verbose).await,
+        DbCommands::CheckConstraints { tables } => check_constraints(config_path, tables, verbose).await,
+        DbCommands::MigrateCheck { file } => migrate_check(config_path, &file, verbose).await,
+        DbCommands::Copy {
+            from_config,
+            tables,
+            truncate_target,
+            force,
+        } => copy(config_path, &from_config, tables, truncate_target, force, verbose).await,
+        DbCommands::Import {
+            file,
+            table,
+            format,
+            truncate,
+            batch_size,
+            on_conflict,
+        } => {
+            crate::commands::import::run(
+                config_path,
+                &file,
+                &table,
+                format,
+                truncate,
+                batch_size,
+                on_conflict,
+                verbose,
+            )
+            .await
+        }
+        DbCommands::Export {
+            table,
+            output,
+            format,
+            where_clause,
+        } => crate::commands::export::run(config_path, &table, output, format, where_clause, verbose).await,
+        DbCommands::Version { json } => version(config_path, json, verbose).await,
+        DbCommands::Shell { no_system_client } => {
+            crate::commands::shell::run(config_path, no_system_client, verbose).await
+        }
     }
 }
 
@@ -27,12 +80,15 @@ pub async fn handle(config_path: &str, cmd: DbCommands, verbose: bool) -> Result
 pub async fn seed(
     config_path: &str,
     seeder: Option<String>,
+    only: Option<String>,
+    except: Option<String>,
     force: bool,
+    dry_run: bool,
     verbose: bool,
 ) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
 
-    if config.is_production() && !force {
+    if config.is_production() && !force && !dry_run {
         return Err("Cannot run seeders in production without --force flag".to_string());
     }
 
@@ -43,7 +99,15 @@ pub async fn seed(
     }
 
     // Get seeders to run
-    let seeders = if let Some(seeder_name) = seeder {
+    let seeders = if let Some(names) = only {
+        find_seeders(seeders_path, &names)?
+    } else if let Some(names) = except {
+        let excluded = find_seeders(seeders_path, &names)?;
+        get_all_seeders(seeders_path)?
+            .into_iter()
+            .filter(|seeder| !excluded.iter().any(|excluded| excluded.name == seeder.name))
+            .collect()
+    } else if let Some(seeder_name) = seeder {
         vec![find_seeder(seeders_path, &seeder_name)?]
     } else {
         // Find the default seeder (DatabaseSeeder)
@@ -62,14 +126,28 @@ pub async fn seed(
         return Ok(());
     }
 
-    println!("\n{}", "Running seeders:".cyan().bold());
+    let title = if dry_run {
+        "Running seeders (dry run):"
+    } else {
+        "Running seeders:"
+    };
+    println!("\n{}", title.cyan().bold());
     println!("{}", "─".repeat(50));
 
+    let mut collector = DryRunCollector::default();
+    let mut database = DatabaseBackend { config: &config };
+
     for seeder in &seeders {
         print!("  Seeding: {}... ", seeder.name);
-        
+
         // Run the seeder
-        match run_seeder(&config, seeder).await {
+        let result = if dry_run {
+            run_seeder(&config, seeder, &mut collector).await
+        } else {
+            run_seeder(&config, seeder, &mut database).await
+        };
+
+        match result {
             Ok(count) => {
                 println!("{} ({} records)", "DONE".green(), count);
             }
@@ -81,6 +159,12 @@ pub async fn seed(
     }
 
     println!("{}", "─".repeat(50));
+
+    if dry_run {
+        println!("{}", collector.render());
+        print_info("Dry run: no rows were inserted");
+    }
+
     print_success(&format!("Ran {} seeder(s)", seeders.len()));
 
     Ok(())
@@ -105,6 +189,7 @@ async fn fresh(config_path: &str, force: bool, verbose: bool) -> Result<(), Stri
             seed: true,
             seeder: None,
             force: true,
+            drop_tracking_table: false,
         },
         verbose,
     )
@@ -153,16 +238,37 @@ async fn status(config_path: &str, verbose: bool) -> Result<(), String> {
 
     // Try to connect
     print!("\n  Connection: ");
-    match test_connection(&config).await {
-        Ok(()) => println!("{}", "OK".green()),
-        Err(e) => println!("{} ({})", "FAILED".red(), e),
+    let schema_version = match test_connection(&config).await {
+        Ok(()) => {
+            println!("{}", "OK".green());
+            runtime_db::migration_table_schema_version(&config, &config.migration.table).await?
+        }
+        Err(e) => {
+            println!("{} ({})", "FAILED".red(), e);
+            None
+        }
+    };
+
+    if let Some(version) = schema_version {
+        println!("  Migrations: {} (schema v{})", config.migration.table, version);
     }
 
     println!("{}", "─".repeat(50));
 
+    if let Some(warning) = schema_version_warning(schema_version) {
+        print_warning(warning);
+    }
+
     Ok(())
 }
 
+/// Warning for a migrations table created by an older CLI
+fn schema_version_warning(version: Option<i64>) -> Option<&'static str> {
+    version
+        .filter(|version| *version < runtime_db::TIDEORM_SCHEMA_VERSION)
+        .map(|_| "Migrations table schema is outdated. Run 'tideorm migrate run' to update it.")
+}
+
 /// Initialize TideORM metadata tables for the current database
 async fn check(config_path: &str, verbose: bool) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
@@ -246,10 +352,10 @@ async fn drop_database(
     Ok(())
 }
 
-/// Wipe all tables (truncate)
+/// Empty every table except the metadata tables, referencing tables first
 async fn wipe(
     config_path: &str,
-    drop_types: bool,
+    cascade: bool,
     force: bool,
     verbose: bool,
 ) -> Result<(), String> {
@@ -264,18 +370,50 @@ async fn wipe(
         return Ok(());
     }
 
+    let tables: Vec<String> = get_all_tables(&config)
+        .await?
+        .into_iter()
+        .filter(|table| {
+            table != &config.migration.table
+                && table != runtime_db::DEFAULT_SEEDERS_TABLE
+                && table != runtime_db::SCHEMA_VERSION_TABLE
+        })
+        .collect();
+
+    if tables.is_empty() {
+        print_info("No tables to wipe");
+        return Ok(());
+    }
+
+    let ordered = wipe_order(&tables, &table_references(&config, &tables).await?);
+
     if verbose {
-        print_info("Wiping all tables...");
+        print_info(&format!("Wiping tables in order: {}", ordered.join(", ")));
     }
 
-    // Truncate all tables
-    wipe_tables(&config, drop_types).await?;
+    wipe_tables(&config, &ordered, cascade).await?;
 
-    print_success("All tables wiped successfully");
+    print_success(&format!("Wiped {} table(s)", ordered.len()));
 
     Ok(())
 }
 
+/// Order tables so the tables holding foreign keys are emptied before the tables they reference
+fn wipe_order(tables: &[String], references: &HashMap<String, Vec<String>>) -> Vec<String> {
+    match super::schema::dependency_order(tables, references) {
+        Some(mut ordered) => {
+            ordered.reverse();
+            ordered
+        }
+        None => {
+            print_warning("Foreign keys form a cycle; wiping tables in alphabetical order");
+            let mut ordered = tables.to_vec();
+            ordered.sort();
+            ordered
+        }
+    }
+}
+
 /// Show table information
 async fn show_table(config_path: &str, table_name: &str, verbose: bool) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
@@ -339,6 +477,565 @@ async fn list_tables(config_path: &str, verbose: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Copy tables from another database into the configured one
+async fn copy(
+    config_path: &str,
+    from_config: &str,
+    tables: Vec<String>,
+    truncate_target: bool,
+    force: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let target = TideConfig::load(config_path)?;
+    let source = TideConfig::load(from_config)?;
+
+    if (source.is_production() || target.is_production()) && !force {
+        return Err("Cannot copy databases in production without --force flag".to_string());
+    }
+
+    if source.database.resolve_url().await? == target.database.resolve_url().await? {
+        return Err("Source and target databases are the same".to_string());
+    }
+
+    let tables = if tables.is_empty() {
+        get_all_tables(&source)
+            .await?
+            .into_iter()
+            .filter(|table| {
+                table != &source.migration.table
+                    && table != runtime_db::DEFAULT_SEEDERS_TABLE
+                    && table != runtime_db::SCHEMA_VERSION_TABLE
+            })
+            .collect()
+    } else {
+        tables
+    };
+
+    if tables.is_empty() {
+        print_info("No tables to copy");
+        return Ok(());
+    }
+
+    let target_tables = get_all_tables(&target).await?;
+    let missing: Vec<&str> = tables
+        .iter()
+        .filter(|table| !target_tables.contains(table))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Tables missing in the target database: {}. Run migrations first.",
+            missing.join(", ")
+        ));
+    }
+
+    let ordered = tables_in_dependency_order(&target, &tables).await?;
+
+    if verbose {
+        print_info(&format!("Copy order: {}", ordered.join(", ")));
+    }
+
+    if truncate_target {
+        for table in ordered.iter().rev() {
+            runtime_db::clear_table(&target, table).await?;
+        }
+        print_info(&format!("Cleared {} target table(s)", ordered.len()));
+    }
+
+    println!("\n{}", "Copying tables:".cyan().bold());
+    println!("{}", "─".repeat(50));
+
+    for table in &ordered {
+        print!("  Copying: {}... ", table);
+
+        match runtime_db::copy_table(&source, &target, table).await {
+            Ok(rows) => println!("{} ({} rows)", "DONE".green(), rows),
+            Err(error) => {
+                println!("{}", "FAILED".red());
+                return Err(format!("Failed to copy {}: {}", table, error));
+            }
+        }
+    }
+
+    println!("{}", "─".repeat(50));
+    print_success(&format!("Copied {} table(s) from {}", ordered.len(), from_config));
+
+    Ok(())
+}
+
+/// Order tables by their foreign keys, referenced tables first
+///
+/// Keeps the requested order when the foreign keys form a cycle.
+pub(crate) async fn tables_in_dependency_order(
+    config: &TideConfig,
+    tables: &[String],
+) -> Result<Vec<String>, String> {
+    let references = table_references(config, tables).await?;
+    Ok(super::schema::dependency_order(tables, &references).unwrap_or_else(|| {
+        print_warning("Foreign keys form a cycle; keeping the requested table order");
+        tables.to_vec()
+    }))
+}
+
+/// Map each table to the tables its foreign keys reference
+async fn table_references(
+    config: &TideConfig,
+    tables: &[String],
+) -> Result<HashMap<String, Vec<String>>, String> {
+    let mut references = HashMap::new();
+    for table in tables {
+        let parents = runtime_db::table_foreign_keys(config, table)
+            .await?
+            .into_iter()
+            .map(|foreign_key| foreign_key.references_table)
+            .collect();
+        references.insert(table.clone(), parents);
+    }
+
+    Ok(references)
+}
+
+/// Refresh table statistics and print a summary
+async fn analyze(
+    config_path: &str,
+    tables: Vec<String>,
+    vacuum: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let is_postgres = matches!(config.database.driver.as_str(), "postgres" | "postgresql");
+
+    if vacuum && !is_postgres {
+        print_warning("--vacuum only applies to PostgreSQL; running ANALYZE only");
+    }
+
+    let tables = if tables.is_empty() {
+        get_all_tables(&config).await?
+    } else {
+        tables
+    };
+
+    if tables.is_empty() {
+        print_info("No tables found");
+        return Ok(());
+    }
+
+    if verbose {
+        print_info(&format!("Analyzing {} table(s)...", tables.len()));
+    }
+
+    let stats = runtime_db::analyze_tables(&config, &tables, vacuum && is_postgres).await?;
+
+    println!("\n{}", "Table Statistics:".cyan().bold());
+    println!("{}", render_analyze_summary(&stats));
+
+    for table in stats.iter().filter(|table| !table.messages.is_empty()) {
+        for message in &table.messages {
+            println!("  {} {}", format!("{}:", table.table).yellow(), message);
+        }
+    }
+
+    print_success(&format!("Analyzed {} table(s)", stats.len()));
+
+    Ok(())
+}
+
+/// Validate the existing rows against each table's constraints
+async fn check_constraints(config_path: &str, tables: Vec<String>, verbose: bool) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    let tables = if tables.is_empty() {
+        get_all_tables(&config).await?
+    } else {
+        tables
+    };
+
+    if tables.is_empty() {
+        print_info("No tables found");
+        return Ok(());
+    }
+
+    if verbose {
+        print_info(&format!("Checking constraints of {} table(s)...", tables.len()));
+    }
+
+    let checks = runtime_db::check_constraints(&config, &tables).await?;
+
+    println!("\n{}", "Constraint Checks:".cyan().bold());
+    println!("{}", render_constraint_summary(&tables, &checks));
+
+    for check in checks.iter().filter(|check| !check.violations.is_empty()) {
+        for violation in &check.violations {
+            println!("  {} {}", format!("{}.{}:", check.table, check.constraint).red(), violation);
+        }
+    }
+
+    let failed = checks.iter().filter(|check| !check.violations.is_empty()).count();
+    if failed > 0 {
+        return Err(format!("{} constraint check(s) failed", failed));
+    }
+
+    print_success(&format!("All {} constraint check(s) passed", checks.len()));
+
+    Ok(())
+}
+
+/// Check the up() SQL of a migration file against the database, keeping no changes
+async fn migrate_check(config_path: &str, file: &str, verbose: bool) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let content = fs::read_to_string(file).map_err(|error| format!("Failed to read {}: {}", file, error))?;
+
+    let (up_sql, _) = crate::commands::migrate::parse_migration_content(&content);
+    let statements: Vec<&str> = up_sql
+        .split(";\n")
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty())
+        .collect();
+    if statements.is_empty() {
+        return Err(format!("{} does not contain executable SQL in up()", file));
+    }
+
+    if verbose {
+        print_info(&format!(
+            "Checking {} statement(s) against {}...",
+            statements.len(),
+            config.database.driver
+        ));
+    }
+
+    let errors = runtime_db::check_sql(&config, &statements).await?;
+    for error in &errors {
+        println!("  {} {}", "✗".red(), error.message);
+        println!("    {}", error.statement.lines().next().unwrap_or_default().dimmed());
+    }
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "{} of {} statement(s) in {} are invalid",
+            errors.len(),
+            statements.len(),
+            file
+        ));
+    }
+
+    print_success("SQL is valid");
+
+    Ok(())
+}
+
+/// Measure round-trip latency with repeated `SELECT 1` queries
+async fn ping(
+    config_path: &str,
+    count: u32,
+    interval_ms: u64,
+    timeout_ms: u64,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    if verbose {
+        print_info("Connecting to the database...");
+    }
+
+    let mut backend = DatabasePinger {
+        db: runtime_db::connect(&config).await?,
+    };
+
+    println!(
+        "PING {} database with SELECT 1 ({} queries)",
+        config.database.driver.cyan(),
+        count
+    );
+
+    let results = run_pings(
+        &mut backend,
+        count,
+        Duration::from_millis(interval_ms),
+        Duration::from_millis(timeout_ms),
+        |sequence, result| match result {
+            Ok(elapsed) => println!("  seq={} time={}", sequence, colorize_latency(*elapsed)),
+            Err(error) => println!("  seq={} {} {}", sequence, "failed:".red(), error),
+        },
+    )
+    .await;
+
+    let failed = results.iter().filter(|result| result.is_err()).count();
+    let latencies: Vec<Duration> = results.into_iter().filter_map(Result::ok).collect();
+
+    println!("\n--- {} ping statistics ---", config.database.driver);
+    println!(
+        "{} queries, {} succeeded, {} failed",
+        count,
+        latencies.len(),
+        failed
+    );
+    if let Some(stats) = PingStats::from_latencies(&latencies) {
+        println!("{}", stats.render());
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} pings failed", failed, count));
+    }
+
+    Ok(())
+}
+
+/// Target of `db ping` round trips
+pub trait PingBackend {
+    /// Run one round trip
+    async fn ping(&mut self) -> Result<(), String>;
+}
+
+/// Backend that runs `SELECT 1` on an open connection
+struct DatabasePinger {
+    db: tideorm::prelude::Database,
+}
+
+impl PingBackend for DatabasePinger {
+    async fn ping(&mut self) -> Result<(), String> {
+        runtime_db::query_json_on_db(&self.db, "SELECT 1").await.map(|_| ())
+    }
+}
+
+/// Send `count` pings, reporting each result as it arrives
+async fn run_pings<B: PingBackend>(
+    backend: &mut B,
+    count: u32,
+    interval: Duration,
+    timeout: Duration,
+    mut report: impl FnMut(u32, &Result<Duration, String>),
+) -> Vec<Result<Duration, String>> {
+    let mut results = Vec::with_capacity(count as usize);
+
+    for sequence in 1..=count {
+        if sequence > 1 && !interval.is_zero() {
+            tokio::time::sleep(interval).await;
+        }
+
+        let started = Instant::now();
+        let result = match tokio::time::timeout(timeout, backend.ping()).await {
+            Ok(Ok(())) => Ok(started.elapsed()),
+            Ok(Err(error)) => Err(error),
+            Err(_) => Err(format!("timed out after {} ms", timeout.as_millis())),
+        };
+
+        report(sequence, &result);
+        results.push(result);
+    }
+
+    results
+}
+
+/// Latency summary in microseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PingStats {
+    min_us: f64,
+    max_us: f64,
+    avg_us: f64,
+    stddev_us: f64,
+}
+
+impl PingStats {
+    /// Summarize successful round trips, or `None` when there were none
+    fn from_latencies(latencies: &[Duration]) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+
+        let micros: Vec<f64> = latencies
+            .iter()
+            .map(|latency| latency.as_secs_f64() * 1_000_000.0)
+            .collect();
+        let count = micros.len() as f64;
+        let avg_us = micros.iter().sum::<f64>() / count;
+        let variance = micros.iter().map(|value| (value - avg_us).powi(2)).sum::<f64>() / count;
+
+        Some(Self {
+            min_us: micros.iter().copied().fold(f64::INFINITY, f64::min),
+            max_us: micros.iter().copied().fold(0.0, f64::max),
+            avg_us,
+            stddev_us: variance.sqrt(),
+        })
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "round-trip min/avg/max/stddev = {:.3}/{:.3}/{:.3}/{:.3} ms",
+            self.min_us / 1000.0,
+            self.avg_us / 1000.0,
+            self.max_us / 1000.0,
+            self.stddev_us / 1000.0
+        )
+    }
+}
+
+/// Print the database server, driver, and CLI versions
+async fn version(config_path: &str, json: bool, verbose: bool) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    if verbose {
+        print_info("Querying the database server version...");
+    }
+
+    let mut source = DatabaseVersionSource {
+        db: runtime_db::connect(&config).await?,
+    };
+    let report = VersionReport::collect(&mut source, &config.database.driver).await?;
+
+    if json {
+        let output = serde_json::to_string_pretty(&report.to_json()).map_err(|error| error.to_string())?;
+        println!("{}", output);
+    } else {
+        println!("{}", report.render());
+    }
+
+    Ok(())
+}
+
+/// Source of the raw server version string for `db version`
+pub trait VersionSource {
+    /// Run the driver's version query
+    async fn server_version(&mut self, driver: &str) -> Result<String, String>;
+}
+
+/// Source that queries the connected database
+struct DatabaseVersionSource {
+    db: tideorm::prelude::Database,
+}
+
+impl VersionSource for DatabaseVersionSource {
+    async fn server_version(&mut self, driver: &str) -> Result<String, String> {
+        let sql = if driver == "sqlite" {
+            "SELECT sqlite_version() AS version"
+        } else {
+            "SELECT version() AS version"
+        };
+
+        let rows = runtime_db::query_json_on_db(&self.db, sql).await?;
+        rows.first()
+            .and_then(|row| row.get("version"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| "The database did not return a version".to_string())
+    }
+}
+
+/// Versions printed by `db version`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VersionReport {
+    server: String,
+    driver: String,
+    cli: String,
+}
+
+impl VersionReport {
+    async fn collect<S: VersionSource>(source: &mut S, driver: &str) -> Result<Self, String> {
+        let raw = source.server_version(driver).await?;
+
+        Ok(Self {
+            server: server_label(driver, &raw),
+            driver: format!("sqlx {}", env!("TIDEORM_SQLX_VERSION")),
+            cli: format!("tideorm {}", env!("CARGO_PKG_VERSION")),
+        })
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "Database server: {}\nDriver: {}\nCLI: {}",
+            self.server, self.driver, self.cli
+        )
+    }
+
+    fn to_json(&self) -> Value {
+        serde_json::json!({
+            "database_server": self.server,
+            "driver": self.driver,
+            "cli": self.cli,
+        })
+    }
+}
+
+/// Product name and version from a raw version string
+///
+/// PostgreSQL's `version()` includes the build platform, which is dropped; MySQL and
+/// SQLite return a bare version number.
+fn server_label(driver: &str, raw: &str) -> String {
+    let raw = raw.trim();
+
+    match driver {
+        "sqlite" => format!("SQLite {}", raw),
+        "mysql" if raw.contains("MariaDB") => format!(
+            "MariaDB {}",
+            raw.split("-MariaDB").next().unwrap_or(raw)
+        ),
+        "mysql" => format!("MySQL {}", raw),
+        _ => raw.split(" on ").next().unwrap_or(raw).to_string(),
+    }
+}
+
+/// Format a latency in milliseconds: green under 5 ms, yellow up to 50 ms, red above
+fn colorize_latency(latency: Duration) -> colored::ColoredString {
+    let text = format!("{:.3} ms", latency.as_secs_f64() * 1000.0);
+
+    if latency < Duration::from_millis(5) {
+        text.green()
+    } else if latency <= Duration::from_millis(50) {
+        text.yellow()
+    } else {
+        text.red()
+    }
+}
+
+/// Render table statistics as a summary table
+fn render_analyze_summary(stats: &[runtime_db::TableStats]) -> String {
+    let optional = |value: Option<i64>| value.map(|value| value.to_string()).unwrap_or_else(|| "-".to_string());
+
+    let mut lines = vec![
+        "─".repeat(80),
+        format!(
+            "  {:<30} {:>14} {:>14} {:>16}",
+            "Table", "Rows (est.)", "Dead tuples", "Index hit ratio"
+        ),
+        "─".repeat(80),
+    ];
+
+    for table in stats {
+        lines.push(format!(
+            "  {:<30} {:>14} {:>14} {:>16}",
+            table.table,
+            optional(table.row_estimate),
+            optional(table.dead_tuples),
+            table
+                .index_hit_ratio
+                .map(|ratio| format!("{:.1}%", ratio * 100.0))
+                .unwrap_or_else(|| "-".to_string())
+        ));
+    }
+
+    lines.push("─".repeat(80));
+    lines.join("\n")
+}
+
+/// Passed and failed constraint counts for each table, in the given table order
+fn render_constraint_summary(tables: &[String], checks: &[runtime_db::ConstraintCheck]) -> String {
+    let mut lines = vec![
+        "─".repeat(60),
+        format!("  {:<30} {:>12} {:>12}", "Table", "Passed", "Failed"),
+        "─".repeat(60),
+    ];
+
+    for table in tables {
+        let (failed, passed): (Vec<_>, Vec<_>) = checks
+            .iter()
+            .filter(|check| &check.table == table)
+            .partition(|check| !check.violations.is_empty());
+        lines.push(format!("  {:<30} {:>12} {:>12}", table, passed.len(), failed.len()));
+    }
+
+    lines.push("─".repeat(60));
+    lines.join("\n")
+}
+
 // =============================================================================
 // HELPER TYPES AND FUNCTIONS
 // =============================================================================
@@ -349,6 +1046,77 @@ pub struct Seeder {
     pub name: String,
 }
 
+/// Destination for the rows a seeder produces
+pub trait SeedBackend {
+    /// Insert one row, given as a JSON object keyed by column name
+    // Only the tests call this until run_seeder can execute project seeders
+    #[allow(dead_code)]
+    async fn insert(&mut self, table: &str, row: Value) -> Result<(), String>;
+}
+
+/// Backend that writes seeded rows to the configured database
+pub struct DatabaseBackend<'a> {
+    config: &'a TideConfig,
+}
+
+impl SeedBackend for DatabaseBackend<'_> {
+    async fn insert(&mut self, table: &str, row: Value) -> Result<(), String> {
+        runtime_db::insert_row(self.config, table, &row).await.map(|_| ())
+    }
+}
+
+/// Backend that records seeded rows instead of inserting them
+#[derive(Debug, Default)]
+pub struct DryRunCollector {
+    rows: Vec<(String, Value)>,
+}
+
+impl SeedBackend for DryRunCollector {
+    async fn insert(&mut self, table: &str, row: Value) -> Result<(), String> {
+        self.rows.push((table.to_string(), row));
+        Ok(())
+    }
+}
+
+impl DryRunCollector {
+    /// Number of collected rows per table, in first-seen order
+    pub fn counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        for (table, _) in &self.rows {
+            match counts.iter_mut().find(|(name, _)| name == table) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((table, 1)),
+            }
+        }
+        counts
+    }
+
+    /// Render each collected row as JSON followed by the per-table totals
+    pub fn render(&self) -> String {
+        if self.rows.is_empty() {
+            return "No rows would be inserted".to_string();
+        }
+
+        let mut lines: Vec<String> = self
+            .rows
+            .iter()
+            .map(|(table, row)| format!("  {} {}", table, row))
+            .collect();
+
+        lines.push(String::new());
+        for (table, count) in self.counts() {
+            lines.push(format!(
+                "  {}: {} row{}",
+                table,
+                count,
+                if count == 1 { "" } else { "s" }
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
 /// Column information
 #[derive(Debug, Clone)]
 pub struct ColumnInfo {
@@ -406,8 +1174,35 @@ fn find_seeder(seeders_path: &str, name: &str) -> Result<Seeder, String> {
         .ok_or_else(|| format!("Seeder not found: {}", name))
 }
 
-/// Run a seeder
-async fn run_seeder(_config: &TideConfig, _seeder: &Seeder) -> Result<u32, String> {
+/// Find each seeder in a comma-separated list, keeping the listed order
+///
+/// Every name that doesn't match a seeder is reported in one error.
+fn find_seeders(seeders_path: &str, names: &str) -> Result<Vec<Seeder>, String> {
+    let mut seeders = Vec::new();
+    let mut missing = Vec::new();
+
+    for name in names.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        match find_seeder(seeders_path, name) {
+            Ok(seeder) => seeders.push(seeder),
+            Err(_) => missing.push(name),
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(format!("Seeders not found: {}", missing.join(", ")));
+    }
+
+    Ok(seeders)
+}
+
+/// Run a seeder, sending its rows to the given backend
+///
+/// `db seed` passes a `DatabaseBackend`, or a `DryRunCollector` with `--dry-run`.
+async fn run_seeder<B: SeedBackend>(
+    _config: &TideConfig,
+    _seeder: &Seeder,
+    _backend: &mut B,
+) -> Result<u32, String> {
     Err(
         "Running Rust seeders requires an application-side seeder runner; the CLI cannot load project seeder modules directly yet."
             .to_string(),
@@ -429,9 +1224,9 @@ async fn drop_db(config: &TideConfig, name: &str) -> Result<(), String> {
     runtime_db::drop_database(config, name).await
 }
 
-/// Wipe all tables
-async fn wipe_tables(config: &TideConfig, drop_types: bool) -> Result<(), String> {
-    runtime_db::wipe_tables(config, drop_types).await
+/// Empty tables in the given order
+async fn wipe_tables(config: &TideConfig, tables: &[String], cascade: bool) -> Result<(), String> {
+    runtime_db::truncate_tables(config, tables, cascade).await
 }
 
 /// Get table columns
@@ -459,12 +1254,476 @@ async fn get_all_tables(config: &TideConfig) -> Result<Vec<String>, String> {
 
 #[cfg(test)]
 mod tests {
-    use super::check;
+    use super::{
+        check, check_constraints, colorize_latency, copy, find_seeders, render_analyze_summary, run_pings,
+        schema_version_warning, server_label, wipe_order, DatabaseBackend, DryRunCollector, PingBackend,
+        PingStats, SeedBackend, VersionReport, VersionSource,
+    };
+    use colored::Colorize;
+    use std::collections::{HashMap, VecDeque};
+    use std::time::Duration;
     use crate::config::TideConfig;
-    use crate::runtime_db;
+    use crate::runtime_db::{self, TableStats};
+    use serde_json::json;
     use std::fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn analyze_summary_lists_each_table_with_placeholders_for_missing_stats() {
+        let stats = vec![
+            TableStats {
+                table: "users".to_string(),
+                row_estimate: Some(1200),
+                dead_tuples: Some(15),
+                index_hit_ratio: Some(0.9876),
+                messages: Vec::new(),
+            },
+            TableStats {
+                table: "posts".to_string(),
+                row_estimate: Some(40),
+                ..Default::default()
+            },
+        ];
+
+        let summary = render_analyze_summary(&stats);
+        let lines: Vec<&str> = summary.lines().collect();
+
+        assert_eq!(
+            lines[1],
+            format!("  {:<30} {:>14} {:>14} {:>16}", "Table", "Rows (est.)", "Dead tuples", "Index hit ratio")
+        );
+        assert_eq!(
+            lines[3],
+            format!("  {:<30} {:>14} {:>14} {:>16}", "users", "1200", "15", "98.8%")
+        );
+        assert_eq!(
+            lines[4],
+            format!("  {:<30} {:>14} {:>14} {:>16}", "posts", "40", "-", "-")
+        );
+    }
+
+    /// Version source returning a fixed string and recording the driver it was asked about
+    struct FixedVersion {
+        raw: &'static str,
+        drivers: Vec<String>,
+    }
+
+    impl VersionSource for FixedVersion {
+        async fn server_version(&mut self, driver: &str) -> Result<String, String> {
+            self.drivers.push(driver.to_string());
+            Ok(self.raw.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn version_report_formats_server_driver_and_cli() {
+        let mut source = FixedVersion {
+            raw: "PostgreSQL 16.2 on x86_64-pc-linux-gnu, compiled by gcc 12.2.0, 64-bit",
+            drivers: Vec::new(),
+        };
+
+        let report = VersionReport::collect(&mut source, "postgres").await.unwrap();
+
+        assert_eq!(source.drivers, vec!["postgres"]);
+        assert_eq!(
+            report.render(),
+            format!(
+                "Database server: PostgreSQL 16.2\nDriver: sqlx {}\nCLI: tideorm {}",
+                env!("TIDEORM_SQLX_VERSION"),
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert_eq!(report.to_json()["database_server"], "PostgreSQL 16.2");
+        assert_ne!(env!("TIDEORM_SQLX_VERSION"), "unknown");
+    }
+
+    #[test]
+    fn server_label_names_each_product() {
+        assert_eq!(server_label("mysql", "8.0.36"), "MySQL 8.0.36");
+        assert_eq!(server_label("mysql", "10.11.6-MariaDB-1:10.11.6+maria~ubu2204"), "MariaDB 10.11.6");
+        assert_eq!(server_label("sqlite", "3.45.1"), "SQLite 3.45.1");
+    }
+
+    #[tokio::test]
+    async fn sqlite_version_query_runs() {
+        let project = TempDbProject::new();
+        let config = TideConfig::load(project.config_path()).expect("config should load");
+        let mut source = super::DatabaseVersionSource {
+            db: runtime_db::connect(&config).await.expect("connect"),
+        };
+
+        let report = VersionReport::collect(&mut source, "sqlite").await.unwrap();
+
+        assert!(report.server.starts_with("SQLite 3."), "{}", report.server);
+    }
+
+    #[test]
+    fn wipe_order_puts_referencing_tables_first() {
+        let tables = vec!["accounts".to_string(), "order_items".to_string(), "orders".to_string()];
+        let references = HashMap::from([
+            ("order_items".to_string(), vec!["orders".to_string()]),
+            ("orders".to_string(), vec!["accounts".to_string()]),
+        ]);
+
+        assert_eq!(wipe_order(&tables, &references), vec!["order_items", "orders", "accounts"]);
+    }
+
+    #[test]
+    fn postgres_wipe_truncates_together_unless_cascading() {
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+        let tables = vec!["orders".to_string(), "accounts".to_string()];
+
+        assert_eq!(
+            runtime_db::truncate_statements(&config, &tables, false).unwrap(),
+            vec![r#"TRUNCATE TABLE "orders", "accounts" RESTART IDENTITY"#]
+        );
+        assert_eq!(
+            runtime_db::truncate_statements(&config, &tables, true).unwrap(),
+            vec![
+                r#"TRUNCATE TABLE "orders" RESTART IDENTITY CASCADE"#,
+                r#"TRUNCATE TABLE "accounts" RESTART IDENTITY CASCADE"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sql_check_prepares_statements_on_sqlite_without_keeping_changes() {
+        let project = TempDbProject::new();
+        let config = TideConfig::load(project.config_path()).expect("config should load");
+        runtime_db::execute(&config, "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .await
+            .expect("schema");
+
+        let valid = runtime_db::check_sql(
+            &config,
+            &[
+                "CREATE TABLE posts (id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users (id))",
+                "CREATE INDEX posts_user_id_idx ON posts (user_id)",
+                "INSERT INTO posts (user_id) SELECT id FROM users",
+            ],
+        )
+        .await
+        .expect("check should run");
+        assert!(valid.is_empty(), "{:?}", valid);
+
+        let errors = runtime_db::check_sql(
+            &config,
+            &[
+                "CREATE TABLE comments (id INTEGER PRIMARY KEY,)",
+                "UPDATE users SET email = 'a@example.com'",
+                "ALTER TABLE users ADD COLUMN email TEXT",
+            ],
+        )
+        .await
+        .expect("check should run");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.statement.as_str(), error.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("CREATE TABLE comments (id INTEGER PRIMARY KEY,)", "near \")\": syntax error"),
+                ("UPDATE users SET email = 'a@example.com'", "no such column: email"),
+            ]
+        );
+
+        // Everything was rolled back
+        assert_eq!(runtime_db::list_tables(&config).await.expect("tables"), ["users"]);
+        let columns = runtime_db::table_columns(&config, "users").await.expect("columns");
+        assert_eq!(columns.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn copy_moves_rows_between_sqlite_databases() {
+        let source = TempDbProject::new();
+        let target = TempDbProject::new();
+        let schema = "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL); \
+                      CREATE TABLE posts (id INTEGER PRIMARY KEY, user_id INTEGER NOT NULL REFERENCES users (id), title TEXT)";
+
+        let source_config = TideConfig::load(source.config_path()).expect("config should load");
+        let target_config = TideConfig::load(target.config_path()).expect("config should load");
+        runtime_db::execute(&source_config, schema).await.expect("source schema");
+        runtime_db::execute(&target_config, schema).await.expect("target schema");
+        runtime_db::execute(
+            &source_config,
+            "INSERT INTO users (id, name) VALUES (1, 'Alice'), (2, 'Bob'); \
+             INSERT INTO posts (id, user_id, title) VALUES (1, 1, 'Hello'), (2, 2, NULL)",
+        )
+        .await
+        .expect("source rows");
+        runtime_db::execute(&target_config, "INSERT INTO users (id, name) VALUES (9, 'Stale')")
+            .await
+            .expect("stale target row");
+
+        copy(target.config_path(), source.config_path(), Vec::new(), true, false, false)
+            .await
+            .expect("copy should succeed");
+
+        let users = runtime_db::query_json(&target_config, "SELECT id, name FROM users ORDER BY id")
+            .await
+            .expect("users should load");
+        let posts = runtime_db::query_json(&target_config, "SELECT id, title FROM posts ORDER BY id")
+            .await
+            .expect("posts should load");
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0]["name"], "Alice");
+        assert_eq!(posts.len(), 2);
+        assert_eq!(posts[1]["title"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn row_copy_preserves_values_and_nulls() {
+        let source = TempDbProject::new();
+        let target = TempDbProject::new();
+        let schema = "CREATE TABLE items (id INTEGER PRIMARY KEY, label TEXT, price REAL, active BOOLEAN)";
+
+        let source_config = TideConfig::load(source.config_path()).expect("config should load");
+        let target_config = TideConfig::load(target.config_path()).expect("config should load");
+        runtime_db::execute(&source_config, schema).await.expect("source schema");
+        runtime_db::execute(&target_config, schema).await.expect("target schema");
+        runtime_db::execute(
+            &source_config,
+            "INSERT INTO items VALUES (1, 'It''s', 9.5, 1), (2, NULL, NULL, 0)",
+        )
+        .await
+        .expect("source rows");
+
+        let copied = runtime_db::copy_table_rows(&source_config, &target_config, "items")
+            .await
+            .expect("row copy should succeed");
+        let items = runtime_db::query_json(&target_config, "SELECT * FROM items ORDER BY id")
+            .await
+            .expect("items should load");
+
+        assert_eq!(copied, 2);
+        assert_eq!(items[0]["label"], "It's");
+        assert_eq!(items[0]["price"], 9.5);
+        assert_eq!(items[1]["label"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn analyze_reports_sqlite_row_estimates() {
+        let fixture = TempDbProject::new();
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT); CREATE INDEX idx_users_email ON users (email); \
+             INSERT INTO users (email) VALUES ('a@example.com'), ('b@example.com'), ('c@example.com'); \
+             CREATE TABLE empty_things (id INTEGER PRIMARY KEY)",
+        )
+        .await
+        .expect("tables should be created");
+
+        let stats = runtime_db::analyze_tables(
+            &config,
+            &["users".to_string(), "empty_things".to_string()],
+            false,
+        )
+        .await
+        .expect("analyze should succeed");
+
+        assert_eq!(stats[0].table, "users");
+        assert_eq!(stats[0].row_estimate, Some(3));
+        assert_eq!(stats[0].dead_tuples, None);
+        assert_eq!(stats[1].row_estimate, Some(0));
+    }
+
+    #[tokio::test]
+    async fn check_constraints_reports_sqlite_integrity_failures_per_table() {
+        let fixture = TempDbProject::new();
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+
+        // Rows written with CHECK constraints ignored, as after an import or a bad migration
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE players (id INTEGER PRIMARY KEY, age INTEGER CHECK (age > 0)); \
+             CREATE TABLE teams (id INTEGER PRIMARY KEY, name TEXT NOT NULL); \
+             INSERT INTO teams (name) VALUES ('Reds'); \
+             PRAGMA ignore_check_constraints = ON; \
+             INSERT INTO players (age) VALUES (-1), (30), (-5); \
+             PRAGMA ignore_check_constraints = OFF",
+        )
+        .await
+        .expect("tables should be created");
+
+        let checks = runtime_db::check_constraints(&config, &["players".to_string(), "teams".to_string()])
+            .await
+            .expect("check should run");
+
+        assert_eq!(
+            checks,
+            vec![
+                runtime_db::ConstraintCheck {
+                    table: "players".to_string(),
+                    constraint: "integrity_check".to_string(),
+                    violations: vec!["CHECK constraint failed in players (2 rows)".to_string()],
+                },
+                runtime_db::ConstraintCheck {
+                    table: "teams".to_string(),
+                    constraint: "integrity_check".to_string(),
+                    violations: Vec::new(),
+                },
+            ]
+        );
+        assert_eq!(
+            check_constraints(fixture.config_path(), vec!["teams".to_string()], false).await,
+            Ok(())
+        );
+        assert_eq!(
+            check_constraints(fixture.config_path(), Vec::new(), false).await,
+            Err("1 constraint check(s) failed".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_collector_records_rows_and_counts_per_table() {
+        let mut collector = DryRunCollector::default();
+        collector.insert("users", json!({"name": "Alice"})).await.unwrap();
+        collector.insert("posts", json!({"title": "Hello"})).await.unwrap();
+        collector.insert("users", json!({"name": "Bob"})).await.unwrap();
+
+        assert_eq!(collector.counts(), vec![("users", 2), ("posts", 1)]);
+
+        let rendered = collector.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], r#"  users {"name":"Alice"}"#);
+        assert_eq!(lines[1], r#"  posts {"title":"Hello"}"#);
+        assert_eq!(lines[2], r#"  users {"name":"Bob"}"#);
+        assert_eq!(lines[4], "  users: 2 rows");
+        assert_eq!(lines[5], "  posts: 1 row");
+        assert_eq!(DryRunCollector::default().render(), "No rows would be inserted");
+    }
+
+    #[test]
+    fn find_seeders_keeps_the_listed_order_and_reports_every_missing_name() {
+        let dir = TempDir::new().unwrap();
+        for file in ["database_seeder.rs", "post_seeder.rs", "user_seeder.rs", "mod.rs"] {
+            fs::write(dir.path().join(file), "").unwrap();
+        }
+        let path = dir.path().to_str().unwrap();
+
+        let names: Vec<String> = find_seeders(path, "UserSeeder, DatabaseSeeder,post_seeder")
+            .unwrap()
+            .into_iter()
+            .map(|seeder| seeder.name)
+            .collect();
+        assert_eq!(names, vec!["UserSeeder", "DatabaseSeeder", "PostSeeder"]);
+
+        assert_eq!(
+            find_seeders(path, "CommentSeeder,UserSeeder,TagSeeder").unwrap_err(),
+            "Seeders not found: CommentSeeder, TagSeeder"
+        );
+    }
+
+    /// Pinger that answers after a scripted delay, or fails
+    struct MockPinger {
+        replies: VecDeque<Result<Duration, String>>,
+    }
+
+    impl PingBackend for MockPinger {
+        async fn ping(&mut self) -> Result<(), String> {
+            match self.replies.pop_front().expect("unexpected ping") {
+                Ok(delay) => {
+                    tokio::time::sleep(delay).await;
+                    Ok(())
+                }
+                Err(error) => Err(error),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn run_pings_measures_delays_and_reports_failures_and_timeouts() {
+        let mut backend = MockPinger {
+            replies: VecDeque::from(vec![
+                Ok(Duration::from_millis(2)),
+                Err("connection reset".to_string()),
+                Ok(Duration::from_millis(500)),
+                Ok(Duration::from_millis(20)),
+            ]),
+        };
+        let mut reported = Vec::new();
+
+        let results = run_pings(
+            &mut backend,
+            4,
+            Duration::ZERO,
+            Duration::from_millis(100),
+            |sequence, _| reported.push(sequence),
+        )
+        .await;
+
+        assert_eq!(reported, vec![1, 2, 3, 4]);
+        assert!(results[0].as_ref().unwrap() >= &Duration::from_millis(2));
+        assert_eq!(results[1], Err("connection reset".to_string()));
+        assert_eq!(results[2], Err("timed out after 100 ms".to_string()));
+        assert!(results[3].as_ref().unwrap() >= &Duration::from_millis(20));
+        assert!(backend.replies.is_empty());
+    }
+
+    #[test]
+    fn ping_stats_summarize_latencies_in_microseconds() {
+        let stats = PingStats::from_latencies(&[
+            Duration::from_micros(1000),
+            Duration::from_micros(2000),
+            Duration::from_micros(3000),
+        ])
+        .unwrap();
+
+        assert_eq!(stats.min_us, 1000.0);
+        assert_eq!(stats.max_us, 3000.0);
+        assert_eq!(stats.avg_us, 2000.0);
+        assert!((stats.stddev_us - 816.4966).abs() < 0.001);
+        assert_eq!(
+            stats.render(),
+            "round-trip min/avg/max/stddev = 1.000/2.000/3.000/0.816 ms"
+        );
+        assert_eq!(PingStats::from_latencies(&[]), None);
+    }
+
+    #[test]
+    fn latency_colors_follow_thresholds() {
+        assert_eq!(colorize_latency(Duration::from_micros(4999)), "4.999 ms".green());
+        assert_eq!(colorize_latency(Duration::from_millis(5)), "5.000 ms".yellow());
+        assert_eq!(colorize_latency(Duration::from_millis(50)), "50.000 ms".yellow());
+        assert_eq!(colorize_latency(Duration::from_millis(51)), "51.000 ms".red());
+    }
+
+    #[tokio::test]
+    async fn database_backend_inserts_json_rows() {
+        let fixture = TempDbProject::new();
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, active BOOLEAN, score REAL)",
+        )
+        .await
+        .expect("table should be created");
+
+        let mut backend = DatabaseBackend { config: &config };
+        backend
+            .insert("users", json!({"name": "O'Brien", "active": true, "score": 1.5}))
+            .await
+            .expect("row should be inserted");
+        backend
+            .insert("users", json!({"name": null, "active": false}))
+            .await
+            .expect("row should be inserted");
+
+        let users = runtime_db::query_json(&config, "SELECT name, active, score FROM users ORDER BY id")
+            .await
+            .expect("users should load");
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0]["name"], "O'Brien");
+        assert_eq!(users[0]["score"], 1.5);
+        assert_eq!(users[1]["name"], serde_json::Value::Null);
+        assert!(backend.insert("users", json!(["not", "an", "object"])).await.is_err());
+    }
+
     #[tokio::test]
     async fn check_creates_metadata_tables_for_sqlite() {
         let fixture = TempDbProject::new();
@@ -486,6 +1745,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn outdated_migration_table_is_reported_until_upgraded() {
+        let fixture = TempDbProject::new();
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+
+        assert_eq!(
+            runtime_db::migration_table_schema_version(&config, "_migrations").await.unwrap(),
+            None
+        );
+        assert_eq!(schema_version_warning(None), None);
+
+        // Layout written by CLIs from before execution times and schema versions
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE \"_migrations\" (\"id\" INTEGER PRIMARY KEY AUTOINCREMENT, \"version\" TEXT NOT NULL UNIQUE, \"name\" TEXT NOT NULL UNIQUE, \"applied_at\" TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+        )
+        .await
+        .expect("legacy migration table should be created");
+
+        let version = runtime_db::migration_table_schema_version(&config, "_migrations")
+            .await
+            .unwrap();
+        assert_eq!(version, Some(1));
+        assert_eq!(
+            schema_version_warning(version),
+            Some("Migrations table schema is outdated. Run 'tideorm migrate run' to update it.")
+        );
+
+        check(fixture.config_path(), false)
+            .await
+            .expect("check should upgrade the migration table");
+
+        let version = runtime_db::migration_table_schema_version(&config, "_migrations")
+            .await
+            .unwrap();
+        assert_eq!(version, Some(runtime_db::TIDEORM_SCHEMA_VERSION));
+        assert_eq!(schema_version_warning(version), None);
+
+        let columns = runtime_db::table_columns(&config, "_migrations").await.unwrap();
+        assert!(columns.iter().any(|column| column.name == "execution_ms"));
+        assert!(columns.iter().any(|column| column.name == "batch"));
+    }
+
     struct TempDbProject {
         _dir: TempDir,
         config_path: String,
@@ -0,0 +1,264 @@
+//! Database export command for TideORM CLI
+//!
+//! Writes the rows of one table to a CSV, TSV or JSON file.
+
+use crate::config::TideConfig;
+use crate::runtime_db;
+use crate::utils::{migration_timestamp, print_info, print_success};
+use serde_json::Value;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Supported export file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Tsv,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            other => Err(format!(
+                "Unsupported export format: {}. Expected csv, json, or tsv",
+                other
+            )),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Tsv => "tsv",
+        }
+    }
+}
+
+/// Writes rows one at a time in the chosen format
+enum RowWriter<W: Write> {
+    /// CSV or TSV; the header is written with the first row
+    Delimited {
+        writer: Box<csv::Writer<W>>,
+        header_written: bool,
+    },
+    /// A JSON array with one object per line
+    Json { writer: W, rows: u64 },
+}
+
+impl<W: Write> RowWriter<W> {
+    fn new(format: ExportFormat, writer: W) -> Self {
+        let delimiter = match format {
+            ExportFormat::Json => return Self::Json { writer, rows: 0 },
+            ExportFormat::Csv => b',',
+            ExportFormat::Tsv => b'\t',
+        };
+
+        Self::Delimited {
+            writer: Box::new(csv::WriterBuilder::new().delimiter(delimiter).from_writer(writer)),
+            header_written: false,
+        }
+    }
+
+    fn write_row(&mut self, row: &[(String, Value)]) -> Result<(), String> {
+        match self {
+            Self::Delimited {
+                writer,
+                header_written,
+            } => {
+                if !*header_written {
+                    write_header(writer, row.iter().map(|(column, _)| column.as_str()))?;
+                    *header_written = true;
+                }
+                writer
+                    .write_record(row.iter().map(|(_, value)| delimited_value(value)))
+                    .map_err(|error| format!("Failed to write row: {}", error))
+            }
+            Self::Json { writer, rows } => {
+                let fields = row
+                    .iter()
+                    .map(|(column, value)| format!("{}: {}", Value::from(column.as_str()), value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let separator = if *rows == 0 { "[\n" } else { ",\n" };
+                *rows += 1;
+
+                write!(writer, "{}  {{{}}}", separator, fields)
+                    .map_err(|error| format!("Failed to write row: {}", error))
+            }
+        }
+    }
+
+    /// Close the document; `columns` provide the CSV/TSV header when no row was written
+    fn finish(self, columns: &[String]) -> Result<W, String> {
+        match self {
+            Self::Delimited {
+                mut writer,
+                header_written,
+            } => {
+                if !header_written {
+                    write_header(&mut writer, columns.iter().map(String::as_str))?;
+                }
+                writer
+                    .into_inner()
+                    .map_err(|error| format!("Failed to write export file: {}", error.error()))
+            }
+            Self::Json { mut writer, rows } => {
+                let closing = if rows == 0 { "[]\n" } else { "\n]\n" };
+                writer
+                    .write_all(closing.as_bytes())
+                    .map_err(|error| format!("Failed to write export file: {}", error))?;
+                Ok(writer)
+            }
+        }
+    }
+}
+
+fn write_header<'a, W: Write>(
+    writer: &mut csv::Writer<W>,
+    columns: impl Iterator<Item = &'a str>,
+) -> Result<(), String> {
+    writer
+        .write_record(columns)
+        .map_err(|error| format!("Failed to write header: {}", error))
+}
+
+/// CSV/TSV field for a value; NULL becomes an empty field
+fn delimited_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Export a table to a CSV, TSV or JSON file
+pub async fn run(
+    config_path: &str,
+    table: &str,
+    output: Option<String>,
+    format: ExportFormat,
+    where_clause: Option<String>,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    let columns: Vec<String> = runtime_db::table_columns(&config, table)
+        .await?
+        .into_iter()
+        .map(|column| column.name)
+        .collect();
+    if columns.is_empty() {
+        return Err(format!("Table not found: {}", table));
+    }
+
+    let output = output
+        .unwrap_or_else(|| format!("{}_{}.{}", table, migration_timestamp(), format.extension()));
+    if verbose {
+        print_info(&format!("Exporting {} to {}", table, output));
+    }
+
+    let file = File::create(&output).map_err(|error| format!("Failed to create {}: {}", output, error))?;
+    let mut writer = RowWriter::new(format, BufWriter::new(file));
+
+    let exported = runtime_db::stream_table_rows(&config, table, where_clause.as_deref(), |row| {
+        writer.write_row(&row)
+    })
+    .await?;
+
+    writer
+        .finish(&columns)?
+        .flush()
+        .map_err(|error| format!("Failed to write {}: {}", output, error))?;
+
+    let size = std::fs::metadata(&output).map(|metadata| metadata.len()).unwrap_or_default();
+    print_success(&format!(
+        "Exported {} rows from {} to {} ({})",
+        exported,
+        table,
+        output,
+        format_size(size)
+    ));
+
+    Ok(())
+}
+
+/// File size in B, KB or MB
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_size, ExportFormat, RowWriter};
+    use serde_json::{json, Value};
+
+    fn rows() -> Vec<Vec<(String, Value)>> {
+        vec![
+            vec![
+                ("id".to_string(), json!(1)),
+                ("name".to_string(), json!("Alice, \"Al\"")),
+                ("email".to_string(), Value::Null),
+            ],
+            vec![
+                ("id".to_string(), json!(2)),
+                ("name".to_string(), json!("Bob")),
+                ("email".to_string(), json!("bob@example.com")),
+            ],
+        ]
+    }
+
+    fn export(format: ExportFormat, rows: &[Vec<(String, Value)>]) -> String {
+        let mut writer = RowWriter::new(format, Vec::new());
+        for row in rows {
+            writer.write_row(row).unwrap();
+        }
+        let columns = vec!["id".to_string(), "name".to_string(), "email".to_string()];
+        String::from_utf8(writer.finish(&columns).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn delimited_exports_have_a_header_and_empty_nulls() {
+        assert_eq!(
+            export(ExportFormat::Csv, &rows()),
+            "id,name,email\n1,\"Alice, \"\"Al\"\"\",\n2,Bob,bob@example.com\n"
+        );
+        assert_eq!(
+            export(ExportFormat::Tsv, &rows()),
+            "id\tname\temail\n1\t\"Alice, \"\"Al\"\"\"\t\n2\tBob\tbob@example.com\n"
+        );
+        assert_eq!(export(ExportFormat::Csv, &[]), "id,name,email\n");
+    }
+
+    #[test]
+    fn json_export_is_an_array_in_column_order() {
+        let content = export(ExportFormat::Json, &rows());
+
+        assert_eq!(
+            content,
+            "[\n  {\"id\": 1, \"name\": \"Alice, \\\"Al\\\"\", \"email\": null},\n  {\"id\": 2, \"name\": \"Bob\", \"email\": \"bob@example.com\"}\n]\n"
+        );
+        assert_eq!(
+            serde_json::from_str::<Value>(&content).unwrap()[0]["email"],
+            Value::Null
+        );
+        assert_eq!(export(ExportFormat::Json, &[]), "[]\n");
+    }
+
+    #[test]
+    fn format_and_size_parsing() {
+        assert_eq!(ExportFormat::parse("TSV").unwrap(), ExportFormat::Tsv);
+        assert!(ExportFormat::parse("xml").unwrap_err().contains("csv, json, or tsv"));
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(3 * 1_048_576), "3.0 MB");
+    }
+}
@@ -0,0 +1,487 @@
+//! Database import command for TideORM CLI
+//!
+//! Loads rows from a CSV or JSON file into an existing table.
+
+use crate::config::TideConfig;
+use crate::runtime_db::{self, OnConflict, RowInserter};
+use crate::utils::{print_info, print_success, print_warning};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Instant;
+
+/// Skipped rows listed without --verbose
+const SKIPPED_PREVIEW: usize = 10;
+
+/// Supported import file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Json,
+}
+
+impl ImportFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            other => Err(format!("Unsupported import format: {}. Expected csv or json", other)),
+        }
+    }
+
+    /// Format implied by the file extension
+    fn detect(path: &Path) -> Result<Self, String> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+
+        Self::parse(extension).map_err(|_| {
+            format!(
+                "Cannot detect the format of {}; pass --format=csv or --format=json",
+                path.display()
+            )
+        })
+    }
+}
+
+/// Rows read from an import file
+#[derive(Debug, Default)]
+struct ParsedRows {
+    columns: Vec<String>,
+    /// One value per column; `None` where a JSON object leaves the key out
+    rows: Vec<Vec<Option<Value>>>,
+    skipped: Vec<SkippedRow>,
+}
+
+/// A record that could not be imported
+#[derive(Debug, PartialEq)]
+struct SkippedRow {
+    /// Line number for CSV, 1-based array position for JSON
+    location: String,
+    reason: String,
+}
+
+/// Column type inferred from every non-empty CSV value in the column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    Text,
+}
+
+/// Import a CSV or JSON file into a table
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    config_path: &str,
+    file: &str,
+    table: &str,
+    format: Option<ImportFormat>,
+    truncate: bool,
+    batch_size: u32,
+    on_conflict: OnConflict,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let path = Path::new(file);
+    let format = match format {
+        Some(format) => format,
+        None => ImportFormat::detect(path)?,
+    };
+
+    let content =
+        std::fs::read_to_string(path).map_err(|error| format!("Failed to read {}: {}", file, error))?;
+    let parsed = match format {
+        ImportFormat::Csv => parse_csv(&content)?,
+        ImportFormat::Json => parse_json(&content)?,
+    };
+
+    if verbose {
+        print_info(&format!(
+            "Read {} rows with columns: {}",
+            parsed.rows.len(),
+            parsed.columns.join(", ")
+        ));
+    }
+
+    let table_columns: Vec<String> = runtime_db::table_columns(&config, table)
+        .await?
+        .into_iter()
+        .map(|column| column.name)
+        .collect();
+    if table_columns.is_empty() {
+        return Err(format!("Table not found: {}", table));
+    }
+
+    let unknown: Vec<&str> = parsed
+        .columns
+        .iter()
+        .filter(|column| !table_columns.contains(column))
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        return Err(format!("Columns not found in table {}: {}", table, unknown.join(", ")));
+    }
+
+    if truncate {
+        let cleared = runtime_db::clear_table(&config, table).await?;
+        print_info(&format!("Cleared {} existing rows from {}", cleared, table));
+    }
+
+    let started = Instant::now();
+    let mut processed = 0u64;
+    let mut conflicts = 0u64;
+
+    if !parsed.rows.is_empty() {
+        let mut inserters = HashMap::new();
+        let progress = ProgressBar::new(parsed.rows.len() as u64);
+        progress.set_style(
+            ProgressStyle::with_template("{bar:40.cyan/blue} {pos}/{len} rows ({per_sec}, {eta} left)")
+                .map_err(|error| error.to_string())?,
+        );
+
+        for batch in parsed.rows.chunks(batch_size as usize) {
+            let inserted = insert_batch(&config, table, &parsed.columns, on_conflict, &mut inserters, batch).await;
+            let affected = match inserted {
+                Ok(affected) => affected,
+                Err(error) => {
+                    progress.abandon();
+                    return Err(format!(
+                        "Import failed after {} rows: {}",
+                        progress.position(),
+                        error
+                    ));
+                }
+            };
+
+            // MySQL counts a replaced row twice, so only ignored conflicts are inferred
+            if on_conflict == OnConflict::Ignore {
+                conflicts += (batch.len() as u64).saturating_sub(affected);
+            }
+            processed += batch.len() as u64;
+            progress.inc(batch.len() as u64);
+        }
+
+        progress.finish_and_clear();
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    print_success(&format!(
+        "Imported {} rows into {} in {:.2}s ({:.0} rows/sec)",
+        processed - conflicts,
+        table,
+        elapsed,
+        processed as f64 / elapsed.max(f64::EPSILON)
+    ));
+
+    if conflicts > 0 {
+        print_warning(&format!(
+            "{} rows skipped because they conflict with existing rows",
+            conflicts
+        ));
+    }
+
+    if !parsed.skipped.is_empty() {
+        print_warning(&format!("{} rows skipped:", parsed.skipped.len()));
+        let shown = if verbose { parsed.skipped.len() } else { SKIPPED_PREVIEW };
+        for skipped in parsed.skipped.iter().take(shown) {
+            println!("  {}: {}", skipped.location, skipped.reason);
+        }
+        if parsed.skipped.len() > shown {
+            println!("  ... and {} more (use -v to list all)", parsed.skipped.len() - shown);
+        }
+    }
+
+    Ok(())
+}
+
+/// Insert a batch, one statement per run of rows that set the same columns
+///
+/// Keys a JSON object leaves out are left out of its INSERT too, so column defaults apply.
+async fn insert_batch(
+    config: &TideConfig,
+    table: &str,
+    columns: &[String],
+    on_conflict: OnConflict,
+    inserters: &mut HashMap<Vec<bool>, RowInserter>,
+    batch: &[Vec<Option<Value>>],
+) -> Result<u64, String> {
+    let present = |row: &Vec<Option<Value>>| row.iter().map(Option::is_some).collect::<Vec<_>>();
+    let mut affected = 0;
+
+    for run in batch.chunk_by(|a, b| present(a) == present(b)) {
+        let mask = present(&run[0]);
+        if !inserters.contains_key(&mask) {
+            let run_columns: Vec<String> = columns
+                .iter()
+                .zip(&mask)
+                .filter(|(_, present)| **present)
+                .map(|(column, _)| column.clone())
+                .collect();
+            let inserter = RowInserter::new(config, table, &run_columns, on_conflict).await?;
+            inserters.insert(mask.clone(), inserter);
+        }
+
+        let rows: Vec<Vec<Value>> = run.iter().map(|row| row.iter().flatten().cloned().collect()).collect();
+        affected += inserters[&mask].insert(&rows).await?;
+    }
+
+    Ok(affected)
+}
+
+/// Read CSV rows with a header line; empty fields become NULL
+fn parse_csv(content: &str) -> Result<ParsedRows, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let columns: Vec<String> = reader
+        .headers()
+        .map_err(|error| format!("Failed to read CSV header: {}", error))?
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+    check_columns(&columns)?;
+
+    let mut records = Vec::new();
+    let mut skipped = Vec::new();
+
+    for result in reader.records() {
+        match result {
+            Ok(record) if record.len() == columns.len() => records.push(record),
+            Ok(record) => skipped.push(SkippedRow {
+                location: csv_location(record.position()),
+                reason: format!("expected {} fields, found {}", columns.len(), record.len()),
+            }),
+            Err(error) => skipped.push(SkippedRow {
+                location: csv_location(error.position()),
+                reason: error.to_string(),
+            }),
+        }
+    }
+
+    let types: Vec<ColumnType> = (0..columns.len())
+        .map(|index| infer_column_type(records.iter().map(|record| &record[index])))
+        .collect();
+
+    let rows = records
+        .iter()
+        .map(|record| {
+            record
+                .iter()
+                .zip(&types)
+                .map(|(field, column_type)| Some(csv_value(field, *column_type)))
+                .collect()
+        })
+        .collect();
+
+    Ok(ParsedRows {
+        columns,
+        rows,
+        skipped,
+    })
+}
+
+fn csv_location(position: Option<&csv::Position>) -> String {
+    match position {
+        Some(position) => format!("line {}", position.line()),
+        None => "unknown line".to_string(),
+    }
+}
+
+/// Narrowest type that fits every non-empty value
+fn infer_column_type<'a>(values: impl Iterator<Item = &'a str> + Clone) -> ColumnType {
+    let mut present = values.filter(|value| !value.is_empty());
+
+    if present.clone().next().is_none() {
+        ColumnType::Text
+    } else if present.clone().all(is_integer) {
+        ColumnType::Integer
+    } else if present.clone().all(is_float) {
+        ColumnType::Float
+    } else if present.all(|value| value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false")) {
+        ColumnType::Boolean
+    } else {
+        ColumnType::Text
+    }
+}
+
+/// Integers that survive a round trip, so values like zip codes keep their leading zeros
+fn is_integer(value: &str) -> bool {
+    value.parse::<i64>().is_ok_and(|number| number.to_string() == value)
+}
+
+/// Finite decimals without a leading zero before another digit
+fn is_float(value: &str) -> bool {
+    let digits = value.trim_start_matches(['+', '-']);
+    let padded = digits.len() > 1 && digits.starts_with('0') && digits.as_bytes()[1].is_ascii_digit();
+
+    !padded && value.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+fn csv_value(field: &str, column_type: ColumnType) -> Value {
+    if field.is_empty() {
+        return Value::Null;
+    }
+
+    match column_type {
+        ColumnType::Integer => field.parse::<i64>().map(Value::from).unwrap_or(Value::Null),
+        ColumnType::Float => field.parse::<f64>().map(Value::from).unwrap_or(Value::Null),
+        ColumnType::Boolean => Value::Bool(field.eq_ignore_ascii_case("true")),
+        ColumnType::Text => Value::String(field.to_string()),
+    }
+}
+
+/// Read a JSON array of objects; keys missing from an object are left out of its row
+fn parse_json(content: &str) -> Result<ParsedRows, String> {
+    let document: Value =
+        serde_json::from_str(content).map_err(|error| format!("Failed to parse JSON: {}", error))?;
+    let Value::Array(items) = document else {
+        return Err("Expected a JSON array of objects".to_string());
+    };
+
+    let mut columns: Vec<String> = Vec::new();
+    let mut objects = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        match item {
+            Value::Object(object) if !object.is_empty() => {
+                for key in object.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+                objects.push(object);
+            }
+            Value::Object(_) => skipped.push(SkippedRow {
+                location: format!("item {}", index + 1),
+                reason: "object has no keys to insert".to_string(),
+            }),
+            other => skipped.push(SkippedRow {
+                location: format!("item {}", index + 1),
+                reason: format!("expected an object, found {}", json_kind(&other)),
+            }),
+        }
+    }
+    check_columns(&columns)?;
+
+    let rows = objects
+        .iter()
+        .map(|object| {
+            columns
+                .iter()
+                .map(|column| object.get(column).cloned())
+                .collect()
+        })
+        .collect();
+
+    Ok(ParsedRows {
+        columns,
+        rows,
+        skipped,
+    })
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn check_columns(columns: &[String]) -> Result<(), String> {
+    if columns.iter().any(String::is_empty) {
+        return Err("Column names must not be empty".to_string());
+    }
+
+    for (index, column) in columns.iter().enumerate() {
+        if columns[..index].contains(column) {
+            return Err(format!("Duplicate column: {}", column));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{infer_column_type, parse_csv, parse_json, ColumnType, ImportFormat, SkippedRow};
+    use serde_json::{json, Value};
+    use std::path::Path;
+
+    #[test]
+    fn csv_columns_get_the_narrowest_type_and_empty_fields_are_null() {
+        let parsed = parse_csv(
+            "id,zip,score,active,name\n1,02139,4.5,true,Alice\n2,10001,,FALSE,\n3,94105,2,false,Carol\n",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.columns, vec!["id", "zip", "score", "active", "name"]);
+        assert_eq!(
+            parsed.rows[1],
+            vec![Some(json!(2)), Some(json!("10001")), Some(Value::Null), Some(json!(false)), Some(Value::Null)]
+        );
+        assert_eq!(parsed.rows[2][2], Some(json!(2.0)));
+        assert!(parsed.skipped.is_empty());
+    }
+
+    #[test]
+    fn csv_rows_with_the_wrong_field_count_are_skipped() {
+        let parsed = parse_csv("id,name\n1,Alice\n2\n3,Carol,extra\n").unwrap();
+
+        assert_eq!(parsed.rows.len(), 1);
+        assert_eq!(
+            parsed.skipped,
+            vec![
+                SkippedRow {
+                    location: "line 3".to_string(),
+                    reason: "expected 2 fields, found 1".to_string(),
+                },
+                SkippedRow {
+                    location: "line 4".to_string(),
+                    reason: "expected 2 fields, found 3".to_string(),
+                },
+            ]
+        );
+        assert!(parse_csv("id,id\n1,2\n").unwrap_err().contains("Duplicate column: id"));
+    }
+
+    #[test]
+    fn infer_column_type_ignores_empty_values() {
+        assert_eq!(infer_column_type(["", "7", "-3"].into_iter()), ColumnType::Integer);
+        assert_eq!(infer_column_type(["1", "1.5", "0.25"].into_iter()), ColumnType::Float);
+        assert_eq!(infer_column_type(["1.5", "007.5"].into_iter()), ColumnType::Text);
+        assert_eq!(infer_column_type(["True", ""].into_iter()), ColumnType::Boolean);
+        assert_eq!(infer_column_type(["1", "yes"].into_iter()), ColumnType::Text);
+        assert_eq!(infer_column_type(["", ""].into_iter()), ColumnType::Text);
+    }
+
+    #[test]
+    fn json_objects_share_the_union_of_their_keys() {
+        let parsed =
+            parse_json(r#"[{"id": 1, "tags": ["a"]}, 5, {"id": 2, "name": "Bob", "tags": null}, {}]"#).unwrap();
+
+        assert_eq!(parsed.columns, vec!["id", "tags", "name"]);
+        assert_eq!(parsed.rows[0], vec![Some(json!(1)), Some(json!(["a"])), None]);
+        assert_eq!(parsed.rows[1], vec![Some(json!(2)), Some(Value::Null), Some(json!("Bob"))]);
+        assert_eq!(parsed.skipped[0].location, "item 2");
+        assert_eq!(parsed.skipped[0].reason, "expected an object, found a number");
+        assert_eq!(parsed.skipped[1].location, "item 4");
+        assert_eq!(parsed.skipped[1].reason, "object has no keys to insert");
+        assert!(parse_json(r#"{"id": 1}"#).unwrap_err().contains("JSON array"));
+    }
+
+    #[test]
+    fn format_is_detected_from_the_extension() {
+        assert_eq!(ImportFormat::detect(Path::new("users.CSV")).unwrap(), ImportFormat::Csv);
+        assert_eq!(ImportFormat::detect(Path::new("data/users.json")).unwrap(), ImportFormat::Json);
+        assert!(ImportFormat::detect(Path::new("users.txt")).unwrap_err().contains("--format"));
+    }
+}
@@ -1,19 +1,33 @@
 //! Init command for TideORM CLI
 
-use crate::config::TideConfig;
+use crate::config::{url_credentials, TideConfig};
 use crate::runtime_db;
-use crate::utils::{confirm, ensure_directory, file_exists, print_info, print_success, print_warning};
+use crate::utils::{
+    confirm, ensure_directory, file_exists, print_info, print_success, print_warning,
+    write_or_update_file,
+};
 use colored::Colorize;
 use dialoguer::{Input, Password};
 use std::collections::VecDeque;
-use std::io::IsTerminal;
+use std::io::{ErrorKind, IsTerminal};
+use std::process::Command;
 use std::sync::{LazyLock, Mutex};
 
+/// TideORM version new projects depend on
+const TIDEORM_VERSION: &str = "0.8.7";
+
 static PROMPT_SCRIPT: LazyLock<Mutex<Option<VecDeque<String>>>> =
     LazyLock::new(|| Mutex::new(None));
 
 /// Initialize a new TideORM project
-pub async fn run(name: &str, database: &str, verbose: bool) -> Result<(), String> {
+pub async fn run(
+    name: &str,
+    database: &str,
+    no_workspace: bool,
+    cargo_init: Option<CargoInit>,
+    no_env: bool,
+    verbose: bool,
+) -> Result<(), String> {
     let project_path = if name == "." {
         std::env::current_dir()
             .map_err(|error| format!("Failed to get current directory: {}", error))?
@@ -21,6 +35,12 @@ pub async fn run(name: &str, database: &str, verbose: bool) -> Result<(), String
         std::path::PathBuf::from(name)
     };
 
+    let workspace_member = if no_workspace || name == "." {
+        None
+    } else {
+        detect_workspace_member(&project_path)?
+    };
+
     if verbose {
         print_info(&format!(
             "Initializing TideORM project in: {}",
@@ -43,21 +63,32 @@ pub async fn run(name: &str, database: &str, verbose: bool) -> Result<(), String
     let should_write_project_config = !file_exists("tideorm.toml") || init_options.overwrite_config;
 
     if should_write_project_config {
-        write_env_file(&init_options)?;
+        write_env_file(&init_options, !no_env)?;
         write_tideorm_config(&init_options)?;
     } else if verbose {
         print_warning("Keeping existing tideorm.toml and skipping env/database initialization");
     }
 
     create_project_structure(verbose)?;
-    create_scaffold_files(&project_path, init_options.database.driver())?;
+    create_scaffold_files(&project_path, init_options.database.driver(), cargo_init)?;
+
+    if !no_env {
+        write_env_example(&init_options)?;
+        ignore_env_file(&init_options.env_file_name)?;
+    }
 
     if should_write_project_config && init_options.create_database_now {
         create_database_from_config("tideorm.toml", &init_options, verbose).await?;
     }
 
     if should_write_project_config && init_options.run_migrations_now {
-        crate::commands::migrate::run("tideorm.toml", None, false, true, None).await?;
+        crate::commands::migrate::run("tideorm.toml", Vec::new(), false, true, true, None, None).await?;
+    }
+
+    if let Some((manifest_path, member)) = &workspace_member
+        && add_workspace_member(manifest_path, member)?
+    {
+        print_success(&format!("Added {} to workspace members", member));
     }
 
     println!("{}", "─".repeat(50));
@@ -68,6 +99,95 @@ pub async fn run(name: &str, database: &str, verbose: bool) -> Result<(), String
     Ok(())
 }
 
+/// Find a `[workspace]` manifest in the current directory and the member path for the project
+///
+/// Errors when the workspace explicitly excludes the project.
+fn detect_workspace_member(
+    project_path: &std::path::Path,
+) -> Result<Option<(std::path::PathBuf, String)>, String> {
+    let workspace_root = std::env::current_dir()
+        .map_err(|error| format!("Failed to get current directory: {}", error))?;
+    let manifest_path = workspace_root.join("Cargo.toml");
+
+    let Ok(content) = std::fs::read_to_string(&manifest_path) else {
+        return Ok(None);
+    };
+    let manifest = content
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|error| format!("Failed to parse {}: {}", manifest_path.display(), error))?;
+    let Some(workspace) = manifest.get("workspace") else {
+        return Ok(None);
+    };
+
+    let relative = if project_path.is_absolute() {
+        match project_path.strip_prefix(&workspace_root) {
+            Ok(relative) => relative,
+            Err(_) => return Ok(None),
+        }
+    } else {
+        project_path
+    };
+    let member = relative
+        .to_string_lossy()
+        .replace('\\', "/")
+        .trim_start_matches("./")
+        .trim_end_matches('/')
+        .to_string();
+
+    if member.is_empty() {
+        return Ok(None);
+    }
+
+    let excluded = workspace
+        .get("exclude")
+        .and_then(|exclude| exclude.as_array())
+        .is_some_and(|exclude| exclude.iter().any(|value| value.as_str() == Some(member.as_str())));
+    if excluded {
+        return Err(format!(
+            "{} is listed in workspace.exclude in {}. Remove it from exclude or pass --no-workspace",
+            member,
+            manifest_path.display()
+        ));
+    }
+
+    Ok(Some((manifest_path, member)))
+}
+
+/// Add a member to the workspace manifest, keeping its formatting
+///
+/// Returns `false` when the member is already listed.
+fn add_workspace_member(manifest_path: &std::path::Path, member: &str) -> Result<bool, String> {
+    let content = std::fs::read_to_string(manifest_path)
+        .map_err(|error| format!("Failed to read {}: {}", manifest_path.display(), error))?;
+    let mut manifest = content
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|error| format!("Failed to parse {}: {}", manifest_path.display(), error))?;
+
+    let workspace = manifest["workspace"]
+        .as_table_like_mut()
+        .ok_or_else(|| format!("[workspace] in {} is not a table", manifest_path.display()))?;
+
+    if workspace.get("members").is_none() {
+        workspace.insert("members", toml_edit::value(toml_edit::Array::new()));
+    }
+
+    let members = workspace
+        .get_mut("members")
+        .and_then(|members| members.as_array_mut())
+        .ok_or_else(|| format!("workspace.members in {} is not an array", manifest_path.display()))?;
+
+    if members.iter().any(|value| value.as_str() == Some(member)) {
+        return Ok(false);
+    }
+
+    members.push(member);
+
+    std::fs::write(manifest_path, manifest.to_string())
+        .map_err(|error| format!("Failed to write {}: {}", manifest_path.display(), error))?;
+
+    Ok(true)
+}
+
 struct WorkingDirectoryGuard {
     original_dir: std::path::PathBuf,
 }
@@ -458,6 +578,38 @@ fn database_label(database: &str) -> &'static str {
     }
 }
 
+/// The `--database` value, else the type named by the DATABASE_URL scheme, else postgres
+pub fn resolve_database(database: Option<&str>, ignore_env: bool) -> String {
+    if let Some(database) = database {
+        return database.to_string();
+    }
+
+    if !ignore_env
+        && let Some(detected) = std::env::var("DATABASE_URL").ok().as_deref().and_then(database_from_url)
+    {
+        print_info(&format!("Detected database type from DATABASE_URL: {}", detected));
+        return detected.to_string();
+    }
+
+    "postgres".to_string()
+}
+
+/// Database type for a connection URL's scheme
+fn database_from_url(url: &str) -> Option<&'static str> {
+    let url = url.trim().to_ascii_lowercase();
+
+    [
+        ("sqlite:", "sqlite"),
+        ("mysql://", "mysql"),
+        ("mariadb://", "mysql"),
+        ("postgres://", "postgres"),
+        ("postgresql://", "postgres"),
+    ]
+    .into_iter()
+    .find(|(prefix, _)| url.starts_with(prefix))
+    .map(|(_, database)| database)
+}
+
 fn normalize_database(database: &str) -> &str {
     match database.to_ascii_lowercase().as_str() {
         "sqlite" => "sqlite",
@@ -466,10 +618,21 @@ fn normalize_database(database: &str) -> &str {
     }
 }
 
-fn write_env_file(options: &InitOptions) -> Result<(), String> {
+/// Write DATABASE_URL to the env file, filling a new file with development defaults
+fn write_env_file(options: &InitOptions, with_defaults: bool) -> Result<(), String> {
     let env_path = std::path::Path::new(&options.env_file_name);
     let existed = env_path.exists();
-    upsert_env_value(env_path, "DATABASE_URL", &options.database.connection_url())?;
+
+    if existed || !with_defaults {
+        upsert_env_value(env_path, "DATABASE_URL", &options.database.connection_url())?;
+    } else {
+        let content: String = env_variables(&options.database)
+            .iter()
+            .map(|variable| format!("{}={}\n", variable.name, variable.development))
+            .collect();
+        std::fs::write(env_path, content)
+            .map_err(|error| format!("Failed to create {}: {}", options.env_file_name, error))?;
+    }
 
     if existed {
         print_success(&format!("Updated {}", options.env_file_name));
@@ -480,6 +643,98 @@ fn write_env_file(options: &InitOptions) -> Result<(), String> {
     Ok(())
 }
 
+/// Environment variable the generated project reads, with its development default
+struct EnvVariable {
+    name: &'static str,
+    description: &'static str,
+    development: String,
+}
+
+fn env_variables(database: &DatabaseInit) -> Vec<EnvVariable> {
+    let mut variables = vec![
+        EnvVariable {
+            name: "DATABASE_URL",
+            description: "Database connection URL, read by tideorm.toml and src/config.rs",
+            development: database.connection_url(),
+        },
+        EnvVariable {
+            name: "TIDEORM_ENV",
+            description: "Environment name: development, testing, or production",
+            development: "development".to_string(),
+        },
+    ];
+
+    match database {
+        DatabaseInit::Postgres { .. } => variables.push(EnvVariable {
+            name: "PGSSLMODE",
+            description: "TLS mode for Postgres connections: disable, prefer, or require",
+            development: "prefer".to_string(),
+        }),
+        DatabaseInit::MySql { password, .. } => variables.push(EnvVariable {
+            name: "MYSQL_PWD",
+            description: "Password used by the mysql client opened with `tideorm db shell`",
+            development: password.clone(),
+        }),
+        DatabaseInit::Sqlite { .. } => {}
+    }
+
+    variables.push(EnvVariable {
+        name: "SECRET_KEY_BASE",
+        description: "Secret for signing tokens and cookies; use a long random value in production",
+        development: "development-secret-key-base-change-me".to_string(),
+    });
+
+    variables
+}
+
+fn generate_env_example(database: &DatabaseInit) -> String {
+    let mut content = String::from(
+        "# Environment variables for this TideORM project.\n# Copy to .env and fill in the values.\n",
+    );
+
+    for variable in env_variables(database) {
+        content.push_str(&format!("\n# {}\n{}=\n", variable.description, variable.name));
+    }
+
+    content
+}
+
+fn write_env_example(options: &InitOptions) -> Result<(), String> {
+    if file_exists(".env.example") {
+        return Ok(());
+    }
+
+    std::fs::write(".env.example", generate_env_example(&options.database))
+        .map_err(|error| format!("Failed to create .env.example: {}", error))?;
+    print_success("Created .env.example");
+    Ok(())
+}
+
+/// Add the env file to an existing .gitignore so credentials are not committed
+fn ignore_env_file(env_file_name: &str) -> Result<(), String> {
+    let gitignore = std::path::Path::new(".gitignore");
+    if !gitignore.exists() {
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(gitignore)
+        .map_err(|error| format!("Failed to read .gitignore: {}", error))?;
+    let entry = format!("/{}", env_file_name.trim_start_matches("./"));
+    if content
+        .lines()
+        .map(str::trim)
+        .any(|line| line == env_file_name || line == entry)
+    {
+        return Ok(());
+    }
+
+    let separator = if content.is_empty() || content.ends_with('\n') { "" } else { "\n" };
+    std::fs::write(gitignore, format!("{}{}{}\n", content, separator, env_file_name))
+        .map_err(|error| format!("Failed to update .gitignore: {}", error))?;
+    print_success(&format!("Added {} to .gitignore", env_file_name));
+    Ok(())
+}
+
 fn write_tideorm_config(options: &InitOptions) -> Result<(), String> {
     if file_exists("tideorm.toml") && !options.overwrite_config {
         print_warning("tideorm.toml already exists, keeping current file...");
@@ -520,16 +775,18 @@ fn create_project_structure(verbose: bool) -> Result<(), String> {
     Ok(())
 }
 
-fn create_scaffold_files(project_path: &std::path::Path, database: &str) -> Result<(), String> {
-    if !file_exists("Cargo.toml") {
-        let package_name = infer_package_name(project_path);
-        let cargo_toml_content = generate_cargo_toml(&package_name, database);
-        std::fs::write("Cargo.toml", cargo_toml_content)
-            .map_err(|error| format!("Failed to create Cargo.toml: {}", error))?;
-        print_success("Created Cargo.toml");
-    }
-
-    if !file_exists("src/main.rs") {
+fn create_scaffold_files(
+    project_path: &std::path::Path,
+    database: &str,
+    cargo_init: Option<CargoInit>,
+) -> Result<(), String> {
+    if cargo_init == Some(CargoInit::Lib) {
+        if !file_exists("src/lib.rs") {
+            std::fs::write("src/lib.rs", generate_lib_rs())
+                .map_err(|error| format!("Failed to create src/lib.rs: {}", error))?;
+            print_success("Created src/lib.rs");
+        }
+    } else if !file_exists("src/main.rs") {
         std::fs::write("src/main.rs", generate_main_rs())
             .map_err(|error| format!("Failed to create src/main.rs: {}", error))?;
         print_success("Created src/main.rs");
@@ -546,17 +803,141 @@ fn create_scaffold_files(project_path: &std::path::Path, database: &str) -> Resu
             .map_err(|error| format!("Failed to create DatabaseSeeder: {}", error))?;
         print_success("Created DatabaseSeeder");
 
-        let mod_path = "src/seeders/mod.rs";
-        let mod_content = std::fs::read_to_string(mod_path).unwrap_or_default();
-        if !mod_content.contains("database_seeder") {
-            std::fs::write(mod_path, format!("{}pub mod database_seeder;\n", mod_content))
-                .map_err(|error| format!("Failed to update mod.rs: {}", error))?;
+        write_or_update_file(
+            "src/seeders/mod.rs",
+            "pub mod database_seeder;\n",
+            "pub mod database_seeder;",
+        )?;
+    }
+
+    // The manifest comes last so `cargo init` picks up the sources written above
+    // instead of creating its own hello-world target
+    if !file_exists("Cargo.toml") {
+        let package_name = infer_package_name(project_path);
+
+        if let Some(kind) = cargo_init {
+            run_cargo_init(&mut SystemCommandExecutor, &package_name, kind, database)?;
+        } else {
+            let cargo_toml_content = generate_cargo_toml(&package_name, database);
+            std::fs::write("Cargo.toml", cargo_toml_content)
+                .map_err(|error| format!("Failed to create Cargo.toml: {}", error))?;
+            print_success("Created Cargo.toml");
+        }
+    }
+
+    Ok(())
+}
+
+/// Crate type created by `tideorm init --cargo-init`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CargoInit {
+    Bin,
+    Lib,
+}
+
+/// Result of an external command
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CommandOutput {
+    success: bool,
+    stderr: String,
+}
+
+/// Runs external programs, so tests can record invocations instead of spawning cargo
+trait CommandExecutor {
+    fn run(&mut self, program: &str, args: &[String]) -> std::io::Result<CommandOutput>;
+}
+
+struct SystemCommandExecutor;
+
+impl CommandExecutor for SystemCommandExecutor {
+    fn run(&mut self, program: &str, args: &[String]) -> std::io::Result<CommandOutput> {
+        let output = Command::new(program).args(args).output()?;
+
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim_end().to_string(),
+        })
+    }
+}
+
+fn cargo_init_args(package_name: &str, kind: CargoInit) -> Vec<String> {
+    let mut args = vec!["init".to_string()];
+    if kind == CargoInit::Lib {
+        args.push("--lib".to_string());
+    }
+    args.push("--name".to_string());
+    args.push(package_name.to_string());
+    args
+}
+
+fn cargo_add_args(database: &str) -> Vec<String> {
+    let database_feature = match database {
+        "sqlite" => "sqlite",
+        "mysql" => "mysql",
+        _ => "postgres",
+    };
+
+    [
+        "add".to_string(),
+        format!("tideorm@{}", TIDEORM_VERSION),
+        "tokio".to_string(),
+        "serde".to_string(),
+        "serde_json".to_string(),
+        "chrono".to_string(),
+        "--features".to_string(),
+    ]
+    .into_iter()
+    .chain(std::iter::once(format!(
+        "tideorm/{},tideorm/runtime-tokio,tokio/full,serde/derive",
+        database_feature
+    )))
+    .collect()
+}
+
+/// Create Cargo.toml with `cargo init` and add the TideORM dependencies with `cargo add`
+///
+/// When cargo is not on PATH the manifest is skipped and the commands are printed instead.
+fn run_cargo_init(
+    executor: &mut impl CommandExecutor,
+    package_name: &str,
+    kind: CargoInit,
+    database: &str,
+) -> Result<(), String> {
+    let init_args = cargo_init_args(package_name, kind);
+    let add_args = cargo_add_args(database);
+
+    match executor.run("cargo", &init_args) {
+        Ok(output) => ensure_cargo_success("cargo init", output)?,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            print_warning("cargo was not found on PATH, skipping Cargo.toml creation");
+            print_info("Install Rust from https://rustup.rs, then run in the project directory:");
+            println!("     {}", format!("cargo {}", init_args.join(" ")).yellow());
+            println!("     {}", format!("cargo {}", add_args.join(" ")).yellow());
+            return Ok(());
         }
+        Err(error) => return Err(format!("Failed to run cargo init: {}", error)),
     }
+    print_success("Created Cargo.toml with cargo init");
+
+    let output = executor
+        .run("cargo", &add_args)
+        .map_err(|error| format!("Failed to run cargo add: {}", error))?;
+    ensure_cargo_success("cargo add", output)?;
+    print_success("Added tideorm, tokio, serde, serde_json, and chrono with cargo add");
 
     Ok(())
 }
 
+fn ensure_cargo_success(command: &str, output: CommandOutput) -> Result<(), String> {
+    if output.success {
+        Ok(())
+    } else if output.stderr.is_empty() {
+        Err(format!("{} failed", command))
+    } else {
+        Err(format!("{} failed:\n{}", command, output.stderr))
+    }
+}
+
 async fn create_database_from_config(
     config_path: &str,
     options: &InitOptions,
@@ -604,6 +985,7 @@ models = "src/models"
 migrations = "src/migrations"
 seeders = "src/seeders"
 factories = "src/factories"
+controllers = "src/controllers"
 config_file = "src/config.rs"
 
 [migration]
@@ -774,14 +1156,14 @@ fn build_url(
     username: &str,
     password: &str,
 ) -> String {
-    if password.is_empty() {
-        format!("{}://{}@{}:{}/{}", scheme, username, host, port, database)
-    } else {
-        format!(
-            "{}://{}:{}@{}:{}/{}",
-            scheme, username, password, host, port, database
-        )
-    }
+    format!(
+        "{}://{}@{}:{}/{}",
+        scheme,
+        url_credentials(username, password),
+        host,
+        port,
+        database
+    )
 }
 
 fn sqlite_url(path: &str) -> String {
@@ -894,10 +1276,12 @@ edition = "2024"
 [dependencies]
 tokio = {{ version = "1", features = ["full"] }}
 serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
 chrono = "0.4"
-tideorm = {{ version = "0.8.7", features = ["{database_feature}", "runtime-tokio"] }}
+tideorm = {{ version = "{tideorm_version}", features = ["{database_feature}", "runtime-tokio"] }}
 "#,
         package_name = package_name,
+        tideorm_version = TIDEORM_VERSION,
         database_feature = database_feature,
     )
 }
@@ -919,6 +1303,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .to_string()
 }
 
+fn generate_lib_rs() -> String {
+    r#"pub mod config;
+pub mod factories;
+pub mod migrations;
+pub mod models;
+pub mod seeders;
+"#
+    .to_string()
+}
+
 fn generate_database_seeder() -> String {
     r#"//! Database Seeder
 //!
@@ -960,15 +1354,106 @@ mod tests {
 #[cfg(test)]
 mod tests {
     use super::{
-        generate_cargo_toml, generate_tideorm_toml, infer_package_name, run, upsert_env_value,
-        DatabaseInit, InitOptions,
+        add_workspace_member, database_from_url, generate_cargo_toml, generate_env_example, generate_tideorm_toml,
+        ignore_env_file, infer_package_name, resolve_database, run, run_cargo_init, upsert_env_value, CargoInit, CommandExecutor, CommandOutput, DatabaseInit,
+        InitOptions,
     };
+    use std::collections::VecDeque;
     use std::fs;
-    use std::sync::{LazyLock, Mutex};
+    use std::io::ErrorKind;
+    use std::sync::LazyLock;
     use tempfile::TempDir;
+    use tokio::sync::Mutex;
 
+    // Async tests hold this across awaits, which clippy rejects for std's Mutex
     static INIT_TEST_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
 
+    /// Records each invocation and replays queued results
+    #[derive(Default)]
+    struct MockExecutor {
+        calls: Vec<(String, Vec<String>)>,
+        results: VecDeque<std::io::Result<CommandOutput>>,
+    }
+
+    impl MockExecutor {
+        fn with_results(results: Vec<std::io::Result<CommandOutput>>) -> Self {
+            Self {
+                calls: Vec::new(),
+                results: results.into(),
+            }
+        }
+    }
+
+    impl CommandExecutor for MockExecutor {
+        fn run(&mut self, program: &str, args: &[String]) -> std::io::Result<CommandOutput> {
+            self.calls.push((program.to_string(), args.to_vec()));
+            self.results.pop_front().unwrap_or(Ok(CommandOutput {
+                success: true,
+                stderr: String::new(),
+            }))
+        }
+    }
+
+    fn cargo_call(args: &str) -> (String, Vec<String>) {
+        (
+            "cargo".to_string(),
+            args.split(' ').map(str::to_string).collect(),
+        )
+    }
+
+    #[test]
+    fn cargo_init_runs_init_then_add() {
+        let mut executor = MockExecutor::default();
+
+        run_cargo_init(&mut executor, "my_app", CargoInit::Bin, "sqlite").unwrap();
+
+        assert_eq!(
+            executor.calls,
+            vec![
+                cargo_call("init --name my_app"),
+                cargo_call(
+                    "add tideorm@0.8.7 tokio serde serde_json chrono --features tideorm/sqlite,tideorm/runtime-tokio,tokio/full,serde/derive"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn cargo_init_passes_lib_flag() {
+        let mut executor = MockExecutor::default();
+
+        run_cargo_init(&mut executor, "my_app", CargoInit::Lib, "postgres").unwrap();
+
+        assert_eq!(executor.calls[0], cargo_call("init --lib --name my_app"));
+        assert!(executor.calls[1].1.last().unwrap().starts_with("tideorm/postgres,"));
+    }
+
+    #[test]
+    fn cargo_init_surfaces_stderr_and_stops() {
+        let mut executor = MockExecutor::with_results(vec![Ok(CommandOutput {
+            success: false,
+            stderr: "error: destination `Cargo.toml` already exists".to_string(),
+        })]);
+
+        let error = run_cargo_init(&mut executor, "my_app", CargoInit::Bin, "sqlite").unwrap_err();
+
+        assert_eq!(
+            error,
+            "cargo init failed:\nerror: destination `Cargo.toml` already exists"
+        );
+        assert_eq!(executor.calls.len(), 1);
+    }
+
+    #[test]
+    fn cargo_init_skips_when_cargo_is_missing() {
+        let mut executor =
+            MockExecutor::with_results(vec![Err(std::io::Error::from(ErrorKind::NotFound))]);
+
+        run_cargo_init(&mut executor, "my_app", CargoInit::Bin, "mysql").unwrap();
+
+        assert_eq!(executor.calls, vec![cargo_call("init --name my_app")]);
+    }
+
     #[test]
     fn generated_cargo_toml_uses_current_tideorm_version() {
         let cargo_toml = generate_cargo_toml("demo_app", "sqlite");
@@ -1007,6 +1492,90 @@ mod tests {
         assert!(toml.contains("database = \"app_db\""));
     }
 
+    #[test]
+    fn env_example_documents_every_variable_without_values() {
+        let postgres = DatabaseInit::Postgres {
+            host: "localhost".to_string(),
+            port: 5432,
+            database: "app".to_string(),
+            username: "postgres".to_string(),
+            password: "secret".to_string(),
+        };
+        let mysql = DatabaseInit::MySql {
+            host: "localhost".to_string(),
+            port: 3306,
+            database: "app".to_string(),
+            username: "root".to_string(),
+            password: "secret".to_string(),
+        };
+        let sqlite = DatabaseInit::Sqlite {
+            path: "database.db".to_string(),
+        };
+
+        for (database, driver_variable) in [
+            (&postgres, Some("PGSSLMODE=")),
+            (&mysql, Some("MYSQL_PWD=")),
+            (&sqlite, None),
+        ] {
+            let example = generate_env_example(database);
+
+            for name in ["DATABASE_URL=", "TIDEORM_ENV=", "SECRET_KEY_BASE="] {
+                assert!(example.contains(&format!("\n{}\n", name)), "{} missing:\n{}", name, example);
+            }
+            if let Some(name) = driver_variable {
+                assert!(example.contains(&format!("\n{}\n", name)));
+            }
+            assert!(!example.contains("secret\n"));
+            assert!(example.contains("# Environment name: development, testing, or production\nTIDEORM_ENV="));
+        }
+
+        let sqlite_example = generate_env_example(&sqlite);
+        assert!(!sqlite_example.contains("PGSSLMODE") && !sqlite_example.contains("MYSQL_PWD"));
+    }
+
+    #[test]
+    fn ignore_env_file_appends_to_existing_gitignore_once() {
+        let _guard = INIT_TEST_LOCK.blocking_lock();
+        let dir = TempDir::new().unwrap();
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(dir.path()).unwrap();
+
+        let missing = ignore_env_file(".env");
+        let gitignore_created = dir.path().join(".gitignore").exists();
+        fs::write(dir.path().join(".gitignore"), "/target").unwrap();
+        ignore_env_file(".env").unwrap();
+        ignore_env_file(".env").unwrap();
+
+        std::env::set_current_dir(&original_dir).unwrap();
+
+        assert!(missing.is_ok());
+        assert!(!gitignore_created);
+        assert_eq!(fs::read_to_string(dir.path().join(".gitignore")).unwrap(), "/target\n.env\n");
+    }
+
+    #[test]
+    fn database_type_is_detected_from_each_url_scheme() {
+        for (url, expected) in [
+            ("sqlite://app.db", Some("sqlite")),
+            ("sqlite:app.db?mode=rwc", Some("sqlite")),
+            ("mysql://root@localhost/app", Some("mysql")),
+            ("mariadb://root@localhost/app", Some("mysql")),
+            ("postgres://postgres@localhost/app", Some("postgres")),
+            ("postgresql://postgres@localhost/app", Some("postgres")),
+            (" POSTGRES://postgres@localhost/app", Some("postgres")),
+            ("mongodb://localhost/app", None),
+            ("", None),
+        ] {
+            assert_eq!(database_from_url(url), expected, "{}", url);
+        }
+    }
+
+    #[test]
+    fn explicit_database_wins_over_database_url() {
+        assert_eq!(resolve_database(Some("mysql"), false), "mysql");
+        assert_eq!(resolve_database(None, true), "postgres");
+    }
+
     #[test]
     fn upsert_env_value_updates_existing_database_url() {
         let dir = TempDir::new().unwrap();
@@ -1023,7 +1592,7 @@ mod tests {
 
     #[tokio::test]
     async fn run_restores_working_directory() {
-        let _guard = INIT_TEST_LOCK.lock().unwrap();
+        let _guard = INIT_TEST_LOCK.lock().await;
         let workspace = TempDir::new().unwrap();
         let original_dir = std::env::current_dir().unwrap();
         unsafe {
@@ -1031,7 +1600,7 @@ mod tests {
         }
         std::env::set_current_dir(workspace.path()).unwrap();
 
-        let result = run("generated", "sqlite", false).await;
+        let result = run("generated", "sqlite", false, None, false, false).await;
         let restored_dir = std::env::current_dir().unwrap();
 
         std::env::set_current_dir(&original_dir).unwrap();
@@ -1041,11 +1610,16 @@ mod tests {
 
         assert!(result.is_ok());
         assert_eq!(restored_dir, workspace.path());
+
+        let env = fs::read_to_string(workspace.path().join("generated/.env")).unwrap();
+        assert!(env.starts_with("DATABASE_URL=sqlite://"));
+        assert!(env.contains("\nTIDEORM_ENV=development\nSECRET_KEY_BASE="));
+        assert!(workspace.path().join("generated/.env.example").exists());
     }
 
     #[tokio::test]
     async fn run_keeps_existing_config_without_mutating_env_file() {
-        let _guard = INIT_TEST_LOCK.lock().unwrap();
+        let _guard = INIT_TEST_LOCK.lock().await;
         let workspace = TempDir::new().unwrap();
         let project_dir = workspace.path().join("existing_project");
         fs::create_dir_all(&project_dir).unwrap();
@@ -1058,7 +1632,7 @@ mod tests {
         }
         std::env::set_current_dir(workspace.path()).unwrap();
 
-        let result = run(project_dir.to_str().unwrap(), "sqlite", false).await;
+        let result = run(project_dir.to_str().unwrap(), "sqlite", false, None, false, false).await;
         let env_contents = fs::read_to_string(project_dir.join(".env")).unwrap();
 
         std::env::set_current_dir(&original_dir).unwrap();
@@ -1069,4 +1643,72 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(env_contents, "DATABASE_URL=preserve-me\n");
     }
+
+    #[test]
+    fn add_workspace_member_keeps_manifest_formatting() {
+        let dir = TempDir::new().unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        fs::write(
+            &manifest,
+            "# Shared workspace\n[workspace]\nresolver = \"2\"\nmembers = [\"core\"]\n",
+        )
+        .unwrap();
+
+        assert!(add_workspace_member(&manifest, "my_app").unwrap());
+        assert!(!add_workspace_member(&manifest, "my_app").unwrap());
+
+        let updated = fs::read_to_string(&manifest).unwrap();
+        assert!(updated.starts_with("# Shared workspace\n[workspace]\nresolver = \"2\"\n"));
+        assert!(updated.contains("members = [\"core\", \"my_app\"]"));
+    }
+
+    #[tokio::test]
+    async fn run_adds_project_to_workspace_members() {
+        let _guard = INIT_TEST_LOCK.lock().await;
+        let workspace = TempDir::new().unwrap();
+        let manifest = workspace.path().join("Cargo.toml");
+        fs::write(&manifest, "[workspace]\nmembers = []\n").unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        unsafe {
+            std::env::set_var("TIDEORM_NONINTERACTIVE", "1");
+        }
+        std::env::set_current_dir(workspace.path()).unwrap();
+
+        let added = run("my_app", "sqlite", false, None, false, false).await;
+        let skipped = run("other_app", "sqlite", true, None, false, false).await;
+
+        std::env::set_current_dir(&original_dir).unwrap();
+        unsafe {
+            std::env::remove_var("TIDEORM_NONINTERACTIVE");
+        }
+
+        assert!(added.is_ok());
+        assert!(skipped.is_ok());
+        let updated = fs::read_to_string(&manifest).unwrap();
+        assert!(updated.contains("\"my_app\""));
+        assert!(!updated.contains("other_app"));
+    }
+
+    #[tokio::test]
+    async fn run_rejects_project_excluded_from_workspace() {
+        let _guard = INIT_TEST_LOCK.lock().await;
+        let workspace = TempDir::new().unwrap();
+        fs::write(
+            workspace.path().join("Cargo.toml"),
+            "[workspace]\nmembers = []\nexclude = [\"my_app\"]\n",
+        )
+        .unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(workspace.path()).unwrap();
+
+        let result = run("my_app", "sqlite", false, None, false, false).await;
+
+        std::env::set_current_dir(&original_dir).unwrap();
+
+        let error = result.unwrap_err();
+        assert!(error.contains("workspace.exclude"));
+        assert!(!workspace.path().join("my_app").exists());
+    }
 }
@@ -2,11 +2,25 @@
 
 use crate::config::TideConfig;
 use crate::generators::{
-    factory::FactoryGenerator, migration::MigrationGenerator,
-    model::ModelGenerator, seeder::SeederGenerator,
+    cast::CastGenerator, command::CommandGenerator, config::ConfigGenerator, contract::ContractGenerator,
+    controller::ControllerFramework,
+    dto::{DtoDirection, DtoGenerator}, factory::FactoryGenerator, graphql::GraphqlGenerator, guard::GuardGenerator,
+    job::JobGenerator,
+    migration::{
+        count_sql_statements, read_sql_file, ColumnModification, ColumnTypeChange, ForeignKeyReference,
+        ForeignKeyRename, IndexChange, MigrationGenerator, MigrationIntent, MigrationNameParser, TableDrop,
+        TableIndex, TableRename,
+    },
+    model::{inherited_fields, ModelBase, ModelGenerator}, notification::{NotificationChannel, NotificationGenerator},
+    observer::{ObserverEvent, ObserverGenerator}, presenter::PresenterGenerator,
+    repository::RepositoryGenerator, resource::ResourceGenerator, seeder::{read_seed_definitions, SeedValues, SeederGenerator, TableSnapshot}, test::TestGenerator,
+    transformer::TransformerGenerator,
+    value_object::ValueObjectGenerator,
 };
 use crate::utils::{RelationDefinition, RelationType};
-use crate::utils::{print_info, print_success};
+use crate::runtime_db;
+use crate::schema_types::ColumnSchema;
+use crate::utils::{print_info, print_success, print_warning, singularize, split_field_definitions};
 use crate::MakeCommands;
 
 /// Handle make subcommands
@@ -23,14 +37,27 @@ pub async fn handle(config_path: &str, cmd: MakeCommands, verbose: bool) -> Resu
             indexed,
             unique,
             nullable,
+            sortable,
+            searchable,
+            no_paginate,
+            check_constraints,
+            generated_columns,
+            extends,
+            base,
             soft_deletes,
             timestamps,
             tokenize,
+            optimistic_locking,
+            derives,
+            no_debug,
+            no_clone,
             output,
             migration,
             seeder,
             factory,
             all,
+            generate_graphql,
+            graphql_output,
         } => {
             make_model(
                 config_path,
@@ -44,13 +71,25 @@ pub async fn handle(config_path: &str, cmd: MakeCommands, verbose: bool) -> Resu
                 indexed,
                 unique,
                 nullable,
+                sortable,
+                searchable,
+                !no_paginate,
+                check_constraints,
+                generated_columns,
+                extends,
+                base,
                 soft_deletes,
                 timestamps,
                 tokenize,
+                optimistic_locking,
+                derives,
+                !no_debug,
+                !no_clone,
                 &output,
                 migration || all,
                 seeder || all,
                 factory || all,
+                generate_graphql.then_some(graphql_output.as_str()),
                 verbose,
             )
             .await
@@ -61,21 +100,172 @@ pub async fn handle(config_path: &str, cmd: MakeCommands, verbose: bool) -> Resu
             create,
             table,
             fields,
+            rename_table,
+            update_fks,
+            drop_table,
+            no_down,
+            from_sql,
+            from_down_sql,
+            add_index,
+            remove_index,
+            add_unique_index,
+            remove_unique_index,
+            add_full_text_index,
+            language,
+            no_concurrent,
+            modify_column,
+            original_type,
             output,
-        } => make_migration(config_path, &name, create, table, fields, &output, verbose).await,
+        } => {
+            let mut indexes =
+                IndexChange::from_flags(add_index, remove_index, add_unique_index, remove_unique_index);
+            if let Some(columns) = add_full_text_index {
+                indexes.extend(IndexChange::full_text(&columns, &language)?);
+            }
+
+            make_migration(
+                config_path,
+                &name,
+                create,
+                table,
+                fields,
+                rename_table,
+                update_fks,
+                drop_table,
+                no_down,
+                from_sql,
+                from_down_sql,
+                &indexes,
+                !no_concurrent,
+                modify_column.as_deref(),
+                original_type,
+                &output,
+                verbose,
+            )
+            .await
+        }
 
         MakeCommands::Seeder {
             name,
             model,
             count,
             output,
-        } => make_seeder(config_path, &name, model, count, &output, verbose).await,
+            sequential: _,
+            faker,
+            truncate,
+            no_truncate: _,
+        } => {
+            let values = if faker {
+                SeedValues::Faker
+            } else {
+                SeedValues::Sequential
+            };
+            make_seeder(config_path, &name, model, count, values, truncate, &output, verbose).await
+        }
+
+        MakeCommands::SeedState {
+            name,
+            tables,
+            limit,
+            output,
+        } => make_seed_state(config_path, &name, &tables, limit, &output, verbose).await,
+
+        MakeCommands::SeederBatch { file, output } => make_seeder_batch(config_path, &file, &output, verbose).await,
 
         MakeCommands::Factory {
             name,
             model,
+            states,
+            output,
+        } => make_factory(config_path, &name, model, states, &output, verbose).await,
+
+        MakeCommands::Observer {
+            name,
+            model,
+            events,
+            output,
+        } => make_observer(config_path, &name, &model, &events, &output, verbose).await,
+        MakeCommands::Repository {
+            name,
+            model,
+            output,
+        } => make_repository(config_path, &name, &model, &output, verbose).await,
+        MakeCommands::Job {
+            name,
+            queue,
+            delay_secs,
+            output,
+        } => make_job(&name, queue, delay_secs, &output, verbose).await,
+        MakeCommands::Cast {
+            name,
+            from_type,
+            to_type,
+            output,
+        } => make_cast(&name, &from_type, &to_type, &output, verbose).await,
+        MakeCommands::Command {
+            name,
+            description,
+            args,
+            global_flags,
+            output,
+        } => make_command(&name, &description, args, global_flags, &output, verbose).await,
+        MakeCommands::Presenter {
+            name,
+            model,
+            fields,
+            output,
+        } => make_presenter(config_path, &name, &model, fields.as_deref(), &output, verbose).await,
+        MakeCommands::Transformer {
+            name,
+            from_model,
+            to_type,
+            batch_size,
+            output,
+        } => make_transformer(config_path, &name, &from_model, &to_type, batch_size, &output, verbose).await,
+        MakeCommands::Resource {
+            name,
+            fields,
+            relations,
+            database,
+            framework,
+            dry_run,
+        } => make_resource(config_path, &name, fields, relations, database, framework, dry_run, verbose).await,
+        MakeCommands::Guard {
+            name,
+            model,
+            redirect_to,
+            output,
+        } => make_guard(config_path, &name, model, redirect_to, &output, verbose).await,
+        MakeCommands::Contract {
+            name,
+            methods,
+            output,
+        } => make_contract(&name, &methods, &output, verbose).await,
+        MakeCommands::Dto {
+            name,
+            model,
+            direction,
+            output,
+        } => make_dto(config_path, &name, model.as_deref(), direction, &output, verbose).await,
+        MakeCommands::Notification {
+            name,
+            channels,
+            model,
+            output,
+        } => make_notification(config_path, &name, &channels, model.as_deref(), &output, verbose).await,
+        MakeCommands::Config {
+            name,
+            fields,
+            prefix,
+            output,
+        } => make_config(&name, &fields, prefix.as_deref(), &output, verbose).await,
+        MakeCommands::ValueObject {
+            name,
+            inner_type,
+            validations,
             output,
-        } => make_factory(config_path, &name, model, &output, verbose).await,
+        } => make_value_object(&name, &inner_type, validations.as_deref(), &output, verbose).await,
+        MakeCommands::Test { model, output } => make_test(config_path, &model, &output, verbose).await,
     }
 }
 
@@ -93,16 +283,34 @@ async fn make_model(
     indexed: Option<String>,
     unique: Option<String>,
     nullable: Option<String>,
+    sortable: Option<String>,
+    searchable: Option<String>,
+    paginate: bool,
+    check_constraints: Option<String>,
+    generated_columns: Option<String>,
+    extends: Option<String>,
+    base: Option<ModelBase>,
     soft_deletes: bool,
     timestamps: bool,
     tokenize: bool,
+    optimistic_locking: bool,
+    derives: Option<String>,
+    derive_debug: bool,
+    derive_clone: bool,
     output: &str,
     create_migration: bool,
     create_seeder: bool,
     create_factory: bool,
+    graphql_output: Option<&str>,
     verbose: bool,
 ) -> Result<(), String> {
+    crate::utils::validate_model_name(name)?;
+
     let config = TideConfig::load_or_default(config_path);
+    let base = match base {
+        Some(base) => base,
+        None => ModelBase::parse(&config.model.base_style)?,
+    };
 
     if verbose {
         print_info(&format!("Generating model: {}", name));
@@ -118,21 +326,71 @@ async fn make_model(
         &config.model.primary_key_type,
     )?;
 
+    // The child table needs the parent's columns too, ahead of its own like in the model
+    let fields_for_migration = match &extends {
+        Some(parent) => {
+            let own_fields = fields_for_migration.unwrap_or_default();
+            let own_specs = split_field_definitions(&own_fields);
+            let own_names: Vec<&str> = own_specs
+                .iter()
+                .filter_map(|spec| spec.split(':').next())
+                .collect();
+
+            let specs: Vec<String> = inherited_fields(output, parent, &config.model.primary_key)?
+                .into_iter()
+                .filter(|field| !own_names.contains(&field.name.as_str()))
+                .map(|field| {
+                    let nullable = if field.nullable { ":nullable" } else { "" };
+                    format!("{}:{}{}", field.name, field.field_type, nullable)
+                })
+                .chain(own_specs.iter().map(|spec| spec.to_string()))
+                .collect();
+
+            (!specs.is_empty()).then(|| specs.join(","))
+        }
+        None => fields_for_migration,
+    };
+
+    let fields_for_migration = match fields_for_migration {
+        Some(fields) if optimistic_locking => Some(format!("{},version:i32:default=1", fields)),
+        None if optimistic_locking => Some("version:i32:default=1".to_string()),
+        fields => fields,
+    };
+
+    // An explicit table name always wins over pluralization
+    let table_name = table.clone().unwrap_or_else(|| {
+        crate::utils::pluralize_with_overrides(
+            &crate::utils::to_snake_case(name),
+            &config.pluralization,
+        )
+    });
+
     // Create model generator
     let generator = ModelGenerator::new(&config)
         .name(name)
         .table(table)
         .fields(fields)
-        .relations(relations)
+        .check_constraints(check_constraints.clone())
+        .generated_columns(generated_columns.clone())
+        .relations(relations.clone())
         .translatable(translatable)
         .attachments_single(attachments_single)
         .attachments_multi(attachments_multi)
         .indexed(indexed)
         .unique(unique)
         .nullable(nullable)
+        .sortable(sortable)
+        .searchable(searchable)
+        .paginate(paginate)
         .soft_deletes(soft_deletes)
         .timestamps(timestamps)
         .tokenize(tokenize)
+        .optimistic_locking(optimistic_locking)
+        .derive_debug(derive_debug)
+        .derive_clone(derive_clone)
+        .derives(derives)
+        .extends(extends)
+        .base(base)
         .output_dir(output);
 
     // Generate model file
@@ -145,11 +403,19 @@ async fn make_model(
             print_info("Generating migration for model...");
         }
 
-        let migration_gen = MigrationGenerator::new(&config);
-        let migration_name = format!("create_{}_table", crate::utils::pluralize(&crate::utils::to_snake_case(name)));
+        if generated_columns.is_some() && config.database.driver == "sqlite" {
+            print_warning(
+                "SQLite supports generated columns from version 3.31, and can't add STORED ones to existing tables",
+            );
+        }
+
+        let migration_gen = MigrationGenerator::new(&config)
+            .check_constraints(check_constraints)
+            .generated_columns(generated_columns);
+        let migration_name = format!("create_{}_table", table_name);
         let migration_path = migration_gen.generate(
             &migration_name,
-            Some(crate::utils::pluralize(&crate::utils::to_snake_case(name))),
+            Some(table_name.clone()),
             None,
             fields_for_migration,
             timestamps,
@@ -182,16 +448,53 @@ async fn make_model(
         print_success(&format!("Created factory: {}", factory_path));
     }
 
+    // Generate GraphQL types if requested
+    if let Some(graphql_output) = graphql_output {
+        if verbose {
+            print_info("Generating GraphQL types for model...");
+        }
+
+        let relations = relations
+            .as_deref()
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|relation| !relation.is_empty())
+                    .map(RelationDefinition::parse)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let graphql_gen = GraphqlGenerator::new(&config)
+            .models_dir(output)
+            .output_dir(graphql_output);
+        let graphql_path = graphql_gen.generate(name, &relations)?;
+        print_success(&format!("Created GraphQL types: {}", graphql_path));
+    }
+
     Ok(())
 }
 
 /// Generate a new migration
+#[allow(clippy::too_many_arguments)]
 async fn make_migration(
     config_path: &str,
     name: &str,
     create: Option<String>,
     table: Option<String>,
     fields: Option<String>,
+    rename_table: Option<TableRename>,
+    update_fks: bool,
+    drop_table: Option<String>,
+    no_down: bool,
+    from_sql: Option<String>,
+    from_down_sql: Option<String>,
+    indexes: &[IndexChange],
+    concurrent: bool,
+    modify_column: Option<&str>,
+    original_type: Option<String>,
     _output: &str,
     verbose: bool,
 ) -> Result<(), String> {
@@ -202,19 +505,333 @@ async fn make_migration(
     }
 
     let generator = MigrationGenerator::new(&config);
-    let path = generator.generate(name, create, table, fields, false, false)?;
+
+    if let Some(drop_table) = drop_table {
+        let drop = drop_table_plan(&config, &drop_table, no_down).await;
+        let path = generator.generate_drop_table(name, &drop)?;
+        print_success(&format!("Created migration: {}", path));
+        return Ok(());
+    }
+
+    if let Some(up_path) = from_sql {
+        let path = sql_file_migration(&generator, name, &up_path, from_down_sql.as_deref())?;
+        print_success(&format!("Created migration: {}", path));
+        return Ok(());
+    }
+
+    if let (Some(table), Some(modify_column)) = (&table, modify_column) {
+        let path = modify_column_migration(&config, &generator, name, table, modify_column, original_type).await?;
+        print_success(&format!("Created migration: {}", path));
+        return Ok(());
+    }
+
+    if let (Some(table), false) = (&table, indexes.is_empty()) {
+        let path = generator.generate_add_index(name, table, indexes, concurrent)?;
+        print_success(&format!("Created migration: {}", path));
+        return Ok(());
+    }
+
+    // Only infer a rename from the name when nothing else was asked for
+    let rename_table = rename_table.or_else(|| {
+        if create.is_some() || table.is_some() || fields.is_some() {
+            return None;
+        }
+
+        match MigrationNameParser::parse(name)? {
+            MigrationIntent::RenameTable { from, to } => Some(TableRename { from, to }),
+        }
+    });
+
+    let path = match rename_table {
+        Some(rename) => {
+            if verbose {
+                print_info(&format!("Renaming table {} to {}", rename.from, rename.to));
+            }
+
+            let foreign_keys = if update_fks {
+                foreign_key_renames(&config, &rename).await?
+            } else {
+                Vec::new()
+            };
+
+            for foreign_key in &foreign_keys {
+                print_info(&format!(
+                    "Renaming {}.{} to {}",
+                    foreign_key.table, foreign_key.from, foreign_key.to
+                ));
+            }
+
+            generator.generate_rename(name, &rename, &foreign_keys)?
+        }
+        None if update_fks => {
+            return Err("--update-fks only applies to table renames; pass --rename-table=old_name:new_name".to_string());
+        }
+        None => generator.generate(name, create, table, fields, false, false)?,
+    };
 
     print_success(&format!("Created migration: {}", path));
 
     Ok(())
 }
 
+/// Generate a `--modify-column` migration from `column:new_type` specs
+///
+/// `original_type` overrides the live type `down()` restores, for a single column.
+pub(crate) async fn modify_column_migration(
+    config: &TideConfig,
+    generator: &MigrationGenerator<'_>,
+    name: &str,
+    table: &str,
+    specs: &str,
+    original_type: Option<String>,
+) -> Result<String, String> {
+    let mut modifications = ColumnModification::parse_list(specs)?;
+    if original_type.is_some() {
+        let [modification] = modifications.as_mut_slice() else {
+            return Err("--original-type applies to a single --modify-column column".to_string());
+        };
+        modification.from = original_type;
+    }
+
+    let change = column_type_change_plan(config, table, modifications).await?;
+    generator.generate_modify_column(name, &change)
+}
+
+/// Read the original column types and, for SQLite's table rebuild, the table's schema
+///
+/// Without a connection the migration is still generated, with TODOs where the schema is needed.
+async fn column_type_change_plan(
+    config: &TideConfig,
+    table: &str,
+    modifications: Vec<ColumnModification>,
+) -> Result<ColumnTypeChange, String> {
+    let mut change = ColumnTypeChange {
+        table: table.to_string(),
+        modifications,
+        ..ColumnTypeChange::default()
+    };
+
+    if let Err(error) = read_column_type_change_schema(config, &mut change).await {
+        print_warning(&format!("Could not read the live schema of {}: {}", table, error));
+        change.columns.clear();
+    }
+
+    if !change.columns.is_empty() {
+        for modification in &mut change.modifications {
+            let live = change
+                .columns
+                .iter()
+                .find(|column| column.name == modification.column)
+                .ok_or_else(|| format!("Column {}.{} not found in the database", table, modification.column))?;
+            modification.from.get_or_insert_with(|| live.data_type.clone());
+        }
+    }
+
+    for modification in &change.modifications {
+        match &modification.from {
+            Some(from) if modification.narrows() => print_warning(&format!(
+                "Changing {}.{} from {} to {} may truncate existing data",
+                table, modification.column, from, modification.to
+            )),
+            Some(_) => {}
+            None => print_warning(&format!(
+                "Original type of {}.{} unknown; pass --original-type or fill in down() before rolling back",
+                table, modification.column
+            )),
+        }
+    }
+
+    Ok(change)
+}
+
+async fn read_column_type_change_schema(config: &TideConfig, change: &mut ColumnTypeChange) -> Result<(), String> {
+    let table = change.table.clone();
+    change.columns = runtime_db::table_columns(config, &table)
+        .await?
+        .into_iter()
+        .map(|column| ColumnSchema {
+            name: column.name,
+            data_type: column.data_type,
+            nullable: column.nullable,
+            key: column.key,
+            default: column.default,
+            extra: column.extra,
+        })
+        .collect();
+
+    if change.columns.is_empty() {
+        return Err(format!("table {} not found", table));
+    }
+
+    if config.database.driver != "sqlite" {
+        return Ok(());
+    }
+
+    change.foreign_keys = runtime_db::table_foreign_keys(config, &table)
+        .await?
+        .into_iter()
+        .map(|foreign_key| ForeignKeyReference {
+            table: table.clone(),
+            column: foreign_key.column,
+            references_table: foreign_key.references_table,
+            references_column: foreign_key.references_column,
+            constraint: foreign_key.constraint,
+        })
+        .collect();
+
+    let primary_key: Vec<String> = change
+        .columns
+        .iter()
+        .filter(|column| column.key.as_deref() == Some("PRI"))
+        .map(|column| column.name.clone())
+        .collect();
+    for index in runtime_db::table_indexes(config, &table).await? {
+        // UNIQUE column constraints show up as sqlite_autoindex_*, a reserved name
+        let name = if index.name.starts_with("sqlite_autoindex_") {
+            if index.columns == primary_key {
+                continue;
+            }
+            format!("{}_{}_key", table, index.columns.join("_"))
+        } else {
+            index.name
+        };
+        change.indexes.push(TableIndex {
+            name,
+            columns: index.columns,
+            unique: index.unique,
+        });
+    }
+
+    Ok(())
+}
+
+/// Foreign key columns named after the renamed table, e.g. posts.user_id when renaming users
+async fn foreign_key_renames(config: &TideConfig, rename: &TableRename) -> Result<Vec<ForeignKeyRename>, String> {
+    let from_column = format!("{}_id", singularize(&rename.from));
+    let to_column = format!("{}_id", singularize(&rename.to));
+    let mut renames = Vec::new();
+
+    for table in runtime_db::list_tables(config).await? {
+        for foreign_key in runtime_db::table_foreign_keys(config, &table).await? {
+            if foreign_key.references_table == rename.from && foreign_key.column == from_column {
+                renames.push(ForeignKeyRename {
+                    table: table.clone(),
+                    from: from_column.clone(),
+                    to: to_column.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(renames)
+}
+
+/// Generate a `--from-sql` migration from SQL files
+///
+/// Files with several statements get a warning, since they run in a single `schema.raw` call.
+pub(crate) fn sql_file_migration(
+    generator: &MigrationGenerator,
+    name: &str,
+    up_path: &str,
+    down_path: Option<&str>,
+) -> Result<String, String> {
+    let up_sql = read_sql_file(up_path)?;
+    let down_sql = down_path.map(read_sql_file).transpose()?;
+
+    for (path, sql) in std::iter::once((up_path, &up_sql)).chain(down_path.zip(down_sql.as_ref())) {
+        let statements = count_sql_statements(sql);
+        if statements > 1 {
+            print_warning(&format!(
+                "{} has {} statements, which run in one schema.raw call; split them up if each needs its own error handling",
+                path, statements
+            ));
+        }
+    }
+
+    generator.generate_from_sql(name, &up_sql, down_sql.as_deref())
+}
+
+/// Read the schema a `--drop-table` migration needs from the live database
+///
+/// Without a connection the migration is still generated, with a TODO in `down()`.
+pub(crate) async fn drop_table_plan(config: &TideConfig, table: &str, irreversible: bool) -> TableDrop {
+    let mut drop = TableDrop {
+        table: table.to_string(),
+        irreversible,
+        ..TableDrop::default()
+    };
+
+    match read_drop_table_schema(config, &mut drop).await {
+        Err(error) => print_warning(&format!(
+            "Could not read the live schema of {}: {}",
+            table, error
+        )),
+        Ok(()) if drop.columns.is_empty() && !irreversible => {
+            print_warning(&format!("Table {} not found in the database", table))
+        }
+        Ok(()) => {}
+    }
+
+    if drop.columns.is_empty() && !irreversible {
+        print_warning("Add the CREATE TABLE statement to down() before running the migration");
+    }
+
+    for dependent in &drop.dependents {
+        print_warning(&format!(
+            "{}.{} has a foreign key referencing {}",
+            dependent.table, dependent.column, table
+        ));
+    }
+
+    drop
+}
+
+async fn read_drop_table_schema(config: &TideConfig, drop: &mut TableDrop) -> Result<(), String> {
+    for other in runtime_db::list_tables(config).await? {
+        for foreign_key in runtime_db::table_foreign_keys(config, &other).await? {
+            let reference = ForeignKeyReference {
+                table: other.clone(),
+                column: foreign_key.column,
+                references_table: foreign_key.references_table,
+                references_column: foreign_key.references_column,
+                constraint: foreign_key.constraint,
+            };
+
+            if other == drop.table {
+                drop.foreign_keys.push(reference);
+            } else if reference.references_table == drop.table {
+                drop.dependents.push(reference);
+            }
+        }
+    }
+
+    if !drop.irreversible {
+        drop.columns = runtime_db::table_columns(config, &drop.table)
+            .await?
+            .into_iter()
+            .map(|column| ColumnSchema {
+                name: column.name,
+                data_type: column.data_type,
+                nullable: column.nullable,
+                key: column.key,
+                default: column.default,
+                extra: column.extra,
+            })
+            .collect();
+    }
+
+    Ok(())
+}
+
 /// Generate a new seeder
+#[allow(clippy::too_many_arguments)]
 async fn make_seeder(
     config_path: &str,
     name: &str,
     model: Option<String>,
     count: u32,
+    values: SeedValues,
+    truncate: bool,
     _output: &str,
     verbose: bool,
 ) -> Result<(), String> {
@@ -224,7 +841,7 @@ async fn make_seeder(
         print_info(&format!("Generating seeder: {}", name));
     }
 
-    let generator = SeederGenerator::new(&config);
+    let generator = SeederGenerator::new(&config).values(values).truncate(truncate);
     let path = generator.generate(name, model, count)?;
 
     print_success(&format!("Created seeder: {}", path));
@@ -232,11 +849,102 @@ async fn make_seeder(
     Ok(())
 }
 
+/// Generate a seeder that recreates the current rows of the given tables
+async fn make_seed_state(
+    config_path: &str,
+    name: &str,
+    tables: &[String],
+    limit: Option<u64>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let mut config = TideConfig::load(config_path)?;
+
+    let existing = runtime_db::list_tables(&config).await?;
+    let missing: Vec<&str> = tables
+        .iter()
+        .filter(|table| !existing.contains(table))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Table(s) not found: {}", missing.join(", ")));
+    }
+
+    // Referenced tables first, so the seeder inserts parents before their children
+    let mut snapshots = Vec::new();
+    for table in crate::commands::db::tables_in_dependency_order(&config, tables).await? {
+        let binary_columns: Vec<String> = runtime_db::table_columns(&config, &table)
+            .await?
+            .into_iter()
+            .filter(|column| is_binary_type(&column.data_type))
+            .map(|column| column.name)
+            .collect();
+        for column in &binary_columns {
+            print_warning(&format!(
+                "Skipping binary column {}.{}: its values can't be written as Rust literals",
+                table, column
+            ));
+        }
+
+        let rows: Vec<_> = runtime_db::table_rows(&config, &table, limit)
+            .await?
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .filter(|(column, _)| !binary_columns.contains(column))
+                    .collect()
+            })
+            .collect();
+
+        if verbose {
+            print_info(&format!("Captured {} row(s) from {}", rows.len(), table));
+        }
+
+        snapshots.push(TableSnapshot { table, rows });
+    }
+
+    config.paths.seeders = output.to_string();
+    let (path, skipped) = SeederGenerator::new(&config).generate_snapshot(name, &snapshots)?;
+
+    for column in skipped {
+        print_warning(&format!("Skipping {}: its values can't be written as Rust literals", column));
+    }
+
+    let row_count: usize = snapshots.iter().map(|snapshot| snapshot.rows.len()).sum();
+    print_success(&format!("Created seeder: {} ({} row(s))", path, row_count));
+
+    Ok(())
+}
+
+/// Generate the seeders described by a seed definition file
+async fn make_seeder_batch(config_path: &str, file: &str, output: &str, verbose: bool) -> Result<(), String> {
+    let mut config = TideConfig::load_or_default(config_path);
+    let definitions = read_seed_definitions(file)?;
+
+    if verbose {
+        print_info(&format!("Generating {} seeder(s) from {}", definitions.len(), file));
+    }
+
+    config.paths.seeders = output.to_string();
+    for path in SeederGenerator::new(&config).generate_batch(file, &definitions)? {
+        print_success(&format!("Created seeder: {}", path));
+    }
+
+    Ok(())
+}
+
+/// Whether a column type holds raw bytes
+fn is_binary_type(data_type: &str) -> bool {
+    let data_type = data_type.to_lowercase();
+    ["blob", "bytea", "binary"].iter().any(|binary| data_type.contains(binary))
+}
+
 /// Generate a new factory
 async fn make_factory(
     config_path: &str,
     name: &str,
     model: Option<String>,
+    states: Option<String>,
     _output: &str,
     verbose: bool,
 ) -> Result<(), String> {
@@ -246,7 +954,7 @@ async fn make_factory(
         print_info(&format!("Generating factory: {}", name));
     }
 
-    let generator = FactoryGenerator::new(&config);
+    let generator = FactoryGenerator::new(&config).states(states.as_deref())?;
     let path = generator.generate(name, model)?;
 
     print_success(&format!("Created factory: {}", path));
@@ -254,7 +962,458 @@ async fn make_factory(
     Ok(())
 }
 
-fn prepare_model_migration_fields(
+/// Generate a new model observer
+async fn make_observer(
+    config_path: &str,
+    name: &str,
+    model: &str,
+    events: &str,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating observer: {}", name));
+    }
+
+    let events = ObserverEvent::parse_list(events)?;
+    let generator = ObserverGenerator::new(&config);
+    let path = generator.generate(name, model, &events, output)?;
+
+    print_success(&format!("Created observer: {}", path));
+    print_info("Declare the observers module with `mod observers;` in src/main.rs so TideORM runs its callbacks");
+
+    Ok(())
+}
+
+/// Generate a new model repository
+async fn make_repository(
+    config_path: &str,
+    name: &str,
+    model: &str,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating repository: {}", name));
+    }
+
+    let generator = RepositoryGenerator::new(&config);
+    let path = generator.generate(name, model, output)?;
+
+    print_success(&format!("Created repository: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new background job
+async fn make_job(
+    name: &str,
+    queue: Option<String>,
+    delay_secs: Option<u64>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating job: {}", name));
+    }
+
+    let generator = JobGenerator::new().queue(queue).delay_secs(delay_secs);
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created job: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new cast
+async fn make_cast(
+    name: &str,
+    from_type: &str,
+    to_type: &str,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating cast: {} ({} <-> {})", name, from_type, to_type));
+    }
+
+    let generator = CastGenerator::new(from_type, to_type)?;
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created cast: {}", path));
+
+    let manifest_path = std::path::Path::new("Cargo.toml");
+    if generator.uses_uuid()
+        && manifest_path.exists()
+        && add_dependency(manifest_path, "dependencies", "uuid", "1")?
+    {
+        print_success("Added uuid to [dependencies] in Cargo.toml");
+    }
+
+    Ok(())
+}
+
+/// Generate a new custom command
+async fn make_command(
+    name: &str,
+    description: &str,
+    args: Option<String>,
+    global_flags: bool,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating command: {}", name));
+    }
+
+    let generator = CommandGenerator::new(description)
+        .args(args.as_deref())?
+        .global_flags(global_flags);
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created command: {}", path));
+
+    let manifest_path = std::path::Path::new("Cargo.toml");
+    if manifest_path.exists()
+        && add_dependency_with_features(manifest_path, "dependencies", "clap", "4", &["derive"])?
+    {
+        print_success("Added clap to [dependencies] in Cargo.toml");
+    }
+
+    print_info("See the module docs for how to register it in src/main.rs");
+
+    Ok(())
+}
+
+/// Generate a new presenter
+async fn make_presenter(
+    config_path: &str,
+    name: &str,
+    model: &str,
+    fields: Option<&str>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating presenter: {}", name));
+    }
+
+    let generator = PresenterGenerator::new(&config).fields(fields);
+    let path = generator.generate(name, model, output)?;
+
+    print_success(&format!("Created presenter: {}", path));
+
+    Ok(())
+}
+
+/// Generate new DTOs
+async fn make_dto(
+    config_path: &str,
+    name: &str,
+    model: Option<&str>,
+    direction: DtoDirection,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating DTO: {}", name));
+    }
+
+    let generator = DtoGenerator::new(&config).direction(direction);
+    let path = generator.generate(name, model, output)?;
+
+    print_success(&format!("Created DTO: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new transformer
+async fn make_transformer(
+    config_path: &str,
+    name: &str,
+    from_model: &str,
+    to_type: &str,
+    batch_size: Option<u32>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating transformer: {} ({} -> {})", name, from_model, to_type));
+    }
+
+    let generator = TransformerGenerator::new(&config).batch_size(batch_size);
+    let path = generator.generate(name, from_model, to_type, output)?;
+
+    print_success(&format!("Created transformer: {}", path));
+
+    Ok(())
+}
+
+/// Generate a model with its migration, factory, seeder, controller and test
+#[allow(clippy::too_many_arguments)]
+async fn make_resource(
+    config_path: &str,
+    name: &str,
+    fields: Option<String>,
+    relations: Option<String>,
+    database: Option<String>,
+    framework: Option<ControllerFramework>,
+    dry_run: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let mut config = TideConfig::load_or_default(config_path);
+    if let Some(database) = database {
+        config.database.driver = database;
+    }
+
+    if verbose {
+        print_info(&format!("Generating resource: {}", name));
+    }
+
+    let generator = ResourceGenerator::new(&config)
+        .fields(fields)
+        .relations(relations)
+        .framework(framework.unwrap_or_default());
+
+    let files = if dry_run {
+        let files = generator.plan(name)?;
+        print_info(&format!("Would create resource {} (dry run):", name));
+        files
+    } else {
+        let files = generator.generate(name)?;
+        print_success(&format!("Created resource: {}", name));
+        files
+    };
+
+    println!("{}", "─".repeat(60));
+    println!("  {:<11} File", "Kind");
+    for file in &files {
+        println!("  {:<11} {}", file.kind, file.path);
+    }
+    println!("{}", "─".repeat(60));
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let manifest_path = std::path::Path::new("Cargo.toml");
+    if manifest_path.exists() {
+        let (crate_name, version) = match framework.unwrap_or_default() {
+            ControllerFramework::Axum => ("axum", "0.8"),
+            ControllerFramework::Actix => ("actix-web", "4"),
+        };
+        if add_dependency(manifest_path, "dependencies", crate_name, version)? {
+            print_success(&format!("Added {} to [dependencies] in Cargo.toml", crate_name));
+        }
+        if add_dependency(manifest_path, "dev-dependencies", "tempfile", "3")? {
+            print_success("Added tempfile to [dev-dependencies] in Cargo.toml");
+        }
+    }
+
+    print_info("Declare the new modules with `mod controllers;` and `#[cfg(test)] mod tests;` in src/main.rs");
+
+    Ok(())
+}
+
+/// Generate a new guard
+async fn make_guard(
+    config_path: &str,
+    name: &str,
+    model: Option<String>,
+    redirect_to: Option<String>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating guard: {}", name));
+    }
+
+    let generator = GuardGenerator::new(&config).model(model).redirect_to(redirect_to);
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created guard: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new contract
+async fn make_contract(name: &str, methods: &str, output: &str, verbose: bool) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating contract: {}", name));
+    }
+
+    let generator = ContractGenerator::new().methods(methods)?;
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created contract: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new env-backed config struct
+async fn make_config(
+    name: &str,
+    fields: &str,
+    prefix: Option<&str>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating config: {}", name));
+    }
+
+    let generator = ConfigGenerator::new().fields(fields)?.prefix(prefix)?;
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created config: {}", path));
+
+    Ok(())
+}
+
+/// Generate a new value object
+async fn make_value_object(
+    name: &str,
+    inner_type: &str,
+    validations: Option<&str>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    if verbose {
+        print_info(&format!("Generating value object: {}", name));
+    }
+
+    let generator = ValueObjectGenerator::new(inner_type)?.validations(validations)?;
+    let path = generator.generate(name, output)?;
+
+    print_success(&format!("Created value object: {}", path));
+
+    let manifest_path = std::path::Path::new("Cargo.toml");
+    if generator.uses_regex()
+        && manifest_path.exists()
+        && add_dependency(manifest_path, "dependencies", "regex", "1")?
+    {
+        print_success("Added regex to [dependencies] in Cargo.toml");
+    }
+
+    Ok(())
+}
+
+/// Generate a new notification
+async fn make_notification(
+    config_path: &str,
+    name: &str,
+    channels: &str,
+    model: Option<&str>,
+    output: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating notification: {}", name));
+    }
+
+    let channels = NotificationChannel::parse_list(channels)?;
+    let generator = NotificationGenerator::new(&config);
+    let path = generator.generate(name, &channels, model, output)?;
+
+    print_success(&format!("Created notification: {}", path));
+
+    Ok(())
+}
+
+/// Generate a test module for an existing model
+async fn make_test(config_path: &str, model: &str, output: &str, verbose: bool) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Generating tests for model: {}", model));
+    }
+
+    let generator = TestGenerator::new(&config);
+    let path = generator.generate(model, output)?;
+
+    print_success(&format!("Created test: {}", path));
+
+    let manifest_path = std::path::Path::new("Cargo.toml");
+    if manifest_path.exists() && add_dependency(manifest_path, "dev-dependencies", "tempfile", "3")? {
+        print_success("Added tempfile to [dev-dependencies] in Cargo.toml");
+    }
+
+    if let Some(module) = std::path::Path::new(output).file_name().and_then(|name| name.to_str()) {
+        print_info(&format!(
+            "Declare the test modules with `#[cfg(test)] mod {};` in src/main.rs",
+            module
+        ));
+    }
+
+    Ok(())
+}
+
+/// Add a dependency to a Cargo manifest section, returning false when it is already listed
+fn add_dependency(
+    manifest_path: &std::path::Path,
+    section: &str,
+    name: &str,
+    version: &str,
+) -> Result<bool, String> {
+    add_dependency_with_features(manifest_path, section, name, version, &[])
+}
+
+/// Like `add_dependency`, but enables the given crate features
+fn add_dependency_with_features(
+    manifest_path: &std::path::Path,
+    section: &str,
+    name: &str,
+    version: &str,
+    features: &[&str],
+) -> Result<bool, String> {
+    let content = std::fs::read_to_string(manifest_path)
+        .map_err(|error| format!("Failed to read {}: {}", manifest_path.display(), error))?;
+    let mut manifest = content
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|error| format!("Failed to parse {}: {}", manifest_path.display(), error))?;
+
+    if manifest.get(section).is_none() {
+        manifest.insert(section, toml_edit::table());
+    }
+
+    let dependencies = manifest[section]
+        .as_table_like_mut()
+        .ok_or_else(|| format!("[{}] in {} is not a table", section, manifest_path.display()))?;
+
+    if dependencies.contains_key(name) {
+        return Ok(false);
+    }
+
+    if features.is_empty() {
+        dependencies.insert(name, toml_edit::value(version));
+    } else {
+        let mut dependency = toml_edit::InlineTable::new();
+        dependency.insert("version", version.into());
+        dependency.insert("features", features.iter().copied().collect::<toml_edit::Array>().into());
+        dependencies.insert(name, toml_edit::value(dependency));
+    }
+
+    std::fs::write(manifest_path, manifest.to_string())
+        .map_err(|error| format!("Failed to write {}: {}", manifest_path.display(), error))?;
+
+    Ok(true)
+}
+
+pub(crate) fn prepare_model_migration_fields(
     fields: Option<String>,
     relations: Option<&str>,
     translatable: Option<&str>,
@@ -265,10 +1424,8 @@ fn prepare_model_migration_fields(
     let mut field_defs: Vec<String> = fields
         .as_deref()
         .map(|value| {
-            value
-                .split(',')
-                .map(str::trim)
-                .filter(|field| !field.is_empty())
+            split_field_definitions(value)
+                .into_iter()
                 .map(ToOwned::to_owned)
                 .collect()
         })
@@ -336,7 +1493,7 @@ fn prepare_model_migration_fields(
 
 #[cfg(test)]
 mod tests {
-    use super::prepare_model_migration_fields;
+    use super::{add_dependency, add_dependency_with_features, prepare_model_migration_fields};
 
     #[test]
     fn test_prepare_model_migration_fields_uses_configured_primary_key_type() {
@@ -371,4 +1528,33 @@ mod tests {
         assert!(fields.contains("title:string"));
         assert!(fields.contains("translations:jsonb:nullable"));
     }
+
+    #[test]
+    fn add_dependency_creates_table_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        std::fs::write(&manifest, "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n").unwrap();
+
+        assert!(add_dependency(&manifest, "dev-dependencies", "tempfile", "3").unwrap());
+        assert!(!add_dependency(&manifest, "dev-dependencies", "tempfile", "3").unwrap());
+        assert!(add_dependency(&manifest, "dependencies", "regex", "1").unwrap());
+
+        let content = std::fs::read_to_string(&manifest).unwrap();
+        assert!(content.contains("[dependencies]\nserde = \"1\"\nregex = \"1\""));
+        assert_eq!(content.matches("tempfile = \"3\"").count(), 1);
+        assert!(content.contains("[dev-dependencies]\ntempfile = \"3\""));
+    }
+
+    #[test]
+    fn add_dependency_with_features_writes_an_inline_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        std::fs::write(&manifest, "[package]\nname = \"app\"\n\n[dependencies]\n").unwrap();
+
+        assert!(add_dependency_with_features(&manifest, "dependencies", "clap", "4", &["derive"]).unwrap());
+        assert!(!add_dependency_with_features(&manifest, "dependencies", "clap", "4", &["derive"]).unwrap());
+
+        let content = std::fs::read_to_string(&manifest).unwrap();
+        assert!(content.contains("clap = { version = \"4\", features = [\"derive\"] }"));
+    }
 }
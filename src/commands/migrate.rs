@@ -1,39 +1,75 @@
 //! Migration commands for TideORM CLI
 
+use crate::commands::schema;
 use crate::config::TideConfig;
-use crate::generators::migration::MigrationGenerator;
+use crate::generators::migration::{IndexChange, MigrationGenerator};
+use crate::logging;
+use crate::migration_lock::MigrationLock;
 use crate::runtime_db;
+use crate::schema_types::{diff_schemas, SchemaDiff};
 use crate::utils::{self, print_info, print_success, print_warning};
-use crate::MigrateCommands;
+use crate::{MigrateCommands, MigrateGenerateArgs};
 use colored::Colorize;
+use notify::{EventKind, RecursiveMode, Watcher};
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tideorm::internal::{ConnectionTrait, Statement};
 
 /// Run pending migrations
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     config_path: &str,
-    path: Option<String>,
+    paths: Vec<String>,
     pretend: bool,
+    pretend_comments: bool,
     force: bool,
     step: Option<u32>,
+    lock_timeout: Option<Duration>,
 ) -> Result<(), String> {
+    if pretend {
+        // Keep stdout to the SQL so it can be piped to psql or mysql
+        logging::log_to_stderr();
+    }
+
     let config = TideConfig::load(config_path)?;
 
     if config.is_production() && !force {
         return Err("Cannot run migrations in production without --force flag".to_string());
     }
 
-    let migrations_path = path.as_deref().unwrap_or(&config.paths.migrations);
+    let migrations_paths = migrations_paths_or_default(&config, paths);
 
-    print_info(&format!("Running migrations from: {}", migrations_path));
+    print_info(&format!("Running migrations from: {}", migrations_paths.join(", ")));
 
     if pretend {
         print_warning("Running in pretend mode - no changes will be made");
     }
 
-    let migrations = get_pending_migrations(&config, migrations_path).await?;
+    // Hold the lock while reading pending migrations too, so a second run sees this run's work
+    let lock = match lock_timeout {
+        Some(timeout) if !pretend => Some(MigrationLock::acquire(&config, timeout).await?),
+        _ => None,
+    };
+
+    let result = run_pending(&config, &migrations_paths, pretend, pretend_comments, step).await;
+
+    match lock {
+        Some(lock) => result.and(lock.release().await),
+        None => result,
+    }
+}
+
+/// Run pending migrations from `migrations_paths`
+async fn run_pending(
+    config: &TideConfig,
+    migrations_paths: &[String],
+    pretend: bool,
+    pretend_comments: bool,
+    step: Option<u32>,
+) -> Result<(), String> {
+    let migrations = get_pending_migrations(config, migrations_paths).await?;
 
     if migrations.is_empty() {
         print_success("Nothing to migrate");
@@ -45,31 +81,43 @@ pub async fn run(
         None => migrations,
     };
 
+    if pretend {
+        print_info(&format!("Showing SQL for {} migration(s)", migrations_to_run.len()));
+        for migration in &migrations_to_run {
+            if migration.up_sql.trim().is_empty() {
+                print_warning(&format!("No SQL found in {}", migration.file_name));
+            }
+            print!("{}", pretend_sql(migration, pretend_comments));
+        }
+        return Ok(());
+    }
+
     println!(
         "\n{} Migrations to run:",
         format!("[{}]", migrations_to_run.len()).cyan()
     );
 
     for (index, migration) in migrations_to_run.iter().enumerate() {
-        println!("  {}. {}", index + 1, migration.file_name.yellow());
-    }
-
-    if pretend {
-        println!("\n{}", "Pretend mode - showing SQL:".cyan());
-        for migration in &migrations_to_run {
-            println!("\n-- Migration: {}", migration.file_name);
-            println!("-- Up:");
-            println!("{}", migration.up_sql);
+        if migrations_paths.len() > 1 {
+            println!("  {}. {} ({})", index + 1, migration.file_name.yellow(), migration.directory);
+        } else {
+            println!("  {}. {}", index + 1, migration.file_name.yellow());
         }
-        return Ok(());
     }
 
+    let batch = next_batch(&get_ran_migrations(config, migrations_paths).await?);
+
     println!("\n{}", "Running migrations...".cyan());
 
-    for migration in &migrations_to_run {
+    run_batch(config, &migrations_to_run, batch).await
+}
+
+/// Run `migrations` in order, recording them all under `batch`
+async fn run_batch(config: &TideConfig, migrations: &[Migration], batch: u32) -> Result<(), String> {
+    for migration in migrations {
         print!("  Migrating: {}... ", migration.file_name);
 
-        match run_migration_up(&config, migration).await {
+        match run_migration_up(config, migration, batch).await {
             Ok(()) => println!("{}", "DONE".green()),
             Err(error) => {
                 println!("{}", "FAILED".red());
@@ -79,13 +127,186 @@ pub async fn run(
     }
 
     print_success(&format!(
-        "Ran {} migration(s) successfully",
-        migrations_to_run.len()
+        "Ran {} migration(s) in batch {}",
+        migrations.len(),
+        batch
     ));
 
     Ok(())
 }
 
+/// A migration's up SQL as it is printed by `migrate run --pretend`, ending in `;`
+/// so consecutive migrations stay separate statements
+fn pretend_sql(migration: &Migration, comments: bool) -> String {
+    let mut sql = String::new();
+
+    if comments {
+        sql.push_str(&format!("-- Migration: {}\n", migration.file_name));
+    }
+
+    let up_sql = migration.up_sql.trim();
+    if !up_sql.is_empty() {
+        sql.push_str(&format!("{};\n", up_sql));
+    }
+
+    if comments {
+        sql.push('\n');
+    }
+
+    sql
+}
+
+/// Run pending migrations, then keep running new migration files as they appear
+#[allow(clippy::too_many_arguments)]
+async fn run_and_watch(
+    config_path: &str,
+    paths: Vec<String>,
+    pretend: bool,
+    pretend_comments: bool,
+    force: bool,
+    step: Option<u32>,
+    lock_timeout: Option<Duration>,
+    debounce_ms: u64,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    if config.is_production() {
+        if !force {
+            return Err("Cannot watch migrations in production without --force flag".to_string());
+        }
+        print_warning("Watching migrations is intended for development; running against PRODUCTION");
+    }
+
+    run(config_path, paths.clone(), pretend, pretend_comments, force, step, lock_timeout).await?;
+
+    let migrations_paths = match paths {
+        paths if paths.is_empty() => vec![config.paths.migrations.clone()],
+        paths => paths,
+    };
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let _ = sender.send(event);
+    })
+    .map_err(|error| format!("Failed to start file watcher: {}", error))?;
+    let mut known_files: HashSet<PathBuf> = HashSet::new();
+
+    for migrations_path in &migrations_paths {
+        // Watcher events carry absolute paths, so compare against the canonical directory
+        let watch_dir = utils::ensure_directory(migrations_path)?;
+        known_files.extend(migration_files(&watch_dir)?);
+        watcher
+            .watch(&watch_dir, RecursiveMode::NonRecursive)
+            .map_err(|error| format!("Failed to watch {}: {}", migrations_path, error))?;
+    }
+
+    print_info(&format!(
+        "Watching {} for new migrations (press Ctrl+C to stop)",
+        migrations_paths.join(", ")
+    ));
+
+    let mut debouncer = WatchDebouncer::new(Duration::from_millis(debounce_ms));
+    let mut ticker = tokio::time::interval(Duration::from_millis(50));
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                println!();
+                print_info("Stopped watching migrations");
+                return Ok(());
+            }
+            event = receiver.recv() => {
+                let Some(event) = event else {
+                    return Ok(());
+                };
+                match event {
+                    Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+                        for file in event.paths.into_iter().filter(|file| is_migration_file(file)) {
+                            if !known_files.contains(&file) {
+                                debouncer.record(file, Instant::now());
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(error) => print_warning(&format!("File watcher error: {}", error)),
+                }
+            }
+            _ = ticker.tick() => {
+                let ready = debouncer.take_ready(Instant::now());
+                if ready.is_empty() {
+                    continue;
+                }
+
+                for file in &ready {
+                    print_info(&format!("Detected new migration: {}", file.display()));
+                }
+                known_files.extend(ready);
+
+                if let Err(error) = run(config_path, migrations_paths.clone(), pretend, pretend_comments, force, None, lock_timeout).await {
+                    eprintln!("{} {}", "Error:".red().bold(), error);
+                }
+            }
+        }
+    }
+}
+
+/// Collects file events and releases each path once it has been quiet for the debounce period
+#[derive(Debug)]
+struct WatchDebouncer {
+    delay: Duration,
+    pending: HashMap<PathBuf, Instant>,
+}
+
+impl WatchDebouncer {
+    fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Record an event for a path, restarting its quiet period
+    fn record(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path, now);
+    }
+
+    /// Remove and return paths whose last event is at least `delay` old
+    fn take_ready(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut ready: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, last_seen)| now.duration_since(**last_seen) >= self.delay)
+            .map(|(path, _)| path.clone())
+            .collect();
+        ready.sort();
+
+        for path in &ready {
+            self.pending.remove(path);
+        }
+
+        ready
+    }
+}
+
+fn is_migration_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "rs")
+        && path.file_stem().is_some_and(|stem| stem != "mod")
+}
+
+fn migration_files(migrations_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(migrations_dir)
+        .map_err(|error| format!("Failed to read migrations directory: {}", error))?
+    {
+        let entry = entry.map_err(|error| format!("Failed to read entry: {}", error))?;
+        if is_migration_file(&entry.path()) {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(files)
+}
+
 /// Handle migration subcommands
 pub async fn handle_subcommand(
     config_path: &str,
@@ -94,51 +315,221 @@ pub async fn handle_subcommand(
 ) -> Result<(), String> {
     match cmd {
         MigrateCommands::Run {
-            path,
+            paths,
             pretend,
+            pretend_no_comments,
             force,
             step,
-        } => run(config_path, path, pretend, force, step).await,
-        MigrateCommands::Generate {
-            name,
-            create,
-            table,
-            fields,
-        } => generate_migration(config_path, &name, create, table, fields, verbose).await,
+            watch: false,
+            no_lock,
+            lock_timeout,
+            ..
+        } => {
+            let lock_timeout = (!no_lock).then(|| Duration::from_secs(lock_timeout));
+            run(config_path, paths, pretend, !pretend_no_comments, force, step, lock_timeout).await
+        }
+        MigrateCommands::Run {
+            paths,
+            pretend,
+            pretend_no_comments,
+            force,
+            step,
+            watch: true,
+            watch_debounce_ms,
+            no_lock,
+            lock_timeout,
+        } => {
+            let lock_timeout = (!no_lock).then(|| Duration::from_secs(lock_timeout));
+            run_and_watch(
+                config_path,
+                paths,
+                pretend,
+                !pretend_no_comments,
+                force,
+                step,
+                lock_timeout,
+                watch_debounce_ms,
+            )
+            .await
+        }
+        MigrateCommands::Generate(args) if args.from_diff => {
+            generate_from_diff(
+                config_path,
+                &args.name,
+                !args.no_drop_columns,
+                args.force,
+                verbose,
+            )
+            .await
+        }
+        MigrateCommands::Generate(args) => {
+            let MigrateGenerateArgs {
+                name,
+                create,
+                table,
+                fields,
+                add_index,
+                remove_index,
+                add_unique_index,
+                remove_unique_index,
+                drop_table,
+                no_down,
+                from_sql,
+                from_down_sql,
+                add_full_text_index,
+                language,
+                no_concurrent,
+                modify_column,
+                original_type,
+                ..
+            } = *args;
+
+            let mut indexes =
+                IndexChange::from_flags(add_index, remove_index, add_unique_index, remove_unique_index);
+            if let Some(columns) = add_full_text_index {
+                indexes.extend(IndexChange::full_text(&columns, &language)?);
+            }
+
+            generate_migration(
+                config_path,
+                &name,
+                create,
+                table,
+                fields,
+                drop_table,
+                no_down,
+                from_sql,
+                from_down_sql,
+                &indexes,
+                !no_concurrent,
+                modify_column.as_deref(),
+                original_type,
+                verbose,
+            )
+            .await
+        }
         MigrateCommands::Up {
             step,
             migration,
             pretend,
         } => migrate_up(config_path, step, migration, pretend, verbose).await,
         MigrateCommands::Down {
+            paths,
             step,
             migration,
+            to: None,
+            pretend,
+        } => migrate_down(config_path, paths, step, migration, pretend, verbose).await,
+        MigrateCommands::Down {
+            paths,
+            step,
+            migration,
+            to: Some(target),
+            pretend,
+        } => {
+            if step.is_some() {
+                return Err("--to cannot be combined with --step".to_string());
+            }
+            if migration.is_some() {
+                return Err("--to cannot be combined with --migration".to_string());
+            }
+            migrate_down_to(config_path, paths, &target, pretend, verbose).await
+        }
+        MigrateCommands::DownTo {
+            paths,
+            migration,
             pretend,
-        } => migrate_down(config_path, step, migration, pretend, verbose).await,
+        } => migrate_down_to(config_path, paths, &migration, pretend, verbose).await,
         MigrateCommands::Redo { step, pretend } => {
             migrate_redo(config_path, step, pretend, verbose).await
         }
-        MigrateCommands::Fresh { seed, seeder, force } => {
-            migrate_fresh(config_path, seed, seeder, force, verbose).await
-        }
-        MigrateCommands::Reset { force, pretend } => {
-            migrate_reset(config_path, force, pretend, verbose).await
+        MigrateCommands::Fresh {
+            seed,
+            seeder,
+            force,
+            drop_tracking_table,
+        } => migrate_fresh(config_path, seed, seeder, force, drop_tracking_table, verbose).await,
+        MigrateCommands::Reset { paths, force, pretend } => {
+            migrate_reset(config_path, paths, force, pretend, verbose).await
         }
         MigrateCommands::Refresh { seed, step, force } => {
             migrate_refresh(config_path, seed, step, force, verbose).await
         }
-        MigrateCommands::Status => migration_status(config_path, verbose).await,
-        MigrateCommands::History { limit } => migration_history(config_path, limit, verbose).await,
+        MigrateCommands::Status { paths, fix_gaps, force } => {
+            migration_status(config_path, paths, fix_gaps, force, verbose).await
+        }
+        MigrateCommands::History {
+            limit,
+            slow_threshold,
+        } => migration_history(config_path, limit, slow_threshold, verbose).await,
+    }
+}
+
+/// Generate a migration that adds and drops the columns that differ between the models and the database
+async fn generate_from_diff(
+    config_path: &str,
+    name: &str,
+    drop_columns: bool,
+    force: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let driver = schema::driver_name(&config);
+
+    let (database, models) = schema::database_and_model_schemas(&config, driver, verbose).await?;
+    let diff = diff_schemas(&database, &models);
+    let plan = SchemaDiff {
+        added_columns: diff.added_columns.clone(),
+        dropped_columns: if drop_columns { diff.dropped_columns.clone() } else { Vec::new() },
+        ..SchemaDiff::default()
+    };
+
+    schema::warn_missing_tables(&diff);
+
+    if !drop_columns && !diff.dropped_columns.is_empty() {
+        print_info(&format!(
+            "Keeping {} column(s) that no model declares (--no-drop-columns)",
+            diff.dropped_columns.len()
+        ));
+    }
+
+    if plan.is_empty() {
+        print_success("No columns differ between the models and the database");
+        return Ok(());
+    }
+
+    println!("\n{}", "Schema diff:".cyan().bold());
+    println!("{}", plan.display());
+    println!();
+
+    if !force && !utils::confirm("Write a migration for these changes?") {
+        print_info("Operation cancelled");
+        return Ok(());
     }
+
+    let path = MigrationGenerator::new(&config).generate_sync(name, &plan)?;
+
+    print_success(&format!("Created migration: {}", path));
+
+    Ok(())
 }
 
 /// Generate a new migration file
+#[allow(clippy::too_many_arguments)]
 async fn generate_migration(
     config_path: &str,
     name: &str,
     create: Option<String>,
     table: Option<String>,
     fields: Option<String>,
+    drop_table: Option<String>,
+    no_down: bool,
+    from_sql: Option<String>,
+    from_down_sql: Option<String>,
+    indexes: &[IndexChange],
+    concurrent: bool,
+    modify_column: Option<&str>,
+    original_type: Option<String>,
     verbose: bool,
 ) -> Result<(), String> {
     let config = TideConfig::load_or_default(config_path);
@@ -148,7 +539,28 @@ async fn generate_migration(
     }
 
     let generator = MigrationGenerator::new(&config);
-    let output_path = generator.generate(name, create, table, fields, false, false)?;
+    let output_path = if let Some(drop_table) = drop_table {
+        let drop = crate::commands::make::drop_table_plan(&config, &drop_table, no_down).await;
+        generator.generate_drop_table(name, &drop)?
+    } else if let Some(up_path) = from_sql {
+        crate::commands::make::sql_file_migration(&generator, name, &up_path, from_down_sql.as_deref())?
+    } else {
+        match (&table, modify_column, indexes.is_empty()) {
+            (Some(table), Some(modify_column), _) => {
+                crate::commands::make::modify_column_migration(
+                    &config,
+                    &generator,
+                    name,
+                    table,
+                    modify_column,
+                    original_type,
+                )
+                .await?
+            }
+            (Some(table), None, false) => generator.generate_add_index(name, table, indexes, concurrent)?,
+            _ => generator.generate(name, create, table, fields, false, false)?,
+        }
+    };
 
     print_success(&format!("Created migration: {}", output_path));
 
@@ -172,7 +584,8 @@ async fn migrate_up(
     if let Some(migration_name) = migration {
         print_info(&format!("Running specific migration: {}", migration_name));
 
-        let migration = find_migration(&config.paths.migrations, &migration_name)?;
+        let migrations_paths = migrations_paths_or_default(&config, Vec::new());
+        let migration = find_migration(&migrations_paths, &migration_name)?;
 
         if pretend {
             println!("\n{}", "Pretend mode - SQL to execute:".cyan());
@@ -180,36 +593,44 @@ async fn migrate_up(
             return Ok(());
         }
 
-        let ran_migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
+        let ran_migrations = get_ran_migrations(&config, &migrations_paths).await?;
         if ran_migrations.iter().any(|ran| ran.version == migration.version) {
             return Err(format!("Migration already ran: {}", migration.file_name));
         }
 
-        run_migration_up(&config, &migration).await?;
-        print_success(&format!("Migration {} completed", migration_name));
+        let batch = next_batch(&ran_migrations);
+        run_migration_up(&config, &migration, batch).await?;
+        print_success(&format!("Migration {} completed in batch {}", migration_name, batch));
     } else {
-        run(config_path, None, pretend, true, step).await?;
+        run(config_path, Vec::new(), pretend, true, true, step, None).await?;
     }
 
     Ok(())
 }
 
 /// Run migration down (rollback)
+///
+/// Without `step`, the migrations of the latest batch are rolled back.
 async fn migrate_down(
     config_path: &str,
-    step: u32,
+    paths: Vec<String>,
+    step: Option<u32>,
     migration: Option<String>,
     pretend: bool,
     verbose: bool,
 ) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
+    let migrations_paths = migrations_paths_or_default(&config, paths);
 
     if verbose {
-        print_info(&format!("Rolling back {} migration(s)...", step));
+        match step {
+            Some(step) => print_info(&format!("Rolling back {} migration(s)...", step)),
+            None => print_info("Rolling back the latest batch..."),
+        }
     }
 
     if let Some(migration_name) = migration {
-        let migration = find_migration(&config.paths.migrations, &migration_name)?;
+        let migration = find_migration(&migrations_paths, &migration_name)?;
 
         if pretend {
             println!("\n{}", "Pretend mode - SQL to execute:".cyan());
@@ -217,7 +638,7 @@ async fn migrate_down(
             return Ok(());
         }
 
-        let ran_migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
+        let ran_migrations = get_ran_migrations(&config, &migrations_paths).await?;
         if !ran_migrations.iter().any(|ran| ran.version == migration.version) {
             return Err(format!("Migration has not been run: {}", migration.file_name));
         }
@@ -225,9 +646,9 @@ async fn migrate_down(
         run_migration_down(&config, &migration).await?;
         print_success(&format!("Rolled back migration: {}", migration_name));
     } else {
-        let migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
-        let migrations_to_rollback: Vec<_> =
-            migrations.into_iter().rev().take(step as usize).collect();
+        let migrations = rollback_order(get_ran_migrations(&config, &migrations_paths).await?);
+        let step = step.unwrap_or_else(|| latest_batch_size(&migrations) as u32);
+        let migrations_to_rollback: Vec<_> = migrations.into_iter().take(step as usize).collect();
 
         if migrations_to_rollback.is_empty() {
             print_info("Nothing to rollback");
@@ -258,6 +679,72 @@ async fn migrate_down(
     Ok(())
 }
 
+/// Roll back every migration that ran after `target`, leaving `target` applied
+async fn migrate_down_to(
+    config_path: &str,
+    paths: Vec<String>,
+    target: &str,
+    pretend: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let migrations_paths = migrations_paths_or_default(&config, paths.clone());
+    let ran_migrations = rollback_order(get_ran_migrations(&config, &migrations_paths).await?);
+    let step = steps_to_migration(&ran_migrations, target)?;
+
+    if step == 0 {
+        print_info(&format!("{} is already the last ran migration", target));
+        return Ok(());
+    }
+
+    migrate_down(config_path, paths, Some(step as u32), None, pretend, verbose).await
+}
+
+/// Batch number for the next run: one past the highest recorded batch
+fn next_batch(ran_migrations: &[Migration]) -> u32 {
+    ran_migrations
+        .iter()
+        .filter_map(|migration| migration.batch)
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
+/// Ran migrations in the order they roll back: latest batch first, newest version first within a batch
+///
+/// A file with an older version can run in a later batch, e.g. after a branch merge, so version
+/// order alone would roll back the wrong migrations. Records from before batches were tracked go last.
+fn rollback_order(mut ran_migrations: Vec<Migration>) -> Vec<Migration> {
+    ran_migrations.sort_by(|a, b| (b.batch, &b.version).cmp(&(a.batch, &a.version)));
+    ran_migrations
+}
+
+/// Number of ran migrations in the latest batch
+///
+/// Migrations recorded before batches were tracked have none, so they roll back one at a time.
+fn latest_batch_size(ran_migrations: &[Migration]) -> usize {
+    match ran_migrations.iter().map(|migration| migration.batch).max() {
+        Some(Some(batch)) => ran_migrations
+            .iter()
+            .filter(|migration| migration.batch == Some(batch))
+            .count(),
+        Some(None) => 1,
+        None => 0,
+    }
+}
+
+/// Number of migrations that roll back before `target`, matched by file name, version, or name
+///
+/// `ran_migrations` must be in `rollback_order`, the order `migrate down` takes them in.
+fn steps_to_migration(ran_migrations: &[Migration], target: &str) -> Result<usize, String> {
+    ran_migrations
+        .iter()
+        .position(|migration| {
+            migration.file_name == target || migration.version == target || migration.name == target
+        })
+        .ok_or_else(|| format!("Migration has not been run: {}", target))
+}
+
 /// Redo last migration(s)
 async fn migrate_redo(
     config_path: &str,
@@ -269,7 +756,7 @@ async fn migrate_redo(
         print_info(&format!("Redoing {} migration(s)...", step));
     }
 
-    migrate_down(config_path, step, None, pretend, verbose).await?;
+    migrate_down(config_path, Vec::new(), Some(step), None, pretend, verbose).await?;
     migrate_up(config_path, Some(step), None, pretend, verbose).await?;
 
     print_success(&format!("Redid {} migration(s)", step));
@@ -283,6 +770,7 @@ async fn migrate_fresh(
     seed: bool,
     seeder: Option<String>,
     force: bool,
+    drop_tracking_table: bool,
     verbose: bool,
 ) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
@@ -303,14 +791,14 @@ async fn migrate_fresh(
     }
 
     print_info("Dropping all tables...");
-    drop_all_tables(&config).await?;
-    print_success("Dropped all tables");
+    let dropped = drop_all_tables(&config, drop_tracking_table).await?;
+    print_success(&format!("Dropped {} table(s)", dropped));
 
-    run(config_path, None, false, true, None).await?;
+    run(config_path, Vec::new(), false, true, true, None, None).await?;
 
     if seed {
         print_info("Running seeders...");
-        crate::commands::db::seed(config_path, seeder, true, verbose).await?;
+        crate::commands::db::seed(config_path, seeder, None, None, true, false, verbose).await?;
     }
 
     print_success("Database refreshed successfully");
@@ -321,6 +809,7 @@ async fn migrate_fresh(
 /// Reset all migrations (rollback all)
 async fn migrate_reset(
     config_path: &str,
+    paths: Vec<String>,
     force: bool,
     pretend: bool,
     verbose: bool,
@@ -335,7 +824,8 @@ async fn migrate_reset(
         print_warning("This will rollback ALL migrations!");
     }
 
-    let migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
+    let migrations_paths = migrations_paths_or_default(&config, paths);
+    let migrations = rollback_order(get_ran_migrations(&config, &migrations_paths).await?);
 
     if migrations.is_empty() {
         print_info("Nothing to reset");
@@ -344,15 +834,21 @@ async fn migrate_reset(
 
     if pretend {
         println!("\n{}", "Pretend mode - migrations to rollback:".cyan());
-        for migration in migrations.iter().rev() {
-            println!("  - {}", migration.file_name);
+        for migration in &migrations {
+            if verbose {
+                println!("\n-- Migration: {}", migration.file_name);
+                println!("-- Down:");
+                println!("{}", migration.down_sql);
+            } else {
+                println!("  - {}", migration.file_name);
+            }
         }
         return Ok(());
     }
 
     println!("Rolling back {} migration(s)...", migrations.len());
 
-    for migration in migrations.iter().rev() {
+    for migration in &migrations {
         print!("  Rolling back: {}... ", migration.file_name);
         run_migration_down(&config, migration).await?;
         println!("{}", "DONE".green());
@@ -378,16 +874,16 @@ async fn migrate_refresh(
     }
 
     if let Some(count) = step {
-        migrate_down(config_path, count, None, false, verbose).await?;
+        migrate_down(config_path, Vec::new(), Some(count), None, false, verbose).await?;
         migrate_up(config_path, Some(count), None, false, verbose).await?;
     } else {
-        migrate_reset(config_path, force, false, verbose).await?;
-        run(config_path, None, false, true, None).await?;
+        migrate_reset(config_path, Vec::new(), force, false, verbose).await?;
+        run(config_path, Vec::new(), false, true, true, None, None).await?;
     }
 
     if seed {
         print_info("Running seeders...");
-        crate::commands::db::seed(config_path, None, true, verbose).await?;
+        crate::commands::db::seed(config_path, None, None, None, true, false, verbose).await?;
     }
 
     print_success("Database refreshed successfully");
@@ -396,63 +892,173 @@ async fn migrate_refresh(
 }
 
 /// Show migration status
-async fn migration_status(config_path: &str, verbose: bool) -> Result<(), String> {
+async fn migration_status(
+    config_path: &str,
+    paths: Vec<String>,
+    fix_gaps: bool,
+    force: bool,
+    verbose: bool,
+) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
+    let migrations_paths = migrations_paths_or_default(&config, paths);
 
     if verbose {
         print_info("Checking migration status...");
     }
 
-    let all_migrations = get_all_migrations(&config.paths.migrations)?;
-    let ran_migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
+    let entries = migration_status_entries(&config, &migrations_paths).await?;
 
     println!("\n{}", "Migration Status:".cyan().bold());
     println!("{}", "─".repeat(60));
 
-    if all_migrations.is_empty() {
+    if entries.is_empty() {
         print_info("No migrations found");
         return Ok(());
     }
 
-    let ran_names: HashSet<_> = ran_migrations
-        .iter()
-        .map(|migration| migration.version.as_str())
-        .collect();
+    let gaps = gap_migrations(&entries);
 
-    for migration in &all_migrations {
-        let status = if ran_names.contains(migration.version.as_str()) {
-            "Ran".green()
+    println!("  {:<8} {:<6} Migration", "Status", "Batch");
+
+    for entry in &entries {
+        let status = if entry.ran {
+            format!("{:<8}", "Ran").green()
+        } else if gaps.contains(&entry.name.as_str()) {
+            format!("{:<8}", "Gap").red()
         } else {
-            "Pending".yellow()
+            format!("{:<8}", "Pending").yellow()
         };
-        println!("  {} {}", status, migration.file_name);
+        let batch = entry.batch.map_or_else(|| "-".to_string(), |batch| batch.to_string());
+        println!("  {} {:<6} {}", status, batch, entry.name);
     }
 
+    let ran = entries.iter().filter(|entry| entry.ran).count();
+
     println!("{}", "─".repeat(60));
     println!(
         "  Total: {} | Ran: {} | Pending: {}",
-        all_migrations.len(),
-        ran_migrations.len(),
-        all_migrations.len() - ran_migrations.len()
+        entries.len(),
+        ran,
+        entries.len() - ran
     );
 
-    Ok(())
+    if gaps.is_empty() {
+        if fix_gaps {
+            print_success("No gap migrations to run");
+        }
+        return Ok(());
+    }
+
+    print_warning(&gap_warning(&gaps));
+
+    if !fix_gaps {
+        print_info("Run 'tideorm migrate status --fix-gaps' to run them");
+        return Ok(());
+    }
+
+    if config.is_production() && !force {
+        return Err("Cannot run migrations in production without --force flag".to_string());
+    }
+
+    let gap_migrations: Vec<_> = get_all_migrations_from(&migrations_paths)?
+        .into_iter()
+        .filter(|migration| gaps.contains(&migration.file_name.as_str()))
+        .collect();
+    let batch = next_batch(&get_ran_migrations(&config, &migrations_paths).await?);
+
+    println!("\n{}", "Running gap migrations...".cyan());
+
+    run_batch(&config, &gap_migrations, batch).await
+}
+
+/// Pending migrations ordered before a migration that already ran
+///
+/// These are usually files merged from another branch with an older timestamp, which
+/// `migrate run` would still apply, just after the newer ones.
+fn gap_migrations(entries: &[MigrationStatusEntry]) -> Vec<&str> {
+    let Some(last_ran) = entries.iter().rposition(|entry| entry.ran) else {
+        return Vec::new();
+    };
+
+    entries[..last_ran]
+        .iter()
+        .filter(|entry| !entry.ran)
+        .map(|entry| entry.name.as_str())
+        .collect()
+}
+
+/// Warning listing the gap migrations found by `gap_migrations`
+fn gap_warning(gaps: &[&str]) -> String {
+    format!(
+        "{} pending migration(s) are older than migrations that already ran: {}",
+        gaps.len(),
+        gaps.join(", ")
+    )
+}
+
+/// Status of a single migration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatusEntry {
+    pub name: String,
+    pub ran: bool,
+    pub batch: Option<u32>,
+    pub ran_at: Option<String>,
+}
+
+/// Load the status of every migration file in `migrations_paths`
+pub async fn migration_status_entries(
+    config: &TideConfig,
+    migrations_paths: &[String],
+) -> Result<Vec<MigrationStatusEntry>, String> {
+    let all_migrations = get_all_migrations_from(migrations_paths)?;
+    let ran_migrations = get_ran_migrations(config, migrations_paths).await?;
+
+    Ok(status_entries(&all_migrations, &ran_migrations))
+}
+
+/// Pair migration files with the records of migrations that have run
+fn status_entries(all_migrations: &[Migration], ran_migrations: &[Migration]) -> Vec<MigrationStatusEntry> {
+    let ran_by_version: HashMap<_, _> = ran_migrations
+        .iter()
+        .map(|migration| (migration.version.as_str(), migration))
+        .collect();
+
+    all_migrations
+        .iter()
+        .map(|migration| {
+            let record = ran_by_version.get(migration.version.as_str());
+            MigrationStatusEntry {
+                name: migration.file_name.clone(),
+                ran: record.is_some(),
+                batch: record.and_then(|record| record.batch),
+                ran_at: record.and_then(|record| record.applied_at.clone()),
+            }
+        })
+        .collect()
 }
 
 /// Show migration history
-async fn migration_history(config_path: &str, limit: u32, verbose: bool) -> Result<(), String> {
+async fn migration_history(
+    config_path: &str,
+    limit: u32,
+    slow_threshold: Option<i64>,
+    verbose: bool,
+) -> Result<(), String> {
     let config = TideConfig::load(config_path)?;
 
     if verbose {
         print_info(&format!("Showing last {} migrations...", limit));
     }
 
-    let ran_migrations = get_ran_migrations(&config, &config.paths.migrations).await?;
+    let ran_migrations = get_ran_migrations(&config, &migrations_paths_or_default(&config, Vec::new())).await?;
 
     println!("\n{}", "Migration History:".cyan().bold());
-    println!("{}", "─".repeat(80));
-    println!("  {:<16} {:<40} {:<20}", "Version", "Migration", "Applied At");
-    println!("{}", "─".repeat(80));
+    println!("{}", "─".repeat(92));
+    println!(
+        "  {:<16} {:<40} {:<20} {:>10}",
+        "Version", "Migration", "Applied At", "Time (ms)"
+    );
+    println!("{}", "─".repeat(92));
 
     if ran_migrations.is_empty() {
         print_info("No migrations have been run");
@@ -460,19 +1066,40 @@ async fn migration_history(config_path: &str, limit: u32, verbose: bool) -> Resu
     }
 
     for migration in ran_migrations.iter().rev().take(limit as usize) {
-        println!(
-            "  {:<16} {:<40} {:<20}",
-            migration.version,
-            migration.file_name,
-            migration.applied_at.as_deref().unwrap_or("N/A")
-        );
+        println!("{}", history_row(migration, slow_threshold));
     }
 
-    println!("{}", "─".repeat(80));
+    println!("{}", "─".repeat(92));
 
     Ok(())
 }
 
+/// One `migrate history` line, with the time in red when it exceeds `slow_threshold`
+fn history_row(migration: &Migration, slow_threshold: Option<i64>) -> String {
+    let execution_ms = format!(
+        "{:>10}",
+        migration
+            .execution_ms
+            .map_or_else(|| "-".to_string(), |ms| ms.to_string())
+    );
+    let is_slow = matches!(
+        (migration.execution_ms, slow_threshold),
+        (Some(ms), Some(threshold)) if ms > threshold
+    );
+
+    format!(
+        "  {:<16} {:<40} {:<20} {}",
+        migration.version,
+        migration.file_name,
+        migration.applied_at.as_deref().unwrap_or("N/A"),
+        if is_slow {
+            execution_ms.red().bold().to_string()
+        } else {
+            execution_ms
+        }
+    )
+}
+
 // =============================================================================
 // HELPER TYPES AND FUNCTIONS
 // =============================================================================
@@ -485,7 +1112,24 @@ pub struct Migration {
     pub name: String,
     pub up_sql: String,
     pub down_sql: String,
+    /// False when the file declares `REQUIRES_TRANSACTION: bool = false`
+    pub requires_transaction: bool,
     pub applied_at: Option<String>,
+    /// Milliseconds `up()` took, when the run was recorded by this CLI
+    pub execution_ms: Option<i64>,
+    /// Run of `migrate run` the migration was applied in; `None` until it has run
+    pub batch: Option<u32>,
+    /// Directory the file was read from; empty for records whose file is gone
+    pub directory: String,
+}
+
+/// Directories given with `--path`, or `paths.migrations` when there are none
+fn migrations_paths_or_default(config: &TideConfig, paths: Vec<String>) -> Vec<String> {
+    if paths.is_empty() {
+        vec![config.paths.migrations.clone()]
+    } else {
+        paths
+    }
 }
 
 /// Get all migrations from the migrations directory
@@ -527,7 +1171,11 @@ fn get_all_migrations(migrations_path: &str) -> Result<Vec<Migration>, String> {
                 name: logical_name,
                 up_sql,
                 down_sql,
+                requires_transaction: parse_requires_transaction(&content),
                 applied_at: None,
+                execution_ms: None,
+                batch: None,
+                directory: migrations_path.to_string(),
             });
         }
     }
@@ -537,13 +1185,38 @@ fn get_all_migrations(migrations_path: &str) -> Result<Vec<Migration>, String> {
     Ok(migrations)
 }
 
+/// Migrations of several directories, in file name order
+///
+/// Timestamp prefixes make that the order they were written in across directories. A file
+/// name found again in a later directory is skipped with a warning.
+fn get_all_migrations_from(migrations_paths: &[String]) -> Result<Vec<Migration>, String> {
+    let mut migrations: Vec<Migration> = Vec::new();
+
+    for migrations_path in migrations_paths {
+        for migration in get_all_migrations(migrations_path)? {
+            match migrations.iter().find(|seen| seen.file_name == migration.file_name) {
+                Some(seen) => print_warning(&format!(
+                    "Skipping {} in {}; it is already in {}",
+                    migration.file_name, migrations_path, seen.directory
+                )),
+                None => migrations.push(migration),
+            }
+        }
+    }
+
+    migrations.sort_by(|left, right| left.file_name.cmp(&right.file_name));
+
+    Ok(migrations)
+}
+
 /// Get pending migrations (not yet run)
 async fn get_pending_migrations(
     config: &TideConfig,
-    migrations_path: &str,
+    migrations_paths: &[String],
 ) -> Result<Vec<Migration>, String> {
-    let all = get_all_migrations(migrations_path)?;
-    let ran = get_ran_migrations(config, migrations_path).await?;
+    let all = get_all_migrations_from(migrations_paths)?;
+    // Only the recorded versions matter here, which don't depend on the directory
+    let ran = get_ran_migrations(config, &migrations_paths_or_default(config, Vec::new())).await?;
     let ran_versions: HashSet<_> = ran.iter().map(|migration| migration.version.as_str()).collect();
 
     Ok(all
@@ -552,15 +1225,17 @@ async fn get_pending_migrations(
         .collect())
 }
 
-/// Get migrations that have been run
+/// Get migrations that have been run, with their SQL read from the files in `migrations_paths`
+///
+/// Records without a file there keep empty SQL, so pass the directories `migrate run` used.
 async fn get_ran_migrations(
     config: &TideConfig,
-    migrations_path: &str,
+    migrations_paths: &[String],
 ) -> Result<Vec<Migration>, String> {
     runtime_db::ensure_migration_table(config, &config.migration.table).await?;
     let db = runtime_db::connect(config).await?;
 
-    let all_migrations = get_all_migrations(migrations_path)?;
+    let all_migrations = get_all_migrations_from(migrations_paths)?;
     let all_by_name: HashMap<_, _> = all_migrations
         .into_iter()
         .map(|migration| (migration.version.clone(), migration))
@@ -603,7 +1278,11 @@ async fn get_ran_migrations(
             name,
             up_sql: String::new(),
             down_sql: String::new(),
+            requires_transaction: true,
             applied_at: None,
+            execution_ms: None,
+            batch: None,
+            directory: String::new(),
         });
 
         if migration.name.is_empty() {
@@ -611,6 +1290,12 @@ async fn get_ran_migrations(
         }
 
         migration.applied_at = applied_at;
+        migration.execution_ms = row.try_get::<Option<i64>>("", "execution_ms").ok().flatten();
+        migration.batch = row
+            .try_get::<Option<i32>>("", "batch")
+            .ok()
+            .flatten()
+            .and_then(|batch| u32::try_from(batch).ok());
         migrations.push(migration);
     }
 
@@ -622,20 +1307,22 @@ fn migration_records_query(config: &TideConfig, table_name: &str) -> String {
     let version = quoted_identifier(config, "version");
     let name = quoted_identifier(config, "name");
     let applied_at = quoted_identifier(config, "applied_at");
+    let execution_ms = quoted_identifier(config, "execution_ms");
+    let batch = quoted_identifier(config, "batch");
     let applied_at_expr = match config.database.driver.as_str() {
         "mysql" => format!("CAST({} AS CHAR) AS {}", applied_at, applied_at),
         _ => format!("CAST({} AS TEXT) AS {}", applied_at, applied_at),
     };
 
     format!(
-        "SELECT {}, {}, {} FROM {} ORDER BY {} ASC",
-        version, name, applied_at_expr, table, version
+        "SELECT {}, {}, {}, {}, {} FROM {} ORDER BY {} ASC",
+        version, name, applied_at_expr, execution_ms, batch, table, version
     )
 }
 
 /// Find a specific migration
-fn find_migration(migrations_path: &str, name: &str) -> Result<Migration, String> {
-    let migrations = get_all_migrations(migrations_path)?;
+fn find_migration(migrations_paths: &[String], name: &str) -> Result<Migration, String> {
+    let migrations = get_all_migrations_from(migrations_paths)?;
 
     migrations
         .into_iter()
@@ -647,7 +1334,7 @@ fn find_migration(migrations_path: &str, name: &str) -> Result<Migration, String
         .ok_or_else(|| format!("Migration not found: {}", name))
 }
 
-fn parse_migration_metadata(file_name: &str, content: &str) -> (String, String) {
+pub(crate) fn parse_migration_metadata(file_name: &str, content: &str) -> (String, String) {
     let version_pattern = regex::Regex::new(r#"fn\s+version\s*\([^)]*\)\s*->\s*&str\s*\{\s*\"([^\"]+)\""#)
         .unwrap();
     let name_pattern = regex::Regex::new(r#"fn\s+name\s*\([^)]*\)\s*->\s*&str\s*\{\s*\"([^\"]+)\""#)
@@ -679,39 +1366,274 @@ fn split_file_name(file_name: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Whether a migration opts out of running inside a transaction, as concurrent
+/// Postgres index migrations do
+fn parse_requires_transaction(content: &str) -> bool {
+    let pattern = regex::Regex::new(r"\bREQUIRES_TRANSACTION\s*:\s*bool\s*=\s*false\b").unwrap();
+    !pattern.is_match(content)
+}
+
 /// Parse migration file content to extract up/down SQL
-fn parse_migration_content(content: &str) -> (String, String) {
-    let mut up_sql = String::new();
-    let mut down_sql = String::new();
+pub(crate) fn parse_migration_content(content: &str) -> (String, String) {
+    let up_sql = function_body(content, "up")
+        .map(extract_sql_from_method)
+        .unwrap_or_default();
+    let down_sql = function_body(content, "down")
+        .map(extract_sql_from_method)
+        .unwrap_or_default();
 
-    let up_pattern = regex::Regex::new(r#"fn\s+up\s*\([^)]*\)[^{]*\{([\s\S]*?)\n\s*\}"#).unwrap();
-    let down_pattern = regex::Regex::new(r#"fn\s+down\s*\([^)]*\)[^{]*\{([\s\S]*?)\n\s*\}"#).unwrap();
+    (up_sql, down_sql)
+}
 
-    if let Some(captures) = up_pattern.captures(content) {
-        up_sql = extract_sql_from_method(&captures[1]);
-    }
+/// Find the body of `fn {name}(...)` by counting braces
+///
+/// Braces inside comments, string, raw string and char literals are ignored.
+fn function_body<'a>(content: &'a str, name: &str) -> Option<&'a str> {
+    let signature = regex::Regex::new(&format!(r"^fn\s+{}\s*\(", regex::escape(name))).unwrap();
+    let bytes = content.as_bytes();
+    let mut index = 0;
+    let mut in_function = false;
+    let mut depth = 0usize;
+    let mut body_start = 0;
+
+    while index < bytes.len() {
+        if let Some(end) = skip_literal_or_comment(content, index) {
+            index = end;
+            continue;
+        }
 
-    if let Some(captures) = down_pattern.captures(content) {
-        down_sql = extract_sql_from_method(&captures[1]);
+        match bytes[index] {
+            b'f' if !in_function && (index == 0 || !is_ident_byte(bytes[index - 1])) => {
+                if let Some(found) = signature.find(&content[index..]) {
+                    in_function = true;
+                    index += found.end();
+                    continue;
+                }
+            }
+            b'{' if in_function => {
+                if depth == 0 {
+                    body_start = index + 1;
+                }
+                depth += 1;
+            }
+            b'}' if in_function && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&content[body_start..index]);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
     }
 
-    (up_sql, down_sql)
+    None
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// End of the comment or literal starting at `index`, if one starts there
+fn skip_literal_or_comment(content: &str, index: usize) -> Option<usize> {
+    let bytes = content.as_bytes();
+
+    match bytes[index] {
+        b'/' if bytes.get(index + 1) == Some(&b'/') => {
+            Some(content[index..].find('\n').map_or(bytes.len(), |offset| index + offset))
+        }
+        b'/' if bytes.get(index + 1) == Some(&b'*') => Some(skip_block_comment(bytes, index)),
+        b'"' => Some(skip_string_literal(bytes, index)),
+        b'r' if is_raw_string_start(bytes, index) => Some(skip_raw_string_literal(bytes, index)),
+        b'\'' => Some(skip_char_literal(content, index)),
+        _ => None,
+    }
+}
+
+/// Skip a (possibly nested) block comment starting at `start`
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 2;
+    let mut depth = 1;
+
+    while index < bytes.len() && depth > 0 {
+        if bytes[index] == b'/' && bytes.get(index + 1) == Some(&b'*') {
+            depth += 1;
+            index += 2;
+        } else if bytes[index] == b'*' && bytes.get(index + 1) == Some(&b'/') {
+            depth -= 1;
+            index += 2;
+        } else {
+            index += 1;
+        }
+    }
+
+    index
+}
+
+/// Skip a string literal whose opening quote is at `start`
+fn skip_string_literal(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+
+    index
+}
+
+/// Whether `r` at `start` opens a raw string rather than ending an identifier
+fn is_raw_string_start(bytes: &[u8], start: usize) -> bool {
+    // Allow the `b` of a raw byte string (`br"..."`)
+    let prefix_start = if start > 0 && bytes[start - 1] == b'b' { start - 1 } else { start };
+    if prefix_start > 0 && is_ident_byte(bytes[prefix_start - 1]) {
+        return false;
+    }
+
+    let hashes = bytes[start + 1..].iter().take_while(|byte| **byte == b'#').count();
+    bytes.get(start + 1 + hashes) == Some(&b'"')
+}
+
+/// Skip a raw string literal such as `r#"..."#` starting at `start`
+fn skip_raw_string_literal(bytes: &[u8], start: usize) -> usize {
+    let hashes = bytes[start + 1..].iter().take_while(|byte| **byte == b'#').count();
+    let mut index = start + hashes + 2;
+
+    while index < bytes.len() {
+        if bytes[index] == b'"'
+            && bytes[index + 1..].iter().take(hashes).filter(|byte| **byte == b'#').count() == hashes
+        {
+            return index + 1 + hashes;
+        }
+        index += 1;
+    }
+
+    index
+}
+
+/// Skip a char literal at `start`, or just the quote when it starts a lifetime
+fn skip_char_literal(content: &str, start: usize) -> usize {
+    let bytes = content.as_bytes();
+
+    if bytes.get(start + 1) == Some(&b'\\') {
+        return bytes[start + 2..]
+            .iter()
+            .position(|byte| *byte == b'\'')
+            .map_or(bytes.len(), |offset| start + 3 + offset);
+    }
+
+    match content[start + 1..].chars().next() {
+        Some(ch) if bytes.get(start + 1 + ch.len_utf8()) == Some(&b'\'') => start + 2 + ch.len_utf8(),
+        _ => start + 1,
+    }
 }
 
 /// Extract SQL from method body
 fn extract_sql_from_method(method_body: &str) -> String {
-    let sql_pattern = regex::Regex::new(r##"r#?\"([^\"]*)\"#?"##).unwrap();
-    let mut sqls = Vec::new();
+    MigrationSqlExtractor::default().scan(method_body).finish()
+}
+
+/// Collects the SQL statements of a migration method, in source order
+///
+/// Statements are taken from raw string literals such as `schema.raw(r#"..."#)`,
+/// and from quoted strings passed to `sqlx::query!("...")` or `.execute("...")`.
+/// Literals inside comments are ignored.
+#[derive(Debug, Default)]
+struct MigrationSqlExtractor {
+    statements: Vec<String>,
+}
+
+impl MigrationSqlExtractor {
+    /// Call prefixes whose quoted string argument is SQL
+    const QUERY_CALLS: [&'static str; 2] = ["sqlx::query!", ".execute"];
+
+    /// Add every statement found in `body`
+    fn scan(mut self, body: &str) -> Self {
+        let bytes = body.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            let Some(end) = skip_literal_or_comment(body, index) else {
+                index += 1;
+                continue;
+            };
+
+            match bytes[index] {
+                b'r' => {
+                    let hashes = bytes[index + 1..].iter().take_while(|byte| **byte == b'#').count();
+                    if let Some(sql) = body.get(index + hashes + 2..end.saturating_sub(hashes + 1)) {
+                        self.push(sql);
+                    }
+                }
+                b'"' if Self::is_query_argument(&body[..index]) => {
+                    if let Some(sql) = body.get(index + 1..end.saturating_sub(1)) {
+                        self.push(&unescape_string_literal(sql));
+                    }
+                }
+                _ => {}
+            }
+
+            index = end.max(index + 1);
+        }
+
+        self
+    }
 
-    for captures in sql_pattern.captures_iter(method_body) {
-        sqls.push(captures[1].to_string());
+    /// Whether the text before a string literal ends in `sqlx::query!(` or `.execute(`
+    fn is_query_argument(before: &str) -> bool {
+        before
+            .trim_end()
+            .strip_suffix('(')
+            .map(str::trim_end)
+            .is_some_and(|call| Self::QUERY_CALLS.iter().any(|prefix| call.ends_with(prefix)))
     }
 
-    sqls.join("\n")
+    fn push(&mut self, sql: &str) {
+        let sql = sql.trim().trim_end_matches(';').trim_end();
+        if !sql.is_empty() {
+            self.statements.push(sql.to_string());
+        }
+    }
+
+    /// All statements joined with `;\n`
+    fn finish(self) -> String {
+        self.statements.join(";\n")
+    }
+}
+
+/// Resolve the escapes of a quoted Rust string literal's contents
+fn unescape_string_literal(literal: &str) -> String {
+    let mut unescaped = String::with_capacity(literal.len());
+    let mut chars = literal.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('0') => unescaped.push('\0'),
+            // A trailing backslash continues the line and skips the next line's indentation
+            Some('\n') => {
+                while chars.next_if(|next| next.is_whitespace()).is_some() {}
+            }
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
 }
 
-/// Run a migration up
-async fn run_migration_up(config: &TideConfig, migration: &Migration) -> Result<(), String> {
+/// Run a migration up, recording it as part of `batch`
+async fn run_migration_up(config: &TideConfig, migration: &Migration, batch: u32) -> Result<(), String> {
     let up_sql = migration.up_sql.trim();
     if up_sql.is_empty() {
         return Err(format!(
@@ -724,23 +1646,47 @@ async fn run_migration_up(config: &TideConfig, migration: &Migration) -> Result<
     runtime_db::ensure_migration_table_on_db(&db, config, &config.migration.table).await?;
     let up_sql = up_sql.to_string();
     let insert_sql = format!(
-        "INSERT INTO {} ({}, {}) VALUES ({}, {})",
+        "INSERT INTO {} ({}, {}, {}) VALUES ({}, {}, {})",
         quoted_identifier(config, &config.migration.table),
         quoted_identifier(config, "version"),
         quoted_identifier(config, "name"),
+        quoted_identifier(config, "batch"),
         sql_string(&migration.version),
-        sql_string(&migration.name)
+        sql_string(&migration.name),
+        batch
     );
 
-    db.transaction(|tx| {
-        Box::pin(async move {
-            execute_on_transaction(tx.connection(), &up_sql).await?;
-            execute_on_transaction(tx.connection(), &insert_sql).await?;
-            Ok(())
+    let started = Instant::now();
+    if migration.requires_transaction {
+        db.transaction(|tx| {
+            Box::pin(async move {
+                execute_on_transaction(tx.connection(), &up_sql).await?;
+                execute_on_transaction(tx.connection(), &insert_sql).await?;
+                Ok(())
+            })
         })
-    })
-    .await
-    .map_err(|error| error.to_string())
+        .await
+        .map_err(|error| error.to_string())?;
+    } else {
+        execute_without_transaction(&db, &up_sql).await?;
+        runtime_db::execute_on_db(&db, &insert_sql).await?;
+    }
+    let execution_ms = started.elapsed().as_millis() as i64;
+
+    runtime_db::execute_on_db(
+        &db,
+        &format!(
+            "UPDATE {} SET {} = {} WHERE {} = {}",
+            quoted_identifier(config, &config.migration.table),
+            quoted_identifier(config, "execution_ms"),
+            execution_ms,
+            quoted_identifier(config, "version"),
+            sql_string(&migration.version)
+        ),
+    )
+    .await?;
+
+    Ok(())
 }
 
 /// Run a migration down
@@ -763,6 +1709,11 @@ async fn run_migration_down(config: &TideConfig, migration: &Migration) -> Resul
         sql_string(&migration.version)
     );
 
+    if !migration.requires_transaction {
+        execute_without_transaction(&db, &down_sql).await?;
+        return runtime_db::execute_on_db(&db, &delete_sql).await.map(|_| ());
+    }
+
     db.transaction(|tx| {
         Box::pin(async move {
             execute_on_transaction(tx.connection(), &down_sql).await?;
@@ -774,9 +1725,51 @@ async fn run_migration_down(config: &TideConfig, migration: &Migration) -> Resul
     .map_err(|error| error.to_string())
 }
 
-/// Drop all tables in the database
-async fn drop_all_tables(config: &TideConfig) -> Result<(), String> {
-    runtime_db::wipe_tables(config, true).await
+/// Drop all tables in the database, dependent tables first
+///
+/// The migrations table is kept but emptied unless `drop_tracking_table` is set.
+/// Every table is attempted before any failures are reported.
+async fn drop_all_tables(config: &TideConfig, drop_tracking_table: bool) -> Result<usize, String> {
+    let tables = runtime_db::list_tables(config).await?;
+    let keep_tracking = !drop_tracking_table && tables.contains(&config.migration.table);
+    let tables: Vec<String> = tables
+        .into_iter()
+        .filter(|table| {
+            drop_tracking_table
+                || (table != &config.migration.table && table != runtime_db::SCHEMA_VERSION_TABLE)
+        })
+        .collect();
+
+    let mut ordered = crate::commands::db::tables_in_dependency_order(config, &tables).await?;
+    ordered.reverse();
+
+    let failures = runtime_db::drop_tables(config, &ordered).await?;
+    runtime_db::drop_enum_types(config).await?;
+
+    if keep_tracking {
+        runtime_db::clear_table(config, &config.migration.table).await?;
+    }
+
+    if !failures.is_empty() {
+        let details = failures
+            .iter()
+            .map(|(table, error)| format!("  {}: {}", table, error))
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(format!("Failed to drop {} table(s):\n{}", failures.len(), details));
+    }
+
+    Ok(ordered.len())
+}
+
+/// Run each statement on its own; Postgres wraps a multi-statement query in an
+/// implicit transaction, which CREATE INDEX CONCURRENTLY refuses to run in
+async fn execute_without_transaction(db: &tideorm::Database, sql: &str) -> Result<(), String> {
+    for statement in sql.split(";\n").filter(|statement| !statement.trim().is_empty()) {
+        runtime_db::execute_on_db(db, statement).await?;
+    }
+
+    Ok(())
 }
 
 async fn execute_on_transaction<C>(connection: &C, sql: &str) -> tideorm::Result<()>
@@ -803,24 +1796,422 @@ fn sql_string(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{get_pending_migrations, get_ran_migrations, run, run_migration_down};
+    use super::{
+        drop_all_tables, function_body, gap_migrations, generate_from_diff, gap_warning, get_all_migrations_from,
+        get_pending_migrations, get_ran_migrations, history_row, is_migration_file, latest_batch_size, migrate_down,
+        migrate_reset, migration_status_entries, next_batch, parse_migration_content, rollback_order, run,
+        run_migration_down, status_entries, steps_to_migration, Migration, MigrationSqlExtractor,
+        MigrationStatusEntry, WatchDebouncer,
+    };
     use crate::config::TideConfig;
+    use crate::runtime_db;
+    use colored::Colorize;
     use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant};
     use tempfile::TempDir;
 
+    #[test]
+    fn steps_to_migration_counts_migrations_ran_after_target() {
+        let migration = |version: &str, name: &str, batch: u32| Migration {
+            file_name: format!("{}_{}", version, name),
+            version: version.to_string(),
+            name: name.to_string(),
+            up_sql: String::new(),
+            down_sql: String::new(),
+            requires_transaction: true,
+            applied_at: None,
+            execution_ms: None,
+            batch: Some(batch),
+            directory: String::new(),
+        };
+        let ran = rollback_order(vec![
+            migration("20240101000000", "create_users", 1),
+            migration("20240201000000", "create_posts", 2),
+            migration("20240301000000", "add_avatar_to_users", 3),
+        ]);
+
+        assert_eq!(steps_to_migration(&ran, "create_users").unwrap(), 2);
+        assert_eq!(steps_to_migration(&ran, "20240201000000").unwrap(), 1);
+        assert_eq!(steps_to_migration(&ran, "20240301000000_add_avatar_to_users").unwrap(), 0);
+        assert_eq!(
+            steps_to_migration(&ran, "create_comments").unwrap_err(),
+            "Migration has not been run: create_comments"
+        );
+        assert!(steps_to_migration(&[], "create_users").is_err());
+
+        // create_posts ran last, so rolling back to add_avatar_to_users only undoes it
+        let merged = rollback_order(vec![
+            migration("20240101000000", "create_users", 1),
+            migration("20240201000000", "create_posts", 2),
+            migration("20240301000000", "add_avatar_to_users", 1),
+        ]);
+        assert_eq!(steps_to_migration(&merged, "add_avatar_to_users").unwrap(), 1);
+        assert_eq!(steps_to_migration(&merged, "create_posts").unwrap(), 0);
+        assert_eq!(steps_to_migration(&merged, "create_users").unwrap(), 2);
+    }
+
+    #[test]
+    fn batches_continue_from_the_highest_recorded_batch() {
+        let migration = |version: &str, batch: Option<u32>| Migration {
+            file_name: format!("{}_example", version),
+            version: version.to_string(),
+            name: "example".to_string(),
+            up_sql: String::new(),
+            down_sql: String::new(),
+            requires_transaction: true,
+            applied_at: None,
+            execution_ms: None,
+            batch,
+            directory: String::new(),
+        };
+
+        assert_eq!(next_batch(&[]), 1);
+        assert_eq!(latest_batch_size(&[]), 0);
+
+        let ran = vec![
+            migration("20240101000000", None),
+            migration("20240201000000", Some(1)),
+            migration("20240301000000", Some(2)),
+            migration("20240401000000", Some(2)),
+        ];
+        assert_eq!(next_batch(&ran), 3);
+        assert_eq!(latest_batch_size(&ran), 2);
+
+        // Records from before batches were tracked roll back one at a time
+        assert_eq!(next_batch(&ran[..1]), 1);
+        assert_eq!(latest_batch_size(&ran[..1]), 1);
+
+        // An older file that ran in a later batch rolls back first
+        let merged = vec![
+            migration("20240101000000", Some(1)),
+            migration("20240201000000", Some(2)),
+            migration("20240301000000", Some(1)),
+            migration("20231201000000", None),
+        ];
+        assert_eq!(latest_batch_size(&merged), 1);
+        let order: Vec<_> = rollback_order(merged)
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+        assert_eq!(order, vec!["20240201000000", "20240301000000", "20240101000000", "20231201000000"]);
+    }
+
+    #[test]
+    fn gap_migrations_are_pending_migrations_before_the_last_ran_one() {
+        let entry = |name: &str, ran: bool| MigrationStatusEntry {
+            name: name.to_string(),
+            ran,
+            batch: ran.then_some(1),
+            ran_at: None,
+        };
+
+        let entries = vec![
+            entry("20240101000000_create_users", true),
+            entry("20240115000000_add_email_to_users", false),
+            entry("20240201000000_create_posts", true),
+            entry("20240210000000_add_slug_to_posts", false),
+            entry("20240301000000_create_comments", true),
+            entry("20240315000000_create_tags", false),
+        ];
+
+        let gaps = gap_migrations(&entries);
+        assert_eq!(gaps, ["20240115000000_add_email_to_users", "20240210000000_add_slug_to_posts"]);
+        assert_eq!(
+            gap_warning(&gaps),
+            "2 pending migration(s) are older than migrations that already ran: \
+             20240115000000_add_email_to_users, 20240210000000_add_slug_to_posts"
+        );
+
+        // Migrations after the last ran one are plain pending migrations
+        assert!(gap_migrations(&entries[..2]).is_empty());
+        assert_eq!(gap_migrations(&entries[3..]), ["20240210000000_add_slug_to_posts"]);
+    }
+
+    #[test]
+    fn migrations_of_several_directories_run_in_file_name_order() {
+        let app = TempDir::new().expect("temp dir should be created");
+        let billing = TempDir::new().expect("temp dir should be created");
+        for (dir, file_name) in [
+            (&app, "20240101000000_create_users"),
+            (&app, "20240301000000_add_avatar_to_users"),
+            (&billing, "20240201000000_create_invoices"),
+            (&billing, "20240401000000_create_payments"),
+            // Already in the app directory, so this copy is skipped
+            (&billing, "20240101000000_create_users"),
+        ] {
+            fs::write(dir.path().join(format!("{}.rs", file_name)), "").expect("migration should be written");
+        }
+        fs::write(billing.path().join("mod.rs"), "").expect("mod.rs should be written");
+
+        let app_path = slash_path(app.path());
+        let billing_path = slash_path(billing.path());
+        let migrations = get_all_migrations_from(&[app_path.clone(), billing_path.clone()]).unwrap();
+
+        assert_eq!(
+            migrations
+                .iter()
+                .map(|migration| (migration.file_name.as_str(), migration.directory.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("20240101000000_create_users", app_path.as_str()),
+                ("20240201000000_create_invoices", billing_path.as_str()),
+                ("20240301000000_add_avatar_to_users", app_path.as_str()),
+                ("20240401000000_create_payments", billing_path.as_str()),
+            ]
+        );
+    }
+
+    #[test]
+    fn status_entries_mark_ran_migrations_with_their_timestamp() {
+        let migration = |version: &str, applied_at: Option<&str>| Migration {
+            file_name: format!("{}_example", version),
+            version: version.to_string(),
+            name: "example".to_string(),
+            up_sql: String::new(),
+            down_sql: String::new(),
+            requires_transaction: true,
+            applied_at: applied_at.map(str::to_string),
+            execution_ms: None,
+            batch: applied_at.map(|_| 2),
+            directory: String::new(),
+        };
+
+        let all = vec![migration("20240101000000", None), migration("20240201000000", None)];
+        let ran = vec![
+            migration("20240101000000", Some("2024-01-01 10:00:00")),
+            migration("20231201000000", Some("2023-12-01 10:00:00")),
+        ];
+
+        let entries = status_entries(&all, &ran);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "20240101000000_example");
+        assert!(entries[0].ran);
+        assert_eq!(entries[0].ran_at.as_deref(), Some("2024-01-01 10:00:00"));
+        assert_eq!(entries[0].batch, Some(2));
+        assert!(!entries[1].ran);
+        assert_eq!(entries[1].ran_at, None);
+        assert_eq!(entries[1].batch, None);
+    }
+
+    #[test]
+    fn parse_migration_content_separates_raw_statements() {
+        let content = r##"
+    async fn up(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT)"#).await?;
+        schema.raw(r#"CREATE UNIQUE INDEX IF NOT EXISTS users_email_idx ON users (email);"#).await?;
+        Ok(())
+    }
+
+    async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"DROP INDEX IF EXISTS users_email_idx"#).await?;
+        schema.raw(r#"DROP TABLE IF EXISTS users"#).await?;
+        Ok(())
+    }
+"##;
+
+        let (up_sql, down_sql) = parse_migration_content(content);
+
+        assert_eq!(
+            up_sql,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT);\nCREATE UNIQUE INDEX IF NOT EXISTS users_email_idx ON users (email)"
+        );
+        assert_eq!(down_sql, "DROP INDEX IF EXISTS users_email_idx;\nDROP TABLE IF EXISTS users");
+    }
+
+    #[test]
+    fn parse_migration_content_handles_nested_blocks_and_closures() {
+        let content = r##"
+    async fn up(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        let tables = ["users"].iter().map(|table| {
+            format!("{}_archive", table)
+        }).collect::<Vec<_>>();
+        if tables.is_empty() {
+            return Ok(());
+        }
+        schema.raw(r#"CREATE TABLE users_archive (id INTEGER PRIMARY KEY)"#).await?;
+        Ok(())
+    }
+
+    async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"DROP TABLE IF EXISTS users_archive"#).await?;
+        Ok(())
+    }
+"##;
+
+        let (up_sql, down_sql) = parse_migration_content(content);
+
+        assert_eq!(up_sql, "CREATE TABLE users_archive (id INTEGER PRIMARY KEY)");
+        assert_eq!(down_sql, "DROP TABLE IF EXISTS users_archive");
+    }
+
+    #[test]
+    fn parse_migration_content_ignores_braces_in_literals_and_comments() {
+        let content = r###"
+    async fn up(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        let _closing = "}";
+        let _escaped = "\"}\"";
+        let _brace = '}';
+        let _label: &'static str = "ok";
+        // a stray } in a comment
+        /* and { another /* nested */ one } here */
+        schema.raw(r#"
+            CREATE TABLE settings (
+                data TEXT NOT NULL DEFAULT '{
+        }'
+            )
+        "#).await?;
+        Ok(())
+    }
+
+    async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"DROP TABLE IF EXISTS settings"#).await?;
+        Ok(())
+    }
+"###;
+
+        let (up_sql, down_sql) = parse_migration_content(content);
+
+        assert!(up_sql.starts_with("CREATE TABLE settings ("));
+        assert!(up_sql.contains("DEFAULT '{\n        }'"));
+        assert!(up_sql.ends_with(")"));
+        assert_eq!(down_sql, "DROP TABLE IF EXISTS settings");
+    }
+
+    #[test]
+    fn sql_extractor_reads_raw_strings() {
+        let body = r###"
+        schema.raw(r#"CREATE TABLE users (id INTEGER PRIMARY KEY)"#).await?;
+        schema.raw(r##"INSERT INTO users (id) VALUES (1) -- "#"##).await?;
+        // schema.raw(r#"DROP TABLE users"#).await?;
+        let _user = "not sql";
+"###;
+
+        assert_eq!(
+            MigrationSqlExtractor::default().scan(body).finish(),
+            "CREATE TABLE users (id INTEGER PRIMARY KEY);\nINSERT INTO users (id) VALUES (1) -- \"#"
+        );
+    }
+
+    #[test]
+    fn sql_extractor_reads_quoted_strings_in_query_macros() {
+        let content = r#"
+    async fn up(&self, conn: &mut PgConnection) -> sqlx::Result<()> {
+        sqlx::query!("CREATE TABLE posts (title TEXT DEFAULT 'a \"b\"')").execute(&mut *conn).await?;
+        sqlx::query!(
+            "CREATE INDEX posts_title_idx \
+             ON posts (title);"
+        )
+        .execute(&mut *conn)
+        .await?;
+        let _label = format!("{}_archive", "posts");
+        Ok(())
+    }
+
+    async fn down(&self, conn: &mut PgConnection) -> sqlx::Result<()> {
+        sqlx::query!("DROP TABLE IF EXISTS posts").execute(&mut *conn).await?;
+        Ok(())
+    }
+"#;
+
+        let (up_sql, down_sql) = parse_migration_content(content);
+
+        assert_eq!(
+            up_sql,
+            "CREATE TABLE posts (title TEXT DEFAULT 'a \"b\"');\nCREATE INDEX posts_title_idx ON posts (title)"
+        );
+        assert_eq!(down_sql, "DROP TABLE IF EXISTS posts");
+    }
+
+    #[test]
+    fn sql_extractor_reads_chained_execute_calls_in_source_order() {
+        let body = r###"
+        ctx.execute("CREATE TABLE tags (id INTEGER PRIMARY KEY)").await?;
+        schema.raw(r#"CREATE TABLE post_tags (tag_id INTEGER)"#).await?;
+        ctx
+            .execute( "CREATE INDEX post_tags_tag_id_idx ON post_tags (tag_id)" )
+            .await?;
+        tracing::info!("created tags");
+"###;
+
+        assert_eq!(
+            MigrationSqlExtractor::default().scan(body).finish(),
+            "CREATE TABLE tags (id INTEGER PRIMARY KEY);\nCREATE TABLE post_tags (tag_id INTEGER);\nCREATE INDEX post_tags_tag_id_idx ON post_tags (tag_id)"
+        );
+    }
+
+    #[test]
+    fn function_body_matches_exact_name_and_skips_raw_strings_with_hashes() {
+        let content = r###"
+    fn update(&self) { let _ = "fn up("; }
+    fn up(&self) -> Result<(), String> {
+        let _sql = r##"SELECT '}"#' AS value"##;
+        Ok(())
+    }
+"###;
+
+        let body = function_body(content, "up").unwrap();
+
+        assert!(body.contains("SELECT '}\"#' AS value"));
+        assert!(body.trim_end().ends_with("Ok(())"));
+        assert!(function_body(content, "down").is_none());
+    }
+
+    #[test]
+    fn watch_debouncer_waits_for_quiet_period() {
+        let mut debouncer = WatchDebouncer::new(Duration::from_millis(200));
+        let start = Instant::now();
+        let file = PathBuf::from("src/migrations/20260321171859_create_users_table.rs");
+
+        debouncer.record(file.clone(), start);
+        assert!(debouncer.take_ready(start + Duration::from_millis(100)).is_empty());
+
+        // A second write restarts the quiet period
+        debouncer.record(file.clone(), start + Duration::from_millis(150));
+        assert!(debouncer.take_ready(start + Duration::from_millis(250)).is_empty());
+
+        assert_eq!(
+            debouncer.take_ready(start + Duration::from_millis(350)),
+            vec![file]
+        );
+        assert!(debouncer.take_ready(start + Duration::from_millis(1000)).is_empty());
+    }
+
+    #[test]
+    fn watch_debouncer_releases_files_independently() {
+        let mut debouncer = WatchDebouncer::new(Duration::from_millis(200));
+        let start = Instant::now();
+        let first = PathBuf::from("a.rs");
+        let second = PathBuf::from("b.rs");
+
+        debouncer.record(first.clone(), start);
+        debouncer.record(second.clone(), start + Duration::from_millis(150));
+
+        assert_eq!(debouncer.take_ready(start + Duration::from_millis(200)), vec![first]);
+        assert_eq!(debouncer.take_ready(start + Duration::from_millis(350)), vec![second]);
+    }
+
+    #[test]
+    fn watcher_only_considers_migration_sources() {
+        assert!(is_migration_file(Path::new("src/migrations/20260321171859_create_users_table.rs")));
+        assert!(!is_migration_file(Path::new("src/migrations/mod.rs")));
+        assert!(!is_migration_file(Path::new("src/migrations/notes.md")));
+    }
+
     #[tokio::test]
     async fn run_tracks_applied_migrations_and_skips_them_later() {
         let fixture = TestProject::new();
 
-        run(fixture.config_path(), None, false, true, None)
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
             .await
             .expect("first migration run should succeed");
 
         let config = TideConfig::load(fixture.config_path()).expect("config should load");
-        let ran = get_ran_migrations(&config, fixture.migrations_path())
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("ran migrations should load");
-        let pending = get_pending_migrations(&config, fixture.migrations_path())
+        let pending = get_pending_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("pending migrations should load");
 
@@ -829,26 +2220,218 @@ mod tests {
         assert_eq!(ran[0].file_name, "20260321171859_create_users_table");
         assert!(pending.is_empty());
 
-        run(fixture.config_path(), None, false, true, None)
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
             .await
             .expect("second migration run should succeed");
 
-        let pending_after_second_run = get_pending_migrations(&config, fixture.migrations_path())
+        let pending_after_second_run = get_pending_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("pending migrations should still be empty");
         assert!(pending_after_second_run.is_empty());
     }
 
+    #[tokio::test]
+    async fn run_records_execution_time_of_each_migration() {
+        let fixture = TestProject::new();
+
+        let started = Instant::now();
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+        let wall_ms = started.elapsed().as_millis() as i64;
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+
+        let execution_ms = ran[0].execution_ms.expect("execution time should be recorded");
+        assert!((0..=wall_ms).contains(&execution_ms), "{} not within 0..={}", execution_ms, wall_ms);
+    }
+
+    #[tokio::test]
+    async fn each_run_records_a_new_batch_and_down_rolls_back_the_latest() {
+        let fixture = TestProject::new();
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("first migration run should succeed");
+
+        for (version, table) in [("20260322090000", "posts"), ("20260322090100", "tags")] {
+            fs::write(
+                Path::new(fixture.migrations_path()).join(format!("{}_create_{}_table.rs", version, table)),
+                TEST_MIGRATION
+                    .replace("20260321171859", version)
+                    .replace("users", table),
+            )
+            .expect("migration should be written");
+        }
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("second migration run should succeed");
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+        let batches: Vec<_> = ran.iter().map(|migration| migration.batch).collect();
+        assert_eq!(batches, vec![Some(1), Some(2), Some(2)]);
+
+        migrate_down(fixture.config_path(), Vec::new(), None, None, false, false)
+            .await
+            .expect("rollback should succeed");
+
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load after rollback");
+        assert_eq!(ran.len(), 1);
+        assert_eq!(ran[0].version, "20260321171859");
+        assert_eq!(ran[0].batch, Some(1));
+    }
+
+    #[tokio::test]
+    async fn down_rolls_back_the_latest_batch_even_when_it_holds_an_older_version() {
+        let fixture = TestProject::new();
+        let write_migration = |version: &str, table: &str| {
+            fs::write(
+                Path::new(fixture.migrations_path()).join(format!("{}_create_{}_table.rs", version, table)),
+                TEST_MIGRATION
+                    .replace("20260321171859", version)
+                    .replace("users", table),
+            )
+            .expect("migration should be written");
+        };
+
+        write_migration("20260322090100", "tags");
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("first migration run should succeed");
+
+        // A migration merged in from another branch, older than the last one that ran
+        write_migration("20260322090000", "posts");
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("second migration run should succeed");
+
+        migrate_down(fixture.config_path(), Vec::new(), None, None, false, false)
+            .await
+            .expect("rollback should succeed");
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let versions: Vec<_> = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load after rollback")
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+        assert_eq!(versions, vec!["20260321171859", "20260322090100"]);
+    }
+
+    #[tokio::test]
+    async fn down_and_status_read_migrations_from_the_paths_run_used() {
+        let fixture = TestProject::new();
+        let extra_dir = TempDir::new().expect("temp dir should be created");
+        fs::write(
+            extra_dir.path().join("20260322090000_create_posts_table.rs"),
+            TEST_MIGRATION
+                .replace("20260321171859", "20260322090000")
+                .replace("users", "posts"),
+        )
+        .expect("migration should be written");
+        let paths = vec![fixture.migrations_path().to_string(), slash_path(extra_dir.path())];
+
+        run(fixture.config_path(), paths.clone(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let entries = migration_status_entries(&config, &paths)
+            .await
+            .expect("status should load");
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| entry.ran));
+
+        // Without the extra directory the posts migration has no down() SQL to run
+        let error = migrate_down(fixture.config_path(), Vec::new(), None, None, false, false)
+            .await
+            .unwrap_err();
+        assert!(error.contains("does not contain executable SQL"), "{}", error);
+
+        migrate_down(fixture.config_path(), paths.clone(), None, None, false, false)
+            .await
+            .expect("rollback should succeed");
+        assert!(get_ran_migrations(&config, &paths)
+            .await
+            .expect("ran migrations should load")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn migration_table_gains_execution_ms_column_when_missing() {
+        let fixture = TestProject::new();
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        crate::runtime_db::execute(
+            &config,
+            "CREATE TABLE \"_migrations\" (\"id\" INTEGER PRIMARY KEY AUTOINCREMENT, \"version\" TEXT NOT NULL UNIQUE, \"name\" TEXT NOT NULL UNIQUE, \"applied_at\" TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+        )
+        .await
+        .expect("legacy migration table should be created");
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+        assert!(ran[0].execution_ms.is_some());
+        assert_eq!(ran[0].batch, Some(1));
+    }
+
+    #[test]
+    fn history_row_right_aligns_time_and_highlights_slow_migrations() {
+        let migration = Migration {
+            file_name: "20240101000000_create_users".to_string(),
+            version: "20240101000000".to_string(),
+            name: "create_users".to_string(),
+            up_sql: String::new(),
+            down_sql: String::new(),
+            requires_transaction: true,
+            applied_at: Some("2024-01-01 10:00:00".to_string()),
+            execution_ms: Some(1250),
+            batch: Some(1),
+            directory: String::new(),
+        };
+
+        let row = history_row(&migration, None);
+        assert_eq!(
+            row,
+            format!(
+                "  {:<16} {:<40} {:<20} {:>10}",
+                "20240101000000", "20240101000000_create_users", "2024-01-01 10:00:00", "1250"
+            )
+        );
+        assert_eq!(history_row(&migration, Some(1250)), row);
+        assert!(history_row(&migration, Some(1000)).ends_with(&format!("{:>10}", "1250").red().bold().to_string()));
+
+        let untimed = Migration {
+            execution_ms: None,
+            ..migration
+        };
+        assert!(history_row(&untimed, Some(0)).ends_with(&format!("{:>10}", "-")));
+    }
+
     #[tokio::test]
     async fn rollback_removes_migration_record() {
         let fixture = TestProject::new();
 
-        run(fixture.config_path(), None, false, true, None)
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
             .await
             .expect("migration run should succeed");
 
         let config = TideConfig::load(fixture.config_path()).expect("config should load");
-        let ran = get_ran_migrations(&config, fixture.migrations_path())
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("ran migrations should load");
 
@@ -856,10 +2439,10 @@ mod tests {
             .await
             .expect("rollback should succeed");
 
-        let ran_after_rollback = get_ran_migrations(&config, fixture.migrations_path())
+        let ran_after_rollback = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("ran migrations should load after rollback");
-        let pending_after_rollback = get_pending_migrations(&config, fixture.migrations_path())
+        let pending_after_rollback = get_pending_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("pending migrations should load after rollback");
 
@@ -867,6 +2450,34 @@ mod tests {
         assert_eq!(pending_after_rollback.len(), 1);
     }
 
+    #[tokio::test]
+    async fn reset_pretend_leaves_migration_records_untouched() {
+        let fixture = TestProject::new();
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+
+        for verbose in [false, true] {
+            migrate_reset(fixture.config_path(), Vec::new(), false, true, verbose)
+                .await
+                .expect("pretend reset should succeed");
+        }
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+        let tables = crate::runtime_db::list_tables(&config)
+            .await
+            .expect("tables should be listed");
+
+        assert_eq!(ran.len(), 1);
+        assert_eq!(ran[0].version, "20260321171859");
+        assert_eq!(ran[0].down_sql, "DROP TABLE IF EXISTS users");
+        assert!(tables.iter().any(|table| table == "users"));
+    }
+
     #[tokio::test]
     async fn get_ran_migrations_reads_metadata_rows_like_library_migrator() {
         let fixture = TestProject::new();
@@ -882,7 +2493,7 @@ mod tests {
         .await
         .expect("migration row should be inserted");
 
-        let ran = get_ran_migrations(&config, fixture.migrations_path())
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
             .await
             .expect("ran migrations should load");
 
@@ -892,6 +2503,93 @@ mod tests {
         assert!(ran[0].applied_at.is_some());
     }
 
+    #[tokio::test]
+    async fn drop_all_tables_drops_dependents_first_and_clears_tracking_table() {
+        let fixture = TestProject::new();
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        crate::runtime_db::execute(
+            &config,
+            "PRAGMA foreign_keys = ON; \
+             CREATE TABLE posts (id INTEGER PRIMARY KEY, user_id INTEGER NOT NULL REFERENCES users (id)); \
+             CREATE TABLE comments (id INTEGER PRIMARY KEY, post_id INTEGER NOT NULL REFERENCES posts (id))",
+        )
+        .await
+        .expect("dependent tables should be created");
+
+        let dropped = drop_all_tables(&config, false)
+            .await
+            .expect("tables should be dropped");
+        let tables = crate::runtime_db::list_tables(&config)
+            .await
+            .expect("tables should be listed");
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+
+        assert_eq!(dropped, 3);
+        assert_eq!(
+            tables,
+            vec!["_migrations".to_string(), crate::runtime_db::SCHEMA_VERSION_TABLE.to_string()]
+        );
+        assert!(ran.is_empty());
+
+        drop_all_tables(&config, true)
+            .await
+            .expect("tracking table should be dropped");
+        let tables = crate::runtime_db::list_tables(&config)
+            .await
+            .expect("tables should be listed");
+
+        assert!(tables.is_empty());
+    }
+
+    #[tokio::test]
+    async fn migrations_opting_out_of_transactions_run_statement_by_statement() {
+        let fixture = TestProject::new();
+        fs::write(
+            Path::new(fixture.migrations_path()).join("20260322090000_index_users.rs"),
+            NON_TRANSACTIONAL_MIGRATION,
+        )
+        .expect("migration should be written");
+
+        run(fixture.config_path(), Vec::new(), false, true, true, None, None)
+            .await
+            .expect("migration run should succeed");
+
+        let config = TideConfig::load(fixture.config_path()).expect("config should load");
+        let ran = get_ran_migrations(&config, &[fixture.migrations_path().to_string()])
+            .await
+            .expect("ran migrations should load");
+        assert_eq!(ran.len(), 2);
+        assert!(ran[0].requires_transaction);
+        assert!(!ran[1].requires_transaction);
+
+        let indexes = crate::runtime_db::query_json(
+            &config,
+            "SELECT name FROM sqlite_master WHERE type = 'index' AND name LIKE 'users_%_idx' ORDER BY name",
+        )
+        .await
+        .expect("indexes should be listed");
+        assert_eq!(indexes.len(), 2);
+
+        run_migration_down(&config, &ran[1])
+            .await
+            .expect("rollback should succeed");
+
+        let indexes = crate::runtime_db::query_json(
+            &config,
+            "SELECT name FROM sqlite_master WHERE type = 'index' AND name LIKE 'users_%_idx'",
+        )
+        .await
+        .expect("indexes should be listed");
+        assert!(indexes.is_empty());
+    }
+
     struct TestProject {
         _dir: TempDir,
         config_path: String,
@@ -985,4 +2683,141 @@ impl Migration for CreateUsersTable {
     }
 }
 "##;
-}
\ No newline at end of file
+    const NON_TRANSACTIONAL_MIGRATION: &str = r##"//! Migration: index_users
+
+use tideorm::prelude::*;
+
+/// Run outside a transaction: CREATE INDEX CONCURRENTLY cannot run inside one
+pub const REQUIRES_TRANSACTION: bool = false;
+
+pub struct IndexUsers;
+
+#[async_trait]
+impl Migration for IndexUsers {
+    fn version(&self) -> &str {
+        "20260322090000"
+    }
+
+    fn name(&self) -> &str {
+        "index_users"
+    }
+
+    async fn up(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"CREATE INDEX IF NOT EXISTS users_name_idx ON users (name)"#).await?;
+        schema.raw(r#"CREATE UNIQUE INDEX IF NOT EXISTS users_id_idx ON users (id)"#).await?;
+        Ok(())
+    }
+
+    async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {
+        schema.raw(r#"DROP INDEX IF EXISTS users_id_idx"#).await?;
+        schema.raw(r#"DROP INDEX IF EXISTS users_name_idx"#).await?;
+        Ok(())
+    }
+}
+"##;
+
+    const USER_MODEL: &str = r#"
+use tideorm::prelude::*;
+
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub bio: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+"#;
+
+    /// Project with a SQLite database, a models directory, and an empty migrations directory
+    fn sqlite_project(dir: &TempDir, model: &str) -> String {
+        let root = dir.path().to_string_lossy().replace('\\', "/");
+        std::fs::create_dir_all(dir.path().join("models")).unwrap();
+        std::fs::create_dir_all(dir.path().join("migrations")).unwrap();
+        std::fs::write(dir.path().join("models/user.rs"), model).unwrap();
+
+        let config_path = dir.path().join("tideorm.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "[database]\ndriver = \"sqlite\"\nsqlite_path = \"{root}/app.sqlite3\"\n\n[paths]\nmodels = \"{root}/models\"\nmigrations = \"{root}/migrations\"\n\n[migration]\ntimestamps = false\n"
+            ),
+        )
+        .unwrap();
+
+        config_path.to_string_lossy().into_owned()
+    }
+
+    fn generated_migrations(dir: &TempDir) -> Vec<String> {
+        let mut contents: Vec<String> = std::fs::read_dir(dir.path().join("migrations"))
+            .unwrap()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.file_name().is_some_and(|name| name != "mod.rs"))
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect();
+        contents.sort();
+        contents
+    }
+
+    #[tokio::test]
+    async fn from_diff_migration_adds_and_drops_differing_columns() {
+        let dir = TempDir::new().unwrap();
+        let config_path = sqlite_project(&dir, USER_MODEL);
+        let config = TideConfig::load(&config_path).unwrap();
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, legacy_flag BOOLEAN, created_at TEXT NOT NULL); \
+             INSERT INTO users (name, legacy_flag, created_at) VALUES ('Alice', 1, '2024-01-01')",
+        )
+        .await
+        .unwrap();
+
+        generate_from_diff(&config_path, "add_missing_columns", true, true, false)
+            .await
+            .unwrap();
+
+        let migrations = generated_migrations(&dir);
+        assert_eq!(migrations.len(), 1);
+        let (up, down) = migrations[0].split_at(migrations[0].find("async fn down").unwrap());
+        assert!(!up.contains("COLUMN name"));
+        assert!(down.contains("ALTER TABLE users DROP COLUMN email"));
+        assert!(down.contains("ALTER TABLE users ADD COLUMN legacy_flag BOOLEAN"));
+
+        // The migration has to apply to the table with its existing row
+        let statements: Vec<String> = regex::Regex::new(r##"(?s)schema\.raw\(r#"(.*?)"#\)"##)
+            .unwrap()
+            .captures_iter(up)
+            .map(|captures| captures[1].to_string())
+            .collect();
+        assert_eq!(statements.len(), 3, "{:?}", statements);
+        for sql in &statements {
+            runtime_db::execute(&config, sql).await.unwrap();
+        }
+
+        let rows = runtime_db::query_json(&config, "SELECT * FROM users").await.unwrap();
+        assert_eq!(
+            rows,
+            [serde_json::json!({"id": 1, "name": "Alice", "created_at": "2024-01-01", "email": "", "bio": null})]
+        );
+    }
+
+    #[tokio::test]
+    async fn from_diff_keeps_database_only_columns_with_no_drop_columns() {
+        let dir = TempDir::new().unwrap();
+        let config_path = sqlite_project(&dir, USER_MODEL);
+        let config = TideConfig::load(&config_path).unwrap();
+        runtime_db::execute(
+            &config,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT NOT NULL, bio TEXT, legacy_flag BOOLEAN, created_at TEXT NOT NULL)",
+        )
+        .await
+        .unwrap();
+
+        generate_from_diff(&config_path, "add_missing_columns", false, true, false)
+            .await
+            .unwrap();
+
+        assert!(generated_migrations(&dir).is_empty());
+    }
+}
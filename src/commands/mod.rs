@@ -1,10 +1,16 @@
 //! Command modules for TideORM CLI
 
+pub mod completions;
 pub mod config;
 pub mod db;
+pub mod export;
+pub mod import;
 pub mod init;
 pub mod make;
 pub mod migrate;
 pub mod models;
+pub mod openapi;
 pub mod schema;
+pub mod shell;
 pub mod ui;
+pub mod validate;
@@ -2,12 +2,50 @@
 
 use crate::config::TideConfig;
 use crate::utils::print_info;
+use chrono::{DateTime, Local, NaiveDate};
 use colored::Colorize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Column `tideorm models --sort-by` orders the list by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelSort {
+    Name,
+    File,
+    Modified,
+    Fields,
+}
+
+impl ModelSort {
+    /// Parse a sort column name
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "file" => Ok(Self::File),
+            "modified" => Ok(Self::Modified),
+            "fields" => Ok(Self::Fields),
+            other => Err(format!(
+                "Unknown sort column: {}. Expected one of: name, file, modified, fields",
+                other
+            )),
+        }
+    }
+}
+
+/// Parse a `YYYY-MM-DD` date for `--since`
+pub fn parse_since(s: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {}. Expected format: YYYY-MM-DD", s))
+}
 
 /// List all models in the project
-pub async fn list(config_path: &str, verbose: bool) -> Result<(), String> {
+pub async fn list(
+    config_path: &str,
+    since: Option<NaiveDate>,
+    sort_by: ModelSort,
+    verbose: bool,
+) -> Result<(), String> {
     let config = TideConfig::load_or_default(config_path);
 
     if verbose {
@@ -23,12 +61,14 @@ pub async fn list(config_path: &str, verbose: bool) -> Result<(), String> {
         ));
     }
 
-    let models = scan_models(&config.paths.models)?;
+    let models = select_models(scan_models(&config.paths.models, &config.pluralization)?, since, sort_by);
 
     println!("\n{}", "TideORM Models:".cyan().bold());
-    println!("{}", "─".repeat(80));
+    println!("{}", "─".repeat(120));
 
-    if models.is_empty() {
+    if models.is_empty() && let Some(since) = since {
+        println!("  No models modified since {}", since);
+    } else if models.is_empty() {
         println!("  No models found in {}", config.paths.models);
         println!("\n  Create a model with:");
         println!(
@@ -36,8 +76,11 @@ pub async fn list(config_path: &str, verbose: bool) -> Result<(), String> {
             "tideorm make model User --fields=\"name:string,email:string:unique\"".yellow()
         );
     } else {
-        println!("  {:<20} {:<30} {:<15} Features", "Model", "Table", "Fields");
-        println!("{}", "─".repeat(80));
+        println!(
+            "  {:<20} {:<20} {:<8} {:<32} {:<18} Features",
+            "Model", "Table", "Fields", "File", "Modified"
+        );
+        println!("{}", "─".repeat(120));
 
         for model in &models {
             let features: Vec<&str> = [
@@ -52,15 +95,17 @@ pub async fn list(config_path: &str, verbose: bool) -> Result<(), String> {
             .collect();
 
             println!(
-                "  {:<20} {:<30} {:<15} {}",
+                "  {:<20} {:<20} {:<8} {:<32} {:<18} {}",
                 model.name.green(),
                 model.table,
                 model.fields.len().to_string(),
+                model.file.display().to_string(),
+                model.modified.map(format_modified).unwrap_or_else(|| "-".to_string()),
                 features.join(", ")
             );
         }
 
-        println!("{}", "─".repeat(80));
+        println!("{}", "─".repeat(120));
         println!("  Total: {} model(s)", models.len());
     }
 
@@ -69,9 +114,13 @@ pub async fn list(config_path: &str, verbose: bool) -> Result<(), String> {
 
 /// Model information
 #[derive(Debug)]
-struct ModelInfo {
-    name: String,
-    table: String,
+pub(crate) struct ModelInfo {
+    pub(crate) name: String,
+    pub(crate) table: String,
+    /// Model file, under the configured models directory
+    file: PathBuf,
+    /// Last modification time, when the filesystem reports one
+    modified: Option<DateTime<Local>>,
     fields: Vec<String>,
     relations: Vec<String>,
     translatable: Vec<String>,
@@ -81,7 +130,7 @@ struct ModelInfo {
 }
 
 /// Scan models directory and extract model information
-fn scan_models(models_path: &str) -> Result<Vec<ModelInfo>, String> {
+fn scan_models(models_path: &str, pluralization: &HashMap<String, String>) -> Result<Vec<ModelInfo>, String> {
     let path = Path::new(models_path);
     let mut models = Vec::new();
 
@@ -103,7 +152,12 @@ fn scan_models(models_path: &str) -> Result<Vec<ModelInfo>, String> {
             let content = fs::read_to_string(&file_path)
                 .map_err(|e| format!("Failed to read model file: {}", e))?;
 
-            if let Some(model_info) = parse_model_file(&content) {
+            if let Some(mut model_info) = parse_model_file(&content, pluralization) {
+                model_info.modified = fs::metadata(&file_path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .map(DateTime::<Local>::from);
+                model_info.file = file_path;
                 models.push(model_info);
             }
         }
@@ -114,29 +168,65 @@ fn scan_models(models_path: &str) -> Result<Vec<ModelInfo>, String> {
     Ok(models)
 }
 
+/// Keep models modified on or after `since`, ordered by `sort_by`.
+/// Newest and largest come first when sorting by modified time and field count.
+fn select_models(models: Vec<ModelInfo>, since: Option<NaiveDate>, sort_by: ModelSort) -> Vec<ModelInfo> {
+    let since = since.and_then(|date| date.and_time(chrono::NaiveTime::MIN).and_local_timezone(Local).earliest());
+    let mut models: Vec<ModelInfo> = models
+        .into_iter()
+        .filter(|model| since.is_none_or(|since| model.modified.is_some_and(|modified| modified >= since)))
+        .collect();
+
+    match sort_by {
+        ModelSort::Name => models.sort_by(|a, b| a.name.cmp(&b.name)),
+        ModelSort::File => models.sort_by(|a, b| a.file.cmp(&b.file)),
+        ModelSort::Modified => models.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| a.name.cmp(&b.name))),
+        ModelSort::Fields => {
+            models.sort_by(|a, b| b.fields.len().cmp(&a.fields.len()).then_with(|| a.name.cmp(&b.name)))
+        }
+    }
+
+    models
+}
+
+/// Format a modification time as `2024-01-15 14:32`
+fn format_modified(modified: DateTime<Local>) -> String {
+    modified.format("%Y-%m-%d %H:%M").to_string()
+}
+
 /// Parse a model file to extract information
-fn parse_model_file(content: &str) -> Option<ModelInfo> {
+pub(crate) fn parse_model_file(content: &str, pluralization: &HashMap<String, String>) -> Option<ModelInfo> {
     // Find struct name with either #[tideorm::model] or #[derive(Model)]
     let struct_pattern = regex::Regex::new(
         r#"(?s)(?:#\[tideorm::model(?:\([^\]]*\))?\]\s*(?:#\[(?:tideorm|index|unique_index)[^\]]*\]\s*)*|#\[derive\([^)]*Model[^)]*\)\]\s*(?:#\[(?:tideorm|index|unique_index)[^\]]*\]\s*)*)pub\s+struct\s+(\w+)"#
     ).ok()?;
 
-    let struct_name = struct_pattern.captures(content)?.get(1)?.as_str();
+    // Models generated with --base=trait implement TideModel instead
+    let trait_pattern = regex::Regex::new(r"impl\s+TideModel\s+for\s+(\w+)").ok()?;
+    let struct_name = struct_pattern
+        .captures(content)
+        .or_else(|| trait_pattern.captures(content))?
+        .get(1)?
+        .as_str();
 
     // Find table name
     let table_pattern = regex::Regex::new(
-        r#"(?:#\[tideorm\([^)]*table\s*=\s*"([^"]+)"[^)]*\)\]|#\[tideorm::model\([^\]]*table\s*=\s*"([^"]+)"[^\]]*\)\])"#
+        r#"(?:#\[tideorm\([^)]*table\s*=\s*"([^"]+)"[^)]*\)\]|#\[tideorm::model\([^\]]*table\s*=\s*"([^"]+)"[^\]]*\)\]|fn\s+table_name\(\)\s*->\s*&'static\s+str\s*\{\s*"([^"]+)")"#
     ).ok()?;
     let table = table_pattern
         .captures(content)
-        .and_then(|c| c.get(1).or_else(|| c.get(2)))
+        .and_then(|c| c.get(1).or_else(|| c.get(2)).or_else(|| c.get(3)))
         .map(|m| m.as_str().to_string())
-        .unwrap_or_else(|| crate::utils::pluralize(&crate::utils::to_snake_case(struct_name)));
+        .unwrap_or_else(|| {
+            crate::utils::pluralize_with_overrides(&crate::utils::to_snake_case(struct_name), pluralization)
+        });
 
     // Find fields
     let field_pattern = regex::Regex::new(r"pub\s+(\w+)\s*:\s*([^,\n}]+)").ok()?;
-    let fields: Vec<String> = field_pattern
-        .captures_iter(content)
+    let fields: Vec<String> = content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .filter_map(|line| field_pattern.captures(line))
         .map(|c| c.get(1).unwrap().as_str().to_string())
         .collect();
 
@@ -162,6 +252,8 @@ fn parse_model_file(content: &str) -> Option<ModelInfo> {
     Some(ModelInfo {
         name: struct_name.to_string(),
         table,
+        file: PathBuf::new(),
+        modified: None,
         fields,
         relations,
         translatable,
@@ -173,7 +265,47 @@ fn parse_model_file(content: &str) -> Option<ModelInfo> {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_model_file;
+    use super::{format_modified, parse_model_file, parse_since, scan_models, select_models, ModelSort};
+    use chrono::{Local, TimeZone};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::time::SystemTime;
+
+    fn write_model(dir: &std::path::Path, file: &str, name: &str, fields: &[&str], modified: SystemTime) {
+        let fields: String = fields.iter().map(|field| format!("    pub {}: String,\n", field)).collect();
+        let path = dir.join(file);
+        fs::write(&path, format!("#[tideorm::model]\npub struct {} {{\n{}}}\n", name, fields)).unwrap();
+        fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+    }
+
+    fn names(models: &[super::ModelInfo]) -> Vec<&str> {
+        models.iter().map(|model| model.name.as_str()).collect()
+    }
+
+    #[test]
+    fn models_are_filtered_by_modification_date_and_sorted() {
+        let dir = tempfile::tempdir().unwrap();
+        let at = |y, m, d| SystemTime::from(Local.with_ymd_and_hms(y, m, d, 14, 32, 0).unwrap());
+        write_model(dir.path(), "animal.rs", "Zebra", &["id"], at(2024, 3, 1));
+        write_model(dir.path(), "apple.rs", "Apple", &["id", "name", "color"], at(2023, 12, 31));
+        write_model(dir.path(), "mango.rs", "Mango", &["id", "name"], at(2024, 1, 15));
+        fs::write(dir.path().join("mod.rs"), "pub mod apple;\n").unwrap();
+
+        let scan = || scan_models(dir.path().to_str().unwrap(), &HashMap::new()).unwrap();
+        let models = scan();
+        assert_eq!(names(&models), vec!["Apple", "Mango", "Zebra"]);
+        assert_eq!(models[1].file, dir.path().join("mango.rs"));
+        assert_eq!(format_modified(models[1].modified.unwrap()), "2024-01-15 14:32");
+
+        let since = parse_since("2024-01-01").unwrap();
+        assert_eq!(names(&select_models(scan(), Some(since), ModelSort::Name)), vec!["Mango", "Zebra"]);
+        assert_eq!(names(&select_models(scan(), None, ModelSort::Modified)), vec!["Zebra", "Mango", "Apple"]);
+        assert_eq!(names(&select_models(scan(), None, ModelSort::Fields)), vec!["Apple", "Mango", "Zebra"]);
+        assert_eq!(names(&select_models(scan(), None, ModelSort::File)), vec!["Zebra", "Apple", "Mango"]);
+
+        assert!(parse_since("15/01/2024").unwrap_err().contains("YYYY-MM-DD"));
+        assert!(ModelSort::parse("size").unwrap_err().contains("name, file, modified, fields"));
+    }
 
     #[test]
     fn test_parse_tideorm_model_attribute() {
@@ -184,12 +316,50 @@ pub struct Post {
     pub id: i64,
     pub user_id: i64,
     pub title: String,
+    // Generated by the database as `lower(title)`; left unmapped since TideORM writes every field on save
+    // pub title_lower: Option<String>,
 }
 "#;
 
-        let model = parse_model_file(content).expect("model should parse");
+        let model = parse_model_file(content, &HashMap::new()).expect("model should parse");
         assert_eq!(model.name, "Post");
         assert_eq!(model.table, "posts");
         assert_eq!(model.fields.len(), 3);
     }
+
+    #[test]
+    fn test_parse_trait_based_model() {
+        let content = r#"
+#[derive(Debug, Clone, Default)]
+pub struct Invoice {
+    pub id: i64,
+    pub number: String,
+}
+
+impl TideModel for Invoice {
+    fn table_name() -> &'static str {
+        "billing_invoices"
+    }
+}
+"#;
+
+        let model = parse_model_file(content, &HashMap::new()).expect("model should parse");
+        assert_eq!(model.name, "Invoice");
+        assert_eq!(model.table, "billing_invoices");
+        assert_eq!(model.fields.len(), 2);
+    }
+
+    #[test]
+    fn test_default_table_name_uses_configured_plurals() {
+        let content = r#"
+#[tideorm::model]
+pub struct Person {
+    pub id: i64,
+}
+"#;
+
+        let pluralization = HashMap::from([("person".to_string(), "persons".to_string())]);
+        assert_eq!(parse_model_file(content, &pluralization).expect("model should parse").table, "persons");
+        assert_eq!(parse_model_file(content, &HashMap::new()).expect("model should parse").table, "people");
+    }
 }
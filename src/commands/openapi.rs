@@ -0,0 +1,690 @@
+//! OpenAPI generation command for TideORM CLI
+//!
+//! Builds an OpenAPI 3.0 document with a schema component per model file and the
+//! endpoints the controllers route to.
+
+use crate::commands::models::parse_model_file;
+use crate::commands::schema::model_fields;
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, pluralize_with_overrides, print_info, print_success, print_warning, to_snake_case, FieldDefinition};
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Output format of `tideorm generate-openapi`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OpenApiFormat {
+    #[default]
+    Yaml,
+    Json,
+}
+
+impl OpenApiFormat {
+    /// Parse a `--format` value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "json" => Ok(Self::Json),
+            other => Err(format!("Unknown format '{}'; expected yaml or json", other)),
+        }
+    }
+}
+
+/// Write the OpenAPI document of the project's models and controllers to `output`
+pub fn run(
+    config_path: &str,
+    output: &str,
+    title: &str,
+    version: &str,
+    format: OpenApiFormat,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Reading models from: {}", config.paths.models));
+    }
+    let models = scan_models(&config.paths.models, &config.pluralization)?;
+
+    let endpoints = match controllers_dir(&config.paths.controllers) {
+        Some(dir) => {
+            if verbose {
+                print_info(&format!("Reading controllers from: {}", dir.display()));
+            }
+            scan_controllers(&dir, &config.pluralization)?
+        }
+        None => {
+            print_warning(&format!(
+                "Controllers directory not found: {}; the document has no paths",
+                config.paths.controllers
+            ));
+            Vec::new()
+        }
+    };
+
+    let document = build_document(title, version, &models, &endpoints);
+    let content = render(&document, format)?;
+
+    if let Some(parent) = Path::new(output).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        ensure_directory(&parent.to_string_lossy())?;
+    }
+    std::fs::write(output, content).map_err(|e| format!("Failed to write {}: {}", output, e))?;
+
+    print_success(&format!(
+        "Wrote OpenAPI document to {} ({} schema(s), {} path(s))",
+        output,
+        document.components.schemas.len(),
+        document.paths.len()
+    ));
+
+    Ok(())
+}
+
+/// A model and the fields its schema component lists
+#[derive(Debug)]
+struct ModelSchema {
+    name: String,
+    fields: Vec<FieldDefinition>,
+}
+
+/// A route of a controller and the handler serving it
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Endpoint {
+    path: String,
+    /// Lowercase HTTP method, e.g. `get`
+    method: String,
+    handler: String,
+    /// Controller module, e.g. `post_controller`
+    module: String,
+    /// Model the controller imports from `crate::models`
+    model: Option<String>,
+    /// First doc comment line of the handler
+    summary: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Document {
+    openapi: &'static str,
+    info: Info,
+    paths: IndexMap<String, IndexMap<String, Operation>>,
+    components: Components,
+}
+
+#[derive(Debug, Serialize)]
+struct Info {
+    title: String,
+    version: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Components {
+    schemas: IndexMap<String, Schema>,
+}
+
+/// Schema object; an empty one accepts any value
+#[derive(Debug, Default, Serialize)]
+struct Schema {
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    schema_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'static str>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    nullable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    items: Option<Box<Schema>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    required: Vec<String>,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    properties: IndexMap<String, Schema>,
+}
+
+impl Schema {
+    fn typed(schema_type: &'static str, format: Option<&'static str>) -> Self {
+        Self {
+            schema_type: Some(schema_type),
+            format,
+            ..Self::default()
+        }
+    }
+
+    fn array(items: Schema) -> Self {
+        Self {
+            schema_type: Some("array"),
+            items: Some(Box::new(items)),
+            ..Self::default()
+        }
+    }
+
+    fn reference(model: &str) -> Self {
+        Self {
+            reference: Some(format!("#/components/schemas/{}", model)),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+    operation_id: String,
+    summary: String,
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parameters: Vec<Parameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_body: Option<RequestBody>,
+    responses: IndexMap<String, Response>,
+}
+
+#[derive(Debug, Serialize)]
+struct Parameter {
+    name: String,
+    #[serde(rename = "in")]
+    location: &'static str,
+    required: bool,
+    schema: Schema,
+}
+
+#[derive(Debug, Serialize)]
+struct RequestBody {
+    required: bool,
+    content: IndexMap<String, MediaType>,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    description: String,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    content: IndexMap<String, MediaType>,
+}
+
+impl Response {
+    fn new(description: &str, schema: Option<Schema>) -> Self {
+        Self {
+            description: description.to_string(),
+            content: json_content(schema),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct MediaType {
+    schema: Schema,
+}
+
+fn json_content(schema: Option<Schema>) -> IndexMap<String, MediaType> {
+    schema
+        .map(|schema| IndexMap::from([("application/json".to_string(), MediaType { schema })]))
+        .unwrap_or_default()
+}
+
+/// Serialize the document as YAML or pretty-printed JSON
+fn render(document: &Document, format: OpenApiFormat) -> Result<String, String> {
+    match format {
+        OpenApiFormat::Yaml => serde_yaml::to_string(document).map_err(|e| format!("Failed to render YAML: {}", e)),
+        OpenApiFormat::Json => serde_json::to_string_pretty(document)
+            .map(|json| format!("{}\n", json))
+            .map_err(|e| format!("Failed to render JSON: {}", e)),
+    }
+}
+
+/// Models of the model files, ordered by name
+fn scan_models(models_dir: &str, pluralization: &HashMap<String, String>) -> Result<Vec<ModelSchema>, String> {
+    let path = Path::new(models_dir);
+    if !path.exists() {
+        return Err(format!("Models directory not found: {}", models_dir));
+    }
+
+    let mut models = Vec::new();
+    for file in rust_files(path)? {
+        let content = std::fs::read_to_string(&file)
+            .map_err(|e| format!("Failed to read model file {}: {}", file.display(), e))?;
+
+        if let Some(model) = parse_model_file(&content, pluralization) {
+            models.push(ModelSchema {
+                fields: model_fields(&content, &model.name),
+                name: model.name,
+            });
+        }
+    }
+
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(models)
+}
+
+/// The controllers directory, or a `handlers` directory next to it when it doesn't exist
+fn controllers_dir(controllers: &str) -> Option<PathBuf> {
+    let path = Path::new(controllers);
+    if path.is_dir() {
+        return Some(path.to_path_buf());
+    }
+
+    let handlers = path.with_file_name("handlers");
+    handlers.is_dir().then_some(handlers)
+}
+
+/// Endpoints of every controller file, in file name and route order
+fn scan_controllers(dir: &Path, pluralization: &HashMap<String, String>) -> Result<Vec<Endpoint>, String> {
+    let mut endpoints = Vec::new();
+
+    for file in rust_files(dir)? {
+        let content = std::fs::read_to_string(&file)
+            .map_err(|e| format!("Failed to read controller file {}: {}", file.display(), e))?;
+        let module = file.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+
+        endpoints.extend(controller_endpoints(&content, module, pluralization));
+    }
+
+    Ok(endpoints)
+}
+
+/// `.rs` files of `dir` other than `mod.rs`, sorted
+fn rust_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|file| {
+            file.extension().is_some_and(|ext| ext == "rs") && file.file_stem().is_some_and(|stem| stem != "mod")
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Endpoints of one controller
+///
+/// Routes come from its `routes` function, in axum (`.route("/posts", get(index))`) or
+/// actix-web (`web::resource("/posts").route(web::get().to(index))`) style. A controller
+/// without one is mapped by its CRUD handler signatures onto the conventional resource routes.
+fn controller_endpoints(content: &str, module: &str, pluralization: &HashMap<String, String>) -> Vec<Endpoint> {
+    let model = regex::Regex::new(r"use\s+crate::models::(?:\w+::)*(\w+)\s*;")
+        .unwrap()
+        .captures(content)
+        .map(|captures| captures[1].to_string());
+
+    let routes = match routes_body(content) {
+        Some(body) => route_handlers(body),
+        None => match &model {
+            Some(model) => crud_routes(content, &pluralize_with_overrides(&to_snake_case(model), pluralization)),
+            None => Vec::new(),
+        },
+    };
+
+    routes
+        .into_iter()
+        .map(|(path, method, handler)| Endpoint {
+            summary: handler_summary(content, &handler),
+            path: openapi_path(&path),
+            method,
+            handler,
+            module: module.to_string(),
+            model: model.clone(),
+        })
+        .collect()
+}
+
+/// Body of the controller's `fn routes`
+fn routes_body(content: &str) -> Option<&str> {
+    let start = regex::Regex::new(r"\bfn\s+routes\s*[(<]").unwrap().find(content)?.start();
+    let body = &content[start..];
+    Some(body.find("\n}").map_or(body, |end| &body[..end]))
+}
+
+/// `(path, method, handler)` of each route in a `routes` body
+fn route_handlers(body: &str) -> Vec<(String, String, String)> {
+    let route_pattern = regex::Regex::new(r#"\.route\(\s*"([^"]*)"|web::resource\(\s*"([^"]*)"\s*\)"#).unwrap();
+    let method_pattern = regex::Regex::new(
+        r"\bweb::(get|post|put|patch|delete)\(\)\s*\.to\((\w+)\)|\b(get|post|put|patch|delete)\((\w+)\)",
+    )
+    .unwrap();
+
+    let routes: Vec<_> = route_pattern.captures_iter(body).collect();
+    let mut handlers = Vec::new();
+
+    for (index, route) in routes.iter().enumerate() {
+        let start = route.get(0).unwrap().end();
+        let end = routes.get(index + 1).map_or(body.len(), |next| next.get(0).unwrap().start());
+        let path = route.get(1).or_else(|| route.get(2)).unwrap().as_str();
+
+        for method in method_pattern.captures_iter(&body[start..end]) {
+            let verb = method.get(1).or_else(|| method.get(3)).unwrap().as_str();
+            let handler = method.get(2).or_else(|| method.get(4)).unwrap().as_str();
+            handlers.push((path.to_string(), verb.to_string(), handler.to_string()));
+        }
+    }
+
+    handlers
+}
+
+/// Conventional routes of the CRUD handlers a controller defines
+fn crud_routes(content: &str, resource: &str) -> Vec<(String, String, String)> {
+    let collection = format!("/{}", resource);
+    let member = format!("/{}/{{id}}", resource);
+
+    [
+        (&collection, "get", "index"),
+        (&collection, "post", "store"),
+        (&member, "get", "show"),
+        (&member, "put", "update"),
+        (&member, "delete", "destroy"),
+    ]
+    .into_iter()
+    .filter(|(_, _, handler)| {
+        regex::Regex::new(&format!(r"\bpub\s+(?:async\s+)?fn\s+{}\s*\(", handler))
+            .unwrap()
+            .is_match(content)
+    })
+    .map(|(path, method, handler)| (path.clone(), method.to_string(), handler.to_string()))
+    .collect()
+}
+
+/// First line of the doc comment right above `fn {handler}`
+fn handler_summary(content: &str, handler: &str) -> Option<String> {
+    let pattern = regex::Regex::new(&format!(
+        r"(?m)^[ \t]*///[ \t]*(.*)\n(?:[ \t]*///.*\n|[ \t]*#\[.*\]\n)*[ \t]*pub\s+(?:async\s+)?fn\s+{}\s*\(",
+        regex::escape(handler)
+    ))
+    .unwrap();
+
+    pattern
+        .captures(content)
+        .map(|captures| captures[1].trim().to_string())
+        .filter(|summary| !summary.is_empty())
+}
+
+/// Route path with `:id` segments written as `{id}`
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => format!("{{{}}}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn build_document(title: &str, version: &str, models: &[ModelSchema], endpoints: &[Endpoint]) -> Document {
+    let schemas: IndexMap<String, Schema> =
+        models.iter().map(|model| (model.name.clone(), model_schema(model))).collect();
+
+    let mut paths: IndexMap<String, IndexMap<String, Operation>> = IndexMap::new();
+    for endpoint in endpoints {
+        let model = endpoint
+            .model
+            .as_deref()
+            .and_then(|name| models.iter().find(|model| model.name == name));
+        paths
+            .entry(endpoint.path.clone())
+            .or_default()
+            .insert(endpoint.method.clone(), operation(endpoint, model));
+    }
+
+    Document {
+        openapi: "3.0.3",
+        info: Info {
+            title: title.to_string(),
+            version: version.to_string(),
+        },
+        paths,
+        components: Components { schemas },
+    }
+}
+
+/// Object schema of a model, requiring its non-nullable fields
+fn model_schema(model: &ModelSchema) -> Schema {
+    Schema {
+        schema_type: Some("object"),
+        required: model
+            .fields
+            .iter()
+            .filter(|field| !field.nullable)
+            .map(|field| field.name.clone())
+            .collect(),
+        properties: model
+            .fields
+            .iter()
+            .map(|field| {
+                let mut schema = field_schema(&field.field_type);
+                schema.nullable = field.nullable;
+                (field.name.clone(), schema)
+            })
+            .collect(),
+        ..Schema::default()
+    }
+}
+
+/// Schema of a model field type
+fn field_schema(field_type: &str) -> Schema {
+    match field_type {
+        "i8" | "i16" | "i32" => Schema::typed("integer", Some("int32")),
+        "i64" => Schema::typed("integer", Some("int64")),
+        "f32" => Schema::typed("number", Some("float")),
+        "f64" => Schema::typed("number", Some("double")),
+        "bool" => Schema::typed("boolean", None),
+        "datetime" => Schema::typed("string", Some("date-time")),
+        "date" => Schema::typed("string", Some("date")),
+        "uuid" => Schema::typed("string", Some("uuid")),
+        // rust_decimal serializes to a string to keep its precision
+        "decimal" => Schema::typed("string", Some("decimal")),
+        "bytes" => Schema::typed("string", Some("byte")),
+        "int_array" => Schema::array(field_schema("i32")),
+        "bigint_array" => Schema::array(field_schema("i64")),
+        "text_array" => Schema::array(field_schema("string")),
+        "bool_array" => Schema::array(field_schema("bool")),
+        "float_array" => Schema::array(field_schema("f64")),
+        "json_array" => Schema::array(Schema::default()),
+        "json" | "jsonb" => Schema::default(),
+        _ => Schema::typed("string", None),
+    }
+}
+
+/// Operation of an endpoint; the CRUD handlers get the request and response bodies of their model
+fn operation(endpoint: &Endpoint, model: Option<&ModelSchema>) -> Operation {
+    let model_ref = || model.map(|model| Schema::reference(&model.name));
+    let not_found = || ("404".to_string(), Response::new("Not found", None));
+
+    let (request, responses): (Option<Schema>, Vec<(String, Response)>) = match endpoint.handler.as_str() {
+        "index" => (
+            None,
+            vec![("200".to_string(), Response::new("OK", model_ref().map(Schema::array)))],
+        ),
+        "show" => (None, vec![("200".to_string(), Response::new("OK", model_ref())), not_found()]),
+        "store" => (model_ref(), vec![("201".to_string(), Response::new("Created", model_ref()))]),
+        "update" => (model_ref(), vec![("200".to_string(), Response::new("OK", model_ref())), not_found()]),
+        "destroy" => (None, vec![("204".to_string(), Response::new("No content", None)), not_found()]),
+        _ => (None, vec![("200".to_string(), Response::new("OK", None))]),
+    };
+
+    let parameters = path_parameters(&endpoint.path)
+        .into_iter()
+        .map(|name| Parameter {
+            schema: parameter_schema(&name, model),
+            name,
+            location: "path",
+            required: true,
+        })
+        .collect();
+
+    Operation {
+        operation_id: format!("{}.{}", endpoint.module, endpoint.handler),
+        summary: endpoint.summary.clone().unwrap_or_else(|| endpoint.handler.clone()),
+        tags: vec![endpoint.model.clone().unwrap_or_else(|| endpoint.module.clone())],
+        parameters,
+        request_body: request.map(|schema| RequestBody {
+            required: true,
+            content: json_content(Some(schema)),
+        }),
+        responses: responses.into_iter().collect(),
+    }
+}
+
+/// Names of the `{name}` segments of a path
+fn path_parameters(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Schema of a path parameter: the model's primary key for `id`, a string otherwise
+fn parameter_schema(name: &str, model: Option<&ModelSchema>) -> Schema {
+    if name != "id" {
+        return Schema::typed("string", None);
+    }
+
+    model
+        .and_then(|model| {
+            model
+                .fields
+                .iter()
+                .find(|field| field.primary_key)
+                .or_else(|| model.fields.iter().find(|field| field.name == "id"))
+        })
+        .map_or_else(|| field_schema("i64"), |field| field_schema(&field.field_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_document, controller_endpoints, render, scan_controllers, scan_models, OpenApiFormat};
+    use crate::config::TideConfig;
+    use crate::generators::controller::{ControllerFramework, ControllerGenerator};
+    use std::collections::HashMap;
+    use std::path::Path;
+    use tempfile::{tempdir, TempDir};
+
+    /// Models `Post` and `User` with an axum controller for posts and an actix one for users
+    fn project() -> (TempDir, TideConfig) {
+        let dir = tempdir().unwrap();
+        let models = dir.path().join("models");
+        std::fs::create_dir_all(&models).unwrap();
+        std::fs::write(models.join("mod.rs"), "pub mod post;\npub mod user;\n").unwrap();
+        std::fs::write(
+            models.join("post.rs"),
+            r#"#[tideorm::model(table = "posts")]
+pub struct Post {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub title: String,
+    pub body: Option<String>,
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[tideorm(has_many = "Comment")]
+    pub comments: HasMany<Comment>,
+}
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            models.join("user.rs"),
+            r#"#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key)]
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub active: bool,
+}
+"#,
+        )
+        .unwrap();
+
+        let mut config = TideConfig::default();
+        config.paths.models = models.to_string_lossy().into_owned();
+        config.paths.controllers = dir.path().join("controllers").to_string_lossy().into_owned();
+        ControllerGenerator::new(&config)
+            .generate("Post", &config.paths.controllers)
+            .unwrap();
+        ControllerGenerator::new(&config)
+            .framework(ControllerFramework::Actix)
+            .generate("User", &config.paths.controllers)
+            .unwrap();
+
+        (dir, config)
+    }
+
+    fn document(config: &TideConfig, format: OpenApiFormat) -> serde_yaml::Value {
+        let models = scan_models(&config.paths.models, &config.pluralization).unwrap();
+        let endpoints = scan_controllers(Path::new(&config.paths.controllers), &config.pluralization).unwrap();
+        let content = render(&build_document("Blog API", "1.2.0", &models, &endpoints), format).unwrap();
+        serde_yaml::from_str(&content).unwrap()
+    }
+
+    fn keys(value: &serde_yaml::Value) -> Vec<&str> {
+        value.as_mapping().unwrap().keys().map(|key| key.as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn format_parses_yaml_and_json() {
+        assert_eq!(OpenApiFormat::parse("YML"), Ok(OpenApiFormat::Yaml));
+        assert_eq!(OpenApiFormat::parse("json"), Ok(OpenApiFormat::Json));
+        assert_eq!(
+            OpenApiFormat::parse("toml"),
+            Err("Unknown format 'toml'; expected yaml or json".to_string())
+        );
+    }
+
+    #[test]
+    fn document_has_a_schema_per_model_and_the_routes_of_each_controller() {
+        let (_dir, config) = project();
+        let document = document(&config, OpenApiFormat::Yaml);
+
+        assert_eq!(document["openapi"].as_str(), Some("3.0.3"));
+        assert_eq!(document["info"]["title"].as_str(), Some("Blog API"));
+        assert_eq!(keys(&document["components"]["schemas"]), ["Post", "User"]);
+        assert_eq!(keys(&document["paths"]), ["/posts", "/posts/{id}", "/users", "/users/{id}"]);
+        assert_eq!(keys(&document["paths"]["/posts"]), ["get", "post"]);
+        assert_eq!(keys(&document["paths"]["/users/{id}"]), ["get", "put", "delete"]);
+
+        let post = &document["components"]["schemas"]["Post"];
+        assert_eq!(keys(&post["properties"]), ["id", "title", "body", "published_at"]);
+        assert_eq!(post["required"], serde_yaml::from_str::<serde_yaml::Value>("[id, title]").unwrap());
+        assert_eq!(post["properties"]["published_at"]["format"].as_str(), Some("date-time"));
+        assert_eq!(post["properties"]["body"]["nullable"].as_bool(), Some(true));
+
+        let show = &document["paths"]["/users/{id}"]["get"];
+        assert_eq!(show["operationId"].as_str(), Some("user_controller.show"));
+        assert_eq!(show["summary"].as_str(), Some("Show one User"));
+        assert_eq!(show["parameters"][0]["schema"]["format"].as_str(), Some("uuid"));
+        assert_eq!(
+            show["responses"]["200"]["content"]["application/json"]["schema"]["$ref"].as_str(),
+            Some("#/components/schemas/User")
+        );
+        let store = &document["paths"]["/posts"]["post"];
+        assert!(store["requestBody"]["content"]["application/json"].is_mapping());
+        assert_eq!(keys(&store["responses"]), ["201"]);
+    }
+
+    #[test]
+    fn json_output_keeps_the_same_key_order() {
+        let (_dir, config) = project();
+
+        let yaml = document(&config, OpenApiFormat::Yaml);
+        let json = document(&config, OpenApiFormat::Json);
+
+        assert_eq!(yaml, json);
+        assert_eq!(keys(&json), ["openapi", "info", "paths", "components"]);
+    }
+
+    #[test]
+    fn controllers_without_routes_map_their_crud_handlers() {
+        let content = r#"use crate::models::category::Category;
+
+/// List every Category
+pub async fn index() {}
+
+pub async fn show(id: i64) {}
+"#;
+
+        let endpoints = controller_endpoints(content, "category_handler", &HashMap::new());
+        let routes: Vec<_> = endpoints
+            .iter()
+            .map(|endpoint| (endpoint.method.as_str(), endpoint.path.as_str(), endpoint.summary.as_deref()))
+            .collect();
+
+        assert_eq!(
+            routes,
+            [("get", "/categories", Some("List every Category")), ("get", "/categories/{id}", None)]
+        );
+    }
+}
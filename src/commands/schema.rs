@@ -1,22 +1,65 @@
 //! Schema command for TideORM CLI
 
+use crate::commands::models::parse_model_file;
 use crate::config::TideConfig;
+use crate::generators::migration::MigrationGenerator;
+use crate::logging;
 use crate::runtime_db;
-use crate::utils::print_info;
+use crate::schema_types::{diff_schemas_with, ColumnSchema, SchemaDiff, TableSchema};
+use crate::utils::{print_info, print_success, print_warning, FieldDefinition};
 use colored::Colorize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// How `tideorm schema` prints the schema
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaFormat {
+    #[default]
+    Table,
+    Mermaid,
+}
+
+impl SchemaFormat {
+    /// Parse a `--format` value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "table" => Ok(Self::Table),
+            "mermaid" => Ok(Self::Mermaid),
+            other => Err(format!("Unknown format '{}'; expected table or mermaid", other)),
+        }
+    }
+}
 
 /// Show schema information
-pub async fn show(config_path: &str, table: Option<String>, verbose: bool) -> Result<(), String> {
+pub async fn show(
+    config_path: &str,
+    table: Option<String>,
+    alphabetical: bool,
+    format: SchemaFormat,
+    output: Option<String>,
+    verbose: bool,
+) -> Result<(), String> {
+    if output.is_some() && format != SchemaFormat::Mermaid {
+        return Err("--output needs --format=mermaid".to_string());
+    }
+    if table.is_some() && format == SchemaFormat::Mermaid {
+        return Err("--format=mermaid draws every table; leave out --table".to_string());
+    }
+    if format == SchemaFormat::Mermaid && output.is_none() {
+        // Keep stdout to the diagram so it can be pasted or redirected
+        logging::log_to_stderr();
+    }
+
     let config = TideConfig::load(config_path)?;
 
     if verbose {
         print_info("Fetching schema information...");
     }
 
-    if let Some(table_name) = table {
-        show_table_schema(&config, &table_name).await
-    } else {
-        show_all_schemas(&config).await
+    match (format, table) {
+        (SchemaFormat::Mermaid, _) => show_mermaid_diagram(&config, alphabetical, output.as_deref()).await,
+        (SchemaFormat::Table, Some(table_name)) => show_table_schema(&config, &table_name).await,
+        (SchemaFormat::Table, None) => show_all_schemas(&config, alphabetical).await,
     }
 }
 
@@ -80,8 +123,8 @@ async fn show_table_schema(config: &TideConfig, table_name: &str) -> Result<(),
 }
 
 /// Show all table schemas
-async fn show_all_schemas(config: &TideConfig) -> Result<(), String> {
-    let tables = get_all_tables(config).await?;
+async fn show_all_schemas(config: &TideConfig, alphabetical: bool) -> Result<(), String> {
+    let tables = ordered_tables(config, alphabetical).await?;
 
     println!("\n{}", "Database Schema:".cyan().bold());
     println!("{}", "═".repeat(80));
@@ -122,21 +165,567 @@ async fn show_all_schemas(config: &TideConfig) -> Result<(), String> {
     Ok(())
 }
 
+/// All tables, referenced ones first unless `alphabetical` is set
+async fn ordered_tables(config: &TideConfig, alphabetical: bool) -> Result<Vec<String>, String> {
+    let mut tables = get_all_tables(config).await?;
+    tables.sort();
+
+    if !alphabetical {
+        let mut references = HashMap::new();
+        for table in &tables {
+            let foreign_keys = get_foreign_keys(config, table).await?;
+            references.insert(
+                table.clone(),
+                foreign_keys
+                    .into_iter()
+                    .map(|foreign_key| foreign_key.references_table)
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        match dependency_order(&tables, &references) {
+            Some(ordered) => tables = ordered,
+            None => print_warning(
+                "Circular foreign keys found; listing tables alphabetically instead",
+            ),
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Print the schema as a Mermaid ER diagram, or write it to `output` in a fenced code block
+async fn show_mermaid_diagram(config: &TideConfig, alphabetical: bool, output: Option<&str>) -> Result<(), String> {
+    let mut tables = Vec::new();
+    for table in ordered_tables(config, alphabetical).await? {
+        tables.push(DiagramTable {
+            columns: get_table_schema(config, &table).await?,
+            unique_columns: get_table_indexes(config, &table)
+                .await?
+                .into_iter()
+                .filter(|index| index.unique && index.columns.len() == 1)
+                .flat_map(|index| index.columns)
+                .collect(),
+            foreign_keys: get_foreign_keys(config, &table).await?,
+            name: table,
+        });
+    }
+
+    let diagram = mermaid_diagram(&tables);
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("```mermaid\n{}```\n", diagram))
+                .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            print_success(&format!("Wrote ER diagram of {} table(s) to {}", tables.len(), path));
+        }
+        None => print!("{}", diagram),
+    }
+
+    Ok(())
+}
+
+/// A table with the keys its ER diagram entity shows
+struct DiagramTable {
+    name: String,
+    columns: Vec<ColumnSchema>,
+    /// Columns with a single-column unique index
+    unique_columns: Vec<String>,
+    foreign_keys: Vec<ForeignKeyInfo>,
+}
+
+impl DiagramTable {
+    fn is_unique(&self, column: &ColumnSchema) -> bool {
+        column.key.as_deref() == Some("UNI") || self.unique_columns.contains(&column.name)
+    }
+}
+
+/// Mermaid `erDiagram` of the tables
+///
+/// Each foreign key is a relationship from the referencing table: `||` on its side when the
+/// foreign key column is unique (one-to-one), `}|` otherwise, and `o|` or `||` on the
+/// referenced side depending on whether the column is nullable.
+fn mermaid_diagram(tables: &[DiagramTable]) -> String {
+    let mut lines = vec!["erDiagram".to_string()];
+
+    for table in tables {
+        lines.push(format!("    {} {{", table.name));
+        for column in &table.columns {
+            let mut keys = Vec::new();
+            if column.key.as_deref() == Some("PRI") {
+                keys.push("PK");
+            }
+            if table.foreign_keys.iter().any(|foreign_key| foreign_key.column == column.name) {
+                keys.push("FK");
+            }
+            if table.is_unique(column) {
+                keys.push("UK");
+            }
+
+            let keys = if keys.is_empty() { String::new() } else { format!(" {}", keys.join(", ")) };
+            lines.push(format!("        {} {}{}", mermaid_type(&column.data_type), column.name, keys));
+        }
+        lines.push("    }".to_string());
+    }
+
+    for table in tables {
+        for foreign_key in &table.foreign_keys {
+            let column = table.columns.iter().find(|column| column.name == foreign_key.column);
+            let many = if column.is_some_and(|column| table.is_unique(column)) { "||" } else { "}|" };
+            let one = if column.is_some_and(|column| column.nullable) { "o|" } else { "||" };
+
+            lines.push(format!(
+                "    {} {}--{} {} : \"{}\"",
+                table.name, many, one, foreign_key.references_table, foreign_key.column
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Column type as a Mermaid attribute type, which can't contain spaces or commas
+fn mermaid_type(data_type: &str) -> String {
+    data_type
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("-")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+
+/// Order tables so referenced tables come before the tables that reference them
+///
+/// Uses Kahn's algorithm, picking ready tables alphabetically. Self-references and
+/// references to unknown tables are ignored. Returns `None` when the foreign keys
+/// form a cycle.
+pub(crate) fn dependency_order(
+    tables: &[String],
+    references: &HashMap<String, Vec<String>>,
+) -> Option<Vec<String>> {
+    let mut in_degree: HashMap<&str, usize> =
+        tables.iter().map(|table| (table.as_str(), 0)).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for table in tables {
+        let mut parents: Vec<&str> = references
+            .get(table)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(|parent| *parent != table && in_degree.contains_key(parent))
+            .collect();
+        parents.sort_unstable();
+        parents.dedup();
+
+        for parent in parents {
+            dependents.entry(parent).or_default().push(table);
+            *in_degree.get_mut(table.as_str())? += 1;
+        }
+    }
+
+    let mut ready: BTreeSet<&str> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(table, _)| *table)
+        .collect();
+    let mut ordered = Vec::with_capacity(tables.len());
+
+    while let Some(table) = ready.pop_first() {
+        ordered.push(table.to_string());
+
+        for dependent in dependents.get(table).into_iter().flatten() {
+            let degree = in_degree.get_mut(dependent)?;
+            *degree -= 1;
+            if *degree == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    (ordered.len() == tables.len()).then_some(ordered)
+}
+
 // =============================================================================
-// HELPER TYPES
+// SCHEMA SYNC
 // =============================================================================
 
-/// Column schema information
-#[derive(Debug)]
-struct ColumnSchema {
-    name: String,
-    data_type: String,
-    nullable: bool,
-    key: Option<String>,
-    default: Option<String>,
-    extra: Option<String>,
+/// Generate a migration that brings the database schema in line with the models
+pub async fn sync(
+    config_path: &str,
+    name: &str,
+    drop_orphans: bool,
+    destructive: bool,
+    force: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+    let driver = driver_name(&config);
+
+    let (database, models) = database_and_model_schemas(&config, driver, verbose).await?;
+    let diff = diff_schemas_with(&database, &models, |from, to| sql_types_match(driver, from, to));
+    let plan = sync_plan(&diff, drop_orphans, destructive);
+
+    warn_missing_tables(&diff);
+
+    if !drop_orphans && !diff.dropped_columns.is_empty() {
+        print_info(&format!(
+            "Keeping {} column(s) that no model declares; pass --drop-orphans to drop them",
+            diff.dropped_columns.len()
+        ));
+    }
+
+    if !destructive && !diff.changed_columns.is_empty() {
+        print_warning(&format!(
+            "Skipping {} column type change(s) that may lose data; pass --destructive to include them",
+            diff.changed_columns.len()
+        ));
+    }
+
+    if plan.is_empty() {
+        if diff.added_columns.is_empty() && diff.dropped_columns.is_empty() && diff.changed_columns.is_empty() {
+            print_success("Database schema already matches the models");
+        } else {
+            print_info("No migration generated");
+        }
+        return Ok(());
+    }
+
+    if (!plan.dropped_columns.is_empty() || !plan.changed_columns.is_empty()) && !force {
+        return Err(
+            "The sync migration drops or retypes columns. Re-run with --force to generate it".to_string(),
+        );
+    }
+
+    for dropped in &plan.dropped_columns {
+        print_warning(&format!(
+            "Dropping column {}.{}: its data will be lost when the migration runs",
+            dropped.table, dropped.column.name
+        ));
+    }
+
+    if verbose {
+        println!("{}", plan.display());
+    }
+
+    let path = MigrationGenerator::new(&config).generate_sync(name, &plan)?;
+
+    print_success(&format!("Created migration: {}", path));
+    print_info("Review the migration, then apply it with 'tideorm migrate run'");
+
+    Ok(())
+}
+
+/// Driver name with aliases normalized
+pub(crate) fn driver_name(config: &TideConfig) -> &str {
+    match config.database.driver.as_str() {
+        "postgresql" => "postgres",
+        driver => driver,
+    }
+}
+
+/// Snapshots of the live tables backing each model, and of the models themselves
+pub(crate) async fn database_and_model_schemas(
+    config: &TideConfig,
+    driver: &str,
+    verbose: bool,
+) -> Result<(Vec<TableSchema>, Vec<TableSchema>), String> {
+    if verbose {
+        print_info(&format!("Reading models from: {}", config.paths.models));
+    }
+
+    let models = model_schemas(&config.paths.models, driver, &config.pluralization)?;
+    if models.is_empty() {
+        return Err(format!("No models found in {}", config.paths.models));
+    }
+
+    let existing_tables = get_all_tables(config).await?;
+    let mut database = Vec::new();
+    for model in &models {
+        if existing_tables.contains(&model.name) {
+            // Models skip generated columns, so leave them out rather than dropping them
+            let columns = get_table_schema(config, &model.name)
+                .await?
+                .into_iter()
+                .filter(|column| !column.is_generated())
+                .collect();
+            database.push(TableSchema {
+                name: model.name.clone(),
+                columns,
+            });
+        }
+    }
+
+    Ok((database, models))
+}
+
+/// Point at `make migration` for models whose table does not exist yet
+pub(crate) fn warn_missing_tables(diff: &SchemaDiff) {
+    for table in &diff.added_tables {
+        print_warning(&format!(
+            "Table {} does not exist yet; create it with 'tideorm make migration create_{}_table --create={}'",
+            table, table, table
+        ));
+    }
+}
+
+/// Keep the column changes a sync migration should apply
+fn sync_plan(diff: &SchemaDiff, drop_orphans: bool, destructive: bool) -> SchemaDiff {
+    SchemaDiff {
+        added_tables: Vec::new(),
+        dropped_tables: Vec::new(),
+        added_columns: diff.added_columns.clone(),
+        dropped_columns: if drop_orphans { diff.dropped_columns.clone() } else { Vec::new() },
+        changed_columns: if destructive { diff.changed_columns.clone() } else { Vec::new() },
+    }
+}
+
+/// Build schema snapshots from the model files
+fn model_schemas(
+    models_dir: &str,
+    driver: &str,
+    pluralization: &HashMap<String, String>,
+) -> Result<Vec<TableSchema>, String> {
+    let path = Path::new(models_dir);
+    if !path.exists() {
+        return Err(format!("Models directory not found: {}", models_dir));
+    }
+
+    let mut files = std::fs::read_dir(path)
+        .map_err(|e| format!("Failed to read models directory: {}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|file| {
+            file.extension().is_some_and(|ext| ext == "rs")
+                && file.file_stem().is_some_and(|stem| stem != "mod")
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let mut schemas = Vec::new();
+    for file in files {
+        let content = std::fs::read_to_string(&file)
+            .map_err(|e| format!("Failed to read model file {}: {}", file.display(), e))?;
+
+        if let Some(model) = parse_model_file(&content, pluralization) {
+            schemas.push(TableSchema {
+                name: model.table,
+                columns: model_columns(&content, &model.name, driver),
+            });
+        }
+    }
+
+    Ok(schemas)
+}
+
+/// Columns declared by a model struct, skipping relation and other non-column fields
+fn model_columns(content: &str, struct_name: &str, driver: &str) -> Vec<ColumnSchema> {
+    model_fields(content, struct_name)
+        .into_iter()
+        .map(|field| ColumnSchema {
+            name: field.name.clone(),
+            data_type: field.sql_type(driver),
+            nullable: field.nullable,
+            key: field.primary_key.then(|| "PRI".to_string()),
+            default: None,
+            extra: None,
+        })
+        .collect()
+}
+
+/// Fields declared by a model struct, skipping relation and other non-column fields
+pub(crate) fn model_fields(content: &str, struct_name: &str) -> Vec<FieldDefinition> {
+    let Some(body) = struct_body(content, struct_name) else {
+        return Vec::new();
+    };
+    // Commented-out fields, such as unmapped generated columns, aren't columns of the model
+    let body = body
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let body = body.as_str();
+
+    let field_pattern = regex::Regex::new(r"(?s)((?:#\[[^\]]*\]\s*)*)pub\s+(\w+)\s*:\s*").unwrap();
+    let mut fields = Vec::new();
+
+    for captures in field_pattern.captures_iter(body) {
+        let attributes = &captures[1];
+        let type_start = captures.get(0).unwrap().end();
+        let rust_type = field_type_text(&body[type_start..]);
+
+        if attributes.contains("skip") {
+            continue;
+        }
+
+        let Some((field_type, nullable)) = field_type_for_rust_type(rust_type) else {
+            continue;
+        };
+
+        fields.push(FieldDefinition {
+            name: captures[2].to_string(),
+            field_type: field_type.to_string(),
+            nullable,
+            unique: false,
+            indexed: false,
+            primary_key: attributes.contains("primary_key"),
+            auto_increment: attributes.contains("auto_increment"),
+            default: None,
+            enum_variants: Vec::new(),
+            decimal_precision: None,
+            decimal_scale: None,
+            check: None,
+            generated: None,
+            index_where: None,
+            is_array: false,
+        });
+    }
+
+    fields
+}
+
+/// Text between the braces of `pub struct {name} { ... }`
+fn struct_body<'a>(content: &'a str, struct_name: &str) -> Option<&'a str> {
+    let pattern = regex::Regex::new(&format!(r"pub\s+struct\s+{}\b[^{{;]*\{{", regex::escape(struct_name))).unwrap();
+    let start = pattern.find(content)?.end();
+    let mut depth = 1;
+
+    for (offset, ch) in content[start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&content[start..start + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The field type at the start of `text`, up to the next top-level comma
+fn field_type_text(text: &str) -> &str {
+    let mut depth = 0i32;
+
+    for (offset, ch) in text.char_indices() {
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' | '\n' if depth == 0 => return text[..offset].trim(),
+            _ => {}
+        }
+    }
+
+    text.trim()
 }
 
+/// Map a model field's Rust type to a field type and nullability
+fn field_type_for_rust_type(rust_type: &str) -> Option<(&'static str, bool)> {
+    let compact: String = rust_type.chars().filter(|ch| !ch.is_whitespace()).collect();
+    let (inner, nullable) = match compact.strip_prefix("Option<").and_then(|rest| rest.strip_suffix('>')) {
+        Some(inner) => (inner.to_string(), true),
+        None => (compact, false),
+    };
+
+    let path = inner.split('<').next().unwrap_or_default();
+    let base = path.rsplit("::").next().unwrap_or(path);
+    let field_type = match inner.as_str() {
+        "Vec<u8>" => "bytes",
+        "serde_json::Value" => "json",
+        "Vec<String>" => "text_array",
+        "Vec<serde_json::Value>" => "json_array",
+        _ => match base {
+            "DateTime" | "NaiveDateTime" => "datetime",
+            "String" => "string",
+            "i8" => "i8",
+            "i16" => "i16",
+            "i32" => "i32",
+            "i64" => "i64",
+            "f32" => "f32",
+            "f64" => "f64",
+            "bool" => "bool",
+            "NaiveDate" => "date",
+            "NaiveTime" => "time",
+            "Uuid" => "uuid",
+            "Json" => "json",
+            "Jsonb" => "jsonb",
+            "Decimal" => "decimal",
+            "IntArray" => "int_array",
+            "BigIntArray" => "bigint_array",
+            "TextArray" => "text_array",
+            "BoolArray" => "bool_array",
+            "FloatArray" => "float_array",
+            "JsonArray" => "json_array",
+            _ => return None,
+        },
+    };
+
+    Some((field_type, nullable))
+}
+
+/// Whether two column types are equivalent for the given driver
+///
+/// Text types compare equal regardless of length because a model's `String`
+/// does not say which one the migration used. SQLite types are compared by
+/// storage class, with date and time types treated as text.
+fn sql_types_match(driver: &str, from: &str, to: &str) -> bool {
+    canonical_sql_type(driver, from) == canonical_sql_type(driver, to)
+}
+
+fn canonical_sql_type(driver: &str, data_type: &str) -> String {
+    let lower = data_type.trim().to_lowercase();
+
+    if driver == "sqlite" {
+        return if lower.contains("int") && lower != "tinyint(1)" {
+            "integer"
+        } else if ["char", "clob", "text", "date", "time"].iter().any(|kind| lower.contains(kind)) {
+            "text"
+        } else if lower.is_empty() || lower.contains("blob") {
+            "blob"
+        } else if ["real", "floa", "doub"].iter().any(|kind| lower.contains(kind)) {
+            "real"
+        } else {
+            lower.split('(').next().unwrap_or_default().trim()
+        }
+        .to_string();
+    }
+
+    if lower == "tinyint(1)" {
+        return "boolean".to_string();
+    }
+    if lower == "array" || lower.ends_with("[]") {
+        return "array".to_string();
+    }
+
+    let base = lower.split('(').next().unwrap_or_default().trim();
+    match base {
+        "character varying" | "varchar" | "character" | "char" | "text" | "tinytext"
+        | "mediumtext" | "longtext" | "enum" => "text",
+        "int" | "int4" | "integer" | "serial" | "mediumint" => "integer",
+        "int8" | "bigint" | "bigserial" => "bigint",
+        "int2" | "smallint" | "tinyint" => "smallint",
+        "float4" | "real" | "float" => "real",
+        "float8" | "double precision" | "double" => "double",
+        "bool" | "boolean" => "boolean",
+        "numeric" | "decimal" => "decimal",
+        "timestamp with time zone" | "timestamptz" => "timestamptz",
+        "timestamp without time zone" | "timestamp" => "timestamp",
+        "time without time zone" | "time" => "time",
+        "bytea" | "blob" | "longblob" | "binary" | "varbinary" => "binary",
+        other => other,
+    }
+    .to_string()
+}
+
+// =============================================================================
+// HELPER TYPES
+// =============================================================================
+
 /// Index information
 #[derive(Debug)]
 struct IndexInfo {
@@ -208,3 +797,282 @@ async fn get_foreign_keys(config: &TideConfig, table_name: &str) -> Result<Vec<F
                 .collect()
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        dependency_order, mermaid_diagram, model_columns, sql_types_match, sync_plan, DiagramTable,
+        ForeignKeyInfo, SchemaFormat,
+    };
+    use crate::config::TideConfig;
+    use crate::generators::migration::MigrationGenerator;
+    use crate::schema_types::{diff_schemas_with, ColumnSchema, SchemaDiff, TableColumn, TableSchema};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    const USER_MODEL: &str = r#"
+use tideorm::prelude::*;
+
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub bio: Option<String>,
+    pub settings: HashMap<String, String>,
+    // Generated by the database as `lower(email)`; left unmapped since TideORM writes every field on save
+    // pub email_lower: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+"#;
+
+    fn tables(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn references(edges: &[(&str, &str)]) -> HashMap<String, Vec<String>> {
+        let mut references: HashMap<String, Vec<String>> = HashMap::new();
+        for (table, parent) in edges {
+            references
+                .entry(table.to_string())
+                .or_default()
+                .push(parent.to_string());
+        }
+        references
+    }
+
+    #[test]
+    fn dependency_order_puts_referenced_tables_first() {
+        let ordered = dependency_order(
+            &tables(&["comments", "posts", "tags", "users"]),
+            &references(&[
+                ("comments", "posts"),
+                ("comments", "users"),
+                ("posts", "users"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(ordered, tables(&["tags", "users", "posts", "comments"]));
+    }
+
+    #[test]
+    fn dependency_order_ignores_self_and_unknown_references() {
+        let ordered = dependency_order(
+            &tables(&["categories", "posts"]),
+            &references(&[
+                ("categories", "categories"),
+                ("posts", "categories"),
+                ("posts", "users"),
+                ("posts", "categories"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(ordered, tables(&["categories", "posts"]));
+    }
+
+    #[test]
+    fn dependency_order_detects_cycles() {
+        let order = dependency_order(
+            &tables(&["accounts", "owners", "settings"]),
+            &references(&[("accounts", "owners"), ("owners", "accounts")]),
+        );
+
+        assert!(order.is_none());
+        assert_eq!(dependency_order(&[], &HashMap::new()), Some(Vec::new()));
+    }
+
+    fn column(name: &str, data_type: &str, nullable: bool) -> ColumnSchema {
+        ColumnSchema {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+            key: None,
+            default: None,
+            extra: None,
+        }
+    }
+
+    #[test]
+    fn model_columns_map_rust_types_and_skip_unknown_and_commented_fields() {
+        let columns = model_columns(USER_MODEL, "User", "postgres");
+        let described: Vec<(&str, &str, bool)> = columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.data_type.as_str(), column.nullable))
+            .collect();
+
+        assert_eq!(
+            described,
+            vec![
+                ("id", "BIGINT", false),
+                ("name", "VARCHAR(255)", false),
+                ("email", "VARCHAR(255)", false),
+                ("bio", "VARCHAR(255)", true),
+                ("created_at", "TIMESTAMPTZ", false),
+            ]
+        );
+        assert_eq!(columns[0].key.as_deref(), Some("PRI"));
+    }
+
+    #[test]
+    fn type_aliases_reported_by_databases_match_model_types() {
+        assert!(sql_types_match("postgres", "character varying", "VARCHAR(255)"));
+        assert!(sql_types_match("postgres", "timestamp with time zone", "TIMESTAMPTZ"));
+        assert!(sql_types_match("postgres", "double precision", "DOUBLE PRECISION"));
+        assert!(sql_types_match("mysql", "tinyint(1)", "TINYINT(1)"));
+        assert!(sql_types_match("mysql", "bigint(20)", "BIGINT"));
+        assert!(sql_types_match("sqlite", "INTEGER", "BIGINT"));
+        assert!(sql_types_match("sqlite", "TEXT", "DATETIME"));
+        assert!(!sql_types_match("postgres", "integer", "BIGINT"));
+        assert!(!sql_types_match("sqlite", "TEXT", "BIGINT"));
+    }
+
+    fn generate_sync(config: &TideConfig, plan: &SchemaDiff) -> String {
+        let path = MigrationGenerator::new(config).generate_sync("sync_schema", plan).unwrap();
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn sync_migration_adds_drops_and_retypes_columns() {
+        let dir = TempDir::new().unwrap();
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+        config.paths.migrations = dir.path().to_string_lossy().into_owned();
+        config.migration.timestamps = false;
+
+        let database = vec![TableSchema {
+            name: "users".to_string(),
+            columns: vec![
+                column("id", "bigint", false),
+                column("name", "character varying", false),
+                column("email", "text", false),
+                column("bio", "integer", true),
+                column("legacy_flag", "boolean", false),
+                column("created_at", "timestamp with time zone", false),
+            ],
+        }];
+        let models = vec![TableSchema {
+            name: "users".to_string(),
+            columns: model_columns(USER_MODEL, "User", "postgres"),
+        }];
+
+        let diff = diff_schemas_with(&database, &models, |from, to| sql_types_match("postgres", from, to));
+        assert_eq!(diff.dropped_columns.len(), 1);
+        assert_eq!(diff.changed_columns.len(), 1);
+        assert!(diff.added_columns.is_empty());
+
+        assert!(sync_plan(&diff, false, false).is_empty());
+
+        let content = generate_sync(&config, &sync_plan(&diff, true, true));
+        let (up, down) = content.split_at(content.find("async fn down").unwrap());
+
+        assert!(up.contains("schema.raw(r#\"ALTER TABLE users DROP COLUMN legacy_flag\"#).await?;"));
+        assert!(up.contains("schema.raw(r#\"ALTER TABLE users ALTER COLUMN bio TYPE VARCHAR(255)\"#).await?;"));
+        assert!(down.contains("schema.raw(r#\"ALTER TABLE users ADD COLUMN legacy_flag boolean NOT NULL DEFAULT FALSE\"#).await?;"));
+        assert!(down.contains("schema.raw(r#\"ALTER TABLE users ALTER COLUMN bio TYPE integer\"#).await?;"));
+
+        let added = SchemaDiff {
+            added_columns: vec![TableColumn {
+                table: "users".to_string(),
+                column: column("nickname", "VARCHAR(255)", true),
+            }],
+            ..SchemaDiff::default()
+        };
+        let content = generate_sync(&config, &added);
+        assert!(content.contains("ALTER TABLE users ADD COLUMN nickname VARCHAR(255)\"#"));
+        assert!(content.contains("ALTER TABLE users DROP COLUMN nickname\"#"));
+    }
+
+    fn keyed_column(name: &str, data_type: &str, nullable: bool, key: Option<&str>) -> ColumnSchema {
+        ColumnSchema {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+            key: key.map(str::to_string),
+            default: None,
+            extra: None,
+        }
+    }
+
+    fn foreign_key(column: &str, references_table: &str) -> ForeignKeyInfo {
+        ForeignKeyInfo {
+            column: column.to_string(),
+            references_table: references_table.to_string(),
+            references_column: "id".to_string(),
+        }
+    }
+
+    #[test]
+    fn schema_format_parses_table_and_mermaid() {
+        assert_eq!(SchemaFormat::parse("Mermaid"), Ok(SchemaFormat::Mermaid));
+        assert_eq!(SchemaFormat::parse("table"), Ok(SchemaFormat::Table));
+        assert_eq!(
+            SchemaFormat::parse("dot"),
+            Err("Unknown format 'dot'; expected table or mermaid".to_string())
+        );
+    }
+
+    #[test]
+    fn mermaid_diagram_lists_tables_and_foreign_key_cardinalities() {
+        let tables = [
+            DiagramTable {
+                name: "users".to_string(),
+                columns: vec![
+                    keyed_column("id", "BIGINT", false, Some("PRI")),
+                    keyed_column("email", "character varying", false, Some("UNI")),
+                    keyed_column("balance", "DECIMAL(10, 2)", true, None),
+                ],
+                unique_columns: Vec::new(),
+                foreign_keys: Vec::new(),
+            },
+            DiagramTable {
+                name: "posts".to_string(),
+                columns: vec![
+                    keyed_column("id", "BIGINT", false, Some("PRI")),
+                    keyed_column("author_id", "BIGINT", false, None),
+                    keyed_column("editor_id", "BIGINT", true, None),
+                ],
+                unique_columns: Vec::new(),
+                foreign_keys: vec![foreign_key("author_id", "users"), foreign_key("editor_id", "users")],
+            },
+            DiagramTable {
+                name: "profiles".to_string(),
+                columns: vec![
+                    keyed_column("id", "BIGINT", false, Some("PRI")),
+                    keyed_column("user_id", "BIGINT", false, None),
+                ],
+                unique_columns: vec!["user_id".to_string()],
+                foreign_keys: vec![foreign_key("user_id", "users")],
+            },
+        ];
+
+        let diagram = mermaid_diagram(&tables);
+
+        assert_eq!(
+            diagram.lines().collect::<Vec<_>>(),
+            [
+                "erDiagram",
+                "    users {",
+                "        BIGINT id PK",
+                "        character_varying email UK",
+                "        DECIMAL(10-2) balance",
+                "    }",
+                "    posts {",
+                "        BIGINT id PK",
+                "        BIGINT author_id FK",
+                "        BIGINT editor_id FK",
+                "    }",
+                "    profiles {",
+                "        BIGINT id PK",
+                "        BIGINT user_id FK, UK",
+                "    }",
+                "    posts }|--|| users : \"author_id\"",
+                "    posts }|--o| users : \"editor_id\"",
+                "    profiles ||--|| users : \"user_id\"",
+            ]
+        );
+        assert!(diagram.ends_with("\"user_id\"\n"));
+    }
+}
@@ -0,0 +1,308 @@
+//! Database shell command for TideORM CLI
+//!
+//! Opens the native database client for the configured driver, falling back
+//! to a minimal built-in REPL when the client is not installed.
+
+use crate::config::TideConfig;
+use crate::runtime_db;
+use crate::utils::{print_info, print_warning};
+use colored::Colorize;
+use serde_json::Value;
+use std::io::ErrorKind;
+use std::process::Command;
+
+/// Environment variables forwarded to the native client
+const PASSTHROUGH_ENV: [&str; 3] = ["TERM", "PAGER", "EDITOR"];
+
+/// Open an interactive shell connected to the configured database
+pub async fn run(config_path: &str, no_system_client: bool, verbose: bool) -> Result<(), String> {
+    let config = TideConfig::load(config_path)?;
+
+    if !no_system_client {
+        // The client takes host, user and database separately, so a secret URL must be resolved first
+        let mut client_config = config.clone();
+        client_config.database = config.database.resolved().await?;
+        let client = system_client_command(&client_config)?;
+
+        if verbose {
+            print_info(&format!("Launching {} {}", client.program, client.args.join(" ")));
+        }
+
+        match client.to_command().status() {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => {
+                return Err(format!("{} exited with status {}", client.program, status));
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                print_warning(&format!(
+                    "{} was not found on PATH, using the built-in shell",
+                    client.program
+                ));
+            }
+            Err(error) => return Err(format!("Failed to launch {}: {}", client.program, error)),
+        }
+    }
+
+    run_builtin_shell(&config).await
+}
+
+/// Native client invocation derived from the database configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ShellCommand {
+    program: String,
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+}
+
+impl ShellCommand {
+    fn to_command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        for (key, value) in &self.envs {
+            command.env(key, value);
+        }
+        command
+    }
+}
+
+fn system_client_command(config: &TideConfig) -> Result<ShellCommand, String> {
+    let database = &config.database;
+    let mut args = Vec::new();
+    let mut envs = Vec::new();
+
+    let program = match database.driver.as_str() {
+        "postgres" | "postgresql" => {
+            args.push(format!("--host={}", database.host));
+            args.push(format!("--port={}", database.port.unwrap_or(5432)));
+            args.push(format!(
+                "--username={}",
+                database.username.as_deref().unwrap_or("postgres")
+            ));
+            args.push(format!(
+                "--dbname={}",
+                database.database.as_deref().unwrap_or("tideorm")
+            ));
+            args.push("--set".to_string());
+            args.push("ON_ERROR_STOP=1".to_string());
+            if let Some(password) = database.password.as_deref().filter(|value| !value.is_empty()) {
+                envs.push(("PGPASSWORD".to_string(), password.to_string()));
+            }
+            "psql"
+        }
+        "mysql" => {
+            args.push(format!("--host={}", database.host));
+            args.push(format!("--port={}", database.port.unwrap_or(3306)));
+            args.push(format!(
+                "--user={}",
+                database.username.as_deref().unwrap_or("root")
+            ));
+            args.push(format!(
+                "--database={}",
+                database.database.as_deref().unwrap_or("tideorm")
+            ));
+            if let Some(password) = database.password.as_deref().filter(|value| !value.is_empty()) {
+                envs.push(("MYSQL_PWD".to_string(), password.to_string()));
+            }
+            "mysql"
+        }
+        "sqlite" => {
+            args.push(
+                database
+                    .sqlite_path
+                    .as_deref()
+                    .unwrap_or("database.db")
+                    .to_string(),
+            );
+            "sqlite3"
+        }
+        driver => return Err(format!("Unsupported database driver: {}", driver)),
+    };
+
+    for key in PASSTHROUGH_ENV {
+        if let Ok(value) = std::env::var(key) {
+            envs.push((key.to_string(), value));
+        }
+    }
+
+    Ok(ShellCommand {
+        program: program.to_string(),
+        args,
+        envs,
+    })
+}
+
+/// Minimal REPL that runs each line against the database
+async fn run_builtin_shell(config: &TideConfig) -> Result<(), String> {
+    let db = runtime_db::connect(config).await?;
+    let mut editor = rustyline::DefaultEditor::new()
+        .map_err(|error| format!("Failed to start shell: {}", error))?;
+
+    println!(
+        "{} connected to {} ({})",
+        "TideORM shell".cyan().bold(),
+        config.database.driver.green(),
+        "type \\q or exit to quit".dimmed()
+    );
+
+    loop {
+        let line = match editor.readline("tideorm> ") {
+            Ok(line) => line,
+            Err(rustyline::error::ReadlineError::Interrupted)
+            | Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(error) => return Err(format!("Failed to read input: {}", error)),
+        };
+
+        let statement = line.trim().trim_end_matches(';').trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        if matches!(statement, "\\q" | "exit" | "quit") {
+            break;
+        }
+
+        let _ = editor.add_history_entry(line.as_str());
+
+        if runtime_db::formats_result_set(statement) {
+            match runtime_db::query_json_on_db(&db, statement).await {
+                Ok(rows) => println!("{}", render_table(&rows)),
+                Err(error) => eprintln!("{} {}", "Error:".red().bold(), error),
+            }
+        } else {
+            match runtime_db::execute_on_db(&db, statement).await {
+                Ok(affected) => println!("Rows affected: {}", affected),
+                Err(error) => eprintln!("{} {}", "Error:".red().bold(), error),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Render query rows as a plain-text table
+fn render_table(rows: &[Value]) -> String {
+    let Some(Value::Object(first)) = rows.first() else {
+        return "(0 rows)".to_string();
+    };
+
+    let columns: Vec<&String> = first.keys().collect();
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| match row.get(column.as_str()) {
+                    None | Some(Value::Null) => "NULL".to_string(),
+                    Some(Value::String(value)) => value.clone(),
+                    Some(value) => value.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            cells
+                .iter()
+                .map(|row| row[index].chars().count())
+                .chain(std::iter::once(column.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |values: Vec<&str>| {
+        values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!(" {:<width$} ", value, width = width))
+            .collect::<Vec<_>>()
+            .join("|")
+    };
+
+    let mut lines = vec![format_row(columns.iter().map(|column| column.as_str()).collect())];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+"),
+    );
+    for row in &cells {
+        lines.push(format_row(row.iter().map(String::as_str).collect()));
+    }
+    lines.push(format!(
+        "({} row{})",
+        rows.len(),
+        if rows.len() == 1 { "" } else { "s" }
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_table, system_client_command};
+    use crate::config::TideConfig;
+    use serde_json::json;
+
+    #[test]
+    fn postgres_client_uses_config_components_and_stops_on_error() {
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+        config.database.host = "db.local".to_string();
+        config.database.port = Some(5433);
+        config.database.username = Some("app".to_string());
+        config.database.password = Some("secret".to_string());
+        config.database.database = Some("app_db".to_string());
+
+        let command = system_client_command(&config).unwrap();
+
+        assert_eq!(command.program, "psql");
+        assert!(command.args.contains(&"--host=db.local".to_string()));
+        assert!(command.args.contains(&"--port=5433".to_string()));
+        assert!(command.args.contains(&"--username=app".to_string()));
+        assert!(command.args.contains(&"--dbname=app_db".to_string()));
+        assert!(command.args.windows(2).any(|pair| pair == ["--set", "ON_ERROR_STOP=1"]));
+        assert!(command.envs.contains(&("PGPASSWORD".to_string(), "secret".to_string())));
+    }
+
+    #[test]
+    fn mysql_and_sqlite_clients_are_selected_by_driver() {
+        let mut config = TideConfig::default();
+        config.database.driver = "mysql".to_string();
+        config.database.database = Some("shop".to_string());
+
+        let command = system_client_command(&config).unwrap();
+        assert_eq!(command.program, "mysql");
+        assert!(command.args.contains(&"--database=shop".to_string()));
+        assert!(!command.envs.iter().any(|(key, _)| key == "MYSQL_PWD"));
+
+        config.database.driver = "sqlite".to_string();
+        config.database.sqlite_path = Some("data/app.db".to_string());
+
+        let command = system_client_command(&config).unwrap();
+        assert_eq!(command.program, "sqlite3");
+        assert_eq!(command.args, vec!["data/app.db".to_string()]);
+    }
+
+    #[test]
+    fn render_table_aligns_columns_and_counts_rows() {
+        let rows = vec![
+            json!({"id": 1, "name": "Alice"}),
+            json!({"id": 22, "name": null}),
+        ];
+
+        let table = render_table(&rows);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], " id | name  ");
+        assert_eq!(lines[1], "----+-------");
+        assert_eq!(lines[2], " 1  | Alice ");
+        assert_eq!(lines[3], " 22 | NULL  ");
+        assert_eq!(lines[4], "(2 rows)");
+        assert_eq!(render_table(&[]), "(0 rows)");
+    }
+}
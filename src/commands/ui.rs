@@ -4,11 +4,16 @@
 //! with a query playground and model generator.
 
 use colored::Colorize;
+use crate::commands::migrate::{migration_status_entries, MigrationStatusEntry};
+use crate::utils::print_warning;
 use crate::{config::TideConfig, runtime_db};
 use serde::Deserialize;
 use serde_json::json;
-use std::io::Cursor;
-use std::path::Path;
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
 use tiny_http::{Header, Method, Response, Server, StatusCode};
 
 #[derive(Deserialize)]
@@ -21,13 +26,68 @@ struct QueryRequest {
     query: String,
 }
 
+#[derive(Deserialize)]
+struct RollbackRequest {
+    #[serde(default = "default_rollback_step")]
+    step: u32,
+}
+
+fn default_rollback_step() -> u32 {
+    1
+}
+
+/// CORS headers sent with every response when `--cors-origin` is set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsOptions {
+    /// `*` or the frontend origin allowed to call the API, e.g. `http://localhost:3000`
+    origin: String,
+    /// How long browsers may cache a preflight response, in seconds
+    max_age: Option<u64>,
+}
+
+impl CorsOptions {
+    /// Options for `origin`, which must be `*` or printable ASCII without spaces to fit in a header
+    pub fn new(origin: String, max_age: Option<u64>) -> Result<Self, String> {
+        if origin.is_empty() || !origin.bytes().all(|byte| byte.is_ascii_graphic()) {
+            return Err(format!(
+                "Invalid --cors-origin {:?}: expected * or an origin like http://localhost:3000",
+                origin
+            ));
+        }
+
+        Ok(Self { origin, max_age })
+    }
+
+    fn headers(&self) -> Vec<Header> {
+        let mut headers = vec![
+            Header::from_bytes(&b"Access-Control-Allow-Origin"[..], self.origin.as_bytes()).unwrap(),
+            Header::from_bytes(&b"Access-Control-Allow-Methods"[..], &b"GET, POST, OPTIONS"[..]).unwrap(),
+            Header::from_bytes(&b"Access-Control-Allow-Headers"[..], &b"Content-Type"[..]).unwrap(),
+        ];
+
+        if let Some(max_age) = self.max_age {
+            headers.push(
+                Header::from_bytes(&b"Access-Control-Max-Age"[..], max_age.to_string().as_bytes())
+                    .unwrap(),
+            );
+        }
+
+        headers
+    }
+}
+
 /// Embedded static files from src/ui/
 const HTML_CONTENT: &str = include_str!("../ui/index.html");
 const CSS_CONTENT: &str = include_str!("../ui/style.css");
 const JS_CONTENT: &str = include_str!("../ui/app.js");
 
 /// Run the TideORM Studio web UI server
-pub async fn run(host: &str, port: u16, verbose: bool) -> Result<(), String> {
+pub async fn run(
+    host: &str,
+    port: u16,
+    cors: Option<CorsOptions>,
+    verbose: bool,
+) -> Result<(), String> {
     let addr = format!("{}:{}", host, port);
     
     println!("{}", "━".repeat(60).cyan());
@@ -59,11 +119,27 @@ pub async fn run(host: &str, port: u16, verbose: bool) -> Result<(), String> {
         println!();
     }
     
+    if let Some(cors) = &cors {
+        println!("  {} Allowing cross-origin requests from {}", "→".cyan(), cors.origin.bright_green());
+        println!();
+        if cors.origin == "*" {
+            print_warning(
+                "--cors-origin='*' lets any website you visit run /api/query and roll back migrations through this server",
+            );
+        }
+    }
+
     if verbose {
         println!("{} Listening for requests...", "Info:".cyan());
     }
-    
-    // Main request loop
+
+    serve(&server, cors.as_ref(), verbose);
+
+    Ok(())
+}
+
+/// Answer requests until the server shuts down
+fn serve(server: &Server, cors: Option<&CorsOptions>, verbose: bool) {
     for mut request in server.incoming_requests() {
         let url = request.url().to_string();
         let method = request.method().clone();
@@ -98,20 +174,84 @@ pub async fn run(host: &str, port: u16, verbose: bool) -> Result<(), String> {
             (Method::Post, "/api/query") => {
                 handle_query_request(&mut request, verbose)
             }
+
+            (Method::Get, "/api/migrations") => handle_migrations_request(),
+
+            (Method::Post, "/api/migrations/run" | "/api/migrations/rollback") => {
+                let args = match read_body(&mut request)
+                    .and_then(|body| migration_command_args(&url, &body))
+                {
+                    Ok(args) => args,
+                    Err(error) => {
+                        let response = create_json_response(json!({
+                            "success": false,
+                            "error": error,
+                        }));
+                        respond(request, response, cors, verbose);
+                        continue;
+                    }
+                };
+
+                if verbose {
+                    println!("  {} Streaming: tideorm {}", "→".cyan(), args.join(" ").yellow());
+                }
+
+                match spawn_event_stream(&args) {
+                    Ok(stream) => {
+                        let mut headers = vec![
+                            Header::from_bytes(&b"Content-Type"[..], &b"text/event-stream"[..]).unwrap(),
+                            Header::from_bytes(&b"Cache-Control"[..], &b"no-cache"[..]).unwrap(),
+                        ];
+                        headers.extend(cors.map(CorsOptions::headers).unwrap_or_default());
+
+                        // Stream from a separate thread so status polling keeps working
+                        std::thread::spawn(move || {
+                            let response = Response::new(
+                                StatusCode(200),
+                                headers,
+                                stream,
+                                None,
+                                None,
+                            );
+                            let _ = request.respond(response);
+                        });
+                        continue;
+                    }
+                    Err(error) => create_json_response(json!({
+                        "success": false,
+                        "error": error,
+                    })),
+                }
+            }
             
+            // CORS preflight
+            (Method::Options, _) => create_response("", "text/plain"),
+
             // 404 for everything else
             _ => {
                 create_response(r#"{"error": "Not found"}"#, "application/json")
             }
         };
         
-        if let Err(e) = request.respond(response)
-            && verbose {
-            eprintln!("  {} Failed to send response: {}", "Error:".red(), e);
-        }
+        respond(request, response, cors, verbose);
+    }
+}
+
+/// Send a response, adding the CORS headers when enabled
+fn respond<R: Read>(
+    request: tiny_http::Request,
+    mut response: Response<R>,
+    cors: Option<&CorsOptions>,
+    verbose: bool,
+) {
+    for header in cors.map(CorsOptions::headers).unwrap_or_default() {
+        response.add_header(header);
+    }
+
+    if let Err(e) = request.respond(response)
+        && verbose {
+        eprintln!("  {} Failed to send response: {}", "Error:".red(), e);
     }
-    
-    Ok(())
 }
 
 /// Create an HTTP response with the given content and content type
@@ -144,6 +284,192 @@ fn create_json_response(value: serde_json::Value) -> Response<Cursor<Vec<u8>>> {
     create_response(&value.to_string(), "application/json")
 }
 
+fn read_body(request: &mut tiny_http::Request) -> Result<String, String> {
+    let mut body = String::new();
+    request
+        .as_reader()
+        .read_to_string(&mut body)
+        .map_err(|e| format!("Failed to read request: {}", e))?;
+    Ok(body)
+}
+
+/// Handle migration status requests
+fn handle_migrations_request() -> Response<Cursor<Vec<u8>>> {
+    let outcome = TideConfig::load("tideorm.toml").and_then(|config| {
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current()
+                .block_on(migration_status_entries(&config, std::slice::from_ref(&config.paths.migrations)))
+        })
+    });
+
+    match outcome {
+        Ok(entries) => create_json_response(migrations_json(&entries)),
+        Err(error) => create_response_with_status(
+            &json!({ "error": error }).to_string(),
+            "application/json",
+            500,
+        ),
+    }
+}
+
+/// Render migration status entries as the JSON array served to the UI
+fn migrations_json(entries: &[MigrationStatusEntry]) -> serde_json::Value {
+    serde_json::Value::Array(
+        entries
+            .iter()
+            .map(|entry| {
+                json!({
+                    "name": entry.name,
+                    "status": if entry.ran { "ran" } else { "pending" },
+                    "batch": entry.batch,
+                    "ran_at": entry.ran_at,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// CLI arguments for a migration run or rollback request
+fn migration_command_args(url: &str, body: &str) -> Result<Vec<String>, String> {
+    let body = if body.trim().is_empty() { "{}" } else { body };
+
+    match url {
+        "/api/migrations/run" => {
+            serde_json::from_str::<serde_json::Value>(body)
+                .map_err(|error| format!("Invalid request payload: {}", error))?;
+            Ok(vec!["migrate".to_string(), "run".to_string()])
+        }
+        "/api/migrations/rollback" => {
+            let payload: RollbackRequest = serde_json::from_str(body)
+                .map_err(|error| format!("Invalid request payload: {}", error))?;
+            if payload.step == 0 {
+                return Err("step must be at least 1".to_string());
+            }
+            Ok(vec![
+                "migrate".to_string(),
+                "down".to_string(),
+                "--step".to_string(),
+                payload.step.to_string(),
+            ])
+        }
+        _ => Err(format!("Unknown migration action: {}", url)),
+    }
+}
+
+/// Command running this CLI binary, so the UI works when `tideorm` isn't on PATH
+///
+/// Falls back to `tideorm` on PATH, with a warning, when `current_exe` can't resolve
+/// the running binary.
+fn cli_command(current_exe: impl FnOnce() -> std::io::Result<PathBuf>) -> Command {
+    let program = match current_exe() {
+        Ok(path) => path.into_os_string(),
+        Err(error) => {
+            print_warning(&format!(
+                "Failed to resolve the CLI executable ({}); running tideorm from PATH",
+                error
+            ));
+            OsString::from("tideorm")
+        }
+    };
+
+    Command::new(program)
+}
+
+/// Run the CLI in a subprocess and expose its output as server-sent events
+fn spawn_event_stream(args: &[String]) -> Result<EventStream<BufReader<std::process::ChildStdout>>, String> {
+    if !Path::new("tideorm.toml").exists() {
+        return Err("No tideorm.toml found. Run 'tideorm init' first.".to_string());
+    }
+
+    let mut child = cli_command(std::env::current_exe)
+        .args(args)
+        .env("NO_COLOR", "1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Failed to execute command: {}", error))?;
+
+    let stdout = child.stdout.take().ok_or("Failed to capture command output")?;
+    Ok(EventStream::new(BufReader::new(stdout), Some(child)))
+}
+
+/// Reader that turns each output line into a `data:` event
+///
+/// Once the output ends, stderr is sent as `data:` lines too, followed by a
+/// final `done` event carrying whether the command succeeded. Stderr is drained
+/// on its own thread meanwhile, so a child that fills that pipe can't stall the stream.
+struct EventStream<R> {
+    lines: R,
+    child: Option<Child>,
+    stderr: Option<JoinHandle<String>>,
+    pending: Vec<u8>,
+    finished: bool,
+}
+
+impl<R: BufRead> EventStream<R> {
+    fn new(lines: R, mut child: Option<Child>) -> Self {
+        let stderr = child.as_mut().and_then(|child| child.stderr.take()).map(|mut pipe| {
+            std::thread::spawn(move || {
+                let mut stderr = String::new();
+                let _ = pipe.read_to_string(&mut stderr);
+                stderr
+            })
+        });
+
+        Self {
+            lines,
+            child,
+            stderr,
+            pending: Vec::new(),
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self) -> String {
+        let mut events = String::new();
+        let success = match self.child.take() {
+            Some(mut child) => {
+                let stderr = self
+                    .stderr
+                    .take()
+                    .and_then(|reader| reader.join().ok())
+                    .unwrap_or_default();
+                for line in stderr.lines() {
+                    events.push_str(&data_event(line));
+                }
+                child.wait().map(|status| status.success()).unwrap_or(false)
+            }
+            None => true,
+        };
+
+        events.push_str(&format!("event: done\ndata: {}\n\n", json!({ "success": success })));
+        events
+    }
+}
+
+fn data_event(line: &str) -> String {
+    format!("data: {}\n\n", line.trim_end_matches(['\r', '\n']))
+}
+
+impl<R: BufRead> Read for EventStream<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pending.is_empty() && !self.finished {
+            let mut line = String::new();
+            if self.lines.read_line(&mut line)? == 0 {
+                self.finished = true;
+                self.pending = self.finish().into_bytes();
+            } else {
+                self.pending = data_event(&line).into_bytes();
+            }
+        }
+
+        let count = buf.len().min(self.pending.len());
+        buf[..count].copy_from_slice(&self.pending[..count]);
+        self.pending.drain(..count);
+        Ok(count)
+    }
+}
+
 /// Handle CLI command execution requests
 fn handle_execute_request(
     request: &mut tiny_http::Request,
@@ -189,17 +515,7 @@ fn handle_execute_request(
         println!("  {} Executing: tideorm {}", "→".cyan(), command_display.yellow());
     }
 
-    let executable = match std::env::current_exe() {
-        Ok(path) => path,
-        Err(error) => {
-            return create_json_response(json!({
-                "success": false,
-                "error": format!("Failed to resolve CLI executable: {}", error),
-            }));
-        }
-    };
-
-    let output = std::process::Command::new(executable)
+    let output = cli_command(std::env::current_exe)
         .args(&payload.command)
         .output();
 
@@ -304,3 +620,192 @@ fn handle_query_request(
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{cli_command, migration_command_args, migrations_json, serve, CorsOptions, EventStream};
+    use crate::commands::migrate::MigrationStatusEntry;
+    use serde_json::json;
+    use std::io::{Cursor, ErrorKind, Read, Write};
+    use std::net::TcpStream;
+    use std::path::PathBuf;
+    use tiny_http::Server;
+
+    /// Start the UI server on a free port and return its address
+    fn spawn_server(cors: Option<CorsOptions>) -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap().to_string();
+        std::thread::spawn(move || serve(&server, cors.as_ref(), false));
+        addr
+    }
+
+    /// Send a raw HTTP request and return the whole response
+    fn send(addr: &str, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: {}\r\nOrigin: http://localhost:3000\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            method,
+            path,
+            addr,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn migrations_json_reports_status_batch_and_ran_at() {
+        let entries = vec![
+            MigrationStatusEntry {
+                name: "20240101000000_create_users".to_string(),
+                ran: true,
+                batch: Some(1),
+                ran_at: Some("2024-01-01 00:00:00".to_string()),
+            },
+            MigrationStatusEntry {
+                name: "20240201000000_create_posts".to_string(),
+                ran: false,
+                batch: None,
+                ran_at: None,
+            },
+        ];
+
+        assert_eq!(
+            migrations_json(&entries),
+            json!([
+                {
+                    "name": "20240101000000_create_users",
+                    "status": "ran",
+                    "batch": 1,
+                    "ran_at": "2024-01-01 00:00:00",
+                },
+                {
+                    "name": "20240201000000_create_posts",
+                    "status": "pending",
+                    "batch": null,
+                    "ran_at": null,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn migration_actions_map_to_cli_arguments() {
+        assert_eq!(
+            migration_command_args("/api/migrations/run", "{}").unwrap(),
+            vec!["migrate", "run"]
+        );
+        assert_eq!(
+            migration_command_args("/api/migrations/rollback", r#"{"step": 3}"#).unwrap(),
+            vec!["migrate", "down", "--step", "3"]
+        );
+        assert_eq!(
+            migration_command_args("/api/migrations/rollback", "").unwrap(),
+            vec!["migrate", "down", "--step", "1"]
+        );
+        assert!(migration_command_args("/api/migrations/rollback", r#"{"step": 0}"#).is_err());
+        assert!(migration_command_args("/api/migrations/run", "not json").is_err());
+    }
+
+    #[test]
+    fn event_stream_sends_each_line_then_done() {
+        let mut stream = EventStream::new(Cursor::new("Running migrations\r\nDONE\n"), None);
+        let mut body = String::new();
+        stream.read_to_string(&mut body).unwrap();
+
+        assert_eq!(
+            body,
+            "data: Running migrations\n\ndata: DONE\n\nevent: done\ndata: {\"success\":true}\n\n"
+        );
+    }
+
+    #[test]
+    fn event_stream_does_not_stall_on_a_full_stderr_pipe() {
+        // Far more stderr than a pipe buffer holds, written before any stdout
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "head -c 1000000 /dev/zero | tr '\\0' x >&2; echo >&2; echo migrated"])
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdout = std::io::BufReader::new(child.stdout.take().unwrap());
+        let mut stream = EventStream::new(stdout, Some(child));
+        let mut body = String::new();
+        stream.read_to_string(&mut body).unwrap();
+
+        assert!(body.starts_with("data: migrated\n\n"));
+        assert!(body.ends_with("event: done\ndata: {\"success\":true}\n\n"));
+        assert!(body.len() > 1_000_000);
+    }
+
+    #[test]
+    fn cors_headers_are_sent_on_get_post_and_preflight() {
+        let addr = spawn_server(Some(CorsOptions {
+            origin: "http://localhost:3000".to_string(),
+            max_age: Some(600),
+        }));
+
+        let get = send(&addr, "GET", "/api/config-check", "");
+        let post = send(&addr, "POST", "/api/migrations/rollback", r#"{"step": 0}"#);
+        let preflight = send(&addr, "OPTIONS", "/api/query", "");
+
+        for response in [&get, &post, &preflight] {
+            assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+            assert!(
+                response.contains("Access-Control-Allow-Origin: http://localhost:3000\r\n"),
+                "{}",
+                response
+            );
+            assert!(
+                response.contains("Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n"),
+                "{}",
+                response
+            );
+            assert!(
+                response.contains("Access-Control-Allow-Headers: Content-Type\r\n"),
+                "{}",
+                response
+            );
+            assert!(response.contains("Access-Control-Max-Age: 600\r\n"), "{}", response);
+        }
+        assert!(post.contains("step must be at least 1"), "{}", post);
+    }
+
+    #[test]
+    fn cors_origin_must_fit_in_a_header() {
+        assert!(CorsOptions::new("*".to_string(), None).is_ok());
+        assert!(CorsOptions::new("http://localhost:3000".to_string(), Some(600)).is_ok());
+
+        for origin in ["", "http://exämple.com", "http://a.com\r\nX-Evil: 1", "http://a.com b"] {
+            let error = CorsOptions::new(origin.to_string(), None).unwrap_err();
+            assert!(error.starts_with("Invalid --cors-origin"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn cors_headers_are_omitted_by_default() {
+        let addr = spawn_server(None);
+
+        let get = send(&addr, "GET", "/api/config-check", "");
+        let post = send(&addr, "POST", "/api/migrations/rollback", r#"{"step": 0}"#);
+
+        for response in [&get, &post] {
+            assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+            assert!(!response.contains("Access-Control-"), "{}", response);
+        }
+    }
+
+    #[test]
+    fn cli_command_runs_the_current_executable_and_falls_back_to_path() {
+        let command = cli_command(|| Ok(PathBuf::from("/opt/tideorm/bin/tideorm")));
+        assert_eq!(command.get_program(), "/opt/tideorm/bin/tideorm");
+
+        let command = cli_command(|| Err(std::io::Error::new(ErrorKind::NotFound, "no /proc")));
+        assert_eq!(command.get_program(), "tideorm");
+    }
+}
@@ -0,0 +1,654 @@
+//! Validate command for TideORM CLI
+//!
+//! Statically checks model, migration, seeder, and factory files for common
+//! mistakes without connecting to the database.
+
+use crate::commands::migrate::parse_migration_metadata;
+use crate::config::TideConfig;
+use crate::utils::{
+    pluralize_with_overrides, print_info, print_success, print_warning, to_snake_case,
+};
+use colored::Colorize;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Validate project files
+pub async fn run(
+    config_path: &str,
+    strict: bool,
+    check_style: bool,
+    fix_style: bool,
+    verbose: bool,
+) -> Result<(), String> {
+    let config = TideConfig::load_or_default(config_path);
+
+    if verbose {
+        print_info(&format!("Validating models in: {}", config.paths.models));
+        print_info(&format!("Validating migrations in: {}", config.paths.migrations));
+        print_info(&format!("Validating seeders in: {}", config.paths.seeders));
+        print_info(&format!("Validating factories in: {}", config.paths.factories));
+    }
+
+    let mut findings = validate_project(&config)?;
+
+    if check_style || fix_style {
+        if rustfmt_available() {
+            let files = generated_files(&config)?;
+
+            if fix_style {
+                let fixed = fix_formatting(&files);
+                if !fixed.is_empty() {
+                    print_success(&format!("Reformatted {} file(s) with rustfmt", fixed.len()));
+                }
+            }
+
+            for (file, diff) in check_formatting(&files) {
+                if verbose {
+                    println!("{}", diff);
+                }
+                findings.push(Finding::warning(
+                    &file,
+                    first_diff_line(&diff),
+                    "File is not formatted with rustfmt (run with --fix-style to reformat)",
+                ));
+            }
+        } else {
+            print_warning("rustfmt was not found on PATH; skipping style checks");
+        }
+    }
+
+    println!("\n{}", "TideORM Validation:".cyan().bold());
+    println!("{}", "─".repeat(80));
+
+    for finding in &findings {
+        println!("  {}", finding.display());
+    }
+
+    let errors = findings.iter().filter(|finding| finding.severity == Severity::Error).count();
+    let warnings = findings.len() - errors;
+
+    if findings.is_empty() {
+        print_success("No issues found");
+    } else {
+        println!("{}", "─".repeat(80));
+        println!("  {} error(s), {} warning(s)", errors, warnings);
+    }
+
+    check_outcome(&findings, strict)
+}
+
+/// Severity of a validation finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single issue found in a project file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Finding {
+    severity: Severity,
+    file: PathBuf,
+    line: usize,
+    message: String,
+}
+
+impl Finding {
+    fn error(file: &Path, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            file: file.to_path_buf(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn warning(file: &Path, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            file: file.to_path_buf(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn display(&self) -> String {
+        let label = match self.severity {
+            Severity::Error => "error".red().bold(),
+            Severity::Warning => "warning".yellow().bold(),
+        };
+
+        format!(
+            "{}: {}:{} {}",
+            label,
+            self.file.display(),
+            self.line,
+            self.message
+        )
+    }
+}
+
+/// Turn findings into the command result; warnings only fail in strict mode
+fn check_outcome(findings: &[Finding], strict: bool) -> Result<(), String> {
+    let errors = findings.iter().filter(|finding| finding.severity == Severity::Error).count();
+    let warnings = findings.len() - errors;
+
+    if errors > 0 {
+        return Err(format!("Validation failed with {} error(s)", errors));
+    }
+
+    if strict && warnings > 0 {
+        return Err(format!(
+            "Validation failed with {} warning(s) (--strict)",
+            warnings
+        ));
+    }
+
+    Ok(())
+}
+
+/// Run every check against the configured project directories
+fn validate_project(config: &TideConfig) -> Result<Vec<Finding>, String> {
+    let models = source_files(&config.paths.models)?;
+    let migrations = source_files(&config.paths.migrations)?;
+    let seeders = source_files(&config.paths.seeders)?;
+    let factories = source_files(&config.paths.factories)?;
+
+    let mut findings = Vec::new();
+    let migrated_tables = collect_migrated_tables(&migrations);
+    let model_structs = collect_model_structs(&models);
+
+    findings.extend(check_models(config, &models, &migrated_tables));
+    findings.extend(check_migrations(&migrations));
+    findings.extend(check_model_references(config, &seeders, &model_structs, "Seeder"));
+    findings.extend(check_model_references(config, &factories, &model_structs, "Factory"));
+
+    Ok(findings)
+}
+
+/// Whether `rustfmt` can be launched
+fn rustfmt_available() -> bool {
+    Command::new("rustfmt")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Paths of every model, migration, seeder, and factory file
+fn generated_files(config: &TideConfig) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+
+    for directory in [
+        &config.paths.models,
+        &config.paths.migrations,
+        &config.paths.seeders,
+        &config.paths.factories,
+    ] {
+        files.extend(source_files(directory)?.into_iter().map(|(path, _)| path));
+    }
+
+    Ok(files)
+}
+
+/// Rust edition of the project in the current directory, for rustfmt
+fn project_edition() -> String {
+    fs::read_to_string("Cargo.toml")
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+        .and_then(|manifest| {
+            manifest
+                .get("package")?
+                .get("edition")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| "2021".to_string())
+}
+
+fn rustfmt_command(edition: &str) -> Command {
+    let mut command = Command::new("rustfmt");
+    command.args(["--edition", edition, "--color", "never"]);
+    command
+}
+
+/// Run `rustfmt --check` on each file and return the files that would change with their diff
+pub fn check_formatting(files: &[PathBuf]) -> Vec<(PathBuf, String)> {
+    let edition = project_edition();
+
+    files
+        .iter()
+        .filter_map(|file| {
+            let output = rustfmt_command(&edition).arg("--check").arg(file).output().ok()?;
+            if output.status.success() {
+                return None;
+            }
+
+            let stdout = String::from_utf8_lossy(&output.stdout).trim_end().to_string();
+            let diff = if stdout.is_empty() {
+                String::from_utf8_lossy(&output.stderr).trim_end().to_string()
+            } else {
+                stdout
+            };
+
+            Some((file.clone(), diff))
+        })
+        .collect()
+}
+
+/// Reformat files in place, returning the ones rustfmt changed
+fn fix_formatting(files: &[PathBuf]) -> Vec<PathBuf> {
+    let edition = project_edition();
+
+    check_formatting(files)
+        .into_iter()
+        .map(|(file, _)| file)
+        .filter(|file| {
+            rustfmt_command(&edition)
+                .arg(file)
+                .status()
+                .is_ok_and(|status| status.success())
+        })
+        .collect()
+}
+
+/// Line of the first hunk in rustfmt's `Diff in <file>:<line>:` output
+fn first_diff_line(diff: &str) -> usize {
+    let pattern = Regex::new(r"(?m)^Diff in .*?(?::| at line )(\d+):\s*$").unwrap();
+
+    pattern
+        .captures(diff)
+        .and_then(|captures| captures[1].parse().ok())
+        .unwrap_or(1)
+}
+
+/// Read all `.rs` files in a directory except `mod.rs`, sorted by file name
+fn source_files(directory: &str) -> Result<Vec<(PathBuf, String)>, String> {
+    let path = Path::new(directory);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path).map_err(|e| format!("Failed to read {}: {}", directory, e))? {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let file_path = entry.path();
+
+        if file_path.extension().is_none_or(|ext| ext != "rs")
+            || file_path.file_stem().is_some_and(|stem| stem == "mod")
+        {
+            continue;
+        }
+
+        let content = fs::read_to_string(&file_path)
+            .map_err(|e| format!("Failed to read {}: {}", file_path.display(), e))?;
+        files.push((file_path, content));
+    }
+
+    files.sort_by(|left, right| left.0.cmp(&right.0));
+
+    Ok(files)
+}
+
+/// 1-based line number of a byte offset
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+/// Table names created by any migration
+fn collect_migrated_tables(migrations: &[(PathBuf, String)]) -> HashSet<String> {
+    let create_pattern = Regex::new(
+        r#"(?i)CREATE\s+TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?[`"\[]?(\w+)[`"\]]?|create_table\(\s*"(\w+)""#,
+    )
+    .unwrap();
+
+    migrations
+        .iter()
+        .flat_map(|(_, content)| {
+            create_pattern
+                .captures_iter(content)
+                .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+                .map(|table| table.as_str().to_lowercase())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Model struct names declared in the models directory
+fn collect_model_structs(models: &[(PathBuf, String)]) -> HashSet<String> {
+    models
+        .iter()
+        .filter_map(|(_, content)| parse_model(content))
+        .map(|model| model.name)
+        .collect()
+}
+
+/// Model struct and table as declared in a model file
+struct ParsedModel {
+    name: String,
+    table: Option<String>,
+    line: usize,
+}
+
+fn parse_model(content: &str) -> Option<ParsedModel> {
+    let struct_pattern = Regex::new(
+        r#"(?s)(?:#\[tideorm::model(?:\([^\]]*\))?\]|#\[derive\([^)]*Model[^)]*\)\])\s*(?:#\[[^\]]*\]\s*)*pub\s+struct\s+(\w+)"#,
+    )
+    .unwrap();
+    let table_pattern = Regex::new(r#"#\[tideorm(?:::model)?\([^\]]*table\s*=\s*"([^"]+)""#).unwrap();
+
+    let captures = struct_pattern.captures(content)?;
+    let name = captures.get(1)?;
+
+    Some(ParsedModel {
+        name: name.as_str().to_string(),
+        table: table_pattern
+            .captures(content)
+            .map(|captures| captures[1].to_string()),
+        line: line_at(content, name.start()),
+    })
+}
+
+/// Check that every model's table is created by a migration
+fn check_models(
+    config: &TideConfig,
+    models: &[(PathBuf, String)],
+    migrated_tables: &HashSet<String>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for (path, content) in models {
+        let Some(model) = parse_model(content) else {
+            findings.push(Finding::warning(path, 1, "No TideORM model struct found"));
+            continue;
+        };
+
+        let table = model.table.unwrap_or_else(|| {
+            pluralize_with_overrides(&to_snake_case(&model.name), &config.pluralization)
+        });
+
+        if !migrated_tables.contains(&table.to_lowercase()) {
+            findings.push(Finding::warning(
+                path,
+                model.line,
+                format!(
+                    "Model {} uses table '{}', which no migration creates",
+                    model.name, table
+                ),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Check migration methods and version ordering
+fn check_migrations(migrations: &[(PathBuf, String)]) -> Vec<Finding> {
+    let up_pattern = Regex::new(r"fn\s+up\s*\(").unwrap();
+    let down_pattern = Regex::new(r"fn\s+down\s*\(").unwrap();
+    let version_pattern = Regex::new(r"fn\s+version\s*\(").unwrap();
+
+    let mut findings = Vec::new();
+    let mut previous: Option<String> = None;
+
+    for (path, content) in migrations {
+        let file_name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+
+        if !up_pattern.is_match(content) {
+            findings.push(Finding::error(path, 1, "Migration is missing an `up` function"));
+        }
+
+        if !down_pattern.is_match(content) {
+            findings.push(Finding::error(path, 1, "Migration is missing a `down` function"));
+        }
+
+        let (version, _) = parse_migration_metadata(file_name, content);
+        let version_line = version_pattern
+            .find(content)
+            .map(|found| line_at(content, found.start()))
+            .unwrap_or(1);
+
+        if let Some(prefix) = file_name.split('_').next()
+            && prefix.chars().all(|character| character.is_ascii_digit())
+            && prefix != version
+        {
+            findings.push(Finding::warning(
+                path,
+                version_line,
+                format!(
+                    "Migration version {} does not match the file name timestamp {}",
+                    version, prefix
+                ),
+            ));
+        }
+
+        if let Some(previous) = &previous
+            && version.as_str() <= previous.as_str()
+        {
+            findings.push(Finding::error(
+                path,
+                version_line,
+                format!(
+                    "Migration version {} is not greater than the previous version {}",
+                    version, previous
+                ),
+            ));
+        }
+
+        previous = Some(version);
+    }
+
+    findings
+}
+
+/// Check that files referencing `crate::models` point at existing models
+fn check_model_references(
+    config: &TideConfig,
+    files: &[(PathBuf, String)],
+    model_structs: &HashSet<String>,
+    kind: &str,
+) -> Vec<Finding> {
+    let reference_pattern = Regex::new(r"crate::models::(\w+)(?:::(\w+))?").unwrap();
+    let mut findings = Vec::new();
+
+    for (path, content) in files {
+        for captures in reference_pattern.captures_iter(content) {
+            let line = line_at(content, captures.get(0).unwrap().start());
+            let first = &captures[1];
+
+            match captures.get(2) {
+                Some(model) => {
+                    let model_path = Path::new(&config.paths.models).join(format!("{}.rs", first));
+                    if !model_path.exists() {
+                        findings.push(Finding::error(
+                            path,
+                            line,
+                            format!(
+                                "{} references {}, but {} does not exist",
+                                kind,
+                                model.as_str(),
+                                model_path.display()
+                            ),
+                        ));
+                    }
+                }
+                None if first.starts_with(char::is_uppercase) && !model_structs.contains(first) => {
+                    findings.push(Finding::error(
+                        path,
+                        line,
+                        format!(
+                            "{} references {}, but no model file in {} declares it",
+                            kind, first, config.paths.models
+                        ),
+                    ));
+                }
+                None => {}
+            }
+        }
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_formatting, check_outcome, first_diff_line, fix_formatting, rustfmt_available,
+        validate_project, Severity,
+    };
+    use crate::config::TideConfig;
+    use crate::generators::model::ModelGenerator;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const USER_MODEL: &str = r#"use tideorm::prelude::*;
+
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+}
+"#;
+
+    fn migration(version: &str, table: &str, with_down: bool) -> String {
+        let down = if with_down {
+            format!(
+                "    async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {{\n        schema.raw(r#\"DROP TABLE IF EXISTS {table}\"#).await?;\n        Ok(())\n    }}\n"
+            )
+        } else {
+            String::new()
+        };
+
+        format!(
+            "use tideorm::prelude::*;\n\npub struct Migration;\n\n#[async_trait]\nimpl Migration for Migration {{\n    fn version(&self) -> &str {{\n        \"{version}\"\n    }}\n\n    async fn up(&self, schema: &mut Schema) -> tideorm::Result<()> {{\n        schema.raw(r#\"CREATE TABLE IF NOT EXISTS {table} (id INTEGER PRIMARY KEY)\"#).await?;\n        Ok(())\n    }}\n\n{down}}}\n"
+        )
+    }
+
+    fn project() -> (TempDir, TideConfig) {
+        let dir = TempDir::new().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        config.paths.migrations = dir.path().join("migrations").to_string_lossy().into_owned();
+        config.paths.seeders = dir.path().join("seeders").to_string_lossy().into_owned();
+        config.paths.factories = dir.path().join("factories").to_string_lossy().into_owned();
+
+        for path in [
+            &config.paths.models,
+            &config.paths.migrations,
+            &config.paths.seeders,
+            &config.paths.factories,
+        ] {
+            fs::create_dir_all(path).unwrap();
+        }
+
+        (dir, config)
+    }
+
+    fn write(directory: &str, file: &str, content: &str) {
+        fs::write(Path::new(directory).join(file), content).unwrap();
+    }
+
+    #[test]
+    fn consistent_project_has_no_findings() {
+        let (_dir, config) = project();
+        write(&config.paths.models, "user.rs", USER_MODEL);
+        write(
+            &config.paths.migrations,
+            "20260101000000_create_users_table.rs",
+            &migration("20260101000000", "users", true),
+        );
+        write(&config.paths.seeders, "user_seeder.rs", "use crate::models::user::User;\n");
+        write(&config.paths.factories, "user_factory.rs", "use crate::models::user::User;\n");
+
+        let findings = validate_project(&config).unwrap();
+        assert!(findings.is_empty(), "{:?}", findings);
+        assert!(check_outcome(&findings, true).is_ok());
+    }
+
+    #[test]
+    fn reports_errors_with_file_and_line() {
+        let (_dir, config) = project();
+        write(&config.paths.models, "user.rs", USER_MODEL);
+        write(
+            &config.paths.migrations,
+            "20260102000000_create_users_table.rs",
+            &migration("20260102000000", "users", true),
+        );
+        write(
+            &config.paths.migrations,
+            "20260103000000_create_posts_table.rs",
+            &migration("20260101000000", "posts", false),
+        );
+        write(&config.paths.seeders, "post_seeder.rs", "\nuse crate::models::post::Post;\n");
+        write(&config.paths.factories, "comment_factory.rs", "use crate::models::Comment;\n");
+
+        let findings = validate_project(&config).unwrap();
+        let errors: Vec<_> = findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Error)
+            .collect();
+
+        assert!(errors.iter().any(|finding| finding.message.contains("missing a `down` function")));
+        assert!(errors.iter().any(|finding| {
+            finding.message.contains("not greater than the previous version") && finding.line == 7
+        }));
+        assert!(errors.iter().any(|finding| {
+            finding.file.ends_with("post_seeder.rs")
+                && finding.line == 2
+                && finding.message.contains("Seeder references Post")
+        }));
+        assert!(errors.iter().any(|finding| {
+            finding.file.ends_with("comment_factory.rs") && finding.message.contains("Factory references Comment")
+        }));
+        assert!(check_outcome(&findings, false).is_err());
+    }
+
+    #[test]
+    fn warnings_only_fail_in_strict_mode() {
+        let (_dir, config) = project();
+        write(&config.paths.models, "user.rs", USER_MODEL);
+
+        let findings = validate_project(&config).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(findings[0].line, 4);
+        assert!(findings[0].message.contains("table 'users'"));
+
+        assert!(check_outcome(&findings, false).is_ok());
+        assert!(check_outcome(&findings, true).is_err());
+    }
+
+    #[test]
+    fn check_formatting_reports_files_rustfmt_would_change() {
+        if !rustfmt_available() {
+            return;
+        }
+
+        let (_dir, config) = project();
+        let path = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string".to_string()))
+            .output_dir(&config.paths.models)
+            .generate()
+            .unwrap();
+        let files = vec![std::path::PathBuf::from(&path)];
+
+        fix_formatting(&files);
+        assert!(check_formatting(&files).is_empty());
+
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, content.replace("pub struct User {", "pub   struct User{")).unwrap();
+
+        let reported = check_formatting(&files);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].0, files[0]);
+        assert!(reported[0].1.contains("pub   struct User{"));
+        assert!(first_diff_line(&reported[0].1) > 1);
+    }
+}
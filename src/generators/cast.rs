@@ -0,0 +1,192 @@
+//! Cast generator for TideORM CLI
+
+use crate::utils::{ensure_directory, inject_after_marker, to_snake_case, write_or_update_file};
+
+/// Type-cast helper generator
+pub struct CastGenerator {
+    from_type: String,
+    to_type: String,
+}
+
+impl CastGenerator {
+    /// Create a cast generator converting between `from_type` and `to_type`
+    pub fn new(from_type: &str, to_type: &str) -> Result<Self, String> {
+        let from_type = from_type.trim();
+        let to_type = to_type.trim();
+
+        if from_type.is_empty() || to_type.is_empty() {
+            return Err("Both --from-type and --to-type are required".to_string());
+        }
+
+        if from_type == to_type {
+            return Err(format!("--from-type and --to-type are both {}", from_type));
+        }
+
+        Ok(Self {
+            from_type: from_type.to_string(),
+            to_type: to_type.to_string(),
+        })
+    }
+
+    /// Whether the generated code needs the `uuid` crate
+    pub fn uses_uuid(&self) -> bool {
+        [&self.from_type, &self.to_type].iter().any(|ty| ty.contains("uuid::"))
+    }
+
+    /// Generate a cast file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        ensure_directory(output)?;
+
+        let cast_name = if name.ends_with("Cast") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Cast", to_pascal_case(name))
+        };
+
+        let file_name = format!("{}.rs", to_snake_case(&cast_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_cast(&cast_name);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write cast file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &cast_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate cast content
+    fn generate_cast(&self, cast_name: &str) -> String {
+        let from_type = &self.from_type;
+        let to_type = &self.to_type;
+
+        // Strings are parsed and rendered with FromStr/Display; anything else goes
+        // through TryFrom/From, which the two types must implement
+        let (to_body, from_body) = if is_string(from_type) {
+            (
+                format!(
+                    r#"let trimmed = val.trim();
+        if trimmed.is_empty() {{
+            return Err(tideorm::Error::conversion("cannot cast an empty string to {to_type}"));
+        }}
+
+        trimmed
+            .parse::<{to_type}>()
+            .map_err(|e| tideorm::Error::conversion(format!("invalid {to_type} {{:?}}: {{}}", val, e)))"#,
+                    to_type = to_type,
+                ),
+                "val.to_string()".to_string(),
+            )
+        } else {
+            (
+                format!(
+                    r#"<{to_type}>::try_from(val.clone())
+            .map_err(|_| tideorm::Error::conversion(format!("cannot cast {{:?}} to {to_type}", val)))"#,
+                    to_type = to_type,
+                ),
+                format!("<{}>::from(val.clone())", from_type),
+            )
+        };
+
+        format!(
+            r#"//! {cast_name}
+//!
+//! Casts between the stored `{from_type}` and `{to_type}`.
+
+/// Converts `{from_type}` values to `{to_type}` and back
+pub struct {cast_name};
+
+impl {cast_name} {{
+    /// Cast a stored value, failing with a conversion error when it is invalid
+    #[inline]
+    pub fn to(val: &{from_type}) -> tideorm::Result<{to_type}> {{
+        {to_body}
+    }}
+
+    /// Cast a value back to its stored form
+    #[inline]
+    pub fn from(val: &{to_type}) -> {from_type} {{
+        {from_body}
+    }}
+}}
+"#,
+            cast_name = cast_name,
+            from_type = from_type,
+            to_type = to_type,
+            to_body = to_body,
+            from_body = from_body,
+        )
+    }
+
+    /// Update mod.rs with new cast
+    fn update_mod_file(&self, output: &str, cast_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(cast_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, cast_name),
+        )
+    }
+}
+
+fn is_string(rust_type: &str) -> bool {
+    matches!(rust_type, "String" | "std::string::String")
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CastGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn string_casts_parse_and_validate() {
+        let content = CastGenerator::new("String", "uuid::Uuid")
+            .unwrap()
+            .generate_cast("UuidCast");
+
+        assert!(content.contains("pub struct UuidCast;"));
+        assert!(content.contains(
+            "    #[inline]\n    pub fn to(val: &String) -> tideorm::Result<uuid::Uuid> {"
+        ));
+        assert!(content.contains("    #[inline]\n    pub fn from(val: &uuid::Uuid) -> String {\n        val.to_string()\n    }"));
+        assert!(content.contains(".parse::<uuid::Uuid>()"));
+        assert!(content.contains("cannot cast an empty string to uuid::Uuid"));
+        assert!(CastGenerator::new("String", "uuid::Uuid").unwrap().uses_uuid());
+    }
+
+    #[test]
+    fn other_casts_use_try_from_and_from() {
+        let content = CastGenerator::new("i64", "u32").unwrap().generate_cast("CountCast");
+
+        assert!(content.contains("<u32>::try_from(val.clone())"));
+        assert!(content.contains("pub fn from(val: &u32) -> i64 {\n        <i64>::from(val.clone())"));
+        assert!(!content.contains(".parse::<"));
+        assert!(CastGenerator::new("String", " String ").is_err());
+        assert!(!CastGenerator::new("i64", "u32").unwrap().uses_uuid());
+    }
+
+    #[test]
+    fn generate_registers_cast_once() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("casts").to_string_lossy().into_owned();
+        let generator = CastGenerator::new("String", "uuid::Uuid").unwrap();
+
+        let path = generator.generate("uuid", &output).unwrap();
+        generator.generate("UuidCast", &output).unwrap();
+
+        assert!(path.ends_with("uuid_cast.rs"));
+        let mod_content = std::fs::read_to_string(dir.path().join("casts/mod.rs")).unwrap();
+        assert_eq!(mod_content, "pub mod uuid_cast;\npub use uuid_cast::UuidCast;\n");
+    }
+}
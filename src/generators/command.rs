@@ -0,0 +1,244 @@
+//! Custom command generator for TideORM CLI
+
+use crate::utils::{
+    ensure_directory, split_field_definitions, to_snake_case, write_or_update_file, FieldDefinition,
+};
+
+/// Generator for a clap subcommand in the user's project
+pub struct CommandGenerator {
+    description: String,
+    args: Vec<FieldDefinition>,
+    global_flags: bool,
+}
+
+impl CommandGenerator {
+    /// Create a command generator with the given help text
+    pub fn new(description: &str) -> Self {
+        Self {
+            description: description.trim().to_string(),
+            args: Vec::new(),
+            global_flags: false,
+        }
+    }
+
+    /// Add args parsed from `name:type[:modifiers]` definitions, e.g. `verbose:bool,path:string`
+    pub fn args(mut self, args: Option<&str>) -> Result<Self, String> {
+        self.args = split_field_definitions(args.unwrap_or_default())
+            .into_iter()
+            .map(FieldDefinition::parse)
+            .collect::<Result<_, _>>()?;
+
+        for (index, arg) in self.args.iter().enumerate() {
+            if self.args[..index].iter().any(|other| other.name == arg.name) {
+                return Err(format!("Duplicate command arg: {}", arg.name));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Copy the CLI's global `verbose` and `config` flags into the args struct
+    pub fn global_flags(mut self, global_flags: bool) -> Self {
+        self.global_flags = global_flags;
+        self
+    }
+
+    /// Generate a command file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        if self.global_flags
+            && let Some(arg) = self
+                .args
+                .iter()
+                .find(|arg| arg.name == "verbose" || arg.name == "config")
+        {
+            return Err(format!(
+                "--args field '{}' clashes with the flag added by --global-flags",
+                arg.name
+            ));
+        }
+
+        ensure_directory(output)?;
+
+        let command_name = to_pascal_case(name);
+        let module_name = to_snake_case(&command_name);
+        let file_path = format!("{}/{}.rs", output, module_name);
+
+        let content = self.generate_command(&command_name);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write command file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &module_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate command content
+    fn generate_command(&self, command_name: &str) -> String {
+        let module_name = to_snake_case(command_name);
+        let description = if self.description.is_empty() {
+            format!("{} command", command_name)
+        } else {
+            self.description.clone()
+        };
+
+        let mut fields = Vec::new();
+
+        if self.global_flags {
+            fields.push(
+                r#"    /// Path to the TideORM configuration file
+    #[arg(short, long, default_value = "tideorm.toml")]
+    pub config: String,"#
+                    .to_string(),
+            );
+            fields.push(
+                r#"    /// Enable verbose output
+    #[arg(short, long)]
+    pub verbose: bool,"#
+                    .to_string(),
+            );
+        }
+
+        for arg in &self.args {
+            let attribute = match &arg.default {
+                Some(default) => format!("#[arg(long, default_value = {:?})]", default),
+                None => "#[arg(long)]".to_string(),
+            };
+
+            fields.push(format!(
+                "    /// {name}\n    {attribute}\n    pub {name}: {rust_type},",
+                name = arg.name,
+                attribute = attribute,
+                rust_type = arg.rust_type(),
+            ));
+        }
+
+        let fields: String = fields
+            .iter()
+            .map(|field| format!("{}\n", field))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"//! {command_name} command
+//!
+//! {description}
+//!
+//! Register the command in `main.rs`:
+//!
+//! ```ignore
+//! #[derive(clap::Subcommand)]
+//! enum Commands {{
+//!     /// {description}
+//!     {command_name}(commands::{module_name}::{command_name}Args),
+//! }}
+//!
+//! // In main(), dispatch it with the other commands:
+//! Commands::{command_name}(args) => commands::{module_name}::run(&args).await,
+//! ```
+
+/// Arguments for the {command_name} command
+#[derive(Debug, Clone, clap::Args)]
+pub struct {command_name}Args {{
+{fields}}}
+
+/// {description}
+pub async fn run(args: &{command_name}Args) -> Result<(), String> {{
+    // TODO: Implement the command
+    let _ = args;
+    Ok(())
+}}
+"#,
+            command_name = command_name,
+            module_name = module_name,
+            description = description,
+            fields = fields,
+        )
+    }
+
+    /// Update mod.rs with new command
+    fn update_mod_file(&self, output: &str, module_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_decl = format!("pub mod {};", module_name);
+
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)
+    }
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_command_has_args_struct_and_run() {
+        let content = CommandGenerator::new("Sync users from the CRM")
+            .args(Some("verbose:bool,path:string,limit:i32:default=10,since:string:nullable"))
+            .unwrap()
+            .generate_command("SyncUsers");
+
+        assert!(content.contains("#[derive(Debug, Clone, clap::Args)]\npub struct SyncUsersArgs {"));
+        assert!(content.contains("    #[arg(long)]\n    pub verbose: bool,"));
+        assert!(content.contains("    #[arg(long)]\n    pub path: String,"));
+        assert!(content.contains("    #[arg(long, default_value = \"10\")]\n    pub limit: i32,"));
+        assert!(content.contains("    pub since: Option<String>,\n}"));
+        assert!(content.contains(
+            "/// Sync users from the CRM\npub async fn run(args: &SyncUsersArgs) -> Result<(), String> {"
+        ));
+        assert!(content.contains("//!     SyncUsers(commands::sync_users::SyncUsersArgs),"));
+        assert!(content.contains(
+            "//! Commands::SyncUsers(args) => commands::sync_users::run(&args).await,"
+        ));
+        assert!(!content.contains("pub config: String"));
+    }
+
+    #[test]
+    fn global_flags_add_config_and_verbose() {
+        let content = CommandGenerator::new("")
+            .global_flags(true)
+            .generate_command("Prune");
+
+        assert!(content.contains(
+            "    #[arg(short, long, default_value = \"tideorm.toml\")]\n    pub config: String,"
+        ));
+        assert!(content.contains("    #[arg(short, long)]\n    pub verbose: bool,\n}"));
+        assert!(content.contains("/// Prune command\npub async fn run("));
+    }
+
+    #[test]
+    fn invalid_args_are_rejected() {
+        assert!(CommandGenerator::new("").args(Some("path")).is_err());
+        assert!(CommandGenerator::new("").args(Some("path:string,path:string")).is_err());
+
+        let dir = tempdir().unwrap();
+        let output = dir.path().to_string_lossy().into_owned();
+        let error = CommandGenerator::new("")
+            .args(Some("verbose:bool"))
+            .unwrap()
+            .global_flags(true)
+            .generate("Prune", &output)
+            .unwrap_err();
+        assert!(error.contains("'verbose'"));
+    }
+
+    #[test]
+    fn generate_registers_command_once() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("commands");
+        let output_path = output.to_string_lossy().into_owned();
+
+        let generator = CommandGenerator::new("Sync users");
+        let path = generator.generate("sync_users", &output_path).unwrap();
+        generator.generate("SyncUsers", &output_path).unwrap();
+
+        assert!(path.ends_with("sync_users.rs"));
+        let mod_content = std::fs::read_to_string(output.join("mod.rs")).unwrap();
+        assert_eq!(mod_content, "pub mod sync_users;\n");
+    }
+}
@@ -0,0 +1,659 @@
+//! Config generator for TideORM CLI
+
+use crate::utils::{ensure_directory, inject_after_marker, is_identifier, to_snake_case, write_or_update_file};
+use std::path::Path;
+
+/// Type of a config field, parsed from the environment with `FromStr`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigType {
+    String,
+    Bool,
+    Integer(&'static str),
+    Float(&'static str),
+}
+
+impl ConfigType {
+    const INTEGERS: [&'static str; 12] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+
+    fn parse(ty: &str) -> Result<Self, String> {
+        match ty.trim() {
+            "string" | "String" => Ok(Self::String),
+            "bool" => Ok(Self::Bool),
+            "f32" => Ok(Self::Float("f32")),
+            "f64" => Ok(Self::Float("f64")),
+            other => Self::INTEGERS
+                .iter()
+                .find(|integer| **integer == other)
+                .map(|integer| Self::Integer(integer))
+                .ok_or_else(|| {
+                    format!(
+                        "Unsupported config type: {}. Expected string, bool, an integer type, f32, or f64",
+                        other
+                    )
+                }),
+        }
+    }
+
+    fn rust_type(self) -> &'static str {
+        match self {
+            Self::String => "String",
+            Self::Bool => "bool",
+            Self::Integer(ty) | Self::Float(ty) => ty,
+        }
+    }
+
+    /// `value` as a Rust expression of this type
+    fn literal(self, value: &str) -> Result<String, String> {
+        let invalid = || format!("Invalid {} default: '{}'", self.rust_type(), value);
+
+        match self {
+            Self::String => Ok(format!("{:?}.to_string()", value)),
+            Self::Bool => value.trim().parse::<bool>().map(|b| b.to_string()).map_err(|_| invalid()),
+            Self::Integer(ty) => {
+                let number: i128 = value.trim().parse().map_err(|_| invalid())?;
+                if ty.starts_with('u') && number < 0 {
+                    return Err(invalid());
+                }
+                Ok(number.to_string())
+            }
+            Self::Float(_) => {
+                let number: f64 = value.trim().parse().map_err(|_| invalid())?;
+                if !number.is_finite() {
+                    return Err(invalid());
+                }
+                Ok(format!("{:?}", number))
+            }
+        }
+    }
+
+    /// A value the generated tests set for required fields, and the expression it parses to
+    fn sample(self) -> (&'static str, &'static str) {
+        match self {
+            Self::String => ("value", "\"value\""),
+            Self::Bool => ("true", "true"),
+            Self::Integer(_) => ("1", "1"),
+            Self::Float(_) => ("1.5", "1.5"),
+        }
+    }
+}
+
+/// Whether a field must be set, falls back to a default, or is left `None`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Presence {
+    Required,
+    /// Rust expression for the default value, and the default as written
+    Default(String, String),
+    Optional,
+}
+
+/// One `name:type:default` field
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConfigField {
+    name: String,
+    ty: ConfigType,
+    presence: Presence,
+}
+
+impl ConfigField {
+    /// Parse `port:u16:8080`, `db_url:string:required`, or `debug:bool` (left `None` when unset)
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.splitn(3, ':');
+        let name = parts.next().unwrap_or_default().trim();
+        let ty = parts.next().ok_or_else(|| {
+            format!("Invalid config field '{}'. Expected format: name:type[:default|required]", spec)
+        })?;
+
+        if !is_identifier(name) {
+            return Err(format!("Invalid config field name: '{}'", name));
+        }
+
+        let ty = ConfigType::parse(ty)?;
+        let presence = match parts.next() {
+            None => Presence::Optional,
+            Some(value) if value.trim() == "required" => Presence::Required,
+            Some(value) => Presence::Default(ty.literal(value)?, value.to_string()),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            ty,
+            presence,
+        })
+    }
+
+    fn rust_type(&self) -> String {
+        match self.presence {
+            Presence::Optional => format!("Option<{}>", self.ty.rust_type()),
+            _ => self.ty.rust_type().to_string(),
+        }
+    }
+}
+
+/// Generator for a settings struct loaded from environment variables
+#[derive(Debug, Default)]
+pub struct ConfigGenerator {
+    fields: Vec<ConfigField>,
+    prefix: String,
+}
+
+impl ConfigGenerator {
+    /// Create a new config generator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add fields from a comma-separated list, e.g. `port:u16:8080,db_url:string:required`
+    pub fn fields(mut self, fields: &str) -> Result<Self, String> {
+        self.fields = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|spec| !spec.is_empty())
+            .map(ConfigField::parse)
+            .collect::<Result<_, _>>()?;
+
+        if self.fields.is_empty() {
+            return Err("--fields must list at least one field".to_string());
+        }
+
+        for (index, field) in self.fields.iter().enumerate() {
+            if self.fields[..index].iter().any(|other| other.name == field.name) {
+                return Err(format!("Duplicate config field: {}", field.name));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Prefix every environment variable name, e.g. `APP_` reads `port` from `APP_PORT`
+    pub fn prefix(mut self, prefix: Option<&str>) -> Result<Self, String> {
+        if let Some(prefix) = prefix {
+            if !prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("Invalid environment variable prefix: '{}'", prefix));
+            }
+            self.prefix = prefix.to_string();
+        }
+        Ok(self)
+    }
+
+    /// Generate a config file, plus the shared `ConfigError` the first time
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        ensure_directory(output)?;
+
+        let base_name = to_pascal_case(name.strip_suffix("Config").unwrap_or(name));
+        let config_name = format!("{}Config", base_name);
+
+        let file_name = format!("{}.rs", to_snake_case(&config_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        std::fs::write(&file_path, self.generate_config(&config_name))
+            .map_err(|e| format!("Failed to write config file: {}", e))?;
+
+        let error_path = format!("{}/config_error.rs", output);
+        if !Path::new(&error_path).exists() {
+            std::fs::write(&error_path, CONFIG_ERROR)
+                .map_err(|e| format!("Failed to write config error file: {}", e))?;
+        }
+
+        // Update mod.rs
+        self.update_mod_file(output, &config_name)?;
+
+        Ok(file_path)
+    }
+
+    fn env_var(&self, field: &ConfigField) -> String {
+        format!("{}{}", self.prefix, field.name.to_uppercase())
+    }
+
+    /// Generate config content
+    fn generate_config(&self, config_name: &str) -> String {
+        let source = if self.prefix.is_empty() {
+            "environment variables".to_string()
+        } else {
+            format!("`{}*` environment variables", self.prefix)
+        };
+
+        let struct_fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let doc = match &field.presence {
+                    Presence::Required => format!("`{}` (required)", self.env_var(field)),
+                    Presence::Default(_, value) => format!("`{}`, default `{}`", self.env_var(field), value),
+                    Presence::Optional => format!("`{}`, `None` when unset", self.env_var(field)),
+                };
+                format!("    /// {}\n    pub {}: {},", doc, field.name, field.rust_type())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let reads = self
+            .fields
+            .iter()
+            .map(|field| {
+                let var = self.env_var(field);
+                match &field.presence {
+                    Presence::Required => format!("        let {} = errors.required({:?});", field.name, var),
+                    Presence::Default(default, _) if field.ty == ConfigType::String => format!(
+                        "        let {} = errors.var({:?}).unwrap_or_else(|| {});",
+                        field.name, var, default
+                    ),
+                    Presence::Default(default, _) => {
+                        format!("        let {} = errors.var({:?}).unwrap_or({});", field.name, var, default)
+                    }
+                    Presence::Optional => format!("        let {} = errors.var({:?});", field.name, var),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let required: Vec<&ConfigField> = self
+            .fields
+            .iter()
+            .filter(|field| field.presence == Presence::Required)
+            .collect();
+        let field_names = self
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let construct = format!("Self {{ {} }}", field_names);
+
+        // Required values are only unwrapped once every variable has been checked
+        let result = match required.as_slice() {
+            [] => format!(
+                "        if errors.is_empty() {{\n            Ok({})\n        }} else {{\n            Err(errors)\n        }}",
+                construct
+            ),
+            [field] => format!(
+                "        match {name} {{\n            Some({name}) if errors.is_empty() => Ok({construct}),\n            _ => Err(errors),\n        }}",
+                name = field.name,
+                construct = construct
+            ),
+            fields => {
+                let names = fields.iter().map(|field| field.name.as_str()).collect::<Vec<_>>();
+                let patterns = names.iter().map(|name| format!("Some({})", name)).collect::<Vec<_>>();
+                format!(
+                    "        match ({}) {{\n            ({}) if errors.is_empty() => Ok({}),\n            _ => Err(errors),\n        }}",
+                    names.join(", "),
+                    patterns.join(", "),
+                    construct
+                )
+            }
+        };
+
+        format!(
+            r#"//! {config_name}
+//!
+//! Settings loaded from {source}.
+
+use super::ConfigError;
+
+/// Settings read from {source}
+#[derive(Debug)]
+pub struct {config_name} {{
+{struct_fields}
+}}
+
+impl {config_name} {{
+    /// Load the settings, reporting every missing or invalid variable at once
+    pub fn from_env() -> Result<Self, ConfigError> {{
+        let mut errors = ConfigError::default();
+
+{reads}
+
+{result}
+    }}
+}}
+
+{tests}"#,
+            config_name = config_name,
+            source = source,
+            struct_fields = struct_fields,
+            reads = reads,
+            result = result,
+            tests = self.generate_tests(config_name, &required),
+        )
+    }
+
+    /// Tests for `from_env`, run with the variables set by a scoped helper
+    fn generate_tests(&self, config_name: &str, required: &[&ConfigField]) -> String {
+        let vars = self
+            .fields
+            .iter()
+            .map(|field| format!("{:?}", self.env_var(field)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let required_values = required
+            .iter()
+            .map(|field| format!("({:?}, {:?})", self.env_var(field), field.ty.sample().0))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let assertions = self
+            .fields
+            .iter()
+            .map(|field| {
+                let expected = match &field.presence {
+                    Presence::Required => field.ty.sample().1.to_string(),
+                    Presence::Default(_, value) if field.ty == ConfigType::String => format!("{:?}", value),
+                    Presence::Default(default, _) => default.clone(),
+                    Presence::Optional => "None".to_string(),
+                };
+                format!("            assert_eq!(config.{}, {});", field.name, expected)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut tests = format!(
+            r#"    #[test]
+    fn loads_required_values_and_defaults() {{
+        with_env(&[{required_values}], || {{
+            let config = {config_name}::from_env().unwrap();
+{assertions}
+        }});
+    }}
+"#,
+            required_values = required_values,
+            config_name = config_name,
+            assertions = assertions,
+        );
+
+        if !required.is_empty() {
+            let missing = required
+                .iter()
+                .map(|field| format!("{:?}", self.env_var(field)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            tests.push_str(&format!(
+                r#"
+    #[test]
+    fn reports_every_missing_variable() {{
+        with_env(&[], || {{
+            let error = {config_name}::from_env().unwrap_err();
+            assert_eq!(error.missing, vec![{missing}]);
+        }});
+    }}
+"#,
+                config_name = config_name,
+                missing = missing,
+            ));
+        }
+
+        if let Some(field) = self.fields.iter().find(|field| field.ty != ConfigType::String) {
+            let var = self.env_var(field);
+            let mut values = required
+                .iter()
+                .filter(|other| other.name != field.name)
+                .map(|other| format!("({:?}, {:?})", self.env_var(other), other.ty.sample().0))
+                .collect::<Vec<_>>();
+            values.push(format!("({:?}, \"not-a-{}\")", var, field.ty.rust_type()));
+            tests.push_str(&format!(
+                r#"
+    #[test]
+    fn reports_invalid_values() {{
+        with_env(&[{values}], || {{
+            let error = {config_name}::from_env().unwrap_err();
+            assert!(error.invalid[0].starts_with("{var}: "));
+        }});
+    }}
+"#,
+                values = values.join(", "),
+                config_name = config_name,
+                var = var,
+            ));
+        }
+
+        format!(
+            r#"#[cfg(test)]
+mod tests {{
+    use super::{config_name};
+    use std::sync::Mutex;
+
+    const VARS: [&str; {count}] = [{vars}];
+
+    /// The environment is shared by every test thread, so tests that change it take turns
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Run `test` with only `vars` set among the {config_name} variables, then restore them
+    #[allow(unused_unsafe)]
+    fn with_env(vars: &[(&str, &str)], test: impl FnOnce()) {{
+        let _lock = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let saved: Vec<_> = VARS.iter().map(|var| (*var, std::env::var_os(var))).collect();
+
+        // set_var and remove_var are unsafe from the 2024 edition on; the lock keeps them exclusive
+        for var in VARS {{
+            unsafe {{ std::env::remove_var(var) }};
+        }}
+        for (var, value) in vars {{
+            unsafe {{ std::env::set_var(var, value) }};
+        }}
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(test));
+
+        for (var, value) in saved {{
+            match value {{
+                Some(value) => unsafe {{ std::env::set_var(var, value) }},
+                None => unsafe {{ std::env::remove_var(var) }},
+            }}
+        }}
+
+        if let Err(panic) = result {{
+            std::panic::resume_unwind(panic);
+        }}
+    }}
+
+{tests}}}
+"#,
+            config_name = config_name,
+            count = self.fields.len(),
+            vars = vars,
+            tests = tests,
+        )
+    }
+
+    /// Register the config and `ConfigError` in mod.rs, or in `{output}.rs` when the
+    /// project keeps the parent module there (as `tideorm init` does with src/config.rs)
+    fn update_mod_file(&self, output: &str, config_name: &str) -> Result<(), String> {
+        let sibling = format!("{}.rs", output.trim_end_matches('/'));
+        let mod_path = if Path::new(&sibling).exists() {
+            sibling
+        } else {
+            format!("{}/mod.rs", output)
+        };
+
+        for (module_name, exports) in [
+            ("config_error".to_string(), "ConfigError".to_string()),
+            (to_snake_case(config_name), config_name.to_string()),
+        ] {
+            let module_decl = format!("pub mod {};", module_name);
+            write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+            inject_after_marker(
+                &mod_path,
+                &module_decl,
+                &format!("pub use {}::{};", module_name, exports),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error shared by every generated `from_env()`
+const CONFIG_ERROR: &str = r#"//! ConfigError
+//!
+//! Returned by the `from_env()` constructors in this module.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Every missing or invalid environment variable found while loading settings
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigError {
+    /// Required variables that aren't set
+    pub missing: Vec<String>,
+    /// Variables that couldn't be parsed, as `NAME: reason`
+    pub invalid: Vec<String>,
+}
+
+impl ConfigError {
+    /// Parse `var` when it is set, recording a value that doesn't parse
+    pub fn var<T>(&mut self, var: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = match std::env::var(var) {
+            Ok(value) => value,
+            Err(std::env::VarError::NotPresent) => return None,
+            Err(error) => {
+                self.invalid.push(format!("{}: {}", var, error));
+                return None;
+            }
+        };
+
+        match value.parse() {
+            Ok(parsed) => Some(parsed),
+            Err(error) => {
+                self.invalid.push(format!("{}: {:?} {}", var, value, error));
+                None
+            }
+        }
+    }
+
+    /// Like [`Self::var`], recording `var` as missing when it isn't set
+    pub fn required<T>(&mut self, var: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if std::env::var_os(var).is_none() {
+            self.missing.push(var.to_string());
+            return None;
+        }
+        self.var(var)
+    }
+
+    /// Whether every variable was found and parsed
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.invalid.is_empty()
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut problems = Vec::new();
+        if !self.missing.is_empty() {
+            problems.push(format!("missing environment variables: {}", self.missing.join(", ")));
+        }
+        if !self.invalid.is_empty() {
+            problems.push(format!("invalid environment variables: {}", self.invalid.join("; ")));
+        }
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+"#;
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn fields_are_read_with_defaults_and_required_checks() {
+        let content = ConfigGenerator::new()
+            .fields("port:u16:8080,db_url:string:required,host:string:localhost,debug:bool,ratio:f64:1")
+            .unwrap()
+            .prefix(Some("APP_"))
+            .unwrap()
+            .generate_config("AppConfig");
+
+        assert!(content.contains("#[derive(Debug)]\npub struct AppConfig {"));
+        assert!(content.contains("    /// `APP_PORT`, default `8080`\n    pub port: u16,"));
+        assert!(content.contains("    /// `APP_DB_URL` (required)\n    pub db_url: String,"));
+        assert!(content.contains("    pub debug: Option<bool>,"));
+        assert!(content.contains("        let port = errors.var(\"APP_PORT\").unwrap_or(8080);"));
+        assert!(content.contains("        let db_url = errors.required(\"APP_DB_URL\");"));
+        assert!(content.contains(
+            "        let host = errors.var(\"APP_HOST\").unwrap_or_else(|| \"localhost\".to_string());"
+        ));
+        assert!(content.contains("        let ratio = errors.var(\"APP_RATIO\").unwrap_or(1.0);"));
+        assert!(content.contains(
+            "            Some(db_url) if errors.is_empty() => Ok(Self { port, db_url, host, debug, ratio }),"
+        ));
+        assert!(content.contains("            assert_eq!(error.missing, vec![\"APP_DB_URL\"]);"));
+        assert!(content.contains("(\"APP_PORT\", \"not-a-u16\")"));
+    }
+
+    #[test]
+    fn configs_without_required_fields_check_errors_only() {
+        let content = ConfigGenerator::new()
+            .fields("workers:usize:4")
+            .unwrap()
+            .generate_config("WorkerConfig");
+
+        assert!(content.contains("        let workers = errors.var(\"WORKERS\").unwrap_or(4);"));
+        assert!(content.contains("        if errors.is_empty() {\n            Ok(Self { workers })"));
+        assert!(!content.contains("reports_every_missing_variable"));
+
+        let content = ConfigGenerator::new()
+            .fields("key:string:required,secret:string:required")
+            .unwrap()
+            .generate_config("ApiConfig");
+        assert!(content.contains(
+            "        match (key, secret) {\n            (Some(key), Some(secret)) if errors.is_empty() => Ok(Self { key, secret }),"
+        ));
+        assert!(!content.contains("reports_invalid_values"));
+    }
+
+    #[test]
+    fn invalid_fields_are_rejected() {
+        let generator = || ConfigGenerator::new();
+        assert!(generator().fields("").is_err());
+        assert!(generator().fields("port").unwrap_err().contains("name:type"));
+        assert!(generator().fields("port:u16:http").unwrap_err().contains("Invalid u16 default"));
+        assert!(generator().fields("port:u16:-1").is_err());
+        assert!(generator().fields("addr:IpAddr").unwrap_err().contains("Unsupported config type"));
+        assert!(generator().fields("db-url:string").is_err());
+        assert!(generator().fields("port:u16,port:u32").unwrap_err().contains("Duplicate"));
+        assert!(generator().prefix(Some("APP-")).is_err());
+    }
+
+    #[test]
+    fn generate_registers_config_and_error_in_mod_or_parent_file() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("config").to_string_lossy().into_owned();
+        let generator = ConfigGenerator::new().fields("port:u16:8080").unwrap();
+
+        let path = generator.generate("app", &output).unwrap();
+        generator.generate("MailConfig", &output).unwrap();
+
+        assert!(path.ends_with("app_config.rs"));
+        assert!(dir.path().join("config/config_error.rs").exists());
+        let mod_content = std::fs::read_to_string(dir.path().join("config/mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod config_error;\npub use config_error::ConfigError;\npub mod app_config;\npub use app_config::AppConfig;\npub mod mail_config;\npub use mail_config::MailConfig;\n"
+        );
+
+        // tideorm init keeps the config module in src/config.rs, next to the directory
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("config.rs"), "pub fn tideorm_config() {}\n").unwrap();
+        let output = dir.path().join("config").to_string_lossy().into_owned();
+        generator.generate("app", &output).unwrap();
+
+        assert!(!dir.path().join("config/mod.rs").exists());
+        let parent = std::fs::read_to_string(dir.path().join("config.rs")).unwrap();
+        assert!(parent.starts_with("pub fn tideorm_config() {}\npub mod config_error;\n"));
+        assert!(parent.ends_with("pub mod app_config;\npub use app_config::AppConfig;\n"));
+    }
+}
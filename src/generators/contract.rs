@@ -0,0 +1,330 @@
+//! Contract generator for TideORM CLI
+
+use crate::utils::{ensure_directory, inject_after_marker, is_identifier, to_snake_case, write_or_update_file};
+
+/// One async method parsed from a `name:arg_type->return_type` spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ContractMethod {
+    name: String,
+    arg_type: Option<String>,
+    return_type: Option<String>,
+}
+
+impl ContractMethod {
+    /// Parse `find_by_id:i64->Option<T>`; the arg and `->return_type` may be left out
+    fn parse(spec: &str) -> Result<Self, String> {
+        let (name, signature) = spec.split_once(':').ok_or_else(|| {
+            format!(
+                "Invalid method '{}'. Expected format: name:arg_type->return_type",
+                spec
+            )
+        })?;
+
+        let name = name.trim();
+        if !is_identifier(name) {
+            return Err(format!("Invalid method name: '{}'", name));
+        }
+
+        let (arg_type, return_type) = match signature.split_once("->") {
+            Some((arg_type, return_type)) => (arg_type, Some(return_type)),
+            None => (signature, None),
+        };
+
+        let arg_type = non_empty_type(arg_type, spec)?;
+        let return_type = match return_type {
+            Some(return_type) => Some(non_empty_type(return_type, spec)?.ok_or_else(|| {
+                format!("Method '{}' is missing a return type after '->'", name)
+            })?),
+            None => None,
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            arg_type,
+            return_type,
+        })
+    }
+
+    /// Whether the signature refers to the contract's `T` type parameter
+    fn uses_type_param(&self) -> bool {
+        [&self.arg_type, &self.return_type]
+            .into_iter()
+            .flatten()
+            .any(|ty| ty.split(|c: char| !c.is_alphanumeric() && c != '_').any(|token| token == "T"))
+    }
+
+    /// `async fn name(&self, arg: Type) -> Return`, without the trailing `;` or body
+    fn signature(&self) -> String {
+        let arg = match &self.arg_type {
+            Some(arg_type) => format!(", arg: {}", arg_type),
+            None => String::new(),
+        };
+        let return_type = match &self.return_type {
+            Some(return_type) => format!(" -> {}", return_type),
+            None => String::new(),
+        };
+
+        format!("async fn {}(&self{}){}", self.name, arg, return_type)
+    }
+}
+
+/// Generator for an async trait and a stub service implementing it
+#[derive(Default)]
+pub struct ContractGenerator {
+    methods: Vec<ContractMethod>,
+}
+
+impl ContractGenerator {
+    /// Create a new contract generator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add methods from comma-separated specs, e.g. `find_by_id:i64->Option<T>,find_all:->Vec<T>`
+    pub fn methods(mut self, methods: &str) -> Result<Self, String> {
+        self.methods = split_method_specs(methods)
+            .into_iter()
+            .map(ContractMethod::parse)
+            .collect::<Result<_, _>>()?;
+
+        if self.methods.is_empty() {
+            return Err("--methods must list at least one method".to_string());
+        }
+
+        for (index, method) in self.methods.iter().enumerate() {
+            if self.methods[..index].iter().any(|other| other.name == method.name) {
+                return Err(format!("Duplicate contract method: {}", method.name));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Generate a contract file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        ensure_directory(output)?;
+
+        let base_name = to_pascal_case(name.strip_suffix("Contract").unwrap_or(name));
+        let contract_name = format!("{}Contract", base_name);
+        let service_name = format!("{}Service", base_name);
+
+        let file_name = format!("{}.rs", to_snake_case(&contract_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_contract(&contract_name, &service_name);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write contract file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &contract_name, &service_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate contract content
+    fn generate_contract(&self, contract_name: &str, service_name: &str) -> String {
+        let generic = self.methods.iter().any(ContractMethod::uses_type_param);
+        let (trait_params, impl_params, impl_generics) = if generic {
+            ("<T: Send + Sync + 'static>", "<T>", "<T: Send + Sync + 'static>")
+        } else {
+            ("", "", "")
+        };
+
+        let declarations = self
+            .methods
+            .iter()
+            .map(|method| format!("    {};", method.signature()))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let stubs = self
+            .methods
+            .iter()
+            .map(|method| {
+                let unused = if method.arg_type.is_some() {
+                    "        let _ = arg;\n"
+                } else {
+                    ""
+                };
+                format!(
+                    "    {} {{\n{}        todo!(\"{}::{}\")\n    }}",
+                    method.signature(),
+                    unused,
+                    service_name,
+                    method.name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!(
+            r#"//! {contract_name}
+//!
+//! Async contract for {service_name}. Depend on `dyn {contract_name}{impl_params}`
+//! so callers can swap the service for a test double.
+
+use tideorm::async_trait;
+
+/// Operations {service_name} provides
+#[async_trait::async_trait]
+pub trait {contract_name}{trait_params}: Send + Sync {{
+{declarations}
+}}
+
+/// Default {contract_name} implementation
+#[derive(Debug, Clone, Default)]
+pub struct {service_name};
+
+#[async_trait::async_trait]
+impl{impl_generics} {contract_name}{impl_params} for {service_name} {{
+{stubs}
+}}
+"#,
+            contract_name = contract_name,
+            service_name = service_name,
+            trait_params = trait_params,
+            impl_params = impl_params,
+            impl_generics = impl_generics,
+            declarations = declarations,
+            stubs = stubs,
+        )
+    }
+
+    /// Update mod.rs with new contract
+    fn update_mod_file(
+        &self,
+        output: &str,
+        contract_name: &str,
+        service_name: &str,
+    ) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(contract_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{{{}, {}}};", module_name, contract_name, service_name),
+        )
+    }
+}
+
+/// Split a comma-separated `--methods` list, keeping commas inside `<>` and `()`
+/// such as `HashMap<String, i64>`
+fn split_method_specs(methods: &str) -> Vec<&str> {
+    let mut specs = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut previous = ' ';
+
+    for (index, character) in methods.char_indices() {
+        match character {
+            '<' | '(' | '[' => depth += 1,
+            '>' if previous == '-' => {}
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                specs.push(methods[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+        previous = character;
+    }
+
+    specs.push(methods[start..].trim());
+    specs.retain(|spec| !spec.is_empty());
+    specs
+}
+
+/// Trimmed type, or `None` when blank; rejects unbalanced brackets
+fn non_empty_type(ty: &str, spec: &str) -> Result<Option<String>, String> {
+    let ty = ty.trim();
+    if ty.is_empty() {
+        return Ok(None);
+    }
+
+    let opened = ty.matches(['<', '(', '[']).count();
+    let closed = ty.matches(['>', ')', ']']).count();
+    if opened != closed {
+        return Err(format!("Unbalanced brackets in method '{}'", spec));
+    }
+
+    Ok(Some(ty.to_string()))
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContractGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_trait_has_method_signatures() {
+        let content = ContractGenerator::new()
+            .methods("find_by_id:i64->Option<T>,find_all:->Vec<T>,totals:HashMap<String, i64>->Result<(), String>,touch:")
+            .unwrap()
+            .generate_contract("UserContract", "UserService");
+
+        assert!(content.contains(
+            "#[async_trait::async_trait]\npub trait UserContract<T: Send + Sync + 'static>: Send + Sync {"
+        ));
+        assert!(content.contains("    async fn find_by_id(&self, arg: i64) -> Option<T>;"));
+        assert!(content.contains("    async fn find_all(&self) -> Vec<T>;"));
+        assert!(content.contains(
+            "    async fn totals(&self, arg: HashMap<String, i64>) -> Result<(), String>;"
+        ));
+        assert!(content.contains("    async fn touch(&self);"));
+        assert!(content.contains(
+            "impl<T: Send + Sync + 'static> UserContract<T> for UserService {"
+        ));
+        assert!(content.contains("todo!(\"UserService::find_all\")"));
+    }
+
+    #[test]
+    fn contract_without_type_param_is_not_generic() {
+        let content = ContractGenerator::new()
+            .methods("count:->u64, rename:(i64, String)->bool")
+            .unwrap()
+            .generate_contract("BillingContract", "BillingService");
+
+        assert!(content.contains("pub trait BillingContract: Send + Sync {"));
+        assert!(content.contains("    async fn count(&self) -> u64;"));
+        assert!(content.contains("    async fn rename(&self, arg: (i64, String)) -> bool;"));
+        assert!(content.contains("impl BillingContract for BillingService {"));
+    }
+
+    #[test]
+    fn invalid_method_specs_are_rejected() {
+        assert!(ContractGenerator::new().methods("").is_err());
+        assert!(ContractGenerator::new().methods("find_all").is_err());
+        assert!(ContractGenerator::new().methods("find-all:->Vec<T>").is_err());
+        assert!(ContractGenerator::new().methods("find_all:->").is_err());
+        assert!(ContractGenerator::new().methods("find:i64->Option<T").is_err());
+        assert!(ContractGenerator::new().methods("find:i64,find:String").is_err());
+    }
+
+    #[test]
+    fn generate_writes_contract_and_registers_module() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("contracts");
+        let output_path = output.to_string_lossy().into_owned();
+
+        let generator = ContractGenerator::new().methods("find_all:->Vec<T>").unwrap();
+        let path = generator.generate("user", &output_path).unwrap();
+        generator.generate("UserContract", &output_path).unwrap();
+
+        assert!(path.ends_with("user_contract.rs"));
+
+        let mod_content = std::fs::read_to_string(output.join("mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod user_contract;\npub use user_contract::{UserContract, UserService};\n"
+        );
+    }
+}
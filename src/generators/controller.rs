@@ -0,0 +1,303 @@
+//! Controller generator for TideORM CLI
+//!
+//! Emits CRUD handlers for a model, for axum or actix-web.
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, pluralize_with_overrides, to_pascal_case, to_snake_case, write_or_update_file};
+
+/// Web framework the generated handlers are written for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ControllerFramework {
+    #[default]
+    Axum,
+    Actix,
+}
+
+impl ControllerFramework {
+    /// Parse a `--framework` value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "axum" => Ok(Self::Axum),
+            "actix" | "actix-web" => Ok(Self::Actix),
+            other => Err(format!("Unknown framework '{}'; expected axum or actix", other)),
+        }
+    }
+}
+
+/// Controller generator
+pub struct ControllerGenerator<'a> {
+    config: &'a TideConfig,
+    framework: ControllerFramework,
+}
+
+impl<'a> ControllerGenerator<'a> {
+    /// Create a new controller generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            framework: ControllerFramework::default(),
+        }
+    }
+
+    /// Write the handlers for this framework
+    pub fn framework(mut self, framework: ControllerFramework) -> Self {
+        self.framework = framework;
+        self
+    }
+
+    /// File a controller for `model` is written to
+    pub fn file_path(model: &str, output: &str) -> String {
+        format!("{}/{}_controller.rs", output, to_snake_case(model))
+    }
+
+    /// Generate a controller file for a model
+    pub fn generate(&self, model: &str, output: &str) -> Result<String, String> {
+        ensure_directory(output)?;
+
+        let model_pascal = to_pascal_case(model);
+        let file_path = Self::file_path(model, output);
+        let content = match self.framework {
+            ControllerFramework::Axum => self.generate_axum(&model_pascal),
+            ControllerFramework::Actix => self.generate_actix(&model_pascal),
+        };
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write controller file: {}", e))?;
+
+        // Update mod.rs; the handlers are reached through the module, so nothing is re-exported
+        let module_decl = format!("pub mod {}_controller;", to_snake_case(model));
+        write_or_update_file(&format!("{}/mod.rs", output), &format!("{}\n", module_decl), &module_decl)?;
+
+        Ok(file_path)
+    }
+
+    /// URL path of the resource, e.g. `/users`
+    fn route(&self, model_pascal: &str) -> String {
+        format!(
+            "/{}",
+            pluralize_with_overrides(&to_snake_case(model_pascal), &self.config.pluralization)
+        )
+    }
+
+    /// Generate axum handlers
+    fn generate_axum(&self, model_pascal: &str) -> String {
+        format!(
+            r#"//! {model_pascal}Controller
+//!
+//! axum handlers for the {model_pascal} resource. Mount them with `.merge({model_snake}_controller::routes())`.
+
+use crate::models::{model_snake}::{model_pascal};
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{{Json, Router}};
+use tideorm::prelude::*;
+
+/// Primary key type of {model_pascal}
+type {model_pascal}Id = <{model_pascal} as ModelMeta>::PrimaryKey;
+
+/// Routes for {route}
+pub fn routes() -> Router {{
+    Router::new()
+        .route("{route}", get(index).post(store))
+        .route("{route}/{{id}}", get(show).put(update).delete(destroy))
+}}
+
+/// List every {model_pascal}
+pub async fn index() -> Result<Json<Vec<{model_pascal}>>, StatusCode> {{
+    {model_pascal}::query().get().await.map(Json).map_err(server_error)
+}}
+
+/// Show one {model_pascal}
+pub async fn show(Path(id): Path<{model_pascal}Id>) -> Result<Json<{model_pascal}>, StatusCode> {{
+    {model_pascal}::find(id)
+        .await
+        .map_err(server_error)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}}
+
+/// Create a {model_pascal} from the request body
+pub async fn store(Json({model_snake}): Json<{model_pascal}>) -> Result<(StatusCode, Json<{model_pascal}>), StatusCode> {{
+    let {model_snake} = {model_pascal}::create({model_snake}).await.map_err(server_error)?;
+    Ok((StatusCode::CREATED, Json({model_snake})))
+}}
+
+/// Replace a {model_pascal} with the request body
+pub async fn update(
+    Path(id): Path<{model_pascal}Id>,
+    Json(mut {model_snake}): Json<{model_pascal}>,
+) -> Result<Json<{model_pascal}>, StatusCode> {{
+    if {model_pascal}::find(id).await.map_err(server_error)?.is_none() {{
+        return Err(StatusCode::NOT_FOUND);
+    }}
+
+    {model_snake}.{primary_key} = id;
+    {model_snake}.update().await.map(Json).map_err(server_error)
+}}
+
+/// Delete a {model_pascal}
+pub async fn destroy(Path(id): Path<{model_pascal}Id>) -> Result<StatusCode, StatusCode> {{
+    let {model_snake} = {model_pascal}::find(id)
+        .await
+        .map_err(server_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    {model_snake}.delete().await.map_err(server_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}}
+
+/// Hide database errors from clients
+fn server_error(error: tideorm::Error) -> StatusCode {{
+    // TODO: Log the error
+    let _ = error;
+    StatusCode::INTERNAL_SERVER_ERROR
+}}
+"#,
+            model_pascal = model_pascal,
+            model_snake = to_snake_case(model_pascal),
+            primary_key = self.config.model.primary_key,
+            route = self.route(model_pascal),
+        )
+    }
+
+    /// Generate actix-web handlers
+    fn generate_actix(&self, model_pascal: &str) -> String {
+        format!(
+            r#"//! {model_pascal}Controller
+//!
+//! actix-web handlers for the {model_pascal} resource. Mount them with `.configure({model_snake}_controller::routes)`.
+
+use crate::models::{model_snake}::{model_pascal};
+use actix_web::{{web, HttpResponse}};
+use tideorm::prelude::*;
+
+/// Primary key type of {model_pascal}
+type {model_pascal}Id = <{model_pascal} as ModelMeta>::PrimaryKey;
+
+/// Routes for {route}
+pub fn routes(config: &mut web::ServiceConfig) {{
+    config
+        .service(
+            web::resource("{route}")
+                .route(web::get().to(index))
+                .route(web::post().to(store)),
+        )
+        .service(
+            web::resource("{route}/{{id}}")
+                .route(web::get().to(show))
+                .route(web::put().to(update))
+                .route(web::delete().to(destroy)),
+        );
+}}
+
+/// List every {model_pascal}
+pub async fn index() -> HttpResponse {{
+    match {model_pascal}::query().get().await {{
+        Ok(rows) => HttpResponse::Ok().json(rows),
+        Err(error) => server_error(error),
+    }}
+}}
+
+/// Show one {model_pascal}
+pub async fn show(id: web::Path<{model_pascal}Id>) -> HttpResponse {{
+    match {model_pascal}::find(id.into_inner()).await {{
+        Ok(Some({model_snake})) => HttpResponse::Ok().json({model_snake}),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(error) => server_error(error),
+    }}
+}}
+
+/// Create a {model_pascal} from the request body
+pub async fn store({model_snake}: web::Json<{model_pascal}>) -> HttpResponse {{
+    match {model_pascal}::create({model_snake}.into_inner()).await {{
+        Ok({model_snake}) => HttpResponse::Created().json({model_snake}),
+        Err(error) => server_error(error),
+    }}
+}}
+
+/// Replace a {model_pascal} with the request body
+pub async fn update(id: web::Path<{model_pascal}Id>, {model_snake}: web::Json<{model_pascal}>) -> HttpResponse {{
+    let id = id.into_inner();
+    match {model_pascal}::find(id).await {{
+        Ok(Some(_)) => {{}}
+        Ok(None) => return HttpResponse::NotFound().finish(),
+        Err(error) => return server_error(error),
+    }}
+
+    let mut {model_snake} = {model_snake}.into_inner();
+    {model_snake}.{primary_key} = id;
+    match {model_snake}.update().await {{
+        Ok({model_snake}) => HttpResponse::Ok().json({model_snake}),
+        Err(error) => server_error(error),
+    }}
+}}
+
+/// Delete a {model_pascal}
+pub async fn destroy(id: web::Path<{model_pascal}Id>) -> HttpResponse {{
+    match {model_pascal}::find(id.into_inner()).await {{
+        Ok(Some({model_snake})) => match {model_snake}.delete().await {{
+            Ok(_) => HttpResponse::NoContent().finish(),
+            Err(error) => server_error(error),
+        }},
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(error) => server_error(error),
+    }}
+}}
+
+/// Hide database errors from clients
+fn server_error(error: tideorm::Error) -> HttpResponse {{
+    // TODO: Log the error
+    let _ = error;
+    HttpResponse::InternalServerError().finish()
+}}
+"#,
+            model_pascal = model_pascal,
+            model_snake = to_snake_case(model_pascal),
+            primary_key = self.config.model.primary_key,
+            route = self.route(model_pascal),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ControllerFramework, ControllerGenerator};
+    use crate::config::TideConfig;
+
+    #[test]
+    fn framework_parses_axum_and_actix() {
+        assert_eq!(ControllerFramework::parse("Axum"), Ok(ControllerFramework::Axum));
+        assert_eq!(ControllerFramework::parse("actix_web"), Ok(ControllerFramework::Actix));
+        assert_eq!(
+            ControllerFramework::parse("rocket"),
+            Err("Unknown framework 'rocket'; expected axum or actix".to_string())
+        );
+    }
+
+    #[test]
+    fn axum_controller_routes_crud_handlers() {
+        let config = TideConfig::default();
+        let content = ControllerGenerator::new(&config).generate_axum("BlogPost");
+
+        assert!(content.contains("use crate::models::blog_post::BlogPost;"));
+        assert!(content.contains(
+            ".route(\"/blog_posts\", get(index).post(store))\n        .route(\"/blog_posts/{id}\", get(show).put(update).delete(destroy))"
+        ));
+        assert!(content.contains("pub async fn show(Path(id): Path<BlogPostId>) -> Result<Json<BlogPost>, StatusCode> {"));
+        assert!(content.contains("    blog_post.id = id;\n    blog_post.update().await.map(Json).map_err(server_error)"));
+    }
+
+    #[test]
+    fn actix_controller_configures_the_same_routes() {
+        let config = TideConfig::default();
+        let content = ControllerGenerator::new(&config)
+            .framework(ControllerFramework::Actix)
+            .generate_actix("User");
+
+        assert!(content.contains("pub fn routes(config: &mut web::ServiceConfig) {"));
+        assert!(content.contains("web::resource(\"/users/{id}\")"));
+        assert!(content.contains("Ok(user) => HttpResponse::Created().json(user),"));
+        assert!(content.contains("Ok(None) => HttpResponse::NotFound().finish(),"));
+    }
+}
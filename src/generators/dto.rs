@@ -0,0 +1,572 @@
+//! DTO generator for TideORM CLI
+//!
+//! Emits request and response data transfer objects that mirror a model's fields,
+//! so the API shape can change without touching the ORM model.
+
+use crate::commands::schema::model_fields;
+use crate::config::TideConfig;
+use crate::utils::{
+    ensure_directory, inject_after_marker, to_pascal_case, to_snake_case, write_or_update_file,
+    FieldDefinition,
+};
+
+/// Columns TideORM fills in itself, left out of request DTOs
+const MANAGED_COLUMNS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// Field types that come from `tideorm::prelude`
+const PRELUDE_TYPES: [&str; 6] = ["Json", "Jsonb", "IntArray", "BigIntArray", "BoolArray", "FloatArray"];
+
+/// Field types the generated round-trip tests can compare with `assert_eq!`
+const COMPARABLE_TYPES: [&str; 15] = [
+    "String",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "f32",
+    "f64",
+    "bool",
+    "chrono::DateTime<chrono::Utc>",
+    "chrono::NaiveDate",
+    "chrono::NaiveTime",
+    "uuid::Uuid",
+    "rust_decimal::Decimal",
+    "Vec<u8>",
+    "Vec<String>",
+];
+
+/// Which DTOs `make dto` generates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DtoDirection {
+    Request,
+    Response,
+    Both,
+}
+
+impl DtoDirection {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "request" => Ok(Self::Request),
+            "response" => Ok(Self::Response),
+            "both" => Ok(Self::Both),
+            other => Err(format!(
+                "Unsupported DTO direction: {}. Expected request, response, or both",
+                other
+            )),
+        }
+    }
+
+    fn request(self) -> bool {
+        matches!(self, Self::Request | Self::Both)
+    }
+
+    fn response(self) -> bool {
+        matches!(self, Self::Response | Self::Both)
+    }
+}
+
+/// DTO generator
+pub struct DtoGenerator<'a> {
+    config: &'a TideConfig,
+    direction: DtoDirection,
+}
+
+impl<'a> DtoGenerator<'a> {
+    /// Create a new DTO generator producing both request and response DTOs
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            direction: DtoDirection::Both,
+        }
+    }
+
+    /// Only generate the request or the response DTO
+    pub fn direction(mut self, direction: DtoDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Generate a DTO file
+    ///
+    /// An explicit `model` must exist. Without one, the model named like the DTO is
+    /// mirrored when its file exists; otherwise the DTOs start empty, without conversions.
+    pub fn generate(&self, name: &str, model: Option<&str>, output: &str) -> Result<String, String> {
+        let base_name = to_pascal_case(name.strip_suffix("Dto").unwrap_or(name));
+        let model_pascal = to_pascal_case(model.unwrap_or(&base_name));
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(&model_pascal));
+
+        let fields = match std::fs::read_to_string(&model_path) {
+            Ok(content) => Some(model_fields(&content, &model_pascal)),
+            Err(_) if model.is_none() => None,
+            Err(_) => {
+                return Err(format!(
+                    "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                    model_path, model_pascal
+                ));
+            }
+        };
+
+        ensure_directory(output)?;
+
+        let module_name = format!("{}_dto", to_snake_case(&base_name));
+        let file_path = format!("{}/{}.rs", output, module_name);
+
+        let content = self.generate_dto(&base_name, &model_pascal, fields.as_deref());
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write DTO file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &module_name, &base_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate DTO content; `fields` is `None` when there is no model to mirror
+    fn generate_dto(&self, base_name: &str, model_pascal: &str, fields: Option<&[FieldDefinition]>) -> String {
+        let model_snake = to_snake_case(model_pascal);
+        let request_name = format!("{}RequestDto", base_name);
+        let response_name = format!("{}ResponseDto", base_name);
+
+        let mut sections = Vec::new();
+        let mut tests = Vec::new();
+
+        if self.direction.request() {
+            let request_fields: Vec<&FieldDefinition> = fields
+                .unwrap_or_default()
+                .iter()
+                .filter(|field| {
+                    !field.primary_key
+                        && !field.auto_increment
+                        && !MANAGED_COLUMNS.contains(&field.name.as_str())
+                })
+                .collect();
+
+            sections.push(dto_struct(
+                &request_name,
+                &format!("Incoming {} data, e.g. the body of a create or update request", model_pascal),
+                &request_fields,
+                &[],
+            ));
+
+            if fields.is_some() {
+                sections.push(format!(
+                    r#"impl From<{request_name}> for {model_pascal} {{
+    fn from(dto: {request_name}) -> Self {{
+        Self {{
+{to_model}            ..Default::default()
+        }}
+    }}
+}}
+
+impl From<{model_pascal}> for {request_name} {{
+    fn from(model: {model_pascal}) -> Self {{
+        Self {{
+{from_model}        }}
+    }}
+}}
+"#,
+                    request_name = request_name,
+                    model_pascal = model_pascal,
+                    to_model = field_moves(&request_fields, "dto"),
+                    from_model = field_moves(&request_fields, "model"),
+                ));
+
+                tests.push(format!(
+                    r#"
+    #[test]
+    fn request_dto_round_trips_through_{model_snake}() {{
+        let dto = {request_name}::from({model_pascal}::default());
+        let {model_snake} = {model_pascal}::from(dto.clone());
+        let round_trip = {request_name}::from({model_snake});
+{checks}    }}
+"#,
+                    model_snake = model_snake,
+                    model_pascal = model_pascal,
+                    request_name = request_name,
+                    checks = field_checks(&request_fields, "round_trip", "dto"),
+                ));
+            }
+        }
+
+        if self.direction.response() {
+            let response_fields: Vec<&FieldDefinition> = fields
+                .unwrap_or_default()
+                .iter()
+                .filter(|field| field.name != "deleted_at")
+                .collect();
+            let computed: Vec<(String, String, String)> = response_fields
+                .iter()
+                .filter_map(|field| iso_field(field))
+                .collect();
+
+            sections.push(dto_struct(
+                &response_name,
+                &format!("{} as returned by the API", model_pascal),
+                &response_fields,
+                &computed,
+            ));
+
+            if fields.is_some() {
+                let primary_key = response_fields.iter().find(|field| field.primary_key);
+                let unsaved_check = primary_key
+                    .map(|field| {
+                        format!(
+                            "        if model.{} == <{}>::default() {{\n            return Err(\"{} has not been saved yet\".to_string());\n        }}\n\n",
+                            field.name,
+                            field.rust_type(),
+                            model_pascal
+                        )
+                    })
+                    .unwrap_or_default();
+                let computed_values: String = computed
+                    .iter()
+                    .map(|(name, _, value)| format!("            {}: {},\n", name, value))
+                    .collect();
+
+                sections.push(format!(
+                    r#"impl TryFrom<{model_pascal}> for {response_name} {{
+    type Error = String;
+
+    fn try_from(model: {model_pascal}) -> Result<Self, Self::Error> {{
+{unsaved_check}        Ok(Self {{
+{computed_values}{from_model}        }})
+    }}
+}}
+
+impl From<{response_name}> for {model_pascal} {{
+    fn from(dto: {response_name}) -> Self {{
+        Self {{
+{to_model}            ..Default::default()
+        }}
+    }}
+}}
+"#,
+                    model_pascal = model_pascal,
+                    response_name = response_name,
+                    unsaved_check = unsaved_check,
+                    computed_values = computed_values,
+                    from_model = field_moves(&response_fields, "model"),
+                    to_model = field_moves(&response_fields, "dto"),
+                ));
+
+                if let Some((field, value)) =
+                    primary_key.and_then(|field| Some((field, saved_key_value(field)?)))
+                {
+                    let iso_checks: String = computed
+                        .iter()
+                        .map(|(name, _, value)| {
+                            format!(
+                                "        assert_eq!(dto.{}, {});\n",
+                                name,
+                                value.replace("model.", &format!("{}.", model_snake))
+                            )
+                        })
+                        .collect();
+
+                    tests.push(format!(
+                        r#"
+    #[test]
+    fn response_dto_round_trips_through_{model_snake}() {{
+        let {model_snake} = {model_pascal} {{
+            {key}: {value},
+            ..Default::default()
+        }};
+        let dto = {response_name}::try_from({model_snake}.clone()).unwrap();
+{iso_checks}
+        let round_trip = {model_pascal}::from(dto);
+{checks}    }}
+
+    #[test]
+    fn response_dto_rejects_unsaved_{model_snake}() {{
+        assert!({response_name}::try_from({model_pascal}::default()).is_err());
+    }}
+"#,
+                        model_snake = model_snake,
+                        model_pascal = model_pascal,
+                        response_name = response_name,
+                        key = field.name,
+                        value = value,
+                        iso_checks = iso_checks,
+                        checks = field_checks(&response_fields, "round_trip", &model_snake),
+                    ));
+                }
+            }
+        }
+
+        let model_import = if fields.is_some() {
+            format!("use crate::models::{}::{};\n", model_snake, model_pascal)
+        } else {
+            String::new()
+        };
+        let prelude_import = if fields
+            .unwrap_or_default()
+            .iter()
+            .any(|field| PRELUDE_TYPES.contains(&field.base_rust_type().as_str()))
+        {
+            "use tideorm::prelude::*;\n"
+        } else {
+            ""
+        };
+        let tests = if tests.is_empty() {
+            String::new()
+        } else {
+            format!("\n#[cfg(test)]\nmod tests {{\n    use super::*;\n{}}}\n", tests.concat())
+        };
+
+        format!(
+            r#"//! {base_name} DTOs
+//!
+//! Data transfer objects for {model_pascal}, kept separate from the ORM model.
+
+{prelude_import}use serde::{{Deserialize, Serialize}};
+{model_import}
+{sections}{tests}"#,
+            base_name = base_name,
+            model_pascal = model_pascal,
+            prelude_import = prelude_import,
+            model_import = model_import,
+            sections = sections.join("\n"),
+            tests = tests,
+        )
+    }
+
+    /// Update mod.rs with the new DTOs
+    fn update_mod_file(&self, output: &str, module_name: &str, base_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_decl = format!("pub mod {};", module_name);
+
+        let mut exports = Vec::new();
+        if self.direction.request() {
+            exports.push(format!("{}RequestDto", base_name));
+        }
+        if self.direction.response() {
+            exports.push(format!("{}ResponseDto", base_name));
+        }
+        let exports = match exports.as_slice() {
+            [single] => single.clone(),
+            _ => format!("{{{}}}", exports.join(", ")),
+        };
+
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, exports),
+        )
+    }
+}
+
+/// A DTO struct with the mirrored fields followed by `computed` `(name, type, value)` fields
+fn dto_struct(
+    name: &str,
+    doc: &str,
+    fields: &[&FieldDefinition],
+    computed: &[(String, String, String)],
+) -> String {
+    let mut lines: Vec<String> = fields
+        .iter()
+        .map(|field| format!("    pub {}: {},\n", field.name, field.rust_type()))
+        .collect();
+    lines.extend(
+        computed
+            .iter()
+            .map(|(name, rust_type, _)| format!("    pub {}: {},\n", name, rust_type)),
+    );
+
+    let body = if lines.is_empty() {
+        "    // TODO: Add fields\n".to_string()
+    } else {
+        lines.concat()
+    };
+
+    format!(
+        "/// {}\n#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct {} {{\n{}}}\n",
+        doc, name, body
+    )
+}
+
+/// `name: source.name,` for each field, indented for a struct literal
+fn field_moves(fields: &[&FieldDefinition], source: &str) -> String {
+    fields
+        .iter()
+        .map(|field| format!("            {name}: {source}.{name},\n", name = field.name, source = source))
+        .collect()
+}
+
+/// `assert_eq!` for each field whose type supports comparison
+fn field_checks(fields: &[&FieldDefinition], actual: &str, expected: &str) -> String {
+    fields
+        .iter()
+        .filter(|field| COMPARABLE_TYPES.contains(&field.base_rust_type().as_str()))
+        .map(|field| {
+            format!(
+                "        assert_eq!({actual}.{name}, {expected}.{name});\n",
+                actual = actual,
+                expected = expected,
+                name = field.name
+            )
+        })
+        .collect()
+}
+
+/// ISO 8601 string `(name, type, value)` computed from a date or datetime field
+fn iso_field(field: &FieldDefinition) -> Option<(String, String, String)> {
+    let format = match field.field_type.as_str() {
+        "datetime" => "to_rfc3339()",
+        "date" => "to_string()",
+        _ => return None,
+    };
+
+    let (rust_type, value) = if field.nullable {
+        (
+            "Option<String>".to_string(),
+            format!("model.{}.as_ref().map(|value| value.{})", field.name, format),
+        )
+    } else {
+        ("String".to_string(), format!("model.{}.{}", field.name, format))
+    };
+
+    Some((format!("{}_iso", field.name), rust_type, value))
+}
+
+/// A non-default primary key value for the generated round-trip test
+fn saved_key_value(field: &FieldDefinition) -> Option<String> {
+    match field.base_rust_type().as_str() {
+        "i8" | "i16" | "i32" | "i64" => Some("1".to_string()),
+        "String" => Some("\"1\".to_string()".to_string()),
+        "uuid::Uuid" => Some("uuid::Uuid::from_u128(1)".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DtoDirection, DtoGenerator};
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    const USER_MODEL: &str = r#"
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    #[tideorm(nullable)]
+    pub bio: Option<String>,
+    pub born_on: chrono::NaiveDate,
+    #[tideorm(nullable)]
+    pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+"#;
+
+    fn project() -> (tempfile::TempDir, TideConfig, String) {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        std::fs::create_dir_all(&config.paths.models).unwrap();
+        std::fs::write(dir.path().join("models/user.rs"), USER_MODEL).unwrap();
+        let output = dir.path().join("dtos").to_string_lossy().into_owned();
+        (dir, config, output)
+    }
+
+    #[test]
+    fn dtos_mirror_model_fields_with_conversions() {
+        let (dir, config, output) = project();
+
+        let path = DtoGenerator::new(&config).generate("User", Some("User"), &output).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        assert!(path.ends_with("user_dto.rs"));
+        assert!(content.contains("use crate::models::user::User;"));
+        assert!(content.contains(
+            "#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRequestDto {
+    pub name: String,
+    pub bio: Option<String>,
+    pub born_on: chrono::NaiveDate,
+    pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
+}"
+        ));
+        assert!(content.contains(
+            "pub struct UserResponseDto {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+    pub born_on: chrono::NaiveDate,
+    pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub born_on_iso: String,
+    pub verified_at_iso: Option<String>,
+    pub created_at_iso: String,
+}"
+        ));
+        assert!(content.contains("impl From<UserRequestDto> for User {"));
+        assert!(content.contains("            name: dto.name,\n"));
+        assert!(content.contains("impl TryFrom<User> for UserResponseDto {\n    type Error = String;"));
+        assert!(content.contains(
+            "        if model.id == <i64>::default() {\n            return Err(\"User has not been saved yet\".to_string());"
+        ));
+        assert!(content.contains("            created_at_iso: model.created_at.to_rfc3339(),\n"));
+        assert!(content.contains(
+            "            verified_at_iso: model.verified_at.as_ref().map(|value| value.to_rfc3339()),\n"
+        ));
+        assert!(content.contains("impl From<UserResponseDto> for User {"));
+
+        // Round-trip tests for the user's project
+        assert!(content.contains("    fn request_dto_round_trips_through_user() {"));
+        assert!(content.contains("        assert_eq!(round_trip.bio, dto.bio);\n"));
+        assert!(content.contains("            id: 1,\n            ..Default::default()"));
+        assert!(content.contains("        assert_eq!(dto.born_on_iso, user.born_on.to_string());\n"));
+        assert!(content.contains("        assert_eq!(round_trip.created_at, user.created_at);\n"));
+        assert!(content.contains("    fn response_dto_rejects_unsaved_user() {"));
+
+        let mod_content = std::fs::read_to_string(dir.path().join("dtos/mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod user_dto;\npub use user_dto::{UserRequestDto, UserResponseDto};\n"
+        );
+    }
+
+    #[test]
+    fn direction_limits_generated_dtos() {
+        let (dir, config, output) = project();
+
+        let path = DtoGenerator::new(&config)
+            .direction(DtoDirection::parse("request").unwrap())
+            .generate("CreateUserDto", Some("User"), &output)
+            .unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        assert!(path.ends_with("create_user_dto.rs"));
+        assert!(content.contains("pub struct CreateUserRequestDto {"));
+        assert!(!content.contains("ResponseDto"));
+        assert!(!content.contains("_iso"));
+
+        let mod_content = std::fs::read_to_string(dir.path().join("dtos/mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod create_user_dto;\npub use create_user_dto::CreateUserRequestDto;\n"
+        );
+        assert!(DtoDirection::parse("inbound").is_err());
+    }
+
+    #[test]
+    fn dto_without_model_has_no_conversions() {
+        let (_dir, config, output) = project();
+
+        let path = DtoGenerator::new(&config).generate("Login", None, &output).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        assert!(content.contains("pub struct LoginRequestDto {\n    // TODO: Add fields\n}"));
+        assert!(!content.contains("impl "));
+        assert!(!content.contains("use crate::models"));
+        assert!(!content.contains("#[cfg(test)]"));
+
+        let error = DtoGenerator::new(&config)
+            .generate("Login", Some("Account"), &output)
+            .unwrap_err();
+        assert!(error.contains("Model file not found"), "{}", error);
+    }
+}
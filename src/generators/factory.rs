@@ -1,17 +1,81 @@
 //! Factory generator for TideORM CLI
 
+use crate::commands::schema::model_fields;
 use crate::config::TideConfig;
-use crate::utils::{ensure_directory, to_snake_case};
+use crate::utils::{ensure_directory, is_identifier, to_snake_case, write_or_update_file, FieldDefinition};
+
+/// Columns TideORM fills in itself
+const MANAGED_COLUMNS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// A named factory state from `--states`, e.g. `admin:role=admin,verified=true`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FactoryState {
+    name: String,
+    assignments: Vec<(String, String)>,
+}
+
+impl FactoryState {
+    /// Parse `state:field=value[,field=value],state:field=value`
+    ///
+    /// A `field=value` pair without a `state:` prefix belongs to the state before it.
+    fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        let mut states: Vec<Self> = Vec::new();
+
+        for pair in spec.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+            let (target, value) = pair.split_once('=').ok_or_else(|| {
+                format!("Invalid state assignment '{}'. Expected format: state:field=value", pair)
+            })?;
+
+            let field = match target.split_once(':') {
+                Some((state, field)) => {
+                    let state = state.trim();
+                    if !is_identifier(state) {
+                        return Err(format!("Invalid state name: '{}'", state));
+                    }
+                    if states.iter().any(|existing| existing.name == state) {
+                        return Err(format!("Duplicate factory state: {}", state));
+                    }
+                    states.push(Self {
+                        name: state.to_string(),
+                        assignments: Vec::new(),
+                    });
+                    field.trim()
+                }
+                None => target.trim(),
+            };
+
+            let state = states.last_mut().ok_or_else(|| {
+                format!("State assignment '{}' must start with a state name, e.g. admin:{}", pair, pair)
+            })?;
+            if !is_identifier(field) {
+                return Err(format!("Invalid field name '{}' in state {}", field, state.name));
+            }
+            state.assignments.push((field.to_string(), value.trim().to_string()));
+        }
+
+        Ok(states)
+    }
+}
 
 /// Factory generator
 pub struct FactoryGenerator<'a> {
     config: &'a TideConfig,
+    states: Vec<FactoryState>,
 }
 
 impl<'a> FactoryGenerator<'a> {
     /// Create a new factory generator
     pub fn new(config: &'a TideConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            states: Vec::new(),
+        }
+    }
+
+    /// Add named states, e.g. `admin:role=admin,suspended:status=suspended`
+    pub fn states(mut self, states: Option<&str>) -> Result<Self, String> {
+        self.states = FactoryState::parse_list(states.unwrap_or_default())?;
+        Ok(self)
     }
 
     /// Generate a factory file
@@ -27,11 +91,17 @@ impl<'a> FactoryGenerator<'a> {
         let file_name = format!("{}.rs", to_snake_case(&factory_name));
         let file_path = format!("{}/{}", self.config.paths.factories, file_name);
 
+        // Only an explicit --model is looked up; otherwise the fields stay as TODOs
+        let fields = model
+            .as_deref()
+            .map(|model_name| self.model_fields(model_name))
+            .unwrap_or_default();
+
         let model_name = model.unwrap_or_else(|| {
             factory_name.strip_suffix("Factory").unwrap_or(&factory_name).to_string()
         });
 
-        let content = self.generate_factory(&factory_name, &model_name);
+        let content = self.generate_factory(&factory_name, &model_name, &fields)?;
 
         std::fs::write(&file_path, content)
             .map_err(|e| format!("Failed to write factory file: {}", e))?;
@@ -42,12 +112,59 @@ impl<'a> FactoryGenerator<'a> {
         Ok(file_path)
     }
 
+    /// Fields of the model, or none when its file cannot be read
+    fn model_fields(&self, model_name: &str) -> Vec<FieldDefinition> {
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model_name));
+
+        std::fs::read_to_string(model_path)
+            .map(|content| model_fields(&content, &to_pascal_case(model_name)))
+            .unwrap_or_default()
+    }
+
     /// Generate factory content
-    fn generate_factory(&self, factory_name: &str, model_name: &str) -> String {
+    fn generate_factory(
+        &self,
+        factory_name: &str,
+        model_name: &str,
+        fields: &[FieldDefinition],
+    ) -> Result<String, String> {
         let model_pascal = to_pascal_case(model_name);
         let model_snake = to_snake_case(model_name);
 
-        format!(
+        let assignments: Vec<(&str, String)> = fields
+            .iter()
+            .filter(|field| {
+                !field.primary_key
+                    && !field.auto_increment
+                    && !MANAGED_COLUMNS.contains(&field.name.as_str())
+            })
+            .filter_map(|field| {
+                let value = if field.nullable {
+                    "None".to_string()
+                } else {
+                    fake_value(field)?
+                };
+                Some((field.name.as_str(), value))
+            })
+            .collect();
+
+        let field_lines = if assignments.is_empty() {
+            r#"            // TODO: Add default field values
+            // Example:
+            // name: "Example".to_string(),
+            // email: "example@example.com".to_string(),
+"#
+            .to_string()
+        } else {
+            assignments
+                .iter()
+                .map(|(name, value)| format!("            {}: {},\n", name, value))
+                .collect()
+        };
+
+        let states = self.generate_states(factory_name, &model_pascal, &model_snake, fields)?;
+
+        Ok(format!(
             r#"//! {} Factory
 //!
 //! Factory for creating {} instances for testing and seeding.
@@ -62,11 +179,7 @@ impl {factory_name} {{
     /// Create a new {model_pascal} with default values
     pub fn definition() -> {model_pascal} {{
         {model_pascal} {{
-            // TODO: Add default field values
-            // Example:
-            // name: "Example".to_string(),
-            // email: "example@example.com".to_string(),
-            ..Default::default()
+{field_lines}            ..Default::default()
         }}
     }}
 
@@ -112,8 +225,43 @@ impl {factory_name} {{
         Self::with(modifier).save().await
     }}
 
-}}
+    /// A random full name
+    pub fn fake_name() -> String {{
+        const FIRST_NAMES: [&str; 8] = ["Alice", "Bob", "Carol", "David", "Eve", "Frank", "Grace", "Henry"];
+        const LAST_NAMES: [&str; 8] = ["Smith", "Jones", "Brown", "Taylor", "Wilson", "Davies", "Evans", "Thomas"];
+
+        format!(
+            "{{}} {{}}",
+            FIRST_NAMES[Self::random_number(0, 7) as usize],
+            LAST_NAMES[Self::random_number(0, 7) as usize]
+        )
+    }}
+
+    /// An email address that is unique within this process
+    pub fn fake_email() -> String {{
+        format!("{model_snake}{{}}@example.com", Self::sequence())
+    }}
+
+    /// A random number between `min` and `max`, inclusive
+    pub fn random_number(min: i64, max: i64) -> i64 {{
+        use std::hash::BuildHasher;
+
+        let span = max.abs_diff(min) + 1;
+        let random = std::collections::hash_map::RandomState::new().hash_one(Self::sequence());
+        min.min(max) + (random % span) as i64
+    }}
+
+    /// A random boolean
+    pub fn random_bool() -> bool {{
+        Self::random_number(0, 1) == 1
+    }}
 
+    fn sequence() -> u64 {{
+        static SEQUENCE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+        SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }}
+{state_constructors}}}
+{state_trait}
 #[cfg(test)]
 mod tests {{
     use super::*;
@@ -133,34 +281,151 @@ mod tests {{
     fn test_with_modifier() {{
         let _{model_snake} = {factory_name}::with(|_r| {{}});
     }}
-}}
+{state_tests}}}
 "#,
             factory_name,
             model_name,
             model_pascal = model_pascal,
             model_snake = model_snake,
             factory_name = factory_name,
-        )
+            field_lines = field_lines,
+            state_constructors = states.constructors,
+            state_trait = states.state_trait,
+            state_tests = states.tests,
+        ))
+    }
+
+    /// Generate the `state_*` constructors, the chainable states trait and their tests
+    fn generate_states(
+        &self,
+        factory_name: &str,
+        model_pascal: &str,
+        model_snake: &str,
+        fields: &[FieldDefinition],
+    ) -> Result<GeneratedStates, String> {
+        if self.states.is_empty() {
+            return Ok(GeneratedStates::default());
+        }
+
+        let trait_name = format!("{}States", factory_name);
+        let mut generated = GeneratedStates::default();
+        let mut trait_methods = Vec::new();
+        let mut trait_impls = Vec::new();
+        let mut tests = Vec::new();
+
+        for state in &self.states {
+            let mut values = Vec::new();
+            for (field_name, value) in &state.assignments {
+                // Only a model read from disk can be checked; otherwise the value type is guessed
+                let field = fields.iter().find(|field| &field.name == field_name);
+                if field.is_none() && !fields.is_empty() {
+                    return Err(format!(
+                        "Unknown field '{}' in state {} for model {}",
+                        field_name, state.name, model_pascal
+                    ));
+                }
+                values.push((field_name.as_str(), state_value(field, value)));
+            }
+
+            let assign = |receiver: &str, indent: &str| -> String {
+                values
+                    .iter()
+                    .map(|(field, value)| format!("{}{}.{} = {};\n", indent, receiver, field, value))
+                    .collect()
+            };
+
+            generated.constructors.push_str(&format!(
+                "\n    /// A {model} in the `{state}` state\n    pub fn state_{state}() -> {model} {{\n        Self::with(|r| {{\n{assignments}        }})\n    }}\n",
+                model = model_pascal,
+                state = state.name,
+                assignments = assign("r", "            "),
+            ));
+            trait_methods.push(format!(
+                "    /// Apply the `{state}` state\n    fn state_{state}(self) -> Self;",
+                state = state.name
+            ));
+            trait_impls.push(format!(
+                "    fn state_{state}(mut self) -> Self {{\n{assignments}        self\n    }}",
+                state = state.name,
+                assignments = assign("self", "        "),
+            ));
+            tests.push(format!(
+                "\n    #[test]\n    fn test_state_{state}() {{\n        let {model_snake} = {factory}::state_{state}();\n{checks}\n        let {model_snake} = {factory}::make().state_{state}();\n{checks}    }}\n",
+                state = state.name,
+                model_snake = model_snake,
+                factory = factory_name,
+                checks = values
+                    .iter()
+                    .map(|(field, value)| match value.as_str() {
+                        "true" => format!("        assert!({}.{});\n", model_snake, field),
+                        "false" => format!("        assert!(!{}.{});\n", model_snake, field),
+                        _ => format!("        assert_eq!({}.{}, {});\n", model_snake, field, value),
+                    })
+                    .collect::<String>(),
+            ));
+        }
+
+        generated.state_trait = format!(
+            r#"
+/// Named states that chain onto any {model}, e.g. `{factory}::make().state_{first}()`
+pub trait {trait_name} {{
+{methods}
+}}
+
+impl {trait_name} for {model} {{
+{impls}
+}}
+"#,
+            model = model_pascal,
+            factory = factory_name,
+            first = self.states[0].name,
+            trait_name = trait_name,
+            methods = trait_methods.join("\n\n"),
+            impls = trait_impls.join("\n\n"),
+        );
+        generated.tests = tests.concat();
+
+        Ok(generated)
     }
 
     /// Update mod.rs with new factory
     fn update_mod_file(&self, factory_name: &str) -> Result<(), String> {
         let mod_path = format!("{}/mod.rs", self.config.paths.factories);
-        let module_name = to_snake_case(factory_name);
-
-        let existing = std::fs::read_to_string(&mod_path).unwrap_or_default();
-
-        let module_decl = format!("pub mod {};", module_name);
-        if existing.contains(&module_decl) {
-            return Ok(());
-        }
+        let module_decl = format!("pub mod {};", to_snake_case(factory_name));
 
-        let new_content = format!("{}{}\n", existing, module_decl);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)
+    }
+}
 
-        std::fs::write(&mod_path, new_content)
-            .map_err(|e| format!("Failed to update mod.rs: {}", e))?;
+/// Code added to a factory for its `--states`
+#[derive(Default)]
+struct GeneratedStates {
+    constructors: String,
+    state_trait: String,
+    tests: String,
+}
 
-        Ok(())
+/// Rust expression for a state value, typed by the model field when it is known
+fn state_value(field: Option<&FieldDefinition>, value: &str) -> String {
+    let raw = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+
+    let is_string = match field {
+        Some(field) => field.base_rust_type() == "String",
+        None => raw.parse::<f64>().is_err() && raw != "true" && raw != "false",
+    };
+    let expression = if is_string {
+        format!("{:?}.to_string()", raw)
+    } else {
+        raw.to_string()
+    };
+
+    match field {
+        Some(field) if field.nullable && (raw == "null" || raw == "None") => "None".to_string(),
+        Some(field) if field.nullable => format!("Some({})", expression),
+        _ => expression,
     }
 }
 
@@ -168,3 +433,174 @@ mod tests {{
 fn to_pascal_case(s: &str) -> String {
     heck::AsPascalCase(s).to_string()
 }
+
+/// Fake value expression for a required field, using the factory's helpers
+fn fake_value(field: &FieldDefinition) -> Option<String> {
+    let value = match field.field_type.as_str() {
+        "string" if field.name.contains("email") => "Self::fake_email()".to_string(),
+        "string" => "Self::fake_name()".to_string(),
+        "i64" => "Self::random_number(1, 100)".to_string(),
+        "i8" | "i16" | "i32" | "f32" | "f64" => {
+            format!("Self::random_number(1, 100) as {}", field.field_type)
+        }
+        "bool" => "Self::random_bool()".to_string(),
+        "datetime" => "chrono::Utc::now()".to_string(),
+        "date" => "chrono::Utc::now().date_naive()".to_string(),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FactoryGenerator, FactoryState};
+    use crate::config::TideConfig;
+    use tempfile::TempDir;
+
+    const USER_MODEL: &str = r#"use tideorm::prelude::*;
+
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    #[tideorm(nullable)]
+    pub bio: Option<String>,
+    pub age: i32,
+    pub score: i64,
+    pub active: bool,
+    pub born_on: chrono::NaiveDate,
+    pub last_seen_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+"#;
+
+    fn project() -> (TempDir, TideConfig) {
+        let dir = TempDir::new().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().to_string();
+        config.paths.factories = dir.path().join("factories").to_string_lossy().to_string();
+        std::fs::create_dir_all(&config.paths.models).unwrap();
+        (dir, config)
+    }
+
+    #[test]
+    fn factory_for_existing_model_fills_fields_with_fake_values() {
+        let (_dir, config) = project();
+        std::fs::write(format!("{}/user.rs", config.paths.models), USER_MODEL).unwrap();
+
+        let path = FactoryGenerator::new(&config)
+            .generate("UserFactory", Some("User".to_string()))
+            .unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+
+        assert!(content.contains(
+            "        User {
+            name: Self::fake_name(),
+            email: Self::fake_email(),
+            bio: None,
+            age: Self::random_number(1, 100) as i32,
+            score: Self::random_number(1, 100),
+            active: Self::random_bool(),
+            born_on: chrono::Utc::now().date_naive(),
+            last_seen_at: chrono::Utc::now(),
+            ..Default::default()
+        }"
+        ));
+        assert!(!content.contains("id: "));
+        assert!(!content.contains("created_at: "));
+        assert!(!content.contains("TODO"));
+        assert!(content.contains("format!(\"user{}@example.com\", Self::sequence())"));
+    }
+
+    #[test]
+    fn factory_without_model_file_keeps_todo_comments() {
+        let (_dir, config) = project();
+
+        let path = FactoryGenerator::new(&config)
+            .generate("UserFactory", Some("User".to_string()))
+            .unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+
+        assert!(content.contains("// TODO: Add default field values"));
+        assert!(content.contains("        User {\n            // TODO"));
+    }
+
+    #[test]
+    fn states_generate_constructors_and_chainable_trait() {
+        let (_dir, config) = project();
+        std::fs::write(format!("{}/user.rs", config.paths.models), USER_MODEL).unwrap();
+
+        let path = FactoryGenerator::new(&config)
+            .states(Some("admin:name=Admin,active=true,suspended:active=false,bio=Suspended"))
+            .unwrap()
+            .generate("UserFactory", Some("User".to_string()))
+            .unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+
+        assert!(content.contains(
+            "    pub fn state_admin() -> User {
+        Self::with(|r| {
+            r.name = \"Admin\".to_string();
+            r.active = true;
+        })
+    }"
+        ));
+        assert!(content.contains(
+            "    pub fn state_suspended() -> User {
+        Self::with(|r| {
+            r.active = false;
+            r.bio = Some(\"Suspended\".to_string());
+        })
+    }"
+        ));
+        assert!(content.contains("pub trait UserFactoryStates {"));
+        assert!(content.contains(
+            "impl UserFactoryStates for User {
+    fn state_admin(mut self) -> Self {
+        self.name = \"Admin\".to_string();
+        self.active = true;
+        self
+    }"
+        ));
+        assert!(content.contains("        let user = UserFactory::make().state_suspended();\n        assert!(!user.active);"));
+
+        // The base definition keeps its fake values
+        assert!(content.contains("            name: Self::fake_name(),\n"));
+        assert!(content.contains(
+            "    pub fn make() -> User {\n        Self::definition()\n    }"
+        ));
+    }
+
+    #[test]
+    fn factory_without_states_has_no_states_trait() {
+        let (_dir, config) = project();
+
+        let path = FactoryGenerator::new(&config)
+            .generate("PostFactory", None)
+            .unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+
+        assert!(!content.contains("state_"));
+        assert!(!content.contains("PostFactoryStates"));
+    }
+
+    #[test]
+    fn invalid_states_are_rejected() {
+        assert!(FactoryState::parse_list("role=admin").is_err());
+        assert!(FactoryState::parse_list("admin:role").is_err());
+        assert!(FactoryState::parse_list("admin:role=admin,admin:active=true").is_err());
+        assert!(FactoryState::parse_list("super-admin:role=admin").is_err());
+
+        let (_dir, config) = project();
+        std::fs::write(format!("{}/user.rs", config.paths.models), USER_MODEL).unwrap();
+        let error = FactoryGenerator::new(&config)
+            .states(Some("admin:role=admin"))
+            .unwrap()
+            .generate("UserFactory", Some("User".to_string()))
+            .unwrap_err();
+        assert!(error.contains("Unknown field 'role'"), "{}", error);
+    }
+}
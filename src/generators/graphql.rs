@@ -0,0 +1,447 @@
+//! GraphQL type generator for TideORM CLI
+//!
+//! Emits `async-graphql` object, input, and dataloader definitions for a model.
+
+use crate::commands::schema::model_fields;
+use crate::config::TideConfig;
+use crate::utils::{
+    ensure_directory, to_pascal_case, to_snake_case, write_or_update_file, FieldDefinition,
+    RelationDefinition, RelationType,
+};
+
+/// Columns set by the ORM rather than by GraphQL input
+const MANAGED_COLUMNS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// Field types async-graphql can expose without extra wrappers
+const SCALAR_TYPES: [&str; 13] = [
+    "string", "i8", "i16", "i32", "i64", "f32", "f64", "bool", "datetime", "date", "time", "uuid",
+    "decimal",
+];
+
+/// GraphQL type generator
+pub struct GraphqlGenerator<'a> {
+    config: &'a TideConfig,
+    models_dir: String,
+    output_dir: String,
+}
+
+impl<'a> GraphqlGenerator<'a> {
+    /// Create a new GraphQL generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            models_dir: config.paths.models.clone(),
+            output_dir: "src/graphql".to_string(),
+        }
+    }
+
+    /// Set the directory the model file is read from
+    pub fn models_dir(mut self, dir: &str) -> Self {
+        self.models_dir = dir.to_string();
+        self
+    }
+
+    /// Set the GraphQL module directory
+    pub fn output_dir(mut self, dir: &str) -> Self {
+        self.output_dir = dir.to_string();
+        self
+    }
+
+    /// Generate the types file for an existing model
+    pub fn generate(&self, model: &str, relations: &[RelationDefinition]) -> Result<String, String> {
+        let model_pascal = to_pascal_case(model);
+        let model_snake = to_snake_case(model);
+
+        let model_path = format!("{}/{}.rs", self.models_dir, model_snake);
+        let model_content = std::fs::read_to_string(&model_path)
+            .map_err(|_| format!("Model file not found: {}", model_path))?;
+
+        let types_dir = format!("{}/types", self.output_dir);
+        ensure_directory(&types_dir)?;
+
+        let file_path = format!("{}/{}.rs", types_dir, model_snake);
+        let fields = model_fields(&model_content, &model_pascal);
+        let content = self.generate_types(&model_pascal, &fields, relations);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write GraphQL types file: {}", e))?;
+
+        // Update types/mod.rs and the graphql mod.rs above it
+        append_module(&format!("{}/mod.rs", types_dir), &model_snake)?;
+        append_module(&format!("{}/mod.rs", self.output_dir), "types")?;
+
+        Ok(file_path)
+    }
+
+    /// Generate the object, input, and loader definitions
+    fn generate_types(
+        &self,
+        model_name: &str,
+        fields: &[FieldDefinition],
+        relations: &[RelationDefinition],
+    ) -> String {
+        let model_pascal = to_pascal_case(model_name);
+        let model_snake = to_snake_case(model_name);
+
+        let fields: Vec<&FieldDefinition> = fields
+            .iter()
+            .filter(|field| SCALAR_TYPES.contains(&field.field_type.as_str()))
+            .collect();
+
+        let (key_name, key_type) = match fields.iter().find(|field| field.primary_key) {
+            Some(field) => (field.name.clone(), base_type(field)),
+            None => (
+                self.config.model.primary_key.clone(),
+                FieldDefinition::parse(&format!("id:{}", self.config.model.primary_key_type))
+                    .map(|field| field.rust_type())
+                    .unwrap_or_else(|_| "i64".to_string()),
+            ),
+        };
+
+        let input_fields: Vec<&FieldDefinition> = fields
+            .iter()
+            .copied()
+            .filter(|field| !(field.primary_key && field.auto_increment))
+            .filter(|field| !MANAGED_COLUMNS.contains(&field.name.as_str()))
+            .collect();
+
+        let object_fields: String = fields
+            .iter()
+            .map(|field| format!("    pub {}: {},\n", field.name, field.rust_type()))
+            .collect();
+        let object_conversion: String = fields
+            .iter()
+            .map(|field| format!("            {name}: {model_snake}.{name},\n", name = field.name))
+            .collect();
+
+        let create_fields: String = input_fields
+            .iter()
+            .map(|field| format!("    pub {}: {},\n", field.name, field.rust_type()))
+            .collect();
+        let create_conversion: String = input_fields
+            .iter()
+            .map(|field| format!("            {name}: input.{name},\n", name = field.name))
+            .collect();
+
+        let update_fields: String = input_fields
+            .iter()
+            .map(|field| format!("    pub {}: Option<{}>,\n", field.name, base_type(field)))
+            .collect();
+        let update_assignments: String = input_fields
+            .iter()
+            .map(|field| {
+                let value = if field.nullable {
+                    "Some(value)"
+                } else {
+                    "value"
+                };
+                format!(
+                    "        if let Some(value) = self.{name} {{\n            {model_snake}.{name} = {value};\n        }}\n",
+                    name = field.name,
+                )
+            })
+            .collect();
+
+        let mut imports = vec![format!("use crate::models::{model_snake}::{model_pascal};")];
+        let mut resolvers = Vec::new();
+        let mut relation_loaders = Vec::new();
+
+        for relation in relations {
+            let related_pascal = to_pascal_case(&relation.related_model);
+            let related_snake = to_snake_case(&relation.related_model);
+
+            match relation.relation_type {
+                RelationType::BelongsTo => {
+                    let foreign_key = relation
+                        .foreign_key
+                        .clone()
+                        .unwrap_or_else(|| format!("{}_id", related_snake));
+
+                    imports.push(format!(
+                        "use super::{related_snake}::{{{related_pascal}Loader, {related_pascal}Type}};"
+                    ));
+                    resolvers.push(format!(
+                        r#"    /// Load the related {related_pascal}
+    async fn {name}(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<{related_pascal}Type>> {{
+        let loader = ctx.data::<DataLoader<{related_pascal}Loader>>()?;
+        Ok(loader.load_one(self.{foreign_key}).await?)
+    }}"#,
+                        name = relation.name,
+                    ));
+                }
+                RelationType::HasOne | RelationType::HasMany => {
+                    let foreign_key = relation
+                        .foreign_key
+                        .clone()
+                        .unwrap_or_else(|| format!("{}_id", model_snake));
+                    let loader_name = format!("{}{}Loader", model_pascal, to_pascal_case(&relation.name));
+                    let many = relation.relation_type == RelationType::HasMany;
+
+                    imports.push(format!("use crate::models::{related_snake}::{related_pascal};"));
+                    imports.push(format!("use super::{related_snake}::{related_pascal}Type;"));
+
+                    let (value_type, resolver_type, resolver_value, described) = if many {
+                        (
+                            format!("Vec<{related_pascal}Type>"),
+                            format!("Vec<{related_pascal}Type>"),
+                            ".unwrap_or_default()",
+                            format!("{related_pascal} records"),
+                        )
+                    } else {
+                        (
+                            format!("{related_pascal}Type"),
+                            format!("Option<{related_pascal}Type>"),
+                            "",
+                            related_pascal.clone(),
+                        )
+                    };
+                    let collect = if many {
+                        format!(
+                            r#"        let mut grouped: HashMap<{key_type}, {value_type}> = HashMap::new();
+        for record in records {{
+            grouped.entry(record.{foreign_key}).or_default().push(record.into());
+        }}
+        Ok(grouped)"#
+                        )
+                    } else {
+                        format!(
+                            "        Ok(records.into_iter().map(|record| (record.{foreign_key}, record.into())).collect())"
+                        )
+                    };
+
+                    resolvers.push(format!(
+                        r#"    /// Load the related {described}
+    async fn {name}(&self, ctx: &Context<'_>) -> async_graphql::Result<{resolver_type}> {{
+        let loader = ctx.data::<DataLoader<{loader_name}>>()?;
+        Ok(loader.load_one(self.{key_name}).await?{resolver_value})
+    }}"#,
+                        name = relation.name,
+                    ));
+                    relation_loaders.push(format!(
+                        r#"
+/// Batches {related_pascal} lookups by {foreign_key}
+pub struct {loader_name};
+
+impl Loader<{key_type}> for {loader_name} {{
+    type Value = {value_type};
+    type Error = Arc<tideorm::Error>;
+
+    async fn load(&self, keys: &[{key_type}]) -> Result<HashMap<{key_type}, {value_type}>, Self::Error> {{
+        let records = {related_pascal}::query()
+            .where_in("{foreign_key}", keys.to_vec())
+            .get()
+            .await
+            .map_err(Arc::new)?;
+
+{collect}
+    }}
+}}
+"#
+                    ));
+                }
+            }
+        }
+
+        let graphql_imports = if resolvers.is_empty() {
+            "use async_graphql::dataloader::Loader;\nuse async_graphql::{InputObject, SimpleObject};"
+        } else {
+            "use async_graphql::dataloader::{DataLoader, Loader};\nuse async_graphql::{ComplexObject, Context, InputObject, SimpleObject};"
+        };
+        let object_attribute = if resolvers.is_empty() {
+            format!("#[graphql(name = \"{model_pascal}\")]")
+        } else {
+            format!("#[graphql(name = \"{model_pascal}\", complex)]")
+        };
+        let complex_object = if resolvers.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n#[ComplexObject]\nimpl {model_pascal}Type {{\n{}\n}}\n",
+                resolvers.join("\n\n")
+            )
+        };
+
+        format!(
+            r#"//! GraphQL types for the {model_pascal} model
+//!
+//! Auto-generated by TideORM CLI. Requires `async-graphql` with the `dataloader`
+//! feature, plus `chrono`, `uuid`, or `decimal` for fields of those types.
+//! JSON and array columns are not exposed.
+
+{graphql_imports}
+use std::collections::HashMap;
+use std::sync::Arc;
+use tideorm::prelude::*;
+{imports}
+
+/// GraphQL object for {model_pascal}
+#[derive(Debug, Clone, SimpleObject)]
+{object_attribute}
+pub struct {model_pascal}Type {{
+{object_fields}}}
+
+impl From<{model_pascal}> for {model_pascal}Type {{
+    fn from({model_snake}: {model_pascal}) -> Self {{
+        Self {{
+{object_conversion}        }}
+    }}
+}}
+{complex_object}
+/// Input for creating a {model_pascal}
+#[derive(Debug, Clone, InputObject)]
+pub struct Create{model_pascal}Input {{
+{create_fields}}}
+
+impl From<Create{model_pascal}Input> for {model_pascal} {{
+    fn from(input: Create{model_pascal}Input) -> Self {{
+        {model_pascal} {{
+{create_conversion}            ..Default::default()
+        }}
+    }}
+}}
+
+/// Input for updating a {model_pascal}; omitted fields are left unchanged
+#[derive(Debug, Clone, Default, InputObject)]
+pub struct Update{model_pascal}Input {{
+{update_fields}}}
+
+impl Update{model_pascal}Input {{
+    /// Copy the provided fields onto an existing record
+    pub fn apply(self, {model_snake}: &mut {model_pascal}) {{
+{update_assignments}    }}
+}}
+
+/// Batches {model_pascal} lookups by {key_name}
+pub struct {model_pascal}Loader;
+
+impl Loader<{key_type}> for {model_pascal}Loader {{
+    type Value = {model_pascal}Type;
+    type Error = Arc<tideorm::Error>;
+
+    async fn load(&self, keys: &[{key_type}]) -> Result<HashMap<{key_type}, {model_pascal}Type>, Self::Error> {{
+        let records = {model_pascal}::query()
+            .where_in("{key_name}", keys.to_vec())
+            .get()
+            .await
+            .map_err(Arc::new)?;
+
+        Ok(records.into_iter().map(|record| (record.{key_name}, record.into())).collect())
+    }}
+}}
+{relation_loaders}"#,
+            imports = imports.join("\n"),
+            relation_loaders = relation_loaders.join(""),
+        )
+    }
+}
+
+/// Rust type of a field without its `Option` wrapper
+fn base_type(field: &FieldDefinition) -> String {
+    let rust_type = field.rust_type();
+    match rust_type.strip_prefix("Option<").and_then(|inner| inner.strip_suffix('>')) {
+        Some(inner) => inner.to_string(),
+        None => rust_type,
+    }
+}
+
+/// Append `pub mod {module};` to a mod.rs unless it is already declared
+fn append_module(mod_path: &str, module: &str) -> Result<(), String> {
+    let module_decl = format!("pub mod {};", module);
+    write_or_update_file(mod_path, &format!("{}\n", module_decl), &module_decl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphqlGenerator;
+    use crate::config::TideConfig;
+    use crate::utils::{FieldDefinition, RelationDefinition};
+    use tempfile::tempdir;
+
+    fn fields(definitions: &[&str]) -> Vec<FieldDefinition> {
+        definitions
+            .iter()
+            .map(|definition| FieldDefinition::parse(definition).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generated_types_derive_async_graphql_objects_and_inputs() {
+        let config = TideConfig::default();
+        let generator = GraphqlGenerator::new(&config);
+        let content = generator.generate_types(
+            "User",
+            &fields(&[
+                "id:i64:primary_key:auto_increment",
+                "name:string",
+                "bio:string:nullable",
+                "settings:json",
+                "created_at:datetime",
+            ]),
+            &[],
+        );
+
+        assert!(content.contains("#[derive(Debug, Clone, SimpleObject)]\n#[graphql(name = \"User\")]\npub struct UserType {"));
+        assert!(content.contains("impl From<User> for UserType {"));
+        assert!(content.contains("#[derive(Debug, Clone, InputObject)]\npub struct CreateUserInput {\n    pub name: String,\n    pub bio: Option<String>,\n}"));
+        assert!(content.contains("#[derive(Debug, Clone, Default, InputObject)]\npub struct UpdateUserInput {\n    pub name: Option<String>,\n    pub bio: Option<String>,\n}"));
+        assert!(content.contains("            user.bio = Some(value);"));
+        assert!(content.contains("impl Loader<i64> for UserLoader {"));
+        assert!(content.contains(".where_in(\"id\", keys.to_vec())"));
+        assert!(!content.contains("settings"));
+        assert!(!content.contains("ComplexObject"));
+    }
+
+    #[test]
+    fn generated_relations_resolve_through_dataloaders() {
+        let config = TideConfig::default();
+        let generator = GraphqlGenerator::new(&config);
+        let relations = vec![
+            RelationDefinition::parse("author:belongs_to:User:author_id").unwrap(),
+            RelationDefinition::parse("comments:has_many:Comment").unwrap(),
+            RelationDefinition::parse("cover:has_one:Image").unwrap(),
+        ];
+        let content = generator.generate_types(
+            "Post",
+            &fields(&["id:i64:primary_key:auto_increment", "title:string", "author_id:i64"]),
+            &relations,
+        );
+
+        assert!(content.contains("use async_graphql::dataloader::{DataLoader, Loader};"));
+        assert!(content.contains("#[graphql(name = \"Post\", complex)]"));
+        assert!(content.contains("#[ComplexObject]\nimpl PostType {"));
+        assert!(content.contains("use super::user::{UserLoader, UserType};"));
+        assert!(content.contains("ctx.data::<DataLoader<UserLoader>>()?;\n        Ok(loader.load_one(self.author_id).await?)"));
+        assert!(content.contains("async fn comments(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<CommentType>>"));
+        assert!(content.contains("impl Loader<i64> for PostCommentsLoader {\n    type Value = Vec<CommentType>;"));
+        assert!(content.contains(".where_in(\"post_id\", keys.to_vec())"));
+        assert!(content.contains("async fn cover(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<ImageType>>"));
+        assert!(content.contains("impl Loader<i64> for PostCoverLoader {\n    type Value = ImageType;"));
+    }
+
+    #[test]
+    fn generate_writes_types_and_registers_modules() {
+        let dir = tempdir().unwrap();
+        let models_dir = dir.path().join("models");
+        let graphql_dir = dir.path().join("graphql");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(
+            models_dir.join("user.rs"),
+            "pub struct User {\n    #[tideorm(primary_key, auto_increment)]\n    pub id: i64,\n    pub name: String,\n}\n",
+        )
+        .unwrap();
+
+        let config = TideConfig::default();
+        let generator = GraphqlGenerator::new(&config)
+            .models_dir(&models_dir.to_string_lossy())
+            .output_dir(&graphql_dir.to_string_lossy());
+
+        let path = generator.generate("User", &[]).unwrap();
+        generator.generate("User", &[]).unwrap();
+
+        assert!(path.ends_with("types/user.rs"));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("pub struct CreateUserInput {\n    pub name: String,\n}"));
+        assert_eq!(std::fs::read_to_string(graphql_dir.join("mod.rs")).unwrap(), "pub mod types;\n");
+        assert_eq!(std::fs::read_to_string(graphql_dir.join("types/mod.rs")).unwrap(), "pub mod user;\n");
+        assert!(generator.generate("Missing", &[]).unwrap_err().contains("Model file not found"));
+    }
+}
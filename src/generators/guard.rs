@@ -0,0 +1,317 @@
+//! Guard generator for TideORM CLI
+//!
+//! Emits a struct that checks a request before it reaches a route.
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, inject_after_marker, to_pascal_case, to_snake_case, write_or_update_file};
+
+/// Request and error types shared by the guards of an output directory
+const GUARD_TYPES: &str = r#"//! Guard
+//!
+//! Request and error types used by the generated guards.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// The parts of an incoming request the guards look at
+///
+/// Build it from your web framework's request, or replace it with that type.
+#[derive(Debug, Clone, Default)]
+pub struct Request {
+    pub path: String,
+    pub headers: HashMap<String, String>,
+}
+
+impl Request {
+    /// Value of a header, matching its name case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Why a guard turned a request away
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuardError {
+    /// The request is not authenticated
+    Unauthorized,
+    /// The request is authenticated but may not reach the route
+    Forbidden,
+    /// Send the client to this URL instead
+    Redirect(String),
+}
+
+impl fmt::Display for GuardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::Forbidden => write!(f, "forbidden"),
+            Self::Redirect(url) => write!(f, "redirect to {}", url),
+        }
+    }
+}
+
+impl std::error::Error for GuardError {}
+"#;
+
+/// Guard generator
+pub struct GuardGenerator<'a> {
+    config: &'a TideConfig,
+    model: Option<String>,
+    redirect_to: Option<String>,
+}
+
+impl<'a> GuardGenerator<'a> {
+    /// Create a new guard generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            model: None,
+            redirect_to: None,
+        }
+    }
+
+    /// Add an `extract_user` helper loading this model from the request
+    pub fn model(mut self, model: Option<String>) -> Self {
+        self.model = model.map(|model| to_pascal_case(&model));
+        self
+    }
+
+    /// Reject requests with a redirect to this URL instead of an error
+    pub fn redirect_to(mut self, redirect_to: Option<String>) -> Self {
+        self.redirect_to = redirect_to;
+        self
+    }
+
+    /// Generate a guard file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        if let Some(model) = &self.model {
+            let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model));
+            if !std::path::Path::new(&model_path).exists() {
+                return Err(format!(
+                    "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                    model_path, model
+                ));
+            }
+        }
+
+        if self.redirect_to.as_deref().is_some_and(|url| url.trim().is_empty()) {
+            return Err("--redirect-to needs a URL".to_string());
+        }
+
+        let guard_name = if name.ends_with("Guard") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Guard", to_pascal_case(name))
+        };
+
+        ensure_directory(output)?;
+
+        let file_path = format!("{}/{}.rs", output, to_snake_case(&guard_name));
+        std::fs::write(&file_path, self.generate_guard(&guard_name))
+            .map_err(|e| format!("Failed to write guard file: {}", e))?;
+
+        // The shared types go first so mod.rs declares them before the guards using them
+        self.write_guard_types(output)?;
+        self.update_mod_file(output, &to_snake_case(&guard_name), &guard_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate guard content
+    fn generate_guard(&self, guard_name: &str) -> String {
+        let rejection = match self.redirect_to {
+            Some(_) => "self.reject()",
+            None => "GuardError::Unauthorized",
+        };
+
+        let (model_import, check_body, extract_user) = match &self.model {
+            Some(model) => {
+                let user = to_snake_case(model);
+                (
+                    format!("\nuse crate::models::{}::{};", user, model),
+                    format!(
+                        r#"        let Some({user}) = Self::extract_user(request) else {{
+            return Err({rejection});
+        }};
+
+        // TODO: Return Err(GuardError::Forbidden) when the {model} may not reach the route
+        let _ = {user};
+        Ok(())"#,
+                        user = user,
+                        model = model,
+                        rejection = rejection,
+                    ),
+                    format!(
+                        r#"
+
+    /// The {model} the request is authenticated as, from its session or JWT bearer token
+    pub fn extract_user(request: &Request) -> Option<{model}> {{
+        let token = request.header("authorization")?.strip_prefix("Bearer ")?;
+
+        // TODO: Verify the token or look up the session, then load the {model}
+        let _ = token;
+        None
+    }}"#,
+                        model = model,
+                    ),
+                )
+            }
+            None => (
+                String::new(),
+                format!(
+                    r#"        // TODO: Return Err({rejection}) when the request may not pass
+        let _ = request;
+        Ok(())"#,
+                    rejection = rejection,
+                ),
+                String::new(),
+            ),
+        };
+
+        let (definition, reject) = match &self.redirect_to {
+            Some(url) => (
+                format!(
+                    r#"#[derive(Debug, Clone)]
+pub struct {guard_name} {{
+    /// Where rejected requests are sent
+    pub redirect_to: String,
+}}
+
+impl Default for {guard_name} {{
+    fn default() -> Self {{
+        Self {{
+            redirect_to: {url:?}.to_string(),
+        }}
+    }}
+}}"#,
+                    guard_name = guard_name,
+                    url = url.trim(),
+                ),
+                r#"
+
+    /// Redirect sent when the guard turns a request away
+    pub fn reject(&self) -> GuardError {
+        GuardError::Redirect(self.redirect_to.clone())
+    }"#,
+            ),
+            None => (
+                format!("#[derive(Debug, Clone, Default)]\npub struct {};", guard_name),
+                "",
+            ),
+        };
+
+        format!(
+            r#"//! {guard_name}
+//!
+//! Checks requests before they reach a route.
+
+use super::{{GuardError, Request}};{model_import}
+
+/// Checks requests before they reach a route
+{definition}
+
+impl {guard_name} {{
+    /// Let the request through, or say why it was turned away
+    pub async fn check(&self, request: &Request) -> Result<(), GuardError> {{
+{check_body}
+    }}{extract_user}{reject}
+}}
+"#,
+            guard_name = guard_name,
+            model_import = model_import,
+            definition = definition,
+            check_body = check_body,
+            extract_user = extract_user,
+            reject = reject,
+        )
+    }
+
+    /// Write the `Request` and `GuardError` types next to the guards unless they are already there
+    fn write_guard_types(&self, output: &str) -> Result<(), String> {
+        let types_path = format!("{}/guard.rs", output);
+        if !std::path::Path::new(&types_path).exists() {
+            std::fs::write(&types_path, GUARD_TYPES)
+                .map_err(|e| format!("Failed to write guard types: {}", e))?;
+        }
+
+        self.update_mod_file(output, "guard", "{GuardError, Request}")
+    }
+
+    /// Update mod.rs with a module and re-export its items
+    fn update_mod_file(&self, output: &str, module_name: &str, items: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(&mod_path, &module_decl, &format!("pub use {}::{};", module_name, items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuardGenerator;
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn guard_has_a_check_method() {
+        let config = TideConfig::default();
+        let content = GuardGenerator::new(&config).generate_guard("AuthGuard");
+
+        assert!(content.contains("use super::{GuardError, Request};\n\n"));
+        assert!(content.contains("#[derive(Debug, Clone, Default)]\npub struct AuthGuard;"));
+        assert!(content.contains(
+            "    pub async fn check(&self, request: &Request) -> Result<(), GuardError> {\n        // TODO: Return Err(GuardError::Unauthorized) when the request may not pass\n        let _ = request;\n        Ok(())\n    }"
+        ));
+        assert!(!content.contains("extract_user"));
+        assert!(!content.contains("redirect_to"));
+    }
+
+    #[test]
+    fn model_and_redirect_add_extract_user_and_a_redirect_field() {
+        let config = TideConfig::default();
+        let content = GuardGenerator::new(&config)
+            .model(Some("user".to_string()))
+            .redirect_to(Some("/login".to_string()))
+            .generate_guard("AdminGuard");
+
+        assert!(content.contains("use super::{GuardError, Request};\nuse crate::models::user::User;"));
+        assert!(content.contains("pub struct AdminGuard {\n    /// Where rejected requests are sent\n    pub redirect_to: String,\n}"));
+        assert!(content.contains("redirect_to: \"/login\".to_string(),"));
+        assert!(content.contains(
+            "        let Some(user) = Self::extract_user(request) else {\n            return Err(self.reject());\n        };"
+        ));
+        assert!(content.contains("    pub fn extract_user(request: &Request) -> Option<User> {"));
+        assert!(content.contains("request.header(\"authorization\")?.strip_prefix(\"Bearer \")?;"));
+        assert!(content.contains("        GuardError::Redirect(self.redirect_to.clone())"));
+    }
+
+    #[test]
+    fn generate_registers_the_guard_after_its_types() {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        let output = dir.path().join("guards").to_string_lossy().into_owned();
+
+        assert!(GuardGenerator::new(&config)
+            .model(Some("User".to_string()))
+            .generate("Admin", &output)
+            .unwrap_err()
+            .starts_with("Model file not found"));
+
+        let path = GuardGenerator::new(&config).generate("Auth", &output).unwrap();
+        GuardGenerator::new(&config).generate("ApiGuard", &output).unwrap();
+
+        assert!(path.ends_with("auth_guard.rs"));
+        assert!(std::fs::read_to_string(dir.path().join("guards/guard.rs"))
+            .unwrap()
+            .contains("pub enum GuardError {"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("guards/mod.rs")).unwrap(),
+            "pub mod guard;\npub use guard::{GuardError, Request};\npub mod auth_guard;\npub use auth_guard::AuthGuard;\npub mod api_guard;\npub use api_guard::ApiGuard;\n"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Job generator for TideORM CLI
+
+use crate::utils::{ensure_directory, inject_after_marker, is_identifier, to_snake_case, write_or_update_file};
+
+/// Number of attempts a generated job allows before giving up
+const DEFAULT_RETRY_LIMIT: u32 = 3;
+
+/// Background job generator
+#[derive(Default)]
+pub struct JobGenerator {
+    queue: Option<String>,
+    delay_secs: Option<u64>,
+}
+
+impl JobGenerator {
+    /// Create a new job generator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the queue the job is dispatched to
+    pub fn queue(mut self, queue: Option<String>) -> Self {
+        self.queue = queue;
+        self
+    }
+
+    /// Emit a `delay()` method with the given number of seconds
+    pub fn delay_secs(mut self, delay_secs: Option<u64>) -> Self {
+        self.delay_secs = delay_secs;
+        self
+    }
+
+    /// Generate a job file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        if let Some(queue) = self.queue.as_deref().filter(|queue| !is_identifier(queue)) {
+            return Err(format!(
+                "Invalid queue name: '{}'. Use letters, digits, and underscores",
+                queue
+            ));
+        }
+
+        ensure_directory(output)?;
+
+        let job_name = if name.ends_with("Job") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Job", to_pascal_case(name))
+        };
+
+        let file_name = format!("{}.rs", to_snake_case(&job_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_job(&job_name);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write job file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &job_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate job content
+    fn generate_job(&self, job_name: &str) -> String {
+        let queue = self.queue.as_deref().unwrap_or("default");
+
+        let delay_method = match self.delay_secs {
+            Some(seconds) => format!(
+                r#"
+    /// How long to wait before the job becomes available to workers
+    pub fn delay(&self) -> std::time::Duration {{
+        std::time::Duration::from_secs({seconds})
+    }}
+"#,
+                seconds = seconds,
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            r#"//! {job_name}
+//!
+//! Background job dispatched to the "{queue}" queue.
+
+use serde::{{Deserialize, Serialize}};
+
+/// {job_name} background job
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct {job_name} {{
+    // Add the data the job needs here. It is serialized when the job is queued.
+}}
+
+impl {job_name} {{
+    /// Execute the job
+    pub async fn perform(self) -> tideorm::Result<()> {{
+        // TODO: Implement the job
+        Ok(())
+    }}
+
+    /// Queue the job is dispatched to
+    pub fn queue_name(&self) -> &'static str {{
+        "{queue}"
+    }}
+
+    /// Maximum number of attempts before the job is marked as failed
+    pub fn retry_limit(&self) -> u32 {{
+        {retry_limit}
+    }}
+{delay_method}}}
+"#,
+            job_name = job_name,
+            queue = queue,
+            retry_limit = DEFAULT_RETRY_LIMIT,
+            delay_method = delay_method,
+        )
+    }
+
+    /// Update mod.rs with new job
+    fn update_mod_file(&self, output: &str, job_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(job_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, job_name),
+        )
+    }
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JobGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_job_is_serializable_with_default_queue() {
+        let content = JobGenerator::new().generate_job("SendWelcomeEmailJob");
+
+        assert!(content.contains("#[derive(Debug, Clone, Default, Serialize, Deserialize)]"));
+        assert!(content.contains("pub struct SendWelcomeEmailJob {"));
+        assert!(content.contains("pub async fn perform(self) -> tideorm::Result<()>"));
+        assert!(content.contains("pub fn queue_name(&self) -> &'static str {\n        \"default\""));
+        assert!(content.contains("pub fn retry_limit(&self) -> u32 {\n        3"));
+        assert!(!content.contains("fn delay("));
+    }
+
+    #[test]
+    fn generated_job_uses_queue_and_delay_options() {
+        let content = JobGenerator::new()
+            .queue(Some("emails".to_string()))
+            .delay_secs(Some(30))
+            .generate_job("SendWelcomeEmailJob");
+
+        assert!(content.contains("\"emails\""));
+        assert!(content.contains("pub fn delay(&self) -> std::time::Duration"));
+        assert!(content.contains("std::time::Duration::from_secs(30)"));
+    }
+
+    #[test]
+    fn generate_rejects_queue_names_that_are_not_identifiers() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("jobs");
+        let output_path = output.to_string_lossy().into_owned();
+
+        for queue in ["", "emails\"; DROP TABLE jobs; --", "high priority", "1st"] {
+            let error = JobGenerator::new()
+                .queue(Some(queue.to_string()))
+                .generate("SendWelcomeEmail", &output_path)
+                .unwrap_err();
+            assert!(error.starts_with("Invalid queue name"), "{}", error);
+        }
+        assert!(!output.exists());
+
+        JobGenerator::new()
+            .queue(Some("high_priority".to_string()))
+            .generate("SendWelcomeEmail", &output_path)
+            .unwrap();
+    }
+
+    #[test]
+    fn generate_writes_job_and_registers_module() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("jobs");
+        let output_path = output.to_string_lossy().into_owned();
+
+        let generator = JobGenerator::new();
+        let path = generator.generate("send_welcome_email", &output_path).unwrap();
+        generator.generate("SendWelcomeEmail", &output_path).unwrap();
+
+        assert!(path.ends_with("send_welcome_email_job.rs"));
+
+        let mod_content = std::fs::read_to_string(output.join("mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod send_welcome_email_job;\npub use send_welcome_email_job::SendWelcomeEmailJob;\n"
+        );
+    }
+}
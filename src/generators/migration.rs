@@ -1,20 +1,261 @@
 //! Migration generator for TideORM CLI
 
 use crate::config::TideConfig;
+use crate::schema_types::{ColumnSchema, SchemaDiff};
 use crate::utils::{
-    ensure_directory, migration_timestamp, render_template, to_snake_case, FieldDefinition,
+    apply_check_constraints, ensure_directory, migration_timestamp, parse_generated_columns,
+    print_warning, render_template, split_field_definitions, to_snake_case, write_or_update_file,
+    FieldDefinition,
 };
 use serde::Serialize;
 
+/// What a migration does, as implied by its name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationIntent {
+    RenameTable { from: String, to: String },
+}
+
+/// Detects a migration's intent from names like `rename_users_table_to_accounts`
+pub struct MigrationNameParser;
+
+impl MigrationNameParser {
+    /// Intent implied by the migration name, if any
+    pub fn parse(name: &str) -> Option<MigrationIntent> {
+        let name = to_snake_case(name);
+        let rest = name.strip_prefix("rename_")?;
+        let rest = rest.strip_suffix("_table").unwrap_or(rest);
+        // Without `_table` the name may just as well rename a column
+        let (from, to) = rest.split_once("_table_to_")?;
+
+        if from.is_empty() || to.is_empty() {
+            return None;
+        }
+
+        Some(MigrationIntent::RenameTable {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
+/// A `--rename-table=old_name:new_name` argument
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRename {
+    pub from: String,
+    pub to: String,
+}
+
+impl TableRename {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.split_once(':') {
+            Some((from, to)) if !from.trim().is_empty() && !to.trim().is_empty() => Ok(Self {
+                from: from.trim().to_string(),
+                to: to.trim().to_string(),
+            }),
+            _ => Err(format!("Expected old_name:new_name, got: {}", s)),
+        }
+    }
+}
+
+/// A foreign key column renamed along with the table it references
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyRename {
+    pub table: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// A foreign key constraint read from the live database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyReference {
+    pub table: String,
+    pub column: String,
+    pub references_table: String,
+    pub references_column: String,
+    /// Constraint name, when the driver reports one
+    pub constraint: Option<String>,
+}
+
+/// A table dropped by `--drop-table`, with what the live database says about it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableDrop {
+    pub table: String,
+    /// Live columns recreated by `down()`; empty when the schema could not be read
+    pub columns: Vec<ColumnSchema>,
+    /// Foreign keys of the dropped table itself
+    pub foreign_keys: Vec<ForeignKeyReference>,
+    /// Foreign keys in other tables that reference the dropped table
+    pub dependents: Vec<ForeignKeyReference>,
+    /// Leave `down()` without statements so the drop cannot be rolled back
+    pub irreversible: bool,
+}
+
+/// An index added or removed by `--add-index`, `--remove-unique-index` and friends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexChange {
+    pub column: String,
+    pub unique: bool,
+    pub remove: bool,
+    /// Postgres text search configuration of a `--add-full-text-index` column
+    pub full_text: Option<String>,
+}
+
+impl IndexChange {
+    /// Collect the comma-separated columns of the four index flags
+    pub fn from_flags(
+        add_index: Option<String>,
+        remove_index: Option<String>,
+        add_unique_index: Option<String>,
+        remove_unique_index: Option<String>,
+    ) -> Vec<Self> {
+        [
+            (add_index, false, false),
+            (remove_index, false, true),
+            (add_unique_index, true, false),
+            (remove_unique_index, true, true),
+        ]
+        .into_iter()
+        .flat_map(|(columns, unique, remove)| {
+            columns
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|column| !column.is_empty())
+                .map(|column| Self {
+                    column: column.to_string(),
+                    unique,
+                    remove,
+                    full_text: None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+    }
+
+    /// Parse comma-separated `column[:language]` specs of `--add-full-text-index`
+    ///
+    /// Columns without a language use `language`, which only matters for Postgres.
+    pub fn full_text(columns: &str, language: &str) -> Result<Vec<Self>, String> {
+        columns
+            .split(',')
+            .map(str::trim)
+            .filter(|spec| !spec.is_empty())
+            .map(|spec| {
+                let (column, column_language) = match spec.split_once(':') {
+                    Some((column, column_language)) => (column.trim(), column_language.trim()),
+                    None => (spec, language.trim()),
+                };
+
+                if column.is_empty() {
+                    return Err(format!("Missing column in full-text index '{}'", spec));
+                }
+                if column_language.is_empty()
+                    || !column_language
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(format!(
+                        "Invalid text search language '{}' for column {}",
+                        column_language, column
+                    ));
+                }
+
+                Ok(Self {
+                    column: column.to_string(),
+                    unique: false,
+                    remove: false,
+                    full_text: Some(column_language.to_lowercase()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A column type change from `--modify-column=column:new_type`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnModification {
+    pub column: String,
+    pub to: String,
+    /// Type restored by `down()`, from `--original-type` or the live schema
+    pub from: Option<String>,
+}
+
+impl ColumnModification {
+    /// Parse comma-separated `column:new_type` specs; types may contain commas, as in `decimal(10,2)`
+    pub fn parse_list(specs: &str) -> Result<Vec<Self>, String> {
+        let modifications = split_field_definitions(specs)
+            .into_iter()
+            .map(|spec| match spec.split_once(':') {
+                Some((column, to)) if !column.trim().is_empty() && !to.trim().is_empty() => Ok(Self {
+                    column: column.trim().to_string(),
+                    to: to.trim().to_string(),
+                    from: None,
+                }),
+                _ => Err(format!("Expected column:new_type, got: {}", spec)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if modifications.is_empty() {
+            return Err("No column type changes given".to_string());
+        }
+
+        Ok(modifications)
+    }
+
+    /// Whether the new type holds less than the original one, so existing values may be cut off
+    pub fn narrows(&self) -> bool {
+        self.from
+            .as_deref()
+            .is_some_and(|from| narrows_type(from, &self.to))
+    }
+}
+
+/// An index read from the live database, recreated after a SQLite table rebuild
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableIndex {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+/// Column type changes of one table, with the live schema SQLite rebuilds the table from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnTypeChange {
+    pub table: String,
+    pub modifications: Vec<ColumnModification>,
+    /// Live columns; empty when the schema could not be read
+    pub columns: Vec<ColumnSchema>,
+    pub foreign_keys: Vec<ForeignKeyReference>,
+    pub indexes: Vec<TableIndex>,
+}
+
 /// Migration generator
 pub struct MigrationGenerator<'a> {
     config: &'a TideConfig,
+    check_constraints: Option<String>,
+    generated_columns: Option<String>,
 }
 
 impl<'a> MigrationGenerator<'a> {
     /// Create a new migration generator
     pub fn new(config: &'a TideConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            check_constraints: None,
+            generated_columns: None,
+        }
+    }
+
+    /// Set `field:expression` check constraints for the generated columns
+    pub fn check_constraints(mut self, checks: Option<String>) -> Self {
+        self.check_constraints = checks;
+        self
+    }
+
+    /// Add `name:type:expression` columns computed by the database
+    pub fn generated_columns(mut self, columns: Option<String>) -> Self {
+        self.generated_columns = columns;
+        self
     }
 
     /// Generate a migration file
@@ -27,7 +268,112 @@ impl<'a> MigrationGenerator<'a> {
         include_timestamps: bool,
         include_soft_deletes: bool,
     ) -> Result<String, String> {
-        ensure_directory(&self.config.paths.migrations)?;
+        // Parse fields
+        let mut parsed_fields = Self::parse_fields(fields.as_deref())?;
+        if let Some(checks) = &self.check_constraints {
+            apply_check_constraints(&mut parsed_fields, checks)?;
+        }
+        if let Some(columns) = &self.generated_columns {
+            parsed_fields.extend(parse_generated_columns(columns)?);
+        }
+        for field in &parsed_fields {
+            field.check_driver(&self.config.database.driver)?;
+        }
+
+        self.write_migration(name, |migration_name, version| {
+            if let Some(table) = create_table {
+                self.generate_create_table(
+                    migration_name,
+                    version,
+                    &table,
+                    &parsed_fields,
+                    include_timestamps,
+                    include_soft_deletes,
+                )
+            } else if let Some(table) = alter_table {
+                self.generate_alter_table(migration_name, version, &table, &parsed_fields)
+            } else {
+                self.generate_empty(migration_name, version)
+            }
+        })
+    }
+
+    /// Generate a migration renaming a table and, optionally, the foreign key columns pointing at it
+    pub fn generate_rename(
+        &self,
+        name: &str,
+        rename: &TableRename,
+        foreign_keys: &[ForeignKeyRename],
+    ) -> Result<String, String> {
+        self.write_migration(name, |migration_name, version| {
+            self.generate_rename_table(migration_name, version, rename, foreign_keys)
+        })
+    }
+
+    /// Generate a migration dropping a table; `down()` recreates it from the live schema
+    pub fn generate_drop_table(&self, name: &str, drop: &TableDrop) -> Result<String, String> {
+        self.write_migration(name, |migration_name, version| {
+            self.generate_drop_table_content(migration_name, version, drop)
+        })
+    }
+
+    /// Generate a migration running existing SQL; without `down_sql`, `down()` is left as a TODO
+    pub fn generate_from_sql(
+        &self,
+        name: &str,
+        up_sql: &str,
+        down_sql: Option<&str>,
+    ) -> Result<String, String> {
+        self.write_migration(name, |migration_name, version| {
+            self.generate_from_sql_content(migration_name, version, up_sql, down_sql)
+        })
+    }
+
+    /// Generate an index-only migration; removed indexes are recreated by `down()`
+    ///
+    /// With `concurrently`, Postgres builds and drops the indexes without locking writes,
+    /// which cannot happen inside a transaction.
+    pub fn generate_add_index(
+        &self,
+        name: &str,
+        table: &str,
+        changes: &[IndexChange],
+        concurrently: bool,
+    ) -> Result<String, String> {
+        if changes.is_empty() {
+            return Err("No index changes given".to_string());
+        }
+
+        self.write_migration(name, |migration_name, version| {
+            self.generate_index_changes(migration_name, version, table, changes, concurrently)
+        })
+    }
+
+    /// Generate a migration changing column types; `down()` restores the original types
+    pub fn generate_modify_column(&self, name: &str, change: &ColumnTypeChange) -> Result<String, String> {
+        if change.modifications.is_empty() {
+            return Err("No column type changes given".to_string());
+        }
+
+        self.write_migration(name, |migration_name, version| {
+            self.generate_modify_column_content(migration_name, version, change)
+        })
+    }
+
+    /// Generate a migration applying the column changes of a schema diff
+    pub fn generate_sync(&self, name: &str, diff: &SchemaDiff) -> Result<String, String> {
+        self.write_migration(name, |migration_name, version| {
+            self.generate_sync_content(migration_name, version, diff)
+        })
+    }
+
+    /// Write a new migration file and register it in mod.rs
+    fn write_migration(
+        &self,
+        name: &str,
+        content: impl FnOnce(&str, &str) -> Result<String, String>,
+    ) -> Result<String, String> {
+        let migrations_dir = ensure_directory(&self.config.paths.migrations)?;
 
         let migration_name = to_snake_case(name);
         let timestamp = if self.config.migration.timestamps {
@@ -44,9 +390,6 @@ impl<'a> MigrationGenerator<'a> {
 
         let file_path = format!("{}/{}", self.config.paths.migrations, file_name);
 
-        // Parse fields
-        let parsed_fields = Self::parse_fields(fields.as_deref())?;
-
         let version = if timestamp.is_empty() {
             migration_name.clone()
         } else {
@@ -54,22 +397,9 @@ impl<'a> MigrationGenerator<'a> {
         };
 
         // Generate content
-        let content = if let Some(table) = create_table {
-            self.generate_create_table(
-                &migration_name,
-                &version,
-                &table,
-                &parsed_fields,
-                include_timestamps,
-                include_soft_deletes,
-            )?
-        } else if let Some(table) = alter_table {
-            self.generate_alter_table(&migration_name, &version, &table, &parsed_fields)?
-        } else {
-            self.generate_empty(&migration_name, &version)?
-        };
+        let content = content(&migration_name, &version)?;
 
-        std::fs::write(&file_path, content)
+        std::fs::write(migrations_dir.join(&file_name), content)
             .map_err(|e| format!("Failed to write migration file: {}", e))?;
 
         // Update mod.rs
@@ -99,7 +429,11 @@ impl<'a> MigrationGenerator<'a> {
         }
 
         for field in fields {
-            columns.push(self.build_column_sql(field, driver));
+            let column = self.build_column_sql(table, field, driver);
+            columns.push(match field.sql_comment(driver) {
+                Some(comment) => format!("            -- {}\n{}", comment, column),
+                None => column,
+            });
         }
 
         // Add timestamps
@@ -129,6 +463,24 @@ impl<'a> MigrationGenerator<'a> {
             columns.join(",\n")
         );
 
+        // Indexes are created after the table and dropped before it
+        let mut up_index_statements = Vec::new();
+        let mut down_index_statements = Vec::new();
+
+        for field in fields.iter().filter(|field| (field.indexed || field.unique) && !field.primary_key) {
+            // Partial indexes are built concurrently on Postgres
+            let concurrently = field.index_where.is_some() && driver == "postgres";
+            up_index_statements.push(format!(
+                "        schema.raw(r#\"{}\"#).await?;",
+                self.create_partial_index_sql(table, field, concurrently, driver)
+            ));
+            down_index_statements.push(format!(
+                "        schema.raw(r#\"{}\"#).await?;",
+                self.drop_index_sql(table, &field.name, concurrently, driver)
+            ));
+        }
+        down_index_statements.reverse();
+
         let context = MigrationTemplateContext {
             name: name.to_string(),
             version: version.to_string(),
@@ -140,6 +492,9 @@ impl<'a> MigrationGenerator<'a> {
             down_raw_sql: Some(format!("DROP TABLE IF EXISTS {}", table)),
             up_statements: Vec::new(),
             down_statements: Vec::new(),
+            up_index_statements,
+            down_index_statements,
+            requires_transaction: !(driver == "postgres" && fields.iter().any(|field| field.index_where.is_some())),
         };
 
         self.render_migration_template(&context)
@@ -175,9 +530,17 @@ impl<'a> MigrationGenerator<'a> {
                 col_def.push_str(&format!(" DEFAULT {}", default));
             }
 
+            if let Some(check) = &field.check {
+                col_def.push_str(&check_constraint_sql(table, &field.name, check));
+            }
+
+            let comment = field
+                .sql_comment(driver)
+                .map(|comment| format!("-- {}\n        ", comment))
+                .unwrap_or_default();
             up_statements.push(format!(
-                "        schema.raw(r#\"ALTER TABLE {} ADD COLUMN {}\"#).await?;",
-                table, col_def
+                "        schema.raw(r#\"{}ALTER TABLE {} ADD COLUMN {}\"#).await?;",
+                comment, table, col_def
             ));
 
             down_statements.push(format!(
@@ -197,105 +560,656 @@ impl<'a> MigrationGenerator<'a> {
             down_raw_sql: None,
             up_statements,
             down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
         };
 
         self.render_migration_template(&context)
     }
 
-    /// Generate an empty migration
-    fn generate_empty(&self, name: &str, version: &str) -> Result<String, String> {
-        let struct_name = to_pascal_case(name);
+    /// Generate a rename table migration
+    fn generate_rename_table(
+        &self,
+        name: &str,
+        version: &str,
+        rename: &TableRename,
+        foreign_keys: &[ForeignKeyRename],
+    ) -> Result<String, String> {
+        let driver = self.config.database.driver.as_str();
+
+        // Renaming the table keeps existing constraints pointing at it, so only the
+        // conventionally named columns need to follow
+        let mut up_statements = vec![raw_statement(&rename_table_sql(driver, &rename.from, &rename.to))];
+        let mut down_statements = Vec::new();
+
+        for foreign_key in foreign_keys {
+            up_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {}",
+                foreign_key.table, foreign_key.from, foreign_key.to
+            )));
+            down_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {}",
+                foreign_key.table, foreign_key.to, foreign_key.from
+            )));
+        }
+
+        down_statements.push(raw_statement(&rename_table_sql(driver, &rename.to, &rename.from)));
 
         let context = MigrationTemplateContext {
             name: name.to_string(),
             version: version.to_string(),
-            struct_name,
-            description: "TODO: Describe what this migration does.".to_string(),
-            up_mode: "comments".to_string(),
-            down_mode: "comments".to_string(),
+            struct_name: to_pascal_case(name),
+            description: format!("Renames the {} table to {}.", rename.from, rename.to),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
             up_raw_sql: None,
             down_raw_sql: None,
-            up_statements: vec![
-                "        // TODO: Implement the forward migration".to_string(),
-                "        // Example:".to_string(),
-                "        // schema.raw(r#\"".to_string(),
-                "        //     CREATE TABLE example (".to_string(),
-                "        //         id BIGSERIAL PRIMARY KEY,".to_string(),
-                "        //         name VARCHAR(255) NOT NULL".to_string(),
-                "        //     )".to_string(),
-                "        // \"#).await?;".to_string(),
-            ],
-            down_statements: vec![
-                "        // TODO: Implement the reverse migration".to_string(),
-                "        // Example:".to_string(),
-                "        // schema.raw(r#\"DROP TABLE IF EXISTS example\"#).await?;".to_string(),
-            ],
+            up_statements,
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
         };
 
         self.render_migration_template(&context)
     }
 
-    fn render_migration_template(
+    /// Generate a drop table migration
+    fn generate_drop_table_content(
         &self,
-        context: &MigrationTemplateContext,
+        name: &str,
+        version: &str,
+        drop: &TableDrop,
     ) -> Result<String, String> {
-        render_template(
-            "migration",
-            DEFAULT_MIGRATION_TEMPLATE,
-            self.config.migration.template.as_deref(),
-            context,
-        )
+        let driver = self.config.database.driver.as_str();
+        let table = &drop.table;
+
+        let mut up_statements = Vec::new();
+        let mut drop_sql = format!("DROP TABLE IF EXISTS {}", table);
+        match driver {
+            // CASCADE removes the constraints in other tables that reference this one
+            "postgres" if !drop.dependents.is_empty() => drop_sql.push_str(" CASCADE"),
+            // MySQL refuses to drop a referenced table, so drop those constraints first
+            "mysql" => {
+                for dependent in &drop.dependents {
+                    if let Some(constraint) = &dependent.constraint {
+                        up_statements.push(raw_statement(&format!(
+                            "ALTER TABLE {} DROP FOREIGN KEY {}",
+                            dependent.table, constraint
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+        up_statements.push(raw_statement(&drop_sql));
+
+        let down_statements = if drop.irreversible {
+            vec![format!(
+                "        // Irreversible: {} cannot be recreated by rolling back this migration",
+                table
+            )]
+        } else if drop.columns.is_empty() {
+            vec![format!(
+                "        // TODO: add CREATE TABLE statement here to recreate {}",
+                table
+            )]
+        } else {
+            let mut statements = vec![format!(
+                "        schema.raw(r#\"\n        {}\n        \"#).await?;",
+                recreate_table_sql(driver, table, &drop.columns, &drop.foreign_keys)
+            )];
+
+            // SQLite keeps foreign keys in the referencing table's definition, so they
+            // come back with the table
+            if driver != "sqlite" {
+                for dependent in &drop.dependents {
+                    let constraint = dependent
+                        .constraint
+                        .as_deref()
+                        .map(|constraint| format!(" CONSTRAINT {}", constraint))
+                        .unwrap_or_default();
+                    statements.push(raw_statement(&format!(
+                        "ALTER TABLE {} ADD{} FOREIGN KEY ({}) REFERENCES {} ({})",
+                        dependent.table,
+                        constraint,
+                        dependent.column,
+                        dependent.references_table,
+                        dependent.references_column
+                    )));
+                }
+            }
+
+            statements
+        };
+
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name: to_pascal_case(name),
+            description: format!(
+                "Drops the {} table. Check that down() matches the table before running.",
+                table
+            ),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements,
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
+        };
+
+        self.render_migration_template(&context)
     }
 
-    fn parse_fields(fields: Option<&str>) -> Result<Vec<FieldDefinition>, String> {
-        let mut parsed_fields = Vec::new();
+    /// Generate a migration wrapping SQL files in `schema.raw` calls, kept verbatim
+    fn generate_from_sql_content(
+        &self,
+        name: &str,
+        version: &str,
+        up_sql: &str,
+        down_sql: Option<&str>,
+    ) -> Result<String, String> {
+        let down_statements = match down_sql {
+            Some(sql) => vec![raw_sql_block(sql)],
+            None => vec!["        // TODO: Implement the reverse migration".to_string()],
+        };
 
-        if let Some(fields_str) = fields {
-            for field in fields_str.split(',') {
-                let field = field.trim();
-                if field.is_empty() {
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name: to_pascal_case(name),
+            description: "Runs existing SQL.".to_string(),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements: vec![raw_sql_block(up_sql)],
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
+        };
+
+        self.render_migration_template(&context)
+    }
+
+    /// Generate a migration that creates and drops indexes on one table
+    fn generate_index_changes(
+        &self,
+        name: &str,
+        version: &str,
+        table: &str,
+        changes: &[IndexChange],
+        concurrently: bool,
+    ) -> Result<String, String> {
+        let driver = self.config.database.driver.as_str();
+
+        let mut up_statements = Vec::new();
+        let mut down_statements = Vec::new();
+        let mut fts_columns = Vec::new();
+
+        for change in changes {
+            if let Some(language) = &change.full_text {
+                // SQLite keeps full-text columns in one FTS5 virtual table per table
+                if driver == "sqlite" {
+                    fts_columns.push(change.column.as_str());
                     continue;
                 }
 
-                parsed_fields.push(FieldDefinition::parse(field)?);
+                let create =
+                    self.create_full_text_index_sql(table, &change.column, language, concurrently, driver);
+                let drop = self.drop_full_text_index_sql(table, &change.column, concurrently, driver);
+                up_statements.push(raw_statement(&create));
+                down_statements.push(raw_statement(&drop));
+                continue;
+            }
+
+            let create = self.create_index_sql(table, &change.column, change.unique, concurrently, driver);
+            let drop = self.drop_index_sql(table, &change.column, concurrently, driver);
+
+            if change.remove {
+                up_statements.push(raw_statement(&drop));
+                down_statements.push(raw_statement(&create));
+            } else {
+                up_statements.push(raw_statement(&create));
+                down_statements.push(raw_statement(&drop));
             }
         }
 
-        Ok(parsed_fields)
+        if !fts_columns.is_empty() {
+            up_statements.push(raw_statement(&format!(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS {}_fts USING fts5({})",
+                table,
+                fts_columns.join(", ")
+            )));
+            down_statements.push(raw_statement(&format!("DROP TABLE IF EXISTS {}_fts", table)));
+        }
+
+        down_statements.reverse();
+
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name: to_pascal_case(name),
+            description: format!("Changes the indexes on the {} table.", table),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements,
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: !(concurrently && driver == "postgres"),
+        };
+
+        self.render_migration_template(&context)
     }
 
-    fn build_column_sql(&self, field: &FieldDefinition, driver: &str) -> String {
-        if field.primary_key && field.auto_increment {
-            return self.auto_increment_primary_key_sql(&field.name, driver);
-        }
+    /// Generate a migration that adds, drops and retypes columns
+    fn generate_sync_content(
+        &self,
+        name: &str,
+        version: &str,
+        diff: &SchemaDiff,
+    ) -> Result<String, String> {
+        let struct_name = to_pascal_case(name);
+        let driver = &self.config.database.driver;
 
-        let mut col_def = format!("            {} {}", field.name, field.sql_type(driver));
+        let mut up_statements = Vec::new();
+        let mut down_statements = Vec::new();
 
-        if field.primary_key {
-            col_def.push_str(" PRIMARY KEY");
+        for added in &diff.added_columns {
+            if !added.column.nullable && added.column.default.is_none() && fill_default(&added.column.data_type).is_none() {
+                print_warning(&format!(
+                    "Adding {}.{} as nullable: existing rows need a value and {} has no obvious default. \
+                     Backfill it, then make it NOT NULL in a later migration",
+                    added.table, added.column.name, added.column.data_type
+                ));
+            }
+            up_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} ADD COLUMN {}",
+                added.table,
+                added_column_definition(&added.column)
+            )));
+            down_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} DROP COLUMN {}",
+                added.table, added.column.name
+            )));
         }
 
-        if field.auto_increment {
-            col_def.push_str(self.get_auto_increment(driver));
+        for dropped in &diff.dropped_columns {
+            up_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} DROP COLUMN {}",
+                dropped.table, dropped.column.name
+            )));
+            down_statements.push(raw_statement(&format!(
+                "ALTER TABLE {} ADD COLUMN {}",
+                dropped.table,
+                added_column_definition(&dropped.column)
+            )));
         }
 
-        if !field.nullable && !field.primary_key {
-            col_def.push_str(" NOT NULL");
+        for changed in &diff.changed_columns {
+            up_statements.push(self.alter_column_type_statement(
+                &changed.table,
+                &changed.column,
+                &changed.from,
+                &changed.to,
+                driver,
+            ));
+            down_statements.push(self.alter_column_type_statement(
+                &changed.table,
+                &changed.column,
+                &changed.to,
+                &changed.from,
+                driver,
+            ));
         }
 
-        if field.unique {
-            col_def.push_str(" UNIQUE");
-        }
+        down_statements.reverse();
 
-        if let Some(default) = &field.default {
-            col_def.push_str(&format!(" DEFAULT {}", default));
-        }
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name,
+            description: "Syncs the database schema with the models. Review before running.".to_string(),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements,
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
+        };
 
-        col_def
+        self.render_migration_template(&context)
     }
 
-    fn default_primary_key_sql(&self, driver: &str) -> String {
-        self.auto_increment_primary_key_sql(&self.config.model.primary_key, driver)
+    /// Generate a column type change migration
+    ///
+    /// SQLite cannot change a column's type in place, so it copies the rows into a rebuilt
+    /// table, which needs the live schema.
+    fn generate_modify_column_content(
+        &self,
+        name: &str,
+        version: &str,
+        change: &ColumnTypeChange,
+    ) -> Result<String, String> {
+        let driver = self.config.database.driver.as_str();
+        let table = &change.table;
+
+        let restored: Vec<(&str, &str)> = change
+            .modifications
+            .iter()
+            .filter_map(|modification| Some((modification.column.as_str(), modification.from.as_deref()?)))
+            .collect();
+        let unknown: Vec<&str> = change
+            .modifications
+            .iter()
+            .filter(|modification| modification.from.is_none())
+            .map(|modification| modification.column.as_str())
+            .collect();
+        let restore_todo = |column: &str| {
+            format!(
+                "        // TODO: change {}.{} back to its original type (pass --original-type)",
+                table, column
+            )
+        };
+
+        let (up_statements, down_statements) = if driver == "sqlite" {
+            let changed: Vec<(&str, &str)> = change
+                .modifications
+                .iter()
+                .map(|modification| (modification.column.as_str(), modification.to.as_str()))
+                .collect();
+
+            if change.columns.is_empty() {
+                let mut up_statements = vec![format!(
+                    "        // TODO: SQLite cannot change column types in place: rebuild {} with the new types",
+                    table
+                )];
+                up_statements.extend(changed.iter().map(|(column, to)| format!("        // {} -> {}", column, to)));
+                (up_statements, vec![format!("        // TODO: rebuild {} with the original column types", table)])
+            } else if unknown.is_empty() {
+                (sqlite_rebuild_statements(change, &changed), sqlite_rebuild_statements(change, &restored))
+            } else {
+                (
+                    sqlite_rebuild_statements(change, &changed),
+                    unknown.iter().map(|column| restore_todo(column)).collect(),
+                )
+            }
+        } else {
+            let mut up_statements = Vec::new();
+            let mut down_statements = Vec::new();
+
+            for modification in &change.modifications {
+                let live = change
+                    .columns
+                    .iter()
+                    .find(|column| column.name == modification.column);
+                up_statements.push(raw_statement(&modify_column_sql(
+                    driver,
+                    table,
+                    &modification.column,
+                    &modification.to,
+                    live,
+                )));
+                down_statements.push(match &modification.from {
+                    Some(from) => raw_statement(&modify_column_sql(driver, table, &modification.column, from, live)),
+                    None => restore_todo(&modification.column),
+                });
+            }
+
+            down_statements.reverse();
+            (up_statements, down_statements)
+        };
+
+        let columns: Vec<String> = change
+            .modifications
+            .iter()
+            .map(|modification| format!("{}.{}", table, modification.column))
+            .collect();
+
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name: to_pascal_case(name),
+            description: format!("Changes the type of {}.", columns.join(", ")),
+            up_mode: "statements".to_string(),
+            down_mode: "statements".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements,
+            down_statements,
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
+        };
+
+        self.render_migration_template(&context)
+    }
+
+    /// Statement changing a column's type, or a note where the driver cannot
+    fn alter_column_type_statement(
+        &self,
+        table: &str,
+        column: &str,
+        from: &str,
+        to: &str,
+        driver: &str,
+    ) -> String {
+        match driver {
+            "mysql" => raw_statement(&format!("ALTER TABLE {} MODIFY COLUMN {} {}", table, column, to)),
+            "sqlite" => format!(
+                "        // SQLite cannot change column types in place: rebuild {} to change {} from {} to {}",
+                table, column, from, to
+            ),
+            _ => raw_statement(&format!("ALTER TABLE {} ALTER COLUMN {} TYPE {}", table, column, to)),
+        }
+    }
+
+    /// Generate an empty migration
+    fn generate_empty(&self, name: &str, version: &str) -> Result<String, String> {
+        let struct_name = to_pascal_case(name);
+
+        let context = MigrationTemplateContext {
+            name: name.to_string(),
+            version: version.to_string(),
+            struct_name,
+            description: "TODO: Describe what this migration does.".to_string(),
+            up_mode: "comments".to_string(),
+            down_mode: "comments".to_string(),
+            up_raw_sql: None,
+            down_raw_sql: None,
+            up_statements: vec![
+                "        // TODO: Implement the forward migration".to_string(),
+                "        // Example:".to_string(),
+                "        // schema.raw(r#\"".to_string(),
+                "        //     CREATE TABLE example (".to_string(),
+                "        //         id BIGSERIAL PRIMARY KEY,".to_string(),
+                "        //         name VARCHAR(255) NOT NULL".to_string(),
+                "        //     )".to_string(),
+                "        // \"#).await?;".to_string(),
+            ],
+            down_statements: vec![
+                "        // TODO: Implement the reverse migration".to_string(),
+                "        // Example:".to_string(),
+                "        // schema.raw(r#\"DROP TABLE IF EXISTS example\"#).await?;".to_string(),
+            ],
+            up_index_statements: Vec::new(),
+            down_index_statements: Vec::new(),
+            requires_transaction: true,
+        };
+
+        self.render_migration_template(&context)
+    }
+
+    fn render_migration_template(
+        &self,
+        context: &MigrationTemplateContext,
+    ) -> Result<String, String> {
+        render_template(
+            "migration",
+            DEFAULT_MIGRATION_TEMPLATE,
+            self.config.migration.template.as_deref(),
+            context,
+        )
+    }
+
+    fn parse_fields(fields: Option<&str>) -> Result<Vec<FieldDefinition>, String> {
+        let mut parsed_fields = Vec::new();
+
+        if let Some(fields_str) = fields {
+            for field in split_field_definitions(fields_str) {
+                parsed_fields.push(FieldDefinition::parse(field)?);
+            }
+        }
+
+        Ok(parsed_fields)
+    }
+
+    fn build_column_sql(&self, table: &str, field: &FieldDefinition, driver: &str) -> String {
+        if let Some(expression) = &field.generated {
+            return format!(
+                "            {} {} GENERATED ALWAYS AS ({}) STORED",
+                field.name,
+                field.sql_type(driver),
+                expression
+            );
+        }
+
+        if field.primary_key && field.auto_increment {
+            return self.auto_increment_primary_key_sql(&field.name, driver);
+        }
+
+        let mut col_def = format!("            {} {}", field.name, field.sql_type(driver));
+
+        if field.primary_key {
+            col_def.push_str(" PRIMARY KEY");
+        }
+
+        if field.auto_increment {
+            col_def.push_str(self.get_auto_increment(driver));
+        }
+
+        if !field.nullable && !field.primary_key {
+            col_def.push_str(" NOT NULL");
+        }
+
+        if let Some(default) = &field.default {
+            col_def.push_str(&format!(" DEFAULT {}", default));
+        }
+
+        if let Some(check) = &field.check {
+            col_def.push_str(&check_constraint_sql(table, &field.name, check));
+        }
+
+        col_def
+    }
+
+    /// Build the CREATE INDEX statement for an indexed or unique column
+    ///
+    /// `concurrently` only applies to Postgres, where it avoids locking writes to the table.
+    fn create_index_sql(
+        &self,
+        table: &str,
+        column: &str,
+        unique: bool,
+        concurrently: bool,
+        driver: &str,
+    ) -> String {
+        let kind = if unique { "UNIQUE INDEX" } else { "INDEX" };
+        let concurrently = if concurrently && driver == "postgres" { " CONCURRENTLY" } else { "" };
+        let if_not_exists = if driver == "mysql" { "" } else { " IF NOT EXISTS" };
+
+        format!(
+            "CREATE {}{}{} {} ON {} ({})",
+            kind,
+            concurrently,
+            if_not_exists,
+            index_name(table, column),
+            table,
+            column
+        )
+    }
+
+    /// Build the CREATE INDEX statement for a field, limited to the rows matching its `where=`
+    /// condition on Postgres and SQLite
+    ///
+    /// MySQL has no partial indexes, so the field gets a plain index there.
+    fn create_partial_index_sql(&self, table: &str, field: &FieldDefinition, concurrently: bool, driver: &str) -> String {
+        let index_sql = self.create_index_sql(table, &field.name, field.unique, concurrently, driver);
+
+        match &field.index_where {
+            Some(condition) if driver == "mysql" => format!(
+                "-- Partial index not supported in {}; indexing every row instead of WHERE {}\n        {}",
+                driver, condition, index_sql
+            ),
+            Some(condition) => format!("{} WHERE {}", index_sql, condition),
+            None => index_sql,
+        }
+    }
+
+    /// Build the DROP INDEX statement matching `create_index_sql`
+    fn drop_index_sql(&self, table: &str, column: &str, concurrently: bool, driver: &str) -> String {
+        match driver {
+            "mysql" => format!("DROP INDEX {} ON {}", index_name(table, column), table),
+            "postgres" if concurrently => {
+                format!("DROP INDEX CONCURRENTLY IF EXISTS {}", index_name(table, column))
+            }
+            _ => format!("DROP INDEX IF EXISTS {}", index_name(table, column)),
+        }
+    }
+
+    /// Build a full-text index: GIN over `to_tsvector` on Postgres, `FULLTEXT` on MySQL
+    fn create_full_text_index_sql(
+        &self,
+        table: &str,
+        column: &str,
+        language: &str,
+        concurrently: bool,
+        driver: &str,
+    ) -> String {
+        let name = full_text_index_name(table, column);
+        match driver {
+            "mysql" => format!("ALTER TABLE {} ADD FULLTEXT INDEX {} ({})", table, name, column),
+            _ => format!(
+                "CREATE INDEX{} IF NOT EXISTS {} ON {} USING gin(to_tsvector('{}', {}))",
+                if concurrently { " CONCURRENTLY" } else { "" },
+                name,
+                table,
+                language,
+                column
+            ),
+        }
+    }
+
+    /// Build the statement dropping a Postgres or MySQL full-text index
+    fn drop_full_text_index_sql(
+        &self,
+        table: &str,
+        column: &str,
+        concurrently: bool,
+        driver: &str,
+    ) -> String {
+        let name = full_text_index_name(table, column);
+        match driver {
+            "mysql" => format!("ALTER TABLE {} DROP INDEX {}", table, name),
+            "postgres" if concurrently => format!("DROP INDEX CONCURRENTLY IF EXISTS {}", name),
+            _ => format!("DROP INDEX IF EXISTS {}", name),
+        }
+    }
+
+    fn default_primary_key_sql(&self, driver: &str) -> String {
+        self.auto_increment_primary_key_sql(&self.config.model.primary_key, driver)
     }
 
     fn auto_increment_primary_key_sql(&self, field_name: &str, driver: &str) -> String {
@@ -343,24 +1257,17 @@ impl<'a> MigrationGenerator<'a> {
         let file_stem = file_name.trim_end_matches(".rs");
         let module_name = migration_module_name(file_stem);
 
-        let existing = std::fs::read_to_string(&mod_path).unwrap_or_default();
-
         let module_decl = if module_name == file_stem {
             format!("pub mod {};", module_name)
         } else {
             format!("#[path = \"{}\"]\npub mod {};", file_name, module_name)
         };
 
-        if existing.contains(&format!("pub mod {};", module_name)) {
-            return Ok(());
-        }
-
-        let new_content = format!("{}{}\n", existing, module_decl);
-
-        std::fs::write(&mod_path, new_content)
-            .map_err(|e| format!("Failed to update mod.rs: {}", e))?;
-
-        Ok(())
+        write_or_update_file(
+            &mod_path,
+            &format!("{}\n", module_decl),
+            &format!("pub mod {};", module_name),
+        )
     }
 }
 
@@ -369,7 +1276,10 @@ const DEFAULT_MIGRATION_TEMPLATE: &str = r##"//! Migration: {{ name }}
 //! {{ description }}
 
 use tideorm::prelude::*;
-
+{% if not requires_transaction %}
+/// Run outside a transaction: CREATE INDEX CONCURRENTLY cannot run inside one
+pub const REQUIRES_TRANSACTION: bool = false;
+{% endif %}
 /// Migration: {{ name }}
 pub struct {{ struct_name }};
 
@@ -387,14 +1297,16 @@ impl Migration for {{ struct_name }} {
 {% if up_mode == "raw_sql" %}        schema.raw(r#"
 {{ up_raw_sql }}
         "#).await?;
-{% else %}{% for statement in up_statements %}{{ statement }}
+{% for statement in up_index_statements %}{{ statement }}
+{% endfor %}{% else %}{% for statement in up_statements %}{{ statement }}
 {% endfor %}{% endif %}
 
         Ok(())
     }
 
     async fn down(&self, schema: &mut Schema) -> tideorm::Result<()> {
-{% if down_mode == "raw_sql" %}        schema.raw(r#"{{ down_raw_sql }}"#).await?;
+{% if down_mode == "raw_sql" %}{% for statement in down_index_statements %}{{ statement }}
+{% endfor %}        schema.raw(r#"{{ down_raw_sql }}"#).await?;
 {% else %}{% for statement in down_statements %}{{ statement }}
 {% endfor %}{% endif %}
 
@@ -415,6 +1327,9 @@ struct MigrationTemplateContext {
     down_raw_sql: Option<String>,
     up_statements: Vec<String>,
     down_statements: Vec<String>,
+    up_index_statements: Vec<String>,
+    down_index_statements: Vec<String>,
+    requires_transaction: bool,
 }
 
 /// Convert string to PascalCase
@@ -422,6 +1337,374 @@ fn to_pascal_case(s: &str) -> String {
     heck::AsPascalCase(s).to_string()
 }
 
+/// Wrap SQL in a `schema.raw` call for the migration body
+fn raw_statement(sql: &str) -> String {
+    format!("        schema.raw(r#\"{}\"#).await?;", sql)
+}
+
+/// Wrap multi-line SQL in a `schema.raw` call, using as many `#`s as the SQL needs
+fn raw_sql_block(sql: &str) -> String {
+    let mut hashes = "#".to_string();
+    while sql.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+
+    format!("        schema.raw(r{0}\"\n{1}\n        \"{0}).await?;", hashes, sql)
+}
+
+/// Read a SQL file for a migration, dropping the BOM and normalizing line endings
+pub fn read_sql_file(path: &str) -> Result<String, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read SQL file {}: {}", path, e))?;
+    let sql = content
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+    let sql = sql.trim();
+
+    if sql.is_empty() {
+        return Err(format!("SQL file {} is empty", path));
+    }
+
+    Ok(sql.to_string())
+}
+
+/// Number of statements in a SQL script
+///
+/// Semicolons inside quotes, comments and Postgres dollar-quoted bodies don't count.
+pub fn count_sql_statements(sql: &str) -> usize {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut statements = 0;
+    let mut has_content = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match ch {
+            '-' if next == Some('-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+                continue;
+            }
+            '\'' | '"' | '`' => {
+                has_content = true;
+                i += 1;
+                while i < chars.len() && chars[i] != ch {
+                    i += 1;
+                }
+            }
+            '$' => {
+                has_content = true;
+                let tag_end = chars[i + 1..]
+                    .iter()
+                    .position(|c| !(c.is_alphanumeric() || *c == '_'))
+                    .map(|offset| i + 1 + offset);
+                if let Some(end) = tag_end.filter(|end| chars[*end] == '$') {
+                    let tag: String = chars[i..=end].iter().collect();
+                    let rest: String = chars[end + 1..].iter().collect();
+                    match rest.find(&tag) {
+                        Some(close) => i = end + 1 + rest[..close].chars().count() + tag.chars().count() - 1,
+                        None => i = chars.len(),
+                    }
+                }
+            }
+            ';' => {
+                if has_content {
+                    statements += 1;
+                }
+                has_content = false;
+            }
+            ch if !ch.is_whitespace() => has_content = true,
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    statements + usize::from(has_content)
+}
+
+/// Statement renaming a table
+fn rename_table_sql(driver: &str, from: &str, to: &str) -> String {
+    if driver == "mysql" {
+        format!("RENAME TABLE {} TO {}", from, to)
+    } else {
+        format!("ALTER TABLE {} RENAME TO {}", from, to)
+    }
+}
+
+/// Column definition used by ADD COLUMN statements
+/// Column definition for `ADD COLUMN` on a table that may already have rows
+///
+/// Existing rows need a value for a NOT NULL column, so one without a default gets
+/// `fill_default` of its type, or is added nullable when its type has none.
+fn added_column_definition(column: &ColumnSchema) -> String {
+    if column.nullable || column.default.is_some() {
+        return column_definition(column);
+    }
+
+    match fill_default(&column.data_type) {
+        Some(default) => format!("{} DEFAULT {}", column_definition(column), default),
+        None => column_definition(&ColumnSchema {
+            nullable: true,
+            ..column.clone()
+        }),
+    }
+}
+
+/// Value filling existing rows of a new NOT NULL column: 0, false, or an empty string
+fn fill_default(data_type: &str) -> Option<&'static str> {
+    let data_type = data_type.trim().to_lowercase();
+    let numeric = [
+        "tinyint", "smallint", "mediumint", "integer", "int", "bigint", "serial", "bigserial", "smallserial",
+        "decimal", "numeric", "real", "double", "float",
+    ];
+
+    if data_type.starts_with("bool") {
+        Some("FALSE")
+    } else if !data_type.starts_with("interval") && numeric.iter().any(|name| data_type.starts_with(name)) {
+        Some("0")
+    } else if ["char", "varchar", "character", "text", "clob", "tinytext", "mediumtext", "longtext"]
+        .iter()
+        .any(|name| data_type.starts_with(name))
+    {
+        Some("''")
+    } else {
+        None
+    }
+}
+
+fn column_definition(column: &ColumnSchema) -> String {
+    let mut definition = format!("{} {}", column.name, column.data_type);
+
+    if !column.nullable {
+        definition.push_str(" NOT NULL");
+    }
+
+    if let Some(default) = &column.default {
+        definition.push_str(&format!(" DEFAULT {}", default));
+    }
+
+    definition
+}
+
+/// `CREATE TABLE` statement rebuilding a table from its live columns
+fn recreate_table_sql(
+    driver: &str,
+    table: &str,
+    columns: &[ColumnSchema],
+    foreign_keys: &[ForeignKeyReference],
+) -> String {
+    let mut lines: Vec<String> = columns
+        .iter()
+        .map(|column| recreated_column_definition(driver, column))
+        .collect();
+
+    let primary_key: Vec<&str> = columns
+        .iter()
+        .filter(|column| column.key.as_deref() == Some("PRI"))
+        .map(|column| column.name.as_str())
+        .collect();
+    if !primary_key.is_empty() {
+        lines.push(format!("PRIMARY KEY ({})", primary_key.join(", ")));
+    }
+
+    for foreign_key in foreign_keys {
+        lines.push(format!(
+            "FOREIGN KEY ({}) REFERENCES {} ({})",
+            foreign_key.column, foreign_key.references_table, foreign_key.references_column
+        ));
+    }
+
+    format!(
+        "CREATE TABLE {} (\n{}\n        )",
+        table,
+        lines
+            .iter()
+            .map(|line| format!("            {}", line))
+            .collect::<Vec<_>>()
+            .join(",\n")
+    )
+}
+
+/// Column definition for a recreated table, restoring auto-increment columns
+fn recreated_column_definition(driver: &str, column: &ColumnSchema) -> String {
+    let sequence_default = column
+        .default
+        .as_deref()
+        .is_some_and(|default| default.starts_with("nextval("));
+
+    match driver {
+        // The column's sequence is dropped with the table, so use a serial type instead
+        "postgres" if sequence_default => {
+            let serial = match column.data_type.as_str() {
+                "bigint" => "BIGSERIAL",
+                "smallint" => "SMALLSERIAL",
+                _ => "SERIAL",
+            };
+            format!("{} {} NOT NULL", column.name, serial)
+        }
+        "mysql"
+            if column
+                .extra
+                .as_deref()
+                .is_some_and(|extra| extra.to_lowercase().contains("auto_increment")) =>
+        {
+            format!("{} AUTO_INCREMENT", column_definition(column))
+        }
+        _ => column_definition(column),
+    }
+}
+
+/// `ALTER TABLE` statement changing a column's type on Postgres or MySQL
+///
+/// MySQL's `MODIFY COLUMN` replaces the whole definition, so a live `NOT NULL` is kept.
+fn modify_column_sql(driver: &str, table: &str, column: &str, to: &str, live: Option<&ColumnSchema>) -> String {
+    if driver == "mysql" {
+        let not_null = if live.is_some_and(|live| !live.nullable) {
+            " NOT NULL"
+        } else {
+            ""
+        };
+        format!("ALTER TABLE {} MODIFY COLUMN {} {}{}", table, column, to, not_null)
+    } else {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{}",
+            table, column, to, column, to
+        )
+    }
+}
+
+/// Statements rebuilding a SQLite table with the given `(column, type)` replacements
+fn sqlite_rebuild_statements(change: &ColumnTypeChange, types: &[(&str, &str)]) -> Vec<String> {
+    let table = &change.table;
+    let rebuilt = format!("{}__rebuild", table);
+    let columns: Vec<ColumnSchema> = change
+        .columns
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            if let Some((_, data_type)) = types.iter().find(|(name, _)| *name == column.name) {
+                column.data_type = data_type.to_string();
+            }
+            column
+        })
+        .collect();
+    let names = change
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut statements = vec![
+        format!(
+            "        schema.raw(r#\"\n        {}\n        \"#).await?;",
+            recreate_table_sql("sqlite", &rebuilt, &columns, &change.foreign_keys)
+        ),
+        raw_statement(&format!(
+            "INSERT INTO {} ({}) SELECT {} FROM {}",
+            rebuilt, names, names, table
+        )),
+        raw_statement(&format!("DROP TABLE {}", table)),
+        raw_statement(&rename_table_sql("sqlite", &rebuilt, table)),
+    ];
+
+    // Indexes are dropped with the old table
+    for index in &change.indexes {
+        statements.push(raw_statement(&format!(
+            "CREATE {}INDEX {} ON {} ({})",
+            if index.unique { "UNIQUE " } else { "" },
+            index.name,
+            table,
+            index.columns.join(", ")
+        )));
+    }
+
+    statements
+}
+
+/// Whether changing a column from `from` to `to` can cut off existing values
+///
+/// Compares types of the same family by size, e.g. `text` to `varchar(10)` or `bigint` to
+/// `int`, and counts fractional types turning into integers. Unknown types never narrow.
+fn narrows_type(from: &str, to: &str) -> bool {
+    match (type_capacity(from), type_capacity(to)) {
+        (Some((from_family, from_size, from_scale)), Some((to_family, to_size, to_scale))) => {
+            if from_family == to_family {
+                to_size < from_size || to_scale < from_scale
+            } else {
+                to_family == "integer" && matches!(from_family, "float" | "decimal")
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Family, size and scale of a SQL type; unbounded types get the largest size
+fn type_capacity(sql_type: &str) -> Option<(&'static str, u64, u64)> {
+    let sql_type = sql_type.trim().to_lowercase();
+    let sql_type = sql_type.trim_end_matches(" unsigned");
+    let (base, arguments) = match sql_type.split_once('(') {
+        Some((base, arguments)) => (
+            base.trim(),
+            arguments
+                .trim_end_matches(')')
+                .split(',')
+                .map(|argument| argument.trim().parse::<u64>().ok())
+                .collect::<Vec<_>>(),
+        ),
+        None => (sql_type, Vec::new()),
+    };
+    let size = arguments.first().copied().flatten();
+    let scale = arguments.get(1).copied().flatten().unwrap_or(0);
+
+    Some(match base {
+        "char" | "character" | "nchar" | "varchar" | "character varying" | "nvarchar" => {
+            ("text", size.unwrap_or(u64::MAX), 0)
+        }
+        "tinytext" => ("text", 255, 0),
+        "text" => ("text", 65_535, 0),
+        "mediumtext" => ("text", 16_777_215, 0),
+        "longtext" | "clob" => ("text", u64::MAX, 0),
+        "tinyint" | "int1" => ("integer", 1, 0),
+        "smallint" | "int2" | "smallserial" => ("integer", 2, 0),
+        "mediumint" => ("integer", 3, 0),
+        "int" | "integer" | "int4" | "serial" => ("integer", 4, 0),
+        "bigint" | "int8" | "bigserial" => ("integer", 8, 0),
+        "real" | "float4" => ("float", 4, 0),
+        "float" if size.is_some_and(|bits| bits <= 24) => ("float", 4, 0),
+        "float" | "double" | "double precision" | "float8" => ("float", 8, 0),
+        "decimal" | "numeric" => ("decimal", size.unwrap_or(u64::MAX), scale),
+        _ => return None,
+    })
+}
+
+/// Name of the index created for a single column
+fn index_name(table: &str, field_name: &str) -> String {
+    format!("{}_{}_idx", table, field_name)
+}
+
+fn full_text_index_name(table: &str, field_name: &str) -> String {
+    format!("{}_{}_ftidx", table, field_name)
+}
+
+/// Named column-level `CHECK` constraint
+fn check_constraint_sql(table: &str, field_name: &str, check: &str) -> String {
+    format!(" CONSTRAINT chk_{}_{} CHECK ({})", table, field_name, check)
+}
+
 fn migration_module_name(file_stem: &str) -> String {
     if file_stem
         .chars()
@@ -437,6 +1720,8 @@ fn migration_module_name(file_stem: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schema_types::TableColumn;
+    use std::path::Path;
     use tempfile::tempdir;
 
     #[test]
@@ -489,6 +1774,235 @@ mod tests {
         assert_eq!(migration_module_name("create_posts_table"), "create_posts_table");
     }
 
+    #[test]
+    fn test_generate_keeps_full_struct_name_with_and_without_timestamps() {
+        for timestamps in [true, false] {
+            let dir = tempdir().unwrap();
+            let mut config = TideConfig::default();
+            config.paths.migrations = dir.path().to_string_lossy().into_owned();
+            config.migration.timestamps = timestamps;
+
+            let generator = MigrationGenerator::new(&config);
+            let file_path = generator
+                .generate("create_users_table", Some("users".to_string()), None, None, false, false)
+                .unwrap();
+
+            let content = std::fs::read_to_string(&file_path).unwrap();
+            let mod_content = std::fs::read_to_string(dir.path().join("mod.rs")).unwrap();
+            assert!(content.contains("pub struct CreateUsersTable;"));
+
+            if timestamps {
+                let file_name = Path::new(&file_path).file_name().unwrap().to_str().unwrap();
+                assert!(mod_content.contains(&format!("#[path = \"{}\"]", file_name)));
+                assert!(mod_content.contains(&format!(
+                    "pub mod m_{};",
+                    file_name.trim_end_matches(".rs")
+                )));
+            } else {
+                assert!(file_path.ends_with("create_users_table.rs"));
+                assert_eq!(mod_content, "pub mod create_users_table;\n");
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_table_emits_separate_index_statements() {
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+
+        let generator = MigrationGenerator::new(&config);
+        let fields = MigrationGenerator::parse_fields(Some(
+            "email:string:unique,slug:string:indexed,team_id:bigint:index,bio:text",
+        ))
+        .unwrap();
+        let content = generator
+            .generate_create_table("create_users_table", "20260316_001", "users", &fields, false, false)
+            .unwrap();
+
+        assert_eq!(content.matches("CREATE INDEX IF NOT EXISTS").count(), 2);
+        assert_eq!(content.matches("CREATE UNIQUE INDEX IF NOT EXISTS").count(), 1);
+        assert_eq!(content.matches("DROP INDEX IF EXISTS").count(), 3);
+        assert!(content.contains("CREATE UNIQUE INDEX IF NOT EXISTS users_email_idx ON users (email)"));
+        assert!(content.contains("CREATE INDEX IF NOT EXISTS users_slug_idx ON users (slug)"));
+        assert!(content.contains("CREATE INDEX IF NOT EXISTS users_team_id_idx ON users (team_id)"));
+        assert!(!content.contains(" UNIQUE,"));
+        assert!(!content.contains("users_bio_idx"));
+
+        let down = &content[content.find("async fn down").unwrap()..];
+        assert!(down.find("DROP INDEX IF EXISTS users_email_idx").unwrap() < down.find("DROP TABLE").unwrap());
+    }
+
+    #[test]
+    fn test_check_constraints_are_named_per_column() {
+        let config = TideConfig::default();
+        let generator = MigrationGenerator::new(&config);
+        let mut fields = MigrationGenerator::parse_fields(Some("age:i32,score:i32:nullable,name:string")).unwrap();
+        apply_check_constraints(&mut fields, "age:age > 0,score:score BETWEEN 0 AND 100").unwrap();
+
+        let content = generator
+            .generate_create_table("create_players_table", "20260316_001", "players", &fields, false, false)
+            .unwrap();
+        assert!(content.contains("age INTEGER NOT NULL CONSTRAINT chk_players_age CHECK (age > 0),"));
+        assert!(content.contains("score INTEGER CONSTRAINT chk_players_score CHECK (score BETWEEN 0 AND 100),"));
+        assert!(!content.contains("chk_players_name"));
+
+        let content = generator
+            .generate_alter_table("add_age_to_players", "20260316_002", "players", &fields[..1])
+            .unwrap();
+        assert!(content.contains(
+            "ALTER TABLE players ADD COLUMN age INTEGER NOT NULL CONSTRAINT chk_players_age CHECK (age > 0)"
+        ));
+    }
+
+    #[test]
+    fn test_mysql_index_statements_name_the_table_on_drop() {
+        let mut config = TideConfig::default();
+        config.database.driver = "mysql".to_string();
+
+        let generator = MigrationGenerator::new(&config);
+        let fields = MigrationGenerator::parse_fields(Some("email:string:unique,slug:string:indexed")).unwrap();
+        let content = generator
+            .generate_create_table("create_users_table", "20260316_001", "users", &fields, false, false)
+            .unwrap();
+
+        assert!(content.contains("CREATE UNIQUE INDEX users_email_idx ON users (email)"));
+        assert!(content.contains("CREATE INDEX users_slug_idx ON users (slug)"));
+        assert!(content.contains("DROP INDEX users_email_idx ON users"));
+        assert!(content.contains("DROP INDEX users_slug_idx ON users"));
+        assert!(!content.contains("IF NOT EXISTS users_"));
+    }
+
+    /// SQL of the `schema.raw` statements of a migration's `up` function
+    fn up_sql(content: &str) -> Vec<String> {
+        let up = &content[..content.find("async fn down").unwrap()];
+        regex::Regex::new(r##"(?s)schema\.raw\(r#"(.*?)"#\)"##)
+            .unwrap()
+            .captures_iter(up)
+            .map(|captures| captures[1].to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_sync_migration_adds_not_null_columns_to_tables_with_rows() {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.database.driver = "sqlite".to_string();
+        config.database.sqlite_path = Some(dir.path().join("app.sqlite3").to_string_lossy().into_owned());
+        crate::runtime_db::execute(
+            &config,
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL); INSERT INTO users (name) VALUES ('Alice')",
+        )
+        .await
+        .unwrap();
+
+        let added = |name: &str, data_type: &str| TableColumn {
+            table: "users".to_string(),
+            column: ColumnSchema {
+                name: name.to_string(),
+                data_type: data_type.to_string(),
+                nullable: false,
+                key: None,
+                default: None,
+                extra: None,
+            },
+        };
+        let diff = SchemaDiff {
+            added_columns: vec![
+                added("age", "INTEGER"),
+                added("nickname", "VARCHAR(255)"),
+                added("active", "BOOLEAN"),
+                added("born_at", "DATETIME"),
+            ],
+            ..SchemaDiff::default()
+        };
+        let content = MigrationGenerator::new(&config)
+            .generate_sync_content("sync_schema", "20260316_001", &diff)
+            .unwrap();
+
+        let statements = up_sql(&content);
+        assert_eq!(
+            statements,
+            [
+                "ALTER TABLE users ADD COLUMN age INTEGER NOT NULL DEFAULT 0",
+                "ALTER TABLE users ADD COLUMN nickname VARCHAR(255) NOT NULL DEFAULT ''",
+                "ALTER TABLE users ADD COLUMN active BOOLEAN NOT NULL DEFAULT FALSE",
+                "ALTER TABLE users ADD COLUMN born_at DATETIME",
+            ]
+        );
+        for sql in &statements {
+            crate::runtime_db::execute(&config, sql).await.unwrap();
+        }
+
+        let rows = crate::runtime_db::query_json(&config, "SELECT name, age, nickname, active, born_at FROM users")
+            .await
+            .unwrap();
+        assert_eq!(
+            rows,
+            [serde_json::json!({"name": "Alice", "age": 0, "nickname": "", "active": 0, "born_at": null})]
+        );
+    }
+
+    #[test]
+    fn test_array_columns_note_their_json_storage() {
+        let fields = MigrationGenerator::parse_fields(Some("tags:string[]")).unwrap();
+        let generate = |driver: &str| {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+            let generator = MigrationGenerator::new(&config);
+            (
+                generator
+                    .generate_create_table("create_posts_table", "20260316_001", "posts", &fields, false, false)
+                    .unwrap(),
+                generator
+                    .generate_alter_table("add_tags_to_posts", "20260316_002", "posts", &fields)
+                    .unwrap(),
+            )
+        };
+
+        let (create, alter) = generate("postgres");
+        assert!(create.contains("            tags VARCHAR(255)[] NOT NULL"));
+        assert!(alter.contains("schema.raw(r#\"ALTER TABLE posts ADD COLUMN tags VARCHAR(255)[] NOT NULL\"#)"));
+        assert!(!create.contains("JSON array"));
+
+        let (create, alter) = generate("mysql");
+        assert!(create.contains("            -- Stored as JSON array\n            tags JSON NOT NULL"));
+        assert!(alter.contains(
+            "schema.raw(r#\"-- Stored as JSON array\n        ALTER TABLE posts ADD COLUMN tags JSON NOT NULL\"#)"
+        ));
+
+        let (create, _) = generate("sqlite");
+        assert!(create.contains("            -- Stored as JSON array\n            tags TEXT NOT NULL"));
+    }
+
+    #[test]
+    fn test_partial_index_sql_for_each_driver() {
+        let fields = MigrationGenerator::parse_fields(Some("status:string:indexed:where=status='active',total:i64")).unwrap();
+        let create = |driver: &str| {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+            MigrationGenerator::new(&config)
+                .generate_create_table("create_orders_table", "20260316_001", "orders", &fields, false, false)
+                .unwrap()
+        };
+
+        let content = create("postgres");
+        assert!(content.contains(
+            "CREATE INDEX CONCURRENTLY IF NOT EXISTS orders_status_idx ON orders (status) WHERE status='active'"
+        ));
+        assert!(content.contains("DROP INDEX CONCURRENTLY IF EXISTS orders_status_idx"));
+        assert!(content.contains("pub const REQUIRES_TRANSACTION: bool = false;"));
+
+        let content = create("sqlite");
+        assert!(content.contains("CREATE INDEX IF NOT EXISTS orders_status_idx ON orders (status) WHERE status='active'"));
+        assert!(!content.contains("REQUIRES_TRANSACTION"));
+
+        let content = create("mysql");
+        assert!(content.contains(
+            "-- Partial index not supported in mysql; indexing every row instead of WHERE status='active'\n        CREATE INDEX orders_status_idx ON orders (status)\"#"
+        ));
+        assert!(!content.contains("REQUIRES_TRANSACTION"));
+    }
+
     #[test]
     fn test_migration_template_override_is_used() {
         let dir = tempdir().unwrap();
@@ -506,4 +2020,543 @@ mod tests {
 
         assert_eq!(content, "// custom migration create_users_table Creates the users table.");
     }
+
+    #[test]
+    fn test_name_parser_detects_rename_table_intent() {
+        assert_eq!(
+            MigrationNameParser::parse("rename_users_table_to_accounts"),
+            Some(MigrationIntent::RenameTable {
+                from: "users".to_string(),
+                to: "accounts".to_string(),
+            })
+        );
+        assert_eq!(
+            MigrationNameParser::parse("RenameBlogPostsTableToArticlesTable"),
+            Some(MigrationIntent::RenameTable {
+                from: "blog_posts".to_string(),
+                to: "articles".to_string(),
+            })
+        );
+        assert_eq!(MigrationNameParser::parse("create_users_table"), None);
+        assert_eq!(MigrationNameParser::parse("rename_users"), None);
+        assert_eq!(MigrationNameParser::parse("rename__table_to_accounts"), None);
+    }
+
+    #[test]
+    fn test_name_parser_leaves_column_style_renames_alone() {
+        assert_eq!(MigrationNameParser::parse("rename_users_to_accounts"), None);
+        assert_eq!(MigrationNameParser::parse("rename_email_to_email_address"), None);
+        assert_eq!(MigrationNameParser::parse("RenameEmailToEmailAddress"), None);
+    }
+
+    #[test]
+    fn test_table_rename_argument_needs_both_names() {
+        assert_eq!(
+            TableRename::parse("users:accounts").unwrap(),
+            TableRename {
+                from: "users".to_string(),
+                to: "accounts".to_string(),
+            }
+        );
+        assert!(TableRename::parse("users").is_err());
+        assert!(TableRename::parse("users:").is_err());
+    }
+
+    #[test]
+    fn test_rename_table_statements_per_driver() {
+        let rename = TableRename::parse("users:accounts").unwrap();
+
+        for (driver, up, down) in [
+            ("postgres", "ALTER TABLE users RENAME TO accounts", "ALTER TABLE accounts RENAME TO users"),
+            ("sqlite", "ALTER TABLE users RENAME TO accounts", "ALTER TABLE accounts RENAME TO users"),
+            ("mysql", "RENAME TABLE users TO accounts", "RENAME TABLE accounts TO users"),
+        ] {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+
+            let content = MigrationGenerator::new(&config)
+                .generate_rename_table("rename_users_to_accounts", "20260316_001", &rename, &[])
+                .unwrap();
+            let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+
+            assert!(up_body.contains(&format!("schema.raw(r#\"{}\"#).await?;", up)), "{}", driver);
+            assert!(down_body.contains(&format!("schema.raw(r#\"{}\"#).await?;", down)), "{}", driver);
+            assert!(content.contains("//! Renames the users table to accounts."));
+        }
+    }
+
+    #[test]
+    fn test_rename_table_renames_foreign_key_columns_and_reverses_them_first() {
+        let config = TideConfig::default();
+        let rename = TableRename::parse("users:accounts").unwrap();
+        let foreign_keys = [ForeignKeyRename {
+            table: "posts".to_string(),
+            from: "user_id".to_string(),
+            to: "account_id".to_string(),
+        }];
+
+        let content = MigrationGenerator::new(&config)
+            .generate_rename_table("rename_users_to_accounts", "20260316_001", &rename, &foreign_keys)
+            .unwrap();
+        let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+
+        assert!(up_body.contains("ALTER TABLE posts RENAME COLUMN user_id TO account_id"));
+        assert!(
+            down_body.find("ALTER TABLE posts RENAME COLUMN account_id TO user_id").unwrap()
+                < down_body.find("ALTER TABLE accounts RENAME TO users").unwrap()
+        );
+    }
+
+    fn index_change(column: &str, unique: bool, remove: bool) -> IndexChange {
+        IndexChange {
+            column: column.to_string(),
+            unique,
+            remove,
+            full_text: None,
+        }
+    }
+
+    #[test]
+    fn test_index_migration_sql_for_each_driver() {
+        let changes = [
+            index_change("email", false, false),
+            index_change("slug", true, false),
+            index_change("legacy_code", false, true),
+        ];
+
+        for (driver, up, down) in [
+            (
+                "postgres",
+                [
+                    "CREATE INDEX CONCURRENTLY IF NOT EXISTS users_email_idx ON users (email)",
+                    "CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_slug_idx ON users (slug)",
+                    "DROP INDEX CONCURRENTLY IF EXISTS users_legacy_code_idx",
+                ],
+                [
+                    "CREATE INDEX CONCURRENTLY IF NOT EXISTS users_legacy_code_idx ON users (legacy_code)",
+                    "DROP INDEX CONCURRENTLY IF EXISTS users_slug_idx",
+                    "DROP INDEX CONCURRENTLY IF EXISTS users_email_idx",
+                ],
+            ),
+            (
+                "sqlite",
+                [
+                    "CREATE INDEX IF NOT EXISTS users_email_idx ON users (email)",
+                    "CREATE UNIQUE INDEX IF NOT EXISTS users_slug_idx ON users (slug)",
+                    "DROP INDEX IF EXISTS users_legacy_code_idx",
+                ],
+                [
+                    "CREATE INDEX IF NOT EXISTS users_legacy_code_idx ON users (legacy_code)",
+                    "DROP INDEX IF EXISTS users_slug_idx",
+                    "DROP INDEX IF EXISTS users_email_idx",
+                ],
+            ),
+            (
+                "mysql",
+                [
+                    "CREATE INDEX users_email_idx ON users (email)",
+                    "CREATE UNIQUE INDEX users_slug_idx ON users (slug)",
+                    "DROP INDEX users_legacy_code_idx ON users",
+                ],
+                [
+                    "CREATE INDEX users_legacy_code_idx ON users (legacy_code)",
+                    "DROP INDEX users_slug_idx ON users",
+                    "DROP INDEX users_email_idx ON users",
+                ],
+            ),
+        ] {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+
+            let content = MigrationGenerator::new(&config)
+                .generate_index_changes("index_users", "20260316_001", "users", &changes, true)
+                .unwrap();
+            let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+
+            let expected_up = up.map(raw_statement).join("\n");
+            let expected_down = down.map(raw_statement).join("\n");
+            assert!(up_body.contains(&expected_up), "{}: {}", driver, up_body);
+            assert!(down_body.contains(&expected_down), "{}: {}", driver, down_body);
+            assert_eq!(
+                content.contains("pub const REQUIRES_TRANSACTION: bool = false;"),
+                driver == "postgres",
+                "{}",
+                driver
+            );
+        }
+    }
+
+    #[test]
+    fn test_full_text_index_sql_for_each_driver() {
+        let changes = IndexChange::full_text("title, body:german", "english").unwrap();
+
+        for (driver, up, down) in [
+            (
+                "postgres",
+                vec![
+                    "CREATE INDEX CONCURRENTLY IF NOT EXISTS posts_title_ftidx ON posts USING gin(to_tsvector('english', title))",
+                    "CREATE INDEX CONCURRENTLY IF NOT EXISTS posts_body_ftidx ON posts USING gin(to_tsvector('german', body))",
+                ],
+                vec![
+                    "DROP INDEX CONCURRENTLY IF EXISTS posts_body_ftidx",
+                    "DROP INDEX CONCURRENTLY IF EXISTS posts_title_ftidx",
+                ],
+            ),
+            (
+                "mysql",
+                vec![
+                    "ALTER TABLE posts ADD FULLTEXT INDEX posts_title_ftidx (title)",
+                    "ALTER TABLE posts ADD FULLTEXT INDEX posts_body_ftidx (body)",
+                ],
+                vec![
+                    "ALTER TABLE posts DROP INDEX posts_body_ftidx",
+                    "ALTER TABLE posts DROP INDEX posts_title_ftidx",
+                ],
+            ),
+            (
+                "sqlite",
+                vec!["CREATE VIRTUAL TABLE IF NOT EXISTS posts_fts USING fts5(title, body)"],
+                vec!["DROP TABLE IF EXISTS posts_fts"],
+            ),
+        ] {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+
+            let content = MigrationGenerator::new(&config)
+                .generate_index_changes("search_posts", "20260316_001", "posts", &changes, true)
+                .unwrap();
+            let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+
+            let expected_up = up.into_iter().map(raw_statement).collect::<Vec<_>>().join("\n");
+            let expected_down = down.into_iter().map(raw_statement).collect::<Vec<_>>().join("\n");
+            assert!(up_body.contains(&expected_up), "{}: {}", driver, up_body);
+            assert!(down_body.contains(&expected_down), "{}: {}", driver, down_body);
+        }
+    }
+
+    #[test]
+    fn test_full_text_specs_are_validated() {
+        let changes = IndexChange::full_text("title,body:Spanish", "simple").unwrap();
+        assert_eq!(changes[0].full_text.as_deref(), Some("simple"));
+        assert_eq!(changes[1].column, "body");
+        assert_eq!(changes[1].full_text.as_deref(), Some("spanish"));
+
+        assert!(IndexChange::full_text(":english", "english").is_err());
+        assert!(IndexChange::full_text("title:", "english").is_err());
+        assert!(IndexChange::full_text("title", "english'); DROP TABLE posts; --").is_err());
+    }
+
+    #[test]
+    fn test_index_migration_without_concurrency_runs_in_transaction() {
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+
+        let content = MigrationGenerator::new(&config)
+            .generate_index_changes(
+                "index_users_email",
+                "20260316_001",
+                "users",
+                &[index_change("email", false, false)],
+                false,
+            )
+            .unwrap();
+
+        assert!(content.contains("CREATE INDEX IF NOT EXISTS users_email_idx ON users (email)"));
+        assert!(content.contains("DROP INDEX IF EXISTS users_email_idx"));
+        assert!(!content.contains("CONCURRENTLY"));
+        assert!(!content.contains("REQUIRES_TRANSACTION"));
+    }
+
+    fn live_column(name: &str, data_type: &str, key: Option<&str>, default: Option<&str>) -> ColumnSchema {
+        ColumnSchema {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable: key.is_none(),
+            key: key.map(str::to_string),
+            default: default.map(str::to_string),
+            extra: None,
+        }
+    }
+
+    fn users_drop() -> TableDrop {
+        TableDrop {
+            table: "users".to_string(),
+            columns: vec![
+                live_column("id", "bigint", Some("PRI"), Some("nextval('users_id_seq'::regclass)")),
+                live_column("email", "character varying", None, None),
+                live_column("team_id", "bigint", Some("FK"), None),
+            ],
+            foreign_keys: vec![ForeignKeyReference {
+                table: "users".to_string(),
+                column: "team_id".to_string(),
+                references_table: "teams".to_string(),
+                references_column: "id".to_string(),
+                constraint: Some("users_team_id_fkey".to_string()),
+            }],
+            dependents: vec![ForeignKeyReference {
+                table: "posts".to_string(),
+                column: "user_id".to_string(),
+                references_table: "users".to_string(),
+                references_column: "id".to_string(),
+                constraint: Some("posts_user_id_fkey".to_string()),
+            }],
+            irreversible: false,
+        }
+    }
+
+    fn drop_table_content(driver: &str, drop: &TableDrop) -> (String, String) {
+        let mut config = TideConfig::default();
+        config.database.driver = driver.to_string();
+
+        let content = MigrationGenerator::new(&config)
+            .generate_drop_table_content("drop_users_table", "20260316_001", drop)
+            .unwrap();
+        let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+        (up_body.to_string(), down_body.to_string())
+    }
+
+    fn modify_column_content(driver: &str, change: &ColumnTypeChange) -> (String, String) {
+        let mut config = TideConfig::default();
+        config.database.driver = driver.to_string();
+
+        let content = MigrationGenerator::new(&config)
+            .generate_modify_column_content("change_users_email", "20260316_001", change)
+            .unwrap();
+        let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+        (up_body.to_string(), down_body.to_string())
+    }
+
+    fn email_change(from: Option<&str>) -> ColumnTypeChange {
+        ColumnTypeChange {
+            table: "users".to_string(),
+            modifications: vec![ColumnModification {
+                column: "email".to_string(),
+                to: "text".to_string(),
+                from: from.map(str::to_string),
+            }],
+            columns: vec![
+                live_column("id", "INTEGER", Some("PRI"), None),
+                live_column("email", "varchar(255)", Some("UNI"), None),
+            ],
+            ..ColumnTypeChange::default()
+        }
+    }
+
+    #[test]
+    fn test_modify_column_parses_types_with_commas() {
+        let modifications = ColumnModification::parse_list("price:decimal(10,2), email:text").unwrap();
+        assert_eq!(
+            modifications
+                .iter()
+                .map(|modification| (modification.column.as_str(), modification.to.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("price", "decimal(10,2)"), ("email", "text")]
+        );
+        assert!(ColumnModification::parse_list("email").is_err());
+        assert!(ColumnModification::parse_list("").is_err());
+    }
+
+    #[test]
+    fn test_modify_column_alters_in_place_on_postgres_and_mysql() {
+        let change = email_change(Some("varchar(255)"));
+
+        let (up_body, down_body) = modify_column_content("postgres", &change);
+        assert!(up_body.contains(&raw_statement("ALTER TABLE users ALTER COLUMN email TYPE text USING email::text")));
+        assert!(down_body.contains(&raw_statement(
+            "ALTER TABLE users ALTER COLUMN email TYPE varchar(255) USING email::varchar(255)"
+        )));
+
+        // The live column is NOT NULL, which MODIFY COLUMN would otherwise drop
+        let (up_body, down_body) = modify_column_content("mysql", &change);
+        assert!(up_body.contains(&raw_statement("ALTER TABLE users MODIFY COLUMN email text NOT NULL")));
+        assert!(down_body.contains(&raw_statement("ALTER TABLE users MODIFY COLUMN email varchar(255) NOT NULL")));
+    }
+
+    #[test]
+    fn test_modify_column_rebuilds_the_table_on_sqlite() {
+        let mut change = email_change(Some("varchar(255)"));
+        change.indexes = vec![TableIndex {
+            name: "users_email_key".to_string(),
+            columns: vec!["email".to_string()],
+            unique: true,
+        }];
+
+        let (up_body, down_body) = modify_column_content("sqlite", &change);
+        for (body, email_type) in [(&up_body, "text"), (&down_body, "varchar(255)")] {
+            assert!(body.contains(&format!(
+                "CREATE TABLE users__rebuild (\n            id INTEGER NOT NULL,\n            email {} NOT NULL,\n            PRIMARY KEY (id)\n        )",
+                email_type
+            )), "{}", body);
+            assert!(body.contains(&[
+                raw_statement("INSERT INTO users__rebuild (id, email) SELECT id, email FROM users"),
+                raw_statement("DROP TABLE users"),
+                raw_statement("ALTER TABLE users__rebuild RENAME TO users"),
+                raw_statement("CREATE UNIQUE INDEX users_email_key ON users (email)"),
+            ].join("\n")), "{}", body);
+        }
+    }
+
+    #[test]
+    fn test_modify_column_without_original_type_leaves_down_as_todo() {
+        let change = email_change(None);
+
+        for driver in ["postgres", "mysql", "sqlite"] {
+            let (up_body, down_body) = modify_column_content(driver, &change);
+            assert!(up_body.contains("schema.raw"), "{}: {}", driver, up_body);
+            assert!(!down_body.contains("schema.raw"), "{}: {}", driver, down_body);
+            assert!(down_body.contains("// TODO: change users.email back to its original type"), "{}", driver);
+        }
+
+        // Without the live schema SQLite has nothing to rebuild the table from
+        let change = ColumnTypeChange {
+            columns: Vec::new(),
+            ..email_change(Some("varchar(255)"))
+        };
+        let (up_body, _) = modify_column_content("sqlite", &change);
+        assert!(up_body.contains("// TODO: SQLite cannot change column types in place"));
+        assert!(up_body.contains("// email -> text"));
+    }
+
+    #[test]
+    fn test_narrowing_type_changes_are_detected() {
+        for (from, to) in [
+            ("text", "varchar(10)"),
+            ("varchar(255)", "varchar(100)"),
+            ("character varying", "char(2)"),
+            ("bigint", "int"),
+            ("int unsigned", "smallint"),
+            ("double precision", "real"),
+            ("decimal(10,2)", "decimal(10,0)"),
+            ("numeric(12,2)", "integer"),
+        ] {
+            assert!(narrows_type(from, to), "{} -> {}", from, to);
+        }
+
+        for (from, to) in [
+            ("varchar(10)", "text"),
+            ("varchar(100)", "varchar(255)"),
+            ("int", "BIGINT"),
+            ("text", "jsonb"),
+            ("int", "varchar(5)"),
+            ("decimal(10,2)", "decimal(12,2)"),
+        ] {
+            assert!(!narrows_type(from, to), "{} -> {}", from, to);
+        }
+    }
+
+    #[test]
+    fn test_drop_table_recreates_live_schema_in_down() {
+        let (up_body, down_body) = drop_table_content("postgres", &users_drop());
+
+        assert!(up_body.contains(&raw_statement("DROP TABLE IF EXISTS users CASCADE")));
+        assert!(down_body.contains(
+            "        CREATE TABLE users (\n            id BIGSERIAL NOT NULL,\n            email character varying,\n            team_id bigint NOT NULL,\n            PRIMARY KEY (id),\n            FOREIGN KEY (team_id) REFERENCES teams (id)\n        )"
+        ));
+        assert!(
+            down_body.find("CREATE TABLE users").unwrap()
+                < down_body
+                    .find("ALTER TABLE posts ADD CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id)")
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_drop_table_handles_referencing_tables_per_driver() {
+        let (up_body, down_body) = drop_table_content("mysql", &users_drop());
+        assert!(
+            up_body.find("ALTER TABLE posts DROP FOREIGN KEY posts_user_id_fkey").unwrap()
+                < up_body.find("DROP TABLE IF EXISTS users").unwrap()
+        );
+        assert!(!up_body.contains("CASCADE"));
+        assert!(down_body.contains("ALTER TABLE posts ADD CONSTRAINT posts_user_id_fkey"));
+
+        let (up_body, down_body) = drop_table_content("sqlite", &users_drop());
+        assert!(up_body.contains(&raw_statement("DROP TABLE IF EXISTS users")));
+        assert!(!down_body.contains("ALTER TABLE posts"));
+
+        let standalone = TableDrop {
+            dependents: Vec::new(),
+            ..users_drop()
+        };
+        let (up_body, _) = drop_table_content("postgres", &standalone);
+        assert!(up_body.contains(&raw_statement("DROP TABLE IF EXISTS users")));
+        assert!(!up_body.contains("CASCADE"));
+    }
+
+    #[test]
+    fn test_drop_table_without_live_schema_or_down() {
+        let unknown = TableDrop {
+            table: "users".to_string(),
+            ..TableDrop::default()
+        };
+        let (_, down_body) = drop_table_content("postgres", &unknown);
+        assert!(down_body.contains("// TODO: add CREATE TABLE statement here to recreate users"));
+        assert!(!down_body.contains("schema.raw"));
+
+        let irreversible = TableDrop {
+            irreversible: true,
+            ..users_drop()
+        };
+        let (up_body, down_body) = drop_table_content("postgres", &irreversible);
+        assert!(up_body.contains("DROP TABLE IF EXISTS users CASCADE"));
+        assert!(down_body.contains("// Irreversible: users cannot be recreated"));
+        assert!(!down_body.contains("schema.raw"));
+    }
+
+    #[test]
+    fn test_create_table_emits_generated_columns() {
+        for driver in ["postgres", "mysql", "sqlite"] {
+            let mut config = TideConfig::default();
+            config.database.driver = driver.to_string();
+
+            let mut fields = MigrationGenerator::parse_fields(Some("first_name:string,last_name:string")).unwrap();
+            fields.extend(parse_generated_columns("full_name:string:CONCAT(first_name, ' ', last_name)").unwrap());
+            let content = MigrationGenerator::new(&config)
+                .generate_create_table("create_people_table", "20260316_001", "people", &fields, false, false)
+                .unwrap();
+
+            assert!(
+                content.contains(&format!(
+                    "            full_name {} GENERATED ALWAYS AS (CONCAT(first_name, ' ', last_name)) STORED",
+                    FieldDefinition::parse("full_name:string").unwrap().sql_type(driver)
+                )),
+                "{}",
+                driver
+            );
+            assert!(!content.contains("full_name VARCHAR(255) NOT NULL"), "{}", driver);
+        }
+    }
+
+    #[test]
+    fn test_from_sql_wraps_fixture_files_verbatim() {
+        let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sql");
+        let up = read_sql_file(&format!("{}/create_users.sql", fixtures)).unwrap();
+        let down = read_sql_file(&format!("{}/drop_users.sql", fixtures)).unwrap();
+
+        // The BOM, carriage returns and surrounding blank lines are gone
+        assert!(up.starts_with("-- Schema from the DBA"));
+        assert!(!up.contains('\r'));
+        assert!(up.ends_with("$$ LANGUAGE plpgsql;"));
+
+        // Semicolons in comments, strings and the function body don't split statements
+        assert_eq!(count_sql_statements(&up), 3);
+        assert_eq!(count_sql_statements(&down), 2);
+        assert_eq!(count_sql_statements("SELECT 1"), 1);
+
+        let content = MigrationGenerator::new(&TideConfig::default())
+            .generate_from_sql_content("create_users", "20260316_001", &up, Some(&down))
+            .unwrap();
+        let (up_body, down_body) = content.split_at(content.find("async fn down").unwrap());
+
+        // The SQL mentions "#42, so the raw string needs two hashes
+        assert!(up_body.contains(&format!("        schema.raw(r##\"\n{}\n        \"##).await?;", up)));
+        assert!(down_body.contains(&format!("        schema.raw(r#\"\n{}\n        \"#).await?;", down)));
+
+        let content = MigrationGenerator::new(&TideConfig::default())
+            .generate_from_sql_content("create_users", "20260316_001", &up, None)
+            .unwrap();
+        assert!(content.contains("// TODO: Implement the reverse migration"));
+
+        assert!(read_sql_file(&format!("{}/missing.sql", fixtures)).unwrap_err().contains("missing.sql"));
+    }
 }
@@ -1,6 +1,23 @@
 //! Generator modules for TideORM CLI
 
+pub mod cast;
+pub mod command;
+pub mod config;
+pub mod contract;
+pub mod controller;
+pub mod dto;
 pub mod factory;
+pub mod graphql;
+pub mod guard;
+pub mod job;
 pub mod migration;
 pub mod model;
+pub mod notification;
+pub mod observer;
+pub mod presenter;
+pub mod repository;
+pub mod resource;
 pub mod seeder;
+pub mod test;
+pub mod transformer;
+pub mod value_object;
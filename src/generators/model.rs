@@ -7,13 +7,40 @@
 //! - Attachments (struct-level has_one_files, has_many_files)
 //! - Indexes and unique constraints (struct-level attributes)
 //! - Soft deletes, timestamps, tokenization
+//! - Fields inherited from a parent model (`--extends`)
+//! - Plain structs implementing a `TideModel` trait instead of the proc-macro (`--base=trait`)
 
+use crate::commands::schema::model_fields;
 use crate::config::TideConfig;
 use crate::utils::{
-    ensure_directory, pluralize, render_template, to_pascal_case, to_snake_case,
-    FieldDefinition, RelationDefinition, RelationType,
+    apply_check_constraints, ensure_directory, parse_generated_columns, pluralize_with_overrides, render_template,
+    split_field_definitions, to_pascal_case, to_snake_case, write_or_update_file, FieldDefinition,
+    RelationDefinition, RelationType,
 };
+use regex::Regex;
 use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// How a generated model is declared
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModelBase {
+    /// `#[tideorm::model]` on the struct
+    #[default]
+    ProcMacro,
+    /// A plain struct with an `impl TideModel` block
+    Trait,
+}
+
+impl ModelBase {
+    /// Parse a `--base` or `model.base_style` value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "proc_macro" => Ok(Self::ProcMacro),
+            "trait" => Ok(Self::Trait),
+            other => Err(format!("Unknown model base '{}'; expected proc_macro or trait", other)),
+        }
+    }
+}
 
 /// Model generator
 pub struct ModelGenerator<'a> {
@@ -29,9 +56,18 @@ pub struct ModelGenerator<'a> {
     indexed: Vec<String>,
     unique: Vec<String>,
     nullable: Vec<String>,
+    sortable: Vec<String>,
+    searchable: Vec<String>,
+    paginate: bool,
     soft_deletes: bool,
     timestamps: bool,
     tokenize: bool,
+    optimistic_locking: bool,
+    derive_debug: bool,
+    derive_clone: bool,
+    derives: Vec<String>,
+    extends: Option<String>,
+    base: ModelBase,
     output_dir: String,
 }
 
@@ -51,9 +87,18 @@ impl<'a> ModelGenerator<'a> {
             indexed: Vec::new(),
             unique: Vec::new(),
             nullable: Vec::new(),
+            sortable: Vec::new(),
+            searchable: Vec::new(),
+            paginate: true,
             soft_deletes: config.model.soft_deletes,
             timestamps: config.model.timestamps,
             tokenize: config.model.tokenize,
+            optimistic_locking: false,
+            derive_debug: true,
+            derive_clone: true,
+            derives: Vec::new(),
+            extends: None,
+            base: ModelBase::default(),
             output_dir: config.paths.models.clone(),
         }
     }
@@ -73,12 +118,7 @@ impl<'a> ModelGenerator<'a> {
     /// Set fields from string
     pub fn fields(mut self, fields: Option<String>) -> Self {
         if let Some(fields_str) = fields {
-            for field in fields_str.split(',') {
-                let field = field.trim();
-                if field.is_empty() {
-                    continue;
-                }
-
+            for field in split_field_definitions(&fields_str) {
                 match FieldDefinition::parse(field) {
                     Ok(parsed) => self.fields.push(parsed),
                     Err(err) => self.parse_errors.push(err),
@@ -88,6 +128,27 @@ impl<'a> ModelGenerator<'a> {
         self
     }
 
+    /// Attach `field:expression` check constraints to fields set by [`Self::fields`]
+    pub fn check_constraints(mut self, checks: Option<String>) -> Self {
+        if let Some(checks) = checks
+            && let Err(err) = apply_check_constraints(&mut self.fields, &checks)
+        {
+            self.parse_errors.push(err);
+        }
+        self
+    }
+
+    /// Add `name:type:expression` columns computed by the database
+    pub fn generated_columns(mut self, columns: Option<String>) -> Self {
+        if let Some(columns) = columns {
+            match parse_generated_columns(&columns) {
+                Ok(columns) => self.fields.extend(columns),
+                Err(err) => self.parse_errors.push(err),
+            }
+        }
+        self
+    }
+
     /// Set relations from string
     pub fn relations(mut self, relations: Option<String>) -> Self {
         if let Some(relations_str) = relations {
@@ -172,6 +233,36 @@ impl<'a> ModelGenerator<'a> {
         self
     }
 
+    /// Set sortable fields
+    pub fn sortable(mut self, fields: Option<String>) -> Self {
+        if let Some(fields_str) = fields {
+            self.sortable = fields_str
+                .split(',')
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty())
+                .collect();
+        }
+        self
+    }
+
+    /// Set the fields the generated `search` method matches by default
+    pub fn searchable(mut self, fields: Option<String>) -> Self {
+        if let Some(fields_str) = fields {
+            self.searchable = fields_str
+                .split(',')
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty())
+                .collect();
+        }
+        self
+    }
+
+    /// Enable/disable the generated `paginate` method
+    pub fn paginate(mut self, enabled: bool) -> Self {
+        self.paginate = enabled;
+        self
+    }
+
     /// Enable/disable soft deletes
     pub fn soft_deletes(mut self, enabled: bool) -> Self {
         self.soft_deletes = enabled;
@@ -190,6 +281,49 @@ impl<'a> ModelGenerator<'a> {
         self
     }
 
+    /// Add a `version` field with an `increment_version` helper for optimistic locking
+    pub fn optimistic_locking(mut self, enabled: bool) -> Self {
+        self.optimistic_locking = enabled;
+        self
+    }
+
+    /// Derive `Debug` on the struct; without it the model has no `Debug` impl
+    pub fn derive_debug(mut self, enabled: bool) -> Self {
+        self.derive_debug = enabled;
+        self
+    }
+
+    /// Derive `Clone` on the struct; without it `#[tideorm::model]` generates the impl,
+    /// since TideORM requires models to be `Clone`
+    pub fn derive_clone(mut self, enabled: bool) -> Self {
+        self.derive_clone = enabled;
+        self
+    }
+
+    /// Extra derives (comma-separated trait names, e.g. "PartialEq,Hash")
+    pub fn derives(mut self, derives: Option<String>) -> Self {
+        if let Some(d) = derives {
+            self.derives = d
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        self
+    }
+
+    /// Inherit the fields of an existing model in the output directory
+    pub fn extends(mut self, parent: Option<String>) -> Self {
+        self.extends = parent.map(|parent| to_pascal_case(&parent));
+        self
+    }
+
+    /// Declare the model with the proc-macro or with a `TideModel` impl
+    pub fn base(mut self, base: ModelBase) -> Self {
+        self.base = base;
+        self
+    }
+
     /// Set output directory
     pub fn output_dir(mut self, dir: &str) -> Self {
         self.output_dir = dir.to_string();
@@ -206,8 +340,23 @@ impl<'a> ModelGenerator<'a> {
             return Err(self.parse_errors.join("\n"));
         }
 
+        if self.optimistic_locking && self.fields.iter().any(|field| field.name == "version") {
+            return Err("--optimistic-locking adds a version field, so --fields can't declare one".to_string());
+        }
+
+        for field in &self.fields {
+            field.check_driver(&self.config.database.driver)?;
+        }
+
+        self.validate_relations()?;
+        self.validate_relation_derives()?;
+
+        if self.base == ModelBase::Trait {
+            self.validate_trait_base()?;
+        }
+
         // Ensure output directory exists
-        ensure_directory(&self.output_dir)?;
+        let output_dir = ensure_directory(&self.output_dir)?;
 
         // Generate file content
         let content = self.generate_content()?;
@@ -216,30 +365,118 @@ impl<'a> ModelGenerator<'a> {
         let file_name = format!("{}.rs", to_snake_case(&self.name));
         let file_path = format!("{}/{}", self.output_dir, file_name);
 
-        std::fs::write(&file_path, content)
+        std::fs::write(output_dir.join(&file_name), content)
             .map_err(|e| format!("Failed to write model file: {}", e))?;
 
+        if self.base == ModelBase::Trait {
+            self.write_tide_model_trait()?;
+        }
+
         // Update mod.rs
         self.update_mod_file()?;
 
         Ok(file_path)
     }
 
+    /// Options that only `#[tideorm::model]` understands
+    fn validate_trait_base(&self) -> Result<(), String> {
+        let unsupported: Vec<&str> = [
+            ("--relations", !self.relations.is_empty()),
+            ("--translatable", !self.translatable.is_empty()),
+            ("--attachments-single", !self.attachments_single.is_empty()),
+            ("--attachments-multi", !self.attachments_multi.is_empty()),
+            ("--tokenize", self.tokenize),
+            ("--optimistic-locking", self.optimistic_locking),
+        ]
+        .into_iter()
+        .filter(|(_, used)| *used)
+        .map(|(flag, _)| flag)
+        .collect();
+
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} need #[tideorm::model] and can't be used with --base=trait",
+                unsupported.join(", ")
+            ))
+        }
+    }
+
+    /// Write the `TideModel` trait next to the models unless it already exists
+    fn write_tide_model_trait(&self) -> Result<(), String> {
+        let path = format!("{}/tide_model.rs", self.output_dir);
+        if !std::path::Path::new(&path).exists() {
+            std::fs::write(&path, TIDE_MODEL_TRAIT)
+                .map_err(|e| format!("Failed to write TideModel trait: {}", e))?;
+        }
+
+        let module_decl = "pub mod tide_model;";
+        write_or_update_file(
+            &format!("{}/mod.rs", self.output_dir),
+            &format!("{}\n", module_decl),
+            module_decl,
+        )
+    }
+
     /// Generate the model file content
     fn generate_content(&self) -> Result<String, String> {
+        let inherited = self.inherited_fields()?;
+
+        let mut related_imports: Vec<ModelImportContext> = self
+            .relations
+            .iter()
+            .map(|relation| ModelImportContext {
+                module: to_snake_case(&relation.related_model),
+                name: relation.related_model.clone(),
+            })
+            .collect();
+
+        if let Some(parent) = &self.extends
+            && !related_imports.iter().any(|import| &import.name == parent)
+        {
+            related_imports.push(ModelImportContext {
+                module: to_snake_case(parent),
+                name: parent.clone(),
+            });
+        }
+
+        let mut struct_fields = self.build_struct_fields(&inherited);
+        let (struct_attributes, methods, trait_impls) = match self.base {
+            ModelBase::ProcMacro => (
+                self.build_struct_attributes(),
+                self.build_impl_methods(),
+                Vec::new(),
+            ),
+            // Query helpers and field attributes come from the proc-macro
+            ModelBase::Trait => {
+                related_imports.push(ModelImportContext {
+                    module: "tide_model".to_string(),
+                    name: "{FieldMeta, TideModel}".to_string(),
+                });
+                for field in &mut struct_fields {
+                    field.attribute = None;
+                }
+                let trait_impl = self.build_trait_impl(&struct_fields);
+                (vec![format!("#[derive({})]", self.derive_list(&["Default"]).join(", "))], Vec::new(), vec![trait_impl])
+            }
+        };
+
+        // The proc-macro and query helpers rely on the prelude; a trait-based model only
+        // needs it for field types such as `Json` that it doesn't qualify
+        let uses_prelude = self.base == ModelBase::ProcMacro
+            || !methods.is_empty()
+            || struct_fields.iter().any(|field| uses_prelude_type(&field.declaration));
+
         let context = ModelTemplateContext {
             name: self.name.clone(),
-            related_imports: self
-                .relations
-                .iter()
-                .map(|relation| ModelImportContext {
-                    module: to_snake_case(&relation.related_model),
-                    name: relation.related_model.clone(),
-                })
-                .collect(),
-            struct_attributes: self.build_struct_attributes(),
-            struct_fields: self.build_struct_fields(),
-            methods: self.build_impl_methods(),
+            uses_prelude,
+            related_imports,
+            struct_attributes,
+            struct_fields,
+            methods,
+            from_impls: self.build_from_impls(&inherited),
+            trait_impls,
         };
 
         render_template(
@@ -251,13 +488,17 @@ impl<'a> ModelGenerator<'a> {
     }
 
     fn build_struct_attributes(&self) -> Vec<String> {
-        // Table name
-        let table_name = self.table.clone().unwrap_or_else(|| {
-            pluralize(&to_snake_case(&self.name))
-        });
+        let table_name = self.table_name();
 
         let mut attributes = Vec::new();
         let mut tide_attrs = vec![format!("table = \"{}\"", table_name)];
+
+        // `#[tideorm::model]` drops derives placed after it and would otherwise generate
+        // its own Debug/Clone impls, so derive above it and skip those
+        let derives = self.derive_list(&[]);
+        if !derives.is_empty() {
+            attributes.push(format!("#[derive({})]", derives.join(", ")));
+        }
         
         if self.soft_deletes {
             tide_attrs.push("soft_delete".to_string());
@@ -281,6 +522,10 @@ impl<'a> ModelGenerator<'a> {
             tide_attrs.push(format!("has_many_files = \"{}\"", self.attachments_multi.join(",")));
         }
 
+        tide_attrs.push("skip_debug".to_string());
+        if derives.iter().any(|derive| derive == "Clone") {
+            tide_attrs.push("skip_clone".to_string());
+        }
         attributes.push(format!("#[tideorm::model({})]", tide_attrs.join(", ")));
         
         // Index attributes (struct-level)
@@ -308,7 +553,7 @@ impl<'a> ModelGenerator<'a> {
         attributes
     }
 
-    fn build_struct_fields(&self) -> Vec<ModelFieldTemplateContext> {
+    fn build_struct_fields(&self, inherited: &[FieldDefinition]) -> Vec<ModelFieldTemplateContext> {
         let mut fields = Vec::new();
 
         if !self.has_explicit_primary_key() {
@@ -323,8 +568,22 @@ impl<'a> ModelGenerator<'a> {
             });
         }
 
-        // Regular fields
-        for field in self.generated_fields() {
+        // Inherited fields first, then the model's own
+        for (index, field) in inherited.iter().cloned().chain(self.generated_fields()).enumerate() {
+            // TideORM writes every field on save, which the database rejects for generated
+            // columns, and `#[tideorm(skip)]` fields break its row conversion
+            if let Some(expression) = &field.generated {
+                fields.push(ModelFieldTemplateContext {
+                    doc_comment: Some(format!(
+                        "// Generated by the database as `{}`; left unmapped since TideORM writes every field on save",
+                        expression
+                    )),
+                    attribute: None,
+                    declaration: format!("// pub {}: {},", field.name, field.rust_type()),
+                });
+                continue;
+            }
+
             let mut field_attrs = Vec::new();
             let is_primary_key = field.primary_key || field.name == self.config.model.primary_key;
             let is_auto_increment = field.auto_increment
@@ -350,13 +609,48 @@ impl<'a> ModelGenerator<'a> {
             }
 
             let rust_type = if is_nullable && !field.nullable {
-                format!("Option<{}>", field.rust_type().replace("Option<", "").replace(">", ""))
+                format!("Option<{}>", field.base_rust_type())
             } else {
                 field.rust_type()
             };
 
+            // TideORM has no field attribute for CHECK constraints; the migration creates them
+            let check_comment = field
+                .check
+                .as_ref()
+                .map(|check| format!("/// Database check: `{}`", check));
+
+            let doc_comment = if !field.enum_variants.is_empty() {
+                Some(format!(
+                    "/// One of: {}. Consider replacing `String` with a custom enum",
+                    field.enum_variants.join(", ")
+                ))
+            } else if field.field_type.eq_ignore_ascii_case("json_array") {
+                Some(
+                    "/// JSON array column. Add `#[serde(default)]` to accept payloads without it"
+                        .to_string(),
+                )
+            } else {
+                None
+            };
+
+            let doc_comment = match (doc_comment, check_comment) {
+                (Some(doc_comment), Some(check_comment)) => {
+                    Some(format!("{}\n    {}", doc_comment, check_comment))
+                }
+                (doc_comment, check_comment) => doc_comment.or(check_comment),
+            };
+
+            let doc_comment = match (&self.extends, index == 0 && !inherited.is_empty()) {
+                (Some(parent), true) => Some(match doc_comment {
+                    Some(doc_comment) => format!("// Inherited from {}\n    {}", parent, doc_comment),
+                    None => format!("// Inherited from {}", parent),
+                }),
+                _ => doc_comment,
+            };
+
             fields.push(ModelFieldTemplateContext {
-                doc_comment: None,
+                doc_comment,
                 attribute: (!field_attrs.is_empty())
                     .then(|| format!("#[tideorm({})]", field_attrs.join(", "))),
                 declaration: format!("pub {}: {},", field.name, rust_type),
@@ -413,6 +707,14 @@ impl<'a> ModelGenerator<'a> {
             });
         }
 
+        if self.optimistic_locking {
+            fields.push(ModelFieldTemplateContext {
+                doc_comment: Some("/// Row version; TideORM doesn't check it, so compare it before saving and bump it with `increment_version`".to_string()),
+                attribute: None,
+                declaration: "pub version: i32,".to_string(),
+            });
+        }
+
         // Timestamps (plain DateTime fields, no auto_now attributes)
         if self.timestamps {
             fields.push(ModelFieldTemplateContext {
@@ -439,6 +741,62 @@ impl<'a> ModelGenerator<'a> {
         fields
     }
 
+    /// Debug and Clone unless disabled, then `required`, then the extra derives
+    fn derive_list(&self, required: &[&str]) -> Vec<String> {
+        let mut derives = Vec::new();
+        if self.derive_debug {
+            derives.push("Debug".to_string());
+        }
+        if self.derive_clone {
+            derives.push("Clone".to_string());
+        }
+        for derive in required.iter().map(|derive| derive.to_string()).chain(self.derives.iter().cloned()) {
+            if !derives.contains(&derive) {
+                derives.push(derive);
+            }
+        }
+
+        derives
+    }
+
+    /// `impl TideModel` describing the table and the mapped struct fields
+    fn build_trait_impl(&self, struct_fields: &[ModelFieldTemplateContext]) -> String {
+        let field_metas: String = struct_fields
+            .iter()
+            .filter_map(|field| {
+                let declaration = field.declaration.strip_prefix("pub ")?.strip_suffix(',')?;
+                let (name, rust_type) = declaration.split_once(": ")?;
+                Some(format!(
+                    "            FieldMeta::new(\"{}\", \"{}\", {}),\n",
+                    name,
+                    rust_type,
+                    rust_type.starts_with("Option<")
+                ))
+            })
+            .collect();
+
+        format!(
+            r#"impl TideModel for {name} {{
+    fn table_name() -> &'static str {{
+        "{table}"
+    }}
+
+    fn primary_key() -> &'static str {{
+        "{primary_key}"
+    }}
+
+    fn fields() -> Vec<FieldMeta> {{
+        vec![
+{field_metas}        ]
+    }}
+}}"#,
+            name = self.name,
+            table = self.table_name(),
+            primary_key = self.primary_key_name(),
+            field_metas = field_metas,
+        )
+    }
+
     fn build_impl_methods(&self) -> Vec<String> {
         let mut impl_lines = Vec::new();
 
@@ -462,13 +820,142 @@ impl<'a> ModelGenerator<'a> {
             }
         }
 
+        // Ordering helpers for indexed and sortable fields
+        for field_name in self.sortable_fields() {
+            impl_lines.push(format!(
+                r#"    /// Order by {field} ascending
+    pub fn order_by_{field}_asc() -> QueryBuilder<Self> {{
+        Self::query().order_by("{field}", Order::Asc)
+    }}
+
+    /// Order by {field} descending
+    pub fn order_by_{field}_desc() -> QueryBuilder<Self> {{
+        Self::query().order_by("{field}", Order::Desc)
+    }}
+"#,
+                field = field_name
+            ));
+        }
+
+        if self.paginate {
+            impl_lines.push(
+                r#"    /// One page of rows, counting pages from 1, and the total row count
+    pub async fn paginate(page: u64, per_page: u64) -> tideorm::Result<(u64, Vec<Self>)> {
+        let rows = Self::query()
+            .offset(page.saturating_sub(1) * per_page)
+            .limit(per_page)
+            .get()
+            .await?;
+
+        Ok((Self::count().await?, rows))
+    }
+"#
+                .to_string(),
+            );
+        }
+
+        if self.optimistic_locking {
+            impl_lines.push(
+                r#"    /// Move to the next version; call before saving a change
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+"#
+                .to_string(),
+            );
+        }
+
+        if !self.searchable.is_empty() {
+            let fields = self
+                .searchable
+                .iter()
+                .map(|field| format!("{:?}", field))
+                .collect::<Vec<_>>()
+                .join(", ");
+            impl_lines.push(format!(
+                r#"    /// Fields `search` matches when called without any
+    pub const SEARCHABLE_FIELDS: &'static [&'static str] = &[{fields}];
+
+    /// Rows where any of `fields` (default: `SEARCHABLE_FIELDS`) contains `query`
+    pub async fn search(query: &str, fields: &[&str]) -> tideorm::Result<Vec<Self>> {{
+        let fields = if fields.is_empty() {{ Self::SEARCHABLE_FIELDS }} else {{ fields }};
+        let pattern = format!("%{{}}%", query);
+
+        Self::query()
+            .or_where(|group| fields.iter().fold(group, |group, field| group.where_like(field, &pattern)))
+            .get()
+            .await
+    }}
+"#,
+                fields = fields
+            ));
+        }
+
         impl_lines
     }
 
+    /// `From<Parent>` conversion copying the inherited fields
+    fn build_from_impls(&self, inherited: &[FieldDefinition]) -> Vec<String> {
+        let Some(parent) = &self.extends else {
+            return Vec::new();
+        };
+
+        let parent_var = to_snake_case(parent);
+        let assignments: String = inherited
+            .iter()
+            .map(|field| format!("            {name}: {parent_var}.{name},\n", name = field.name, parent_var = parent_var))
+            .collect();
+
+        vec![format!(
+            r#"impl From<{parent}> for {name} {{
+    fn from({parent_var}: {parent}) -> Self {{
+        Self {{
+{assignments}            ..Default::default()
+        }}
+    }}
+}}"#,
+            parent = parent,
+            name = self.name,
+            parent_var = parent_var,
+            assignments = assignments,
+        )]
+    }
+
+    /// Parent fields this model inherits, minus any it declares itself
+    fn inherited_fields(&self) -> Result<Vec<FieldDefinition>, String> {
+        let Some(parent) = &self.extends else {
+            return Ok(Vec::new());
+        };
+
+        let own_fields = self.generated_fields();
+        Ok(inherited_fields(&self.output_dir, parent, &self.config.model.primary_key)?
+            .into_iter()
+            .filter(|field| !own_fields.iter().any(|own| own.name == field.name))
+            .collect())
+    }
+
+    fn sortable_fields(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+
+        for name in self
+            .indexed
+            .iter()
+            .cloned()
+            .chain(self.generated_indexed_fields())
+            .chain(self.sortable.iter().cloned())
+        {
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+
     fn finder_param_type(&self, field: &FieldDefinition) -> String {
         match field.field_type.to_lowercase().as_str() {
-            "string" | "varchar" | "text" => "&str".to_string(),
-            _ => field.rust_type().replace("Option<", "").replace(">", ""),
+            "string" | "varchar" | "text" if !field.is_array => "&str".to_string(),
+            _ => field.base_rust_type(),
         }
     }
 
@@ -497,6 +984,13 @@ impl<'a> ModelGenerator<'a> {
                 primary_key: false,
                 auto_increment: false,
                 default: None,
+                enum_variants: Vec::new(),
+                decimal_precision: None,
+                decimal_scale: None,
+                check: None,
+                generated: None,
+                index_where: None,
+                is_array: false,
             });
         }
 
@@ -519,41 +1013,191 @@ impl<'a> ModelGenerator<'a> {
             .collect()
     }
 
+    /// Explicit table name, or the pluralized snake_case model name
+    fn table_name(&self) -> String {
+        self.table.clone().unwrap_or_else(|| {
+            pluralize_with_overrides(&to_snake_case(&self.name), &self.config.pluralization)
+        })
+    }
+
+    /// The field marked as primary key, or the configured primary key name
+    fn primary_key_name(&self) -> String {
+        self.generated_fields()
+            .into_iter()
+            .find(|field| field.primary_key)
+            .map(|field| field.name)
+            .unwrap_or_else(|| self.config.model.primary_key.clone())
+    }
+
     fn has_explicit_primary_key(&self) -> bool {
         self.generated_fields().into_iter().any(|field| {
             field.primary_key || field.name == self.config.model.primary_key
         })
     }
 
+    /// Reject BelongsTo relations that would close a cycle with existing models
+    ///
+    /// HasOne and HasMany are not part of the graph; only BelongsTo chains are checked.
+    fn validate_relations(&self) -> Result<(), String> {
+        let mut graph = existing_belongs_to_graph(&self.output_dir);
+
+        graph.insert(
+            self.name.clone(),
+            self.relations
+                .iter()
+                .filter(|relation| relation.relation_type == RelationType::BelongsTo)
+                .map(|relation| relation.related_model.clone())
+                .collect(),
+        );
+
+        match find_belongs_to_cycle(&graph, &self.name) {
+            Some(cycle) => Err(format!("Circular BelongsTo detected: {}", cycle.join(" → "))),
+            None => Ok(()),
+        }
+    }
+
+    /// Reject extra derives that TideORM's relation field types don't implement
+    fn validate_relation_derives(&self) -> Result<(), String> {
+        if self.relations.is_empty() {
+            return Ok(());
+        }
+
+        let unsupported: Vec<&str> = self
+            .derives
+            .iter()
+            .map(String::as_str)
+            .filter(|derive| {
+                let name = derive.rsplit("::").next().unwrap_or(derive);
+                !RELATION_DERIVES.contains(&name)
+            })
+            .collect();
+
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "--derives {} can't be used with --relations; relation fields only implement {}",
+                unsupported.join(", "),
+                RELATION_DERIVES.join(", ")
+            ))
+        }
+    }
+
     /// Update the mod.rs file to include the new model
     fn update_mod_file(&self) -> Result<(), String> {
         let mod_path = format!("{}/mod.rs", self.output_dir);
-        let module_name = to_snake_case(&self.name);
+        let module_decl = format!("pub mod {};", to_snake_case(&self.name));
 
-        // Read existing content
-        let existing = std::fs::read_to_string(&mod_path).unwrap_or_default();
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)
+    }
+}
 
-        // Check if already included
-        let module_decl = format!("pub mod {};", module_name);
-        if existing.contains(&module_decl) {
-            return Ok(());
+/// Fields a child model inherits from `parent` in `models_dir`
+///
+/// The primary key and the timestamp and soft delete columns are left out
+/// because the child declares its own.
+pub fn inherited_fields(
+    models_dir: &str,
+    parent: &str,
+    primary_key: &str,
+) -> Result<Vec<FieldDefinition>, String> {
+    let parent = to_pascal_case(parent);
+    let parent_path = format!("{}/{}.rs", models_dir, to_snake_case(&parent));
+    let content = std::fs::read_to_string(&parent_path)
+        .map_err(|_| format!("Parent model file not found: {}", parent_path))?;
+
+    Ok(model_fields(&content, &parent)
+        .into_iter()
+        .filter(|field| {
+            !field.primary_key
+                && field.name != primary_key
+                && !["id", "created_at", "updated_at", "deleted_at"].contains(&field.name.as_str())
+        })
+        .collect())
+}
+
+/// BelongsTo edges declared by the model files in a directory
+fn existing_belongs_to_graph(models_dir: &str) -> BTreeMap<String, Vec<String>> {
+    let struct_pattern = Regex::new(r"pub\s+struct\s+(\w+)").unwrap();
+    let belongs_to_pattern = Regex::new(r#"belongs_to\s*=\s*"(\w+)""#).unwrap();
+    let mut graph = BTreeMap::new();
+
+    let Ok(entries) = std::fs::read_dir(models_dir) else {
+        return graph;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "rs")
+            || path.file_stem().is_some_and(|stem| stem == "mod")
+        {
+            continue;
         }
 
-        let new_content = format!("{}{}\n", existing, module_decl);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some(name) = struct_pattern.captures(&content).map(|captures| captures[1].to_string()) else {
+            continue;
+        };
+
+        let related = belongs_to_pattern
+            .captures_iter(&content)
+            .map(|captures| captures[1].to_string())
+            .collect();
+        graph.insert(name, related);
+    }
+
+    graph
+}
+
+/// Depth-first search for a BelongsTo path leading from `start` back to itself
+///
+/// Self-references such as a `parent` relation stay in one module and are ignored.
+fn find_belongs_to_cycle(
+    graph: &BTreeMap<String, Vec<String>>,
+    start: &str,
+) -> Option<Vec<String>> {
+    fn visit(
+        graph: &BTreeMap<String, Vec<String>>,
+        start: &str,
+        node: &str,
+        path: &mut Vec<String>,
+        visited: &mut HashSet<String>,
+    ) -> bool {
+        path.push(node.to_string());
+
+        for next in graph.get(node).into_iter().flatten() {
+            if next == node {
+                continue;
+            }
 
-        std::fs::write(&mod_path, new_content)
-            .map_err(|e| format!("Failed to update mod.rs: {}", e))?;
+            if next == start {
+                path.push(start.to_string());
+                return true;
+            }
 
-        Ok(())
+            if visited.insert(next.clone()) && visit(graph, start, next, path, visited) {
+                return true;
+            }
+        }
+
+        path.pop();
+        false
     }
+
+    let mut path = Vec::new();
+    let mut visited = HashSet::from([start.to_string()]);
+
+    visit(graph, start, start, &mut path, &mut visited).then_some(path)
 }
 
 const DEFAULT_MODEL_TEMPLATE: &str = r#"//! {{ name }} Model
 //!
 //! Auto-generated by TideORM CLI
 
-use tideorm::prelude::*;
-{% if related_imports %}
+{% if uses_prelude %}use tideorm::prelude::*;
+{% endif %}{% if related_imports %}
 
 {% for import in related_imports %}
 use super::{{ import.module }}::{{ import.name }};
@@ -568,20 +1212,110 @@ pub struct {{ name }} {
 {% endif %}{% if field.attribute %}    {{ field.attribute }}
 {% endif %}    {{ field.declaration }}
 {% endfor %}}
-
+{% if methods %}
 impl {{ name }} {
 {% for method in methods %}
 {{ method }}
 {% endfor %}}
+{% endif %}{% for from_impl in from_impls %}
+{{ from_impl }}
+{% endfor %}{% for trait_impl in trait_impls %}
+{{ trait_impl }}
+{% endfor %}"#;
+
+/// Written to `{models}/tide_model.rs` for models generated with `--base=trait`
+const TIDE_MODEL_TRAIT: &str = r#"//! Table metadata for models declared without `#[tideorm::model]`
+//!
+//! Generated by TideORM CLI for `make model --base=trait`.
+
+/// A mapped struct field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMeta {
+    /// Column name
+    pub name: &'static str,
+    /// Rust type of the field
+    pub rust_type: &'static str,
+    /// Whether the column accepts NULL
+    pub nullable: bool,
+}
+
+impl FieldMeta {
+    /// Describe a field
+    pub const fn new(name: &'static str, rust_type: &'static str, nullable: bool) -> Self {
+        Self {
+            name,
+            rust_type,
+            nullable,
+        }
+    }
+}
+
+/// Table metadata for a model struct
+pub trait TideModel {
+    /// Database table name
+    fn table_name() -> &'static str;
+
+    /// Primary key column
+    fn primary_key() -> &'static str;
+
+    /// Mapped fields, in declaration order
+    fn fields() -> Vec<FieldMeta>;
+}
 "#;
 
 #[derive(Serialize)]
 struct ModelTemplateContext {
     name: String,
+    uses_prelude: bool,
     related_imports: Vec<ModelImportContext>,
     struct_attributes: Vec<String>,
     struct_fields: Vec<ModelFieldTemplateContext>,
     methods: Vec<String>,
+    from_impls: Vec<String>,
+    trait_impls: Vec<String>,
+}
+
+/// Traits TideORM's `BelongsTo`, `HasOne` and `HasMany` fields implement
+const RELATION_DERIVES: &[&str] = &["Debug", "Clone", "Default", "Serialize", "Deserialize"];
+
+/// Types `tideorm::prelude` exports that field types may name without a path
+const PRELUDE_TYPES: &[&str] = &[
+    "BigIntArray",
+    "BoolArray",
+    "DateTime",
+    "Decimal",
+    "FloatArray",
+    "IntArray",
+    "Json",
+    "JsonArray",
+    "JsonValue",
+    "Jsonb",
+    "NaiveDate",
+    "NaiveDateTime",
+    "NaiveTime",
+    "Text",
+    "TextArray",
+    "Utc",
+    "Uuid",
+];
+
+/// Whether a field declaration names a prelude type without a path, like `Json` but not `chrono::Utc`
+fn uses_prelude_type(declaration: &str) -> bool {
+    let ty = declaration.split_once(':').map_or(declaration, |(_, ty)| ty);
+    let mut rest = ty;
+
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+        let qualified = rest[..start].ends_with("::");
+        let end = rest[start..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(rest.len(), |offset| start + offset);
+        if !qualified && PRELUDE_TYPES.contains(&&rest[start..end]) {
+            return true;
+        }
+        rest = &rest[end..];
+    }
+
+    false
 }
 
 #[derive(Serialize)]
@@ -634,6 +1368,54 @@ mod tests {
         assert_eq!(rel.relation_type, RelationType::HasMany);
     }
     
+    #[test]
+    fn test_generated_columns_are_left_unmapped() {
+        let config = TideConfig::default();
+        let content = ModelGenerator::new(&config)
+            .name("Person")
+            .fields(Some("first_name:string,last_name:string".to_string()))
+            .generated_columns(Some("full_name:string:CONCAT(first_name, ' ', last_name)".to_string()))
+            .generate_content()
+            .unwrap();
+
+        assert!(content.contains(
+            "    // Generated by the database as `CONCAT(first_name, ' ', last_name)`; left unmapped since TideORM writes every field on save\n    // pub full_name: Option<String>,"
+        ));
+        assert!(!content.contains("#[tideorm(skip)]"));
+        assert!(content.find("pub last_name: String,").unwrap() < content.find("// pub full_name").unwrap());
+
+        let error = ModelGenerator::new(&config)
+            .name("Person")
+            .generated_columns(Some("full_name:string".to_string()))
+            .generate()
+            .unwrap_err();
+        assert!(error.contains("Expected format: name:type:expression"));
+    }
+
+    #[test]
+    fn test_model_derives() {
+        let config = TideConfig::default();
+
+        let content = ModelGenerator::new(&config)
+            .name("User")
+            .derives(Some("PartialEq, Hash,Clone".to_string()))
+            .generate_content()
+            .unwrap();
+        // Derives must come before the model attribute, which strips any after it
+        let derive = content.find("#[derive(Debug, Clone, PartialEq, Hash)]").unwrap();
+        assert!(derive < content.find("#[tideorm::model(table = \"users\", skip_debug, skip_clone)]").unwrap());
+
+        // Without Debug the model has none; TideORM still needs Clone, so the macro provides it
+        let content = ModelGenerator::new(&config)
+            .name("User")
+            .derive_debug(false)
+            .derive_clone(false)
+            .generate_content()
+            .unwrap();
+        assert!(!content.contains("#[derive("));
+        assert!(content.contains("#[tideorm::model(table = \"users\", skip_debug)]"));
+    }
+
     #[test]
     fn test_generated_model_uses_correct_syntax() {
         let config = TideConfig::default();
@@ -646,7 +1428,8 @@ mod tests {
         let content = generator.generate_content().unwrap();
         
         // Generated models should use the canonical TideORM model attribute
-        assert!(content.contains("#[tideorm::model(table = \"users\")]") );
+        assert!(content.contains("#[derive(Debug, Clone)]"));
+        assert!(content.contains("#[tideorm::model(table = \"users\", skip_debug, skip_clone)]") );
         assert!(!content.contains("#[derive(tideorm::Model)]"));
         
         // Should use struct-level #[index()] and #[unique_index()]
@@ -662,6 +1445,211 @@ mod tests {
         assert!(content.contains("pub updated_at: chrono::DateTime<chrono::Utc>,"));
     }
     
+    #[test]
+    fn test_enum_field_is_a_documented_string() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("Account")
+            .fields(Some("status:enum(active|inactive|deleted)".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(content.contains(
+            "    /// One of: active, inactive, deleted. Consider replacing `String` with a custom enum\n    pub status: String,"
+        ));
+    }
+
+    #[test]
+    fn test_check_constraints_are_documented_on_fields() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("Player")
+            .fields(Some("age:i32,rank:enum(gold|silver)".to_string()))
+            .check_constraints(Some("age:age > 0,rank:rank <> 'gold'".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(content.contains("    /// Database check: `age > 0`\n    pub age: i32,"));
+        assert!(content.contains(
+            "custom enum\n    /// Database check: `rank <> 'gold'`\n    pub rank: String,"
+        ));
+        assert!(!content.contains("#[tide("));
+
+        let generator = ModelGenerator::new(&config)
+            .name("Player")
+            .fields(Some("age:i32".to_string()))
+            .check_constraints(Some("level:level > 0".to_string()));
+        assert!(generator.generate().unwrap_err().contains("unknown field: level"));
+    }
+
+    #[test]
+    fn test_find_belongs_to_cycle() {
+        let graph = BTreeMap::from([
+            ("User".to_string(), vec!["Company".to_string()]),
+            ("Company".to_string(), vec!["Region".to_string()]),
+            ("Region".to_string(), vec!["User".to_string()]),
+            ("Post".to_string(), vec!["User".to_string(), "Post".to_string()]),
+        ]);
+
+        assert_eq!(
+            find_belongs_to_cycle(&graph, "User"),
+            Some(vec![
+                "User".to_string(),
+                "Company".to_string(),
+                "Region".to_string(),
+                "User".to_string(),
+            ])
+        );
+        assert_eq!(find_belongs_to_cycle(&graph, "Post"), None);
+
+        let acyclic = BTreeMap::from([
+            ("Comment".to_string(), vec!["Post".to_string(), "User".to_string()]),
+            ("Post".to_string(), vec!["User".to_string()]),
+            ("User".to_string(), Vec::new()),
+        ]);
+        assert_eq!(find_belongs_to_cycle(&acyclic, "Comment"), None);
+    }
+
+    #[test]
+    fn test_generate_rejects_circular_belongs_to() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().to_string_lossy().into_owned();
+        let config = TideConfig::default();
+
+        ModelGenerator::new(&config)
+            .name("User")
+            .relations(Some("company:belongs_to:Company".to_string()))
+            .output_dir(&output)
+            .generate()
+            .unwrap();
+
+        let error = ModelGenerator::new(&config)
+            .name("Company")
+            .relations(Some("owner:belongs_to:User".to_string()))
+            .output_dir(&output)
+            .generate()
+            .unwrap_err();
+        assert_eq!(error, "Circular BelongsTo detected: Company → User → Company");
+        assert!(!dir.path().join("company.rs").exists());
+
+        ModelGenerator::new(&config)
+            .name("Company")
+            .relations(Some("users:has_many:User".to_string()))
+            .output_dir(&output)
+            .generate()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_model_base_parses_flag_and_config_values() {
+        assert_eq!(ModelBase::parse("proc_macro"), Ok(ModelBase::ProcMacro));
+        assert_eq!(ModelBase::parse("proc-macro"), Ok(ModelBase::ProcMacro));
+        assert_eq!(ModelBase::parse("Trait"), Ok(ModelBase::Trait));
+        assert!(ModelBase::parse("active_record").is_err());
+    }
+
+    #[test]
+    fn test_proc_macro_base_keeps_the_model_attribute() {
+        let config = TideConfig::default();
+        let content = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("email:string:unique".to_string()))
+            .base(ModelBase::ProcMacro)
+            .generate_content()
+            .unwrap();
+
+        assert!(content.contains("#[tideorm::model(table = \"users\""));
+        assert!(content.contains("#[tideorm(primary_key, auto_increment)]"));
+        assert!(!content.contains("TideModel"));
+    }
+
+    #[test]
+    fn test_trait_base_implements_tide_model_without_the_proc_macro() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().to_string_lossy().into_owned();
+        let config = TideConfig::default();
+
+        ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("email:string:unique,bio:text:nullable".to_string()))
+            .timestamps(false)
+            .base(ModelBase::Trait)
+            .output_dir(&output)
+            .generate()
+            .unwrap();
+
+        let content = std::fs::read_to_string(dir.path().join("user.rs")).unwrap();
+        assert!(content.contains("use super::tide_model::{FieldMeta, TideModel};"));
+        assert!(!content.contains("tideorm::prelude"));
+        assert!(!content.contains("impl User {"));
+        assert!(content.contains("#[derive(Debug, Clone, Default)]\n\npub struct User {"));
+        assert!(!content.contains("#[tideorm"));
+        assert!(!content.contains("find_by_email"));
+        assert!(!content.contains("paginate"));
+        assert!(content.contains(
+            "impl TideModel for User {\n    fn table_name() -> &'static str {\n        \"users\"\n    }\n\n    fn primary_key() -> &'static str {\n        \"id\"\n    }\n\n    fn fields() -> Vec<FieldMeta> {\n        vec![\n            FieldMeta::new(\"id\", \"i64\", false),\n            FieldMeta::new(\"email\", \"String\", false),\n            FieldMeta::new(\"bio\", \"Option<String>\", true),\n        ]\n    }\n}"
+        ));
+
+        let trait_file = std::fs::read_to_string(dir.path().join("tide_model.rs")).unwrap();
+        assert!(trait_file.contains("pub trait TideModel {"));
+        assert!(trait_file.contains("pub struct FieldMeta {"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("mod.rs")).unwrap(),
+            "pub mod tide_model;\npub mod user;\n"
+        );
+    }
+
+    #[test]
+    fn test_prelude_is_imported_for_unqualified_prelude_types() {
+        assert!(uses_prelude_type("pub data: Json,"));
+        assert!(uses_prelude_type("pub tags: Option<IntArray>,"));
+        assert!(!uses_prelude_type("pub created_at: chrono::DateTime<chrono::Utc>,"));
+        assert!(!uses_prelude_type("pub id: uuid::Uuid,"));
+        assert!(!uses_prelude_type("pub name: String,"));
+    }
+
+    #[test]
+    fn test_trait_base_rejects_proc_macro_only_options() {
+        let config = TideConfig::default();
+        let error = ModelGenerator::new(&config)
+            .name("Post")
+            .relations(Some("user:belongs_to:User".to_string()))
+            .tokenize(true)
+            .base(ModelBase::Trait)
+            .output_dir(&tempdir().unwrap().path().to_string_lossy())
+            .generate()
+            .unwrap_err();
+
+        assert_eq!(error, "--relations, --tokenize need #[tideorm::model] and can't be used with --base=trait");
+    }
+
+    #[test]
+    fn test_relations_reject_derives_their_fields_lack() {
+        let config = TideConfig::default();
+        let dir = tempdir().unwrap();
+        let output = dir.path().to_string_lossy().to_string();
+
+        let error = ModelGenerator::new(&config)
+            .name("Invoice")
+            .relations(Some("account:belongs_to:Account".to_string()))
+            .derives(Some("PartialEq,serde::Serialize,Hash".to_string()))
+            .output_dir(&output)
+            .generate()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "--derives PartialEq, Hash can't be used with --relations; relation fields only implement Debug, Clone, Default, Serialize, Deserialize"
+        );
+
+        ModelGenerator::new(&config)
+            .name("Invoice")
+            .relations(Some("account:belongs_to:Account".to_string()))
+            .derives(Some("Default".to_string()))
+            .output_dir(&output)
+            .generate()
+            .unwrap();
+    }
+
     #[test]
     fn test_relations_as_struct_fields() {
         let config = TideConfig::default();
@@ -688,7 +1676,7 @@ mod tests {
 
         let content = generator.generate_content().unwrap();
 
-        assert!(content.contains("#[tideorm::model(table = \"products\", translatable = \"name,description\")]"));
+        assert!(content.contains("#[tideorm::model(table = \"products\", translatable = \"name,description\", skip_debug, skip_clone)]"));
         assert!(content.contains("pub translations: Option<JsonValue>,"));
     }
 
@@ -720,6 +1708,126 @@ mod tests {
         assert!(content.contains("pub author: BelongsTo<User>,"));
     }
 
+    #[test]
+    fn test_sortable_and_indexed_fields_generate_order_helpers() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string,email:string:indexed".to_string()))
+            .sortable(Some("name,created_at".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(content.contains("pub fn order_by_name_asc() -> QueryBuilder<Self>"));
+        assert!(content.contains("pub fn order_by_name_desc() -> QueryBuilder<Self>"));
+        assert!(content.contains("pub fn order_by_created_at_asc() -> QueryBuilder<Self>"));
+        assert!(content.contains("pub fn order_by_email_desc() -> QueryBuilder<Self>"));
+        assert!(content.contains("Self::query().order_by(\"name\", Order::Asc)"));
+        assert!(content.contains("Self::query().order_by(\"email\", Order::Desc)"));
+        assert_eq!(content.matches("pub fn order_by_email_asc()").count(), 1);
+    }
+
+    #[test]
+    fn test_paginate_counts_pages_from_one_and_returns_the_total() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(content.contains(
+            "    pub async fn paginate(page: u64, per_page: u64) -> tideorm::Result<(u64, Vec<Self>)> {\n        let rows = Self::query()\n            .offset(page.saturating_sub(1) * per_page)\n            .limit(per_page)\n            .get()\n            .await?;\n\n        Ok((Self::count().await?, rows))\n    }\n"
+        ));
+        assert!(!content.contains("fn search"));
+
+        let content = generator.paginate(false).generate_content().unwrap();
+        assert!(!content.contains("paginate"));
+    }
+
+    #[test]
+    fn test_optimistic_locking_rejects_a_version_field() {
+        let config = TideConfig::default();
+        let error = ModelGenerator::new(&config)
+            .name("Account")
+            .fields(Some("name:string,version:i64".to_string()))
+            .optimistic_locking(true)
+            .output_dir(&tempdir().unwrap().path().to_string_lossy())
+            .generate()
+            .unwrap_err();
+
+        assert_eq!(error, "--optimistic-locking adds a version field, so --fields can't declare one");
+    }
+
+    #[test]
+    fn test_searchable_fields_generate_a_search_method() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string,email:string".to_string()))
+            .searchable(Some("name, email".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(content.contains("    pub const SEARCHABLE_FIELDS: &'static [&'static str] = &[\"name\", \"email\"];\n"));
+        assert!(content.contains(
+            "    pub async fn search(query: &str, fields: &[&str]) -> tideorm::Result<Vec<Self>> {\n        let fields = if fields.is_empty() { Self::SEARCHABLE_FIELDS } else { fields };\n        let pattern = format!(\"%{}%\", query);\n\n        Self::query()\n            .or_where(|group| fields.iter().fold(group, |group, field| group.where_like(field, &pattern)))\n            .get()\n            .await\n    }\n"
+        ));
+    }
+
+    #[test]
+    fn test_generated_methods_do_not_allow_dead_code() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string,email:string:unique".to_string()))
+            .sortable(Some("name".to_string()))
+            .searchable(Some("name".to_string()));
+
+        let methods = generator.build_impl_methods();
+        assert!(methods.iter().any(|method| method.contains("fn find_by_email")));
+        assert!(methods.iter().all(|method| !method.contains("// TODO")));
+        assert!(!generator.generate_content().unwrap().contains("dead_code"));
+    }
+
+    #[test]
+    fn test_unsorted_fields_do_not_generate_order_helpers() {
+        let config = TideConfig::default();
+        let generator = ModelGenerator::new(&config)
+            .name("User")
+            .fields(Some("name:string,email:string:unique".to_string()));
+
+        let content = generator.generate_content().unwrap();
+
+        assert!(!content.contains("order_by_"));
+    }
+
+    #[test]
+    fn test_table_name_uses_pluralization_overrides() {
+        let mut config = TideConfig::default();
+        config
+            .pluralization
+            .insert("staff".to_string(), "staff_members".to_string());
+
+        let content = ModelGenerator::new(&config).name("Staff").generate_content().unwrap();
+        assert!(content.contains("#[tideorm::model(table = \"staff_members\", skip_debug, skip_clone)]"));
+    }
+
+    #[test]
+    fn test_explicit_table_name_skips_pluralization() {
+        let mut config = TideConfig::default();
+        config
+            .pluralization
+            .insert("person".to_string(), "persons".to_string());
+
+        let content = ModelGenerator::new(&config)
+            .name("Person")
+            .table(Some("person_records".to_string()))
+            .generate_content()
+            .unwrap();
+        assert!(content.contains("#[tideorm::model(table = \"person_records\", skip_debug, skip_clone)]"));
+    }
+
     #[test]
     fn test_model_template_override_is_used() {
         let dir = tempdir().unwrap();
@@ -734,4 +1842,50 @@ mod tests {
 
         assert_eq!(content, "// custom model for User");
     }
+
+    #[test]
+    fn test_extends_inherits_parent_fields_and_converts_from_parent() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().to_string_lossy().into_owned();
+        let config = TideConfig::default();
+
+        ModelGenerator::new(&config)
+            .name("Content")
+            .fields(Some("title:string,body:text,published_at:datetime:nullable".to_string()))
+            .timestamps(true)
+            .output_dir(&output)
+            .generate()
+            .unwrap();
+
+        let content = ModelGenerator::new(&config)
+            .name("Post")
+            .fields(Some("slug:string:unique,title:string".to_string()))
+            .extends(Some("content".to_string()))
+            .timestamps(true)
+            .output_dir(&output)
+            .generate_content()
+            .unwrap();
+
+        assert!(content.contains("use super::content::Content;"));
+        assert!(content.contains("    // Inherited from Content\n    pub body: String,"));
+        assert!(content.contains(
+            "    #[tideorm(nullable)]\n    pub published_at: Option<chrono::DateTime<chrono::Utc>>,"
+        ));
+        let field_position = |field: &str| content.find(&format!("pub {}:", field)).unwrap();
+        assert!(field_position("id") < field_position("body"));
+        assert!(field_position("published_at") < field_position("slug"));
+        assert_eq!(content.matches("pub title: String").count(), 1);
+        assert_eq!(content.matches("pub created_at:").count(), 1);
+        assert!(content.contains(
+            "impl From<Content> for Post {\n    fn from(content: Content) -> Self {\n        Self {\n            body: content.body,\n            published_at: content.published_at,\n            ..Default::default()\n        }\n    }\n}\n"
+        ));
+
+        let error = ModelGenerator::new(&config)
+            .name("Video")
+            .extends(Some("Media".to_string()))
+            .output_dir(&output)
+            .generate_content()
+            .unwrap_err();
+        assert!(error.starts_with("Parent model file not found"));
+    }
 }
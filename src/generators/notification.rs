@@ -0,0 +1,339 @@
+//! Notification generator for TideORM CLI
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, inject_after_marker, to_snake_case, write_or_update_file};
+use std::path::Path;
+
+/// Written to the notifications mod.rs the first time a notification is generated
+const NOTIFIABLE_TRAIT: &str = r#"//! Notifications
+//!
+//! Auto-generated by TideORM CLI
+
+/// A recipient of notifications, such as a user
+pub trait Notifiable {
+    /// Where to deliver a notification on `channel` ("email", "slack", "database"),
+    /// or None to skip that channel for this recipient
+    fn route_notification_for(&self, channel: &str) -> Option<String>;
+}
+
+"#;
+
+/// Delivery channels a notification can be sent through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationChannel {
+    Email,
+    Slack,
+    Database,
+}
+
+impl NotificationChannel {
+    /// All supported channels in delivery order
+    pub const ALL: [NotificationChannel; 3] = [
+        NotificationChannel::Email,
+        NotificationChannel::Slack,
+        NotificationChannel::Database,
+    ];
+
+    /// Parse a single channel name
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "email" | "mail" => Ok(Self::Email),
+            "slack" => Ok(Self::Slack),
+            "database" | "db" => Ok(Self::Database),
+            other => Err(format!(
+                "Unknown notification channel: {}. Expected one of: email, slack, database",
+                other
+            )),
+        }
+    }
+
+    /// Parse a comma-separated list of channels, keeping delivery order
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, String> {
+        let mut requested = Vec::new();
+        for channel in s.split(',').map(str::trim).filter(|channel| !channel.is_empty()) {
+            requested.push(Self::parse(channel)?);
+        }
+
+        if requested.is_empty() {
+            return Err("At least one notification channel is required".to_string());
+        }
+
+        Ok(Self::ALL
+            .into_iter()
+            .filter(|channel| requested.contains(channel))
+            .collect())
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Slack => "slack",
+            Self::Database => "database",
+        }
+    }
+
+    fn summary(self) -> &'static str {
+        match self {
+            Self::Email => "Deliver the notification by email",
+            Self::Slack => "Post the notification to Slack",
+            Self::Database => "Store the notification in the database",
+        }
+    }
+
+    fn route(self) -> &'static str {
+        match self {
+            Self::Email => "address",
+            Self::Slack => "webhook_url",
+            Self::Database => "recipient_id",
+        }
+    }
+
+    fn todo(self) -> &'static str {
+        match self {
+            Self::Email => "TODO: Send `self.subject` and `self.body` to the address with your mailer",
+            Self::Slack => "TODO: POST `self.subject` and `self.body` to the Slack webhook URL",
+            Self::Database => "TODO: Save `self.subject` and `self.body` for the recipient, e.g. with a Notification model",
+        }
+    }
+}
+
+/// Notification generator
+pub struct NotificationGenerator<'a> {
+    config: &'a TideConfig,
+}
+
+impl<'a> NotificationGenerator<'a> {
+    /// Create a new notification generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self { config }
+    }
+
+    /// Generate a notification file
+    pub fn generate(
+        &self,
+        name: &str,
+        channels: &[NotificationChannel],
+        model: Option<&str>,
+        output: &str,
+    ) -> Result<String, String> {
+        if let Some(model) = model {
+            let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model));
+            if !Path::new(&model_path).exists() {
+                return Err(format!(
+                    "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                    model_path,
+                    to_pascal_case(model)
+                ));
+            }
+        }
+
+        ensure_directory(output)?;
+
+        let notification_name = if name.ends_with("Notification") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Notification", to_pascal_case(name))
+        };
+
+        let file_name = format!("{}.rs", to_snake_case(&notification_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_notification(&notification_name, channels, model);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write notification file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &notification_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate notification content
+    fn generate_notification(
+        &self,
+        notification_name: &str,
+        channels: &[NotificationChannel],
+        model: Option<&str>,
+    ) -> String {
+        let (model_import, model_field) = match model {
+            Some(model) => {
+                let model_pascal = to_pascal_case(model);
+                let model_snake = to_snake_case(model);
+                (
+                    format!("use crate::models::{}::{};\n", model_snake, model_pascal),
+                    format!(
+                        "    /// The {} this notification is about\n    pub {}: {},\n",
+                        model_pascal, model_snake, model_pascal
+                    ),
+                )
+            }
+            None => (String::new(), String::new()),
+        };
+
+        let channel_names = channels
+            .iter()
+            .map(|channel| channel.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let dispatch: String = channels
+            .iter()
+            .map(|channel| {
+                format!(
+                    r#"        if let Some({route}) = recipient.route_notification_for("{name}") {{
+            self.to_{name}(&{route}).await?;
+        }}
+"#,
+                    route = channel.route(),
+                    name = channel.name(),
+                )
+            })
+            .collect();
+
+        let methods = channels
+            .iter()
+            .map(|channel| {
+                format!(
+                    r#"    /// {summary}
+    pub async fn to_{name}(&self, _{route}: &str) -> tideorm::Result<()> {{
+        // {todo}
+        Ok(())
+    }}
+"#,
+                    summary = channel.summary(),
+                    name = channel.name(),
+                    route = channel.route(),
+                    todo = channel.todo(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"//! {notification_name}
+//!
+//! Notification delivered via {channel_names}.
+
+use super::Notifiable;
+{model_import}
+/// {notification_name}
+#[derive(Debug, Clone, Default)]
+pub struct {notification_name} {{
+    pub subject: String,
+    pub body: String,
+{model_field}}}
+
+impl {notification_name} {{
+    /// Deliver the notification on every channel the recipient has a route for
+    pub async fn send(&self, recipient: &dyn Notifiable) -> tideorm::Result<()> {{
+{dispatch}        Ok(())
+    }}
+
+{methods}}}
+"#,
+            notification_name = notification_name,
+            channel_names = channel_names,
+            model_import = model_import,
+            model_field = model_field,
+            dispatch = dispatch,
+            methods = methods,
+        )
+    }
+
+    /// Update mod.rs with the new notification, declaring `Notifiable` first if needed
+    fn update_mod_file(&self, output: &str, notification_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(notification_name);
+
+        write_or_update_file(&mod_path, NOTIFIABLE_TRAIT, "pub trait Notifiable")?;
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, notification_name),
+        )
+    }
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NotificationChannel, NotificationGenerator};
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn listed_channels_get_methods_and_dispatch() {
+        let config = TideConfig::default();
+        let channels = NotificationChannel::parse_list("database,email").unwrap();
+        let content = NotificationGenerator::new(&config).generate_notification(
+            "InvoicePaidNotification",
+            &channels,
+            None,
+        );
+
+        assert!(content.contains("//! Notification delivered via email, database."));
+        assert!(content.contains("pub struct InvoicePaidNotification {\n    pub subject: String,\n    pub body: String,\n}"));
+        assert!(content.contains("pub async fn send(&self, recipient: &dyn Notifiable) -> tideorm::Result<()>"));
+        assert!(content.contains(
+            "    pub async fn to_email(&self, _address: &str) -> tideorm::Result<()> {\n        // TODO: Send"
+        ));
+        assert!(content.contains(
+            "    pub async fn to_database(&self, _recipient_id: &str) -> tideorm::Result<()> {\n        // TODO: Save"
+        ));
+        assert!(content.contains("route_notification_for(\"email\")"));
+        assert!(content.contains("self.to_database(&recipient_id).await?;"));
+        assert!(!content.contains("to_slack"));
+        assert!(!content.contains("crate::models"));
+    }
+
+    #[test]
+    fn parse_list_rejects_unknown_and_empty_channels() {
+        assert!(NotificationChannel::parse_list("email,sms").unwrap_err().contains("Unknown notification channel: sms"));
+        assert!(NotificationChannel::parse_list(" , ").is_err());
+        assert_eq!(
+            NotificationChannel::parse_list("slack,mail,slack").unwrap(),
+            vec![NotificationChannel::Email, NotificationChannel::Slack]
+        );
+    }
+
+    #[test]
+    fn generate_declares_notifiable_once_and_embeds_model() {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        std::fs::create_dir_all(&config.paths.models).unwrap();
+        std::fs::write(dir.path().join("models/invoice.rs"), "pub struct Invoice {}").unwrap();
+        let output = dir.path().join("notifications").to_string_lossy().into_owned();
+
+        let generator = NotificationGenerator::new(&config);
+        let channels = [NotificationChannel::Slack];
+        let path = generator.generate("invoice_paid", &channels, Some("Invoice"), &output).unwrap();
+        generator.generate("OrderShipped", &channels, None, &output).unwrap();
+        generator.generate("OrderShipped", &channels, None, &output).unwrap();
+
+        assert!(path.ends_with("invoice_paid_notification.rs"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("use crate::models::invoice::Invoice;"));
+        assert!(content.contains("    pub invoice: Invoice,\n"));
+
+        let mod_content = std::fs::read_to_string(dir.path().join("notifications/mod.rs")).unwrap();
+        assert_eq!(mod_content.matches("pub trait Notifiable").count(), 1);
+        assert!(mod_content.ends_with(
+            "pub mod invoice_paid_notification;\npub use invoice_paid_notification::InvoicePaidNotification;\n\
+             pub mod order_shipped_notification;\npub use order_shipped_notification::OrderShippedNotification;\n"
+        ));
+
+        assert!(generator
+            .generate("Missing", &channels, Some("Ghost"), &output)
+            .unwrap_err()
+            .contains("Model file not found"));
+    }
+}
@@ -0,0 +1,368 @@
+//! Observer generator for TideORM CLI
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, inject_after_marker, to_snake_case, write_or_update_file};
+use std::path::Path;
+
+/// Model lifecycle events an observer can hook into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObserverEvent {
+    Creating,
+    Created,
+    Updating,
+    Updated,
+    Deleting,
+    Deleted,
+}
+
+impl ObserverEvent {
+    /// All supported events in lifecycle order
+    pub const ALL: [ObserverEvent; 6] = [
+        ObserverEvent::Creating,
+        ObserverEvent::Created,
+        ObserverEvent::Updating,
+        ObserverEvent::Updated,
+        ObserverEvent::Deleting,
+        ObserverEvent::Deleted,
+    ];
+
+    /// Parse a single event name
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "creating" => Ok(Self::Creating),
+            "created" => Ok(Self::Created),
+            "updating" => Ok(Self::Updating),
+            "updated" => Ok(Self::Updated),
+            "deleting" => Ok(Self::Deleting),
+            "deleted" => Ok(Self::Deleted),
+            other => Err(format!(
+                "Unknown observer event: {}. Expected one of: creating, created, updating, updated, deleting, deleted",
+                other
+            )),
+        }
+    }
+
+    /// Parse a comma-separated list of events, keeping lifecycle order
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, String> {
+        let mut requested = Vec::new();
+        for event in s.split(',').map(str::trim).filter(|event| !event.is_empty()) {
+            requested.push(Self::parse(event)?);
+        }
+
+        if requested.is_empty() {
+            return Err("At least one observer event is required".to_string());
+        }
+
+        Ok(Self::ALL
+            .into_iter()
+            .filter(|event| requested.contains(event))
+            .collect())
+    }
+
+    fn method_name(self) -> &'static str {
+        match self {
+            Self::Creating => "creating",
+            Self::Created => "created",
+            Self::Updating => "updating",
+            Self::Updated => "updated",
+            Self::Deleting => "deleting",
+            Self::Deleted => "deleted",
+        }
+    }
+
+    /// TideORM `Callbacks` method that runs this hook
+    fn callback_name(self) -> &'static str {
+        match self {
+            Self::Creating => "before_create",
+            Self::Created => "after_create",
+            Self::Updating => "before_update",
+            Self::Updated => "after_update",
+            Self::Deleting => "before_delete",
+            Self::Deleted => "after_delete",
+        }
+    }
+
+    /// Whether the hook runs before the write and may still modify the model
+    fn is_mutable(self) -> bool {
+        matches!(self, Self::Creating | Self::Updating)
+    }
+
+    fn summary(self) -> &'static str {
+        match self {
+            Self::Creating => "Called before a new record is inserted",
+            Self::Created => "Called after a new record has been inserted",
+            Self::Updating => "Called before an existing record is updated",
+            Self::Updated => "Called after an existing record has been updated",
+            Self::Deleting => "Called before a record is deleted",
+            Self::Deleted => "Called after a record has been deleted",
+        }
+    }
+
+    fn state_note(self) -> &'static str {
+        match self {
+            Self::Creating => {
+                "Model state: unsaved values; the primary key is not assigned yet. Changes made here are inserted, and an error aborts the insert."
+            }
+            Self::Created => "Model state: persisted, with the primary key assigned by the database.",
+            Self::Updating => {
+                "Model state: pending changes that have not been written yet. Changes made here are saved, and an error aborts the update."
+            }
+            Self::Updated => "Model state: the values that were just written to the database.",
+            Self::Deleting => "Model state: still persisted; returning an error here aborts the delete.",
+            Self::Deleted => "Model state: the last known values; the row no longer exists in the database.",
+        }
+    }
+}
+
+/// Observer generator
+pub struct ObserverGenerator<'a> {
+    config: &'a TideConfig,
+}
+
+impl<'a> ObserverGenerator<'a> {
+    /// Create a new observer generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self { config }
+    }
+
+    /// Generate an observer file
+    pub fn generate(
+        &self,
+        name: &str,
+        model: &str,
+        events: &[ObserverEvent],
+        output: &str,
+    ) -> Result<String, String> {
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model));
+        if !Path::new(&model_path).exists() {
+            return Err(format!(
+                "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                model_path,
+                to_pascal_case(model)
+            ));
+        }
+
+        let observer_name = if name.ends_with("Observer") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Observer", to_pascal_case(name))
+        };
+
+        let file_name = format!("{}.rs", to_snake_case(&observer_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        // A model has a single Callbacks impl, so a second one would not compile
+        let callbacks_impl = format!("Callbacks for {} {{", to_pascal_case(model));
+        let mut sources = vec![model_path];
+        if let Ok(entries) = std::fs::read_dir(output) {
+            sources.extend(
+                entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "rs") && !path.ends_with(&file_name))
+                    .map(|path| path.to_string_lossy().into_owned()),
+            );
+        }
+        for source in &sources {
+            if std::fs::read_to_string(source).is_ok_and(|content| content.contains(&callbacks_impl)) {
+                return Err(format!(
+                    "{} already implements Callbacks in {}; TideORM allows one impl per model",
+                    to_pascal_case(model),
+                    source
+                ));
+            }
+        }
+
+        ensure_directory(output)?;
+
+        let content = self.generate_observer(&observer_name, model, events);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write observer file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &observer_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate observer content
+    fn generate_observer(&self, observer_name: &str, model_name: &str, events: &[ObserverEvent]) -> String {
+        let model_pascal = to_pascal_case(model_name);
+        let model_snake = to_snake_case(model_name);
+
+        let methods = events
+            .iter()
+            .map(|event| {
+                let receiver = if event.is_mutable() {
+                    format!("_{}: &mut {}", model_snake, model_pascal)
+                } else {
+                    format!("_{}: &{}", model_snake, model_pascal)
+                };
+
+                format!(
+                    r#"    /// {summary}
+    pub fn {method}(&self, {receiver}) -> tideorm::Result<()> {{
+        // {note}
+        Ok(())
+    }}
+"#,
+                    summary = event.summary(),
+                    method = event.method_name(),
+                    receiver = receiver,
+                    note = event.state_note(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let callbacks = events
+            .iter()
+            .map(|event| {
+                let receiver = if event.is_mutable() { "&mut self" } else { "&self" };
+                format!(
+                    r#"    fn {callback}({receiver}) -> tideorm::Result<()> {{
+        {observer_name}.{method}(self)
+    }}
+"#,
+                    callback = event.callback_name(),
+                    receiver = receiver,
+                    observer_name = observer_name,
+                    method = event.method_name(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"//! {observer_name}
+//!
+//! Hooks into {model_pascal} model lifecycle events.
+
+use crate::models::{model_snake}::{model_pascal};
+use tideorm::callbacks::Callbacks;
+
+/// Observer for {model_pascal} lifecycle events
+#[derive(Debug, Default)]
+pub struct {observer_name};
+
+impl {observer_name} {{
+{methods}}}
+
+/// Runs {observer_name} from TideORM's save, update, and delete
+impl Callbacks for {model_pascal} {{
+{callbacks}}}
+"#,
+            observer_name = observer_name,
+            model_pascal = model_pascal,
+            model_snake = model_snake,
+            methods = methods,
+            callbacks = callbacks,
+        )
+    }
+
+    /// Update mod.rs with new observer
+    fn update_mod_file(&self, output: &str, observer_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(observer_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, observer_name),
+        )
+    }
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObserverEvent, ObserverGenerator};
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_observer_contains_all_requested_lifecycle_methods() {
+        let config = TideConfig::default();
+        let generator = ObserverGenerator::new(&config);
+        let events = ObserverEvent::parse_list("creating,created,updating,updated,deleting,deleted").unwrap();
+        let content = generator.generate_observer("UserObserver", "User", &events);
+
+        assert!(content.contains("pub struct UserObserver;"));
+        assert!(content.contains("use crate::models::user::User;"));
+        assert!(content.contains("pub fn creating(&self, _user: &mut User)"));
+        assert!(content.contains("pub fn created(&self, _user: &User)"));
+        assert!(content.contains("pub fn updating(&self, _user: &mut User)"));
+        assert!(content.contains("pub fn updated(&self, _user: &User)"));
+        assert!(content.contains("pub fn deleting(&self, _user: &User)"));
+        assert!(content.contains("pub fn deleted(&self, _user: &User)"));
+        assert_eq!(content.matches("// Model state:").count(), 6);
+
+        assert!(content.contains("impl Callbacks for User {"));
+        assert!(content.contains("    fn before_create(&mut self) -> tideorm::Result<()> {\n        UserObserver.creating(self)\n    }"));
+        assert!(content.contains("    fn after_delete(&self) -> tideorm::Result<()> {\n        UserObserver.deleted(self)\n    }"));
+    }
+
+    #[test]
+    fn generated_observer_only_contains_requested_events() {
+        let config = TideConfig::default();
+        let generator = ObserverGenerator::new(&config);
+        let events = ObserverEvent::parse_list("deleted, creating").unwrap();
+        let content = generator.generate_observer("PostObserver", "Post", &events);
+
+        assert!(content.contains("pub fn creating("));
+        assert!(content.contains("pub fn deleted("));
+        assert!(!content.contains("pub fn updated("));
+        assert!(!content.contains("fn after_update("));
+        assert!(content.find("fn creating(").unwrap() < content.find("fn deleted(").unwrap());
+    }
+
+    #[test]
+    fn unknown_events_are_rejected() {
+        assert!(ObserverEvent::parse_list("creating,saving").is_err());
+        assert!(ObserverEvent::parse_list(" , ").is_err());
+    }
+
+    #[test]
+    fn generate_requires_existing_model_file() {
+        let dir = tempdir().unwrap();
+        let models_dir = dir.path().join("models");
+        let observers_dir = dir.path().join("observers");
+        let observers_path = observers_dir.to_string_lossy().into_owned();
+
+        let mut config = TideConfig::default();
+        config.paths.models = models_dir.to_string_lossy().into_owned();
+        let generator = ObserverGenerator::new(&config);
+
+        let error = generator
+            .generate("User", "User", &ObserverEvent::ALL, &observers_path)
+            .unwrap_err();
+        assert!(error.contains("Model file not found"));
+        assert!(!observers_dir.exists());
+
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(models_dir.join("user.rs"), "pub struct User;\n").unwrap();
+
+        let path = generator
+            .generate("User", "User", &ObserverEvent::ALL, &observers_path)
+            .unwrap();
+        assert!(path.ends_with("user_observer.rs"));
+
+        let mod_content = std::fs::read_to_string(observers_dir.join("mod.rs")).unwrap();
+        assert!(mod_content.contains("pub mod user_observer;"));
+        assert!(mod_content.contains("pub use user_observer::UserObserver;"));
+
+        // Regenerating the same observer replaces its impl, but a second observer would add another
+        generator.generate("User", "User", &[ObserverEvent::Creating], &observers_path).unwrap();
+        let error = generator
+            .generate("Audit", "User", &ObserverEvent::ALL, &observers_path)
+            .unwrap_err();
+        assert!(error.starts_with("User already implements Callbacks in "));
+        assert!(!observers_dir.join("audit_observer.rs").exists());
+    }
+}
@@ -0,0 +1,387 @@
+//! Presenter generator for TideORM CLI
+//!
+//! Emits a view-model that wraps a model and formats its fields for display.
+
+use crate::commands::schema::model_fields;
+use crate::config::TideConfig;
+use crate::utils::{
+    ensure_directory, inject_after_marker, to_pascal_case, to_snake_case, write_or_update_file,
+    FieldDefinition,
+};
+
+/// Field types whose values implement `Display`
+const DISPLAY_TYPES: [&str; 14] = [
+    "string", "text", "i8", "i16", "i32", "i64", "f32", "f64", "time", "uuid", "decimal", "json",
+    "jsonb", "enum",
+];
+
+/// A formatting method generated for the presenter
+#[derive(Debug)]
+struct PresenterMethod {
+    name: String,
+    /// Model fields the method reads
+    sources: Vec<String>,
+    doc: String,
+    body: String,
+}
+
+/// Presenter generator
+pub struct PresenterGenerator<'a> {
+    config: &'a TideConfig,
+    fields: Option<Vec<String>>,
+}
+
+impl<'a> PresenterGenerator<'a> {
+    /// Create a new presenter generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            fields: None,
+        }
+    }
+
+    /// Only generate methods for these comma-separated fields or method names
+    pub fn fields(mut self, fields: Option<&str>) -> Self {
+        self.fields = fields.map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .map(ToOwned::to_owned)
+                .collect()
+        });
+        self
+    }
+
+    /// Generate a presenter file for an existing model
+    pub fn generate(&self, name: &str, model: &str, output: &str) -> Result<String, String> {
+        let model_pascal = to_pascal_case(model);
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model));
+        let model_content = std::fs::read_to_string(&model_path).map_err(|_| {
+            format!(
+                "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                model_path, model_pascal
+            )
+        })?;
+
+        let presenter_name = if name.ends_with("Presenter") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Presenter", to_pascal_case(name))
+        };
+
+        let methods = self.select_methods(presenter_methods(&model_fields(&model_content, &model_pascal)))?;
+
+        ensure_directory(output)?;
+
+        let file_name = format!("{}.rs", to_snake_case(&presenter_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = generate_presenter(&presenter_name, &model_pascal, &methods);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write presenter file: {}", e))?;
+
+        // Update mod.rs
+        update_mod_file(output, &presenter_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Keep the methods requested with `--fields`, or all of them
+    fn select_methods(&self, methods: Vec<PresenterMethod>) -> Result<Vec<PresenterMethod>, String> {
+        let Some(requested) = &self.fields else {
+            return Ok(methods);
+        };
+
+        let matches = |method: &PresenterMethod, field: &str| {
+            method.name == field || method.sources.len() == 1 && method.sources[0] == field
+        };
+
+        if let Some(unknown) = requested
+            .iter()
+            .find(|field| !methods.iter().any(|method| matches(method, field)))
+        {
+            return Err(format!(
+                "Unknown presenter field: {}. Available: {}",
+                unknown,
+                methods
+                    .iter()
+                    .map(|method| method.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        Ok(methods
+            .into_iter()
+            .filter(|method| requested.iter().any(|field| matches(method, field)))
+            .collect())
+    }
+}
+
+/// Formatting methods for the model's fields, in declaration order
+fn presenter_methods(fields: &[FieldDefinition]) -> Vec<PresenterMethod> {
+    let mut methods = Vec::new();
+
+    let is_plain_string = |name: &str| {
+        fields
+            .iter()
+            .any(|field| field.name == name && field.field_type == "string" && !field.nullable)
+    };
+    if is_plain_string("first_name") && is_plain_string("last_name") {
+        methods.push(PresenterMethod {
+            name: "full_name".to_string(),
+            sources: vec!["first_name".to_string(), "last_name".to_string()],
+            doc: "First and last name separated by a space".to_string(),
+            body: "format!(\"{} {}\", self.model.first_name, self.model.last_name).trim().to_string()"
+                .to_string(),
+        });
+    }
+
+    for field in fields.iter().filter(|field| !field.primary_key) {
+        let value = format!("self.model.{}", field.name);
+        let label = field.name.replace('_', " ");
+
+        let (name, doc, body) = match field.field_type.as_str() {
+            field_type @ ("datetime" | "date") => {
+                let format = if field_type == "date" { "%b %-d, %Y" } else { "%b %-d, %Y %H:%M" };
+                let body = if field.nullable {
+                    optional(&value, &format!("value.format({:?}).to_string()", format))
+                } else {
+                    format!("{}.format({:?}).to_string()", value, format)
+                };
+                (
+                    format!("{}_human", field.name),
+                    format!("{} formatted for people to read", capitalize(&label)),
+                    body,
+                )
+            }
+            "bool" => {
+                let body = if field.nullable {
+                    format!(
+                        "match {} {{\n            Some(true) => \"Yes\",\n            Some(false) => \"No\",\n            None => \"\",\n        }}\n        .to_string()",
+                        value
+                    )
+                } else {
+                    format!(
+                        "match {} {{\n            true => \"Yes\",\n            false => \"No\",\n        }}\n        .to_string()",
+                        value
+                    )
+                };
+                (field.name.clone(), format!("{} as Yes or No", capitalize(&label)), body)
+            }
+            field_type if DISPLAY_TYPES.contains(&field_type) => {
+                let body = match (field.nullable, field_type) {
+                    (true, _) => optional(&value, "value.to_string()"),
+                    (false, "string" | "text" | "enum") => format!("{}.clone()", value),
+                    (false, _) => format!("{}.to_string()", value),
+                };
+                (field.name.clone(), capitalize(&label), body)
+            }
+            _ => (
+                field.name.clone(),
+                capitalize(&label),
+                format!("format!(\"{{:?}}\", {})", value),
+            ),
+        };
+
+        methods.push(PresenterMethod {
+            name,
+            sources: vec![field.name.clone()],
+            doc,
+            body,
+        });
+    }
+
+    methods
+}
+
+/// Generate presenter content
+fn generate_presenter(presenter_name: &str, model_pascal: &str, methods: &[PresenterMethod]) -> String {
+    let model_snake = to_snake_case(model_pascal);
+
+    let methods: String = methods
+        .iter()
+        .map(|method| {
+            format!(
+                "\n    /// {doc}\n    pub fn {name}(&self) -> String {{\n        {body}\n    }}\n",
+                doc = method.doc,
+                name = method.name,
+                body = method.body,
+            )
+        })
+        .collect();
+
+    format!(
+        r#"//! {presenter_name}
+//!
+//! Formats {model_pascal} fields for display.
+
+use crate::models::{model_snake}::{model_pascal};
+
+/// View-model wrapping a {model_pascal}
+#[derive(Debug, Clone)]
+pub struct {presenter_name} {{
+    model: {model_pascal},
+}}
+
+impl {presenter_name} {{
+    /// Wrap a {model_pascal}
+    pub fn new(model: {model_pascal}) -> Self {{
+        Self {{ model }}
+    }}
+
+    /// The wrapped {model_pascal}
+    pub fn model(&self) -> &{model_pascal} {{
+        &self.model
+    }}
+
+    /// Unwrap the {model_pascal}
+    pub fn into_inner(self) -> {model_pascal} {{
+        self.model
+    }}
+{methods}}}
+
+impl From<{model_pascal}> for {presenter_name} {{
+    fn from(model: {model_pascal}) -> Self {{
+        Self::new(model)
+    }}
+}}
+"#,
+        presenter_name = presenter_name,
+        model_pascal = model_pascal,
+        model_snake = model_snake,
+        methods = methods,
+    )
+}
+
+/// Update mod.rs with new presenter
+fn update_mod_file(output: &str, presenter_name: &str) -> Result<(), String> {
+    let mod_path = format!("{}/mod.rs", output);
+    let module_name = to_snake_case(presenter_name);
+
+    let module_decl = format!("pub mod {};", module_name);
+    write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+    inject_after_marker(
+        &mod_path,
+        &module_decl,
+        &format!("pub use {}::{};", module_name, presenter_name),
+    )
+}
+
+/// Format an `Option` field with `some`, which reads `value`, or as an empty string
+fn optional(value: &str, some: &str) -> String {
+    format!(
+        "match &{} {{\n            Some(value) => {},\n            None => String::new(),\n        }}",
+        value, some
+    )
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PresenterGenerator;
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    const PERSON_MODEL: &str = r#"
+#[tideorm::model(table = "people")]
+pub struct Person {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    #[tideorm(nullable)]
+    pub avatar_url: Option<String>,
+    pub active: bool,
+    pub born_on: chrono::NaiveDate,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+"#;
+
+    fn project() -> (tempfile::TempDir, TideConfig, String) {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        std::fs::create_dir_all(&config.paths.models).unwrap();
+        std::fs::write(dir.path().join("models/person.rs"), PERSON_MODEL).unwrap();
+        let output = dir.path().join("presenters").to_string_lossy().into_owned();
+        (dir, config, output)
+    }
+
+    #[test]
+    fn presenter_wraps_model_and_formats_each_field() {
+        let (dir, config, output) = project();
+
+        let path = PresenterGenerator::new(&config).generate("Person", "Person", &output).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        assert!(path.ends_with("person_presenter.rs"));
+        assert!(content.contains("use crate::models::person::Person;"));
+        assert!(content.contains("pub struct PersonPresenter {\n    model: Person,\n}"));
+        assert!(content.contains(
+            "impl From<Person> for PersonPresenter {\n    fn from(model: Person) -> Self {\n        Self::new(model)\n    }\n}"
+        ));
+        assert!(content.contains(
+            "    pub fn full_name(&self) -> String {\n        format!(\"{} {}\", self.model.first_name, self.model.last_name)"
+        ));
+        assert!(content.contains(
+            "    pub fn avatar_url(&self) -> String {\n        match &self.model.avatar_url {\n            Some(value) => value.to_string(),\n            None => String::new(),\n        }\n    }"
+        ));
+        assert!(content.contains(
+            "    pub fn created_at_human(&self) -> String {\n        self.model.created_at.format(\"%b %-d, %Y %H:%M\").to_string()"
+        ));
+        assert!(content.contains("    pub fn born_on_human(&self) -> String {\n        self.model.born_on.format(\"%b %-d, %Y\")"));
+        assert!(content.contains("match self.model.active {\n            true => \"Yes\","));
+        assert!(!content.contains("pub fn id("));
+
+        let mod_content = std::fs::read_to_string(dir.path().join("presenters/mod.rs")).unwrap();
+        assert_eq!(mod_content, "pub mod person_presenter;\npub use person_presenter::PersonPresenter;\n");
+    }
+
+    #[test]
+    fn fields_limit_generated_methods() {
+        let (_dir, config, output) = project();
+
+        let path = PresenterGenerator::new(&config)
+            .fields(Some("full_name, created_at,avatar_url"))
+            .generate("PersonCard", "Person", &output)
+            .unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let methods: Vec<&str> = content
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("pub fn "))
+            .map(|line| line.split('(').next().unwrap())
+            .collect();
+        assert_eq!(
+            methods,
+            vec!["new", "model", "into_inner", "full_name", "avatar_url", "created_at_human"]
+        );
+        assert!(content.contains("pub struct PersonCardPresenter {"));
+
+        let error = PresenterGenerator::new(&config)
+            .fields(Some("nickname"))
+            .generate("Person", "Person", &output)
+            .unwrap_err();
+        assert!(error.contains("Unknown presenter field: nickname"), "{}", error);
+    }
+
+    #[test]
+    fn missing_model_is_an_error() {
+        let (_dir, config, output) = project();
+
+        let error = PresenterGenerator::new(&config)
+            .generate("Account", "Account", &output)
+            .unwrap_err();
+        assert!(error.contains("Model file not found"), "{}", error);
+    }
+}
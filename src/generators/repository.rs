@@ -0,0 +1,308 @@
+//! Repository generator for TideORM CLI
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, inject_after_marker, to_snake_case, write_or_update_file};
+use std::path::Path;
+
+/// Repository generator
+pub struct RepositoryGenerator<'a> {
+    config: &'a TideConfig,
+}
+
+impl<'a> RepositoryGenerator<'a> {
+    /// Create a new repository generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self { config }
+    }
+
+    /// Generate a repository file
+    pub fn generate(&self, name: &str, model: &str, output: &str) -> Result<String, String> {
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model));
+        if !Path::new(&model_path).exists() {
+            return Err(format!(
+                "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                model_path,
+                to_pascal_case(model)
+            ));
+        }
+
+        ensure_directory(output)?;
+
+        let repository_name = if name.ends_with("Repository") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Repository", to_pascal_case(name))
+        };
+
+        let file_name = format!("{}.rs", to_snake_case(&repository_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_repository(&repository_name, model);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write repository file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &repository_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate repository content
+    fn generate_repository(&self, repository_name: &str, model_name: &str) -> String {
+        let model_pascal = to_pascal_case(model_name);
+        let model_snake = to_snake_case(model_name);
+
+        format!(
+            r#"//! {repository_name}
+//!
+//! Data access for the {model_pascal} model.
+
+use crate::models::{model_snake}::{model_pascal};
+use std::sync::Arc;
+use tideorm::prelude::*;
+
+/// Primary key type of {model_pascal}
+pub type {model_pascal}Id = <{model_pascal} as ModelMeta>::PrimaryKey;
+
+/// Repository wrapping {model_pascal} queries
+///
+/// Uses the injected connection when one is given, otherwise the global one.
+#[derive(Clone, Default)]
+pub struct {repository_name} {{
+    db: Option<Arc<Database>>,
+}}
+
+impl {repository_name} {{
+    /// Create a repository bound to an explicit database connection
+    pub fn new(db: Arc<Database>) -> Self {{
+        Self {{ db: Some(db) }}
+    }}
+
+    /// Create a repository that uses the global database connection
+    pub fn global() -> Self {{
+        Self::default()
+    }}
+
+    fn query(&self) -> QueryBuilder<{model_pascal}> {{
+        match &self.db {{
+            Some(db) => {model_pascal}::query_with(db),
+            None => {model_pascal}::query(),
+        }}
+    }}
+
+    /// Find a record by primary key
+    pub async fn find_by_id(&self, id: {model_pascal}Id) -> tideorm::Result<Option<{model_pascal}>> {{
+        match &self.db {{
+            Some(db) => {model_pascal}::find_with(id, db).await,
+            None => {model_pascal}::find(id).await,
+        }}
+    }}
+
+    /// Load every record
+    pub async fn find_all(&self) -> tideorm::Result<Vec<{model_pascal}>> {{
+        self.query().get().await
+    }}
+
+    /// Load the records whose column equals the given value
+    pub async fn find_by(
+        &self,
+        column: &str,
+        value: impl Into<JsonValue>,
+    ) -> tideorm::Result<Vec<{model_pascal}>> {{
+        self.query().where_eq(column, value).get().await
+    }}
+
+    /// Insert a new record
+    pub async fn create(&self, model: {model_pascal}) -> tideorm::Result<{model_pascal}> {{
+        match &self.db {{
+            Some(db) => db.transaction(|_| Box::pin({model_pascal}::create(model))).await,
+            None => {model_pascal}::create(model).await,
+        }}
+    }}
+
+    /// Persist changes to an existing record
+    pub async fn update(&self, model: {model_pascal}) -> tideorm::Result<{model_pascal}> {{
+        match &self.db {{
+            Some(db) => db.transaction(|_| Box::pin(model.update())).await,
+            None => model.update().await,
+        }}
+    }}
+
+    /// Delete a record, returning the number of affected rows
+    pub async fn delete(&self, model: {model_pascal}) -> tideorm::Result<u64> {{
+        match &self.db {{
+            Some(db) => db.transaction(|_| Box::pin(model.delete())).await,
+            None => model.delete().await,
+        }}
+    }}
+
+    /// Count all records
+    pub async fn count(&self) -> tideorm::Result<u64> {{
+        self.query().count().await
+    }}
+}}
+
+#[cfg(test)]
+pub use mock::Mock{repository_name};
+
+#[cfg(test)]
+mod mock {{
+    use super::{{{model_pascal}, {model_pascal}Id}};
+    use std::sync::Mutex;
+    use tideorm::prelude::*;
+
+    /// In-memory stand-in for {repository_name} that serves canned records
+    #[derive(Default)]
+    pub struct Mock{repository_name} {{
+        records: Mutex<Vec<{model_pascal}>>,
+    }}
+
+    impl Mock{repository_name} {{
+        /// Create a mock that starts with the given records
+        pub fn with_records(records: Vec<{model_pascal}>) -> Self {{
+            Self {{
+                records: Mutex::new(records),
+            }}
+        }}
+
+        fn records(&self) -> std::sync::MutexGuard<'_, Vec<{model_pascal}>> {{
+            self.records.lock().unwrap()
+        }}
+
+        pub async fn find_by_id(&self, id: {model_pascal}Id) -> tideorm::Result<Option<{model_pascal}>> {{
+            let id = id.to_string();
+            Ok(self
+                .records()
+                .iter()
+                .find(|record| record.primary_key().to_string() == id)
+                .cloned())
+        }}
+
+        pub async fn find_all(&self) -> tideorm::Result<Vec<{model_pascal}>> {{
+            Ok(self.records().clone())
+        }}
+
+        pub async fn find_by(
+            &self,
+            column: &str,
+            value: impl Into<JsonValue>,
+        ) -> tideorm::Result<Vec<{model_pascal}>> {{
+            let value = value.into();
+            Ok(self
+                .records()
+                .iter()
+                .filter(|record| record.to_json(None).get(column) == Some(&value))
+                .cloned()
+                .collect())
+        }}
+
+        pub async fn create(&self, model: {model_pascal}) -> tideorm::Result<{model_pascal}> {{
+            self.records().push(model.clone());
+            Ok(model)
+        }}
+
+        pub async fn update(&self, model: {model_pascal}) -> tideorm::Result<{model_pascal}> {{
+            let id = model.primary_key().to_string();
+            let mut records = self.records();
+            if let Some(record) = records.iter_mut().find(|record| record.primary_key().to_string() == id) {{
+                *record = model.clone();
+            }}
+            Ok(model)
+        }}
+
+        pub async fn delete(&self, model: {model_pascal}) -> tideorm::Result<u64> {{
+            let id = model.primary_key().to_string();
+            let mut records = self.records();
+            let before = records.len();
+            records.retain(|record| record.primary_key().to_string() != id);
+            Ok((before - records.len()) as u64)
+        }}
+
+        pub async fn count(&self) -> tideorm::Result<u64> {{
+            Ok(self.records().len() as u64)
+        }}
+    }}
+}}
+"#,
+            repository_name = repository_name,
+            model_pascal = model_pascal,
+            model_snake = model_snake,
+        )
+    }
+
+    /// Update mod.rs with new repository
+    fn update_mod_file(&self, output: &str, repository_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(repository_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, repository_name),
+        )
+    }
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RepositoryGenerator;
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_repository_wraps_model_queries() {
+        let config = TideConfig::default();
+        let generator = RepositoryGenerator::new(&config);
+        let content = generator.generate_repository("UserRepository", "User");
+
+        assert!(content.contains("use crate::models::user::User;"));
+        assert!(content.contains("pub struct UserRepository {\n    db: Option<Arc<Database>>,\n}"));
+        assert!(content.contains("pub fn new(db: Arc<Database>) -> Self"));
+        for method in ["find_by_id", "find_all", "find_by", "create", "update", "delete", "count"] {
+            assert_eq!(
+                content.matches(&format!("pub async fn {}(", method)).count(),
+                2,
+                "expected {} on the repository and its mock",
+                method
+            );
+        }
+        assert!(content.contains("#[cfg(test)]\nmod mock {"));
+        assert!(content.contains("pub struct MockUserRepository {"));
+    }
+
+    #[test]
+    fn generate_requires_existing_model_file() {
+        let dir = tempdir().unwrap();
+        let models_dir = dir.path().join("models");
+        let repositories_dir = dir.path().join("repositories");
+        let repositories_path = repositories_dir.to_string_lossy().into_owned();
+
+        let mut config = TideConfig::default();
+        config.paths.models = models_dir.to_string_lossy().into_owned();
+        let generator = RepositoryGenerator::new(&config);
+
+        let error = generator.generate("User", "User", &repositories_path).unwrap_err();
+        assert!(error.contains("Model file not found"));
+        assert!(!repositories_dir.exists());
+
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(models_dir.join("user.rs"), "pub struct User;\n").unwrap();
+
+        let path = generator.generate("User", "User", &repositories_path).unwrap();
+        assert!(path.ends_with("user_repository.rs"));
+
+        let mod_content = std::fs::read_to_string(repositories_dir.join("mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod user_repository;\npub use user_repository::UserRepository;\n"
+        );
+    }
+}
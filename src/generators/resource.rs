@@ -0,0 +1,406 @@
+//! Resource generator for TideORM CLI
+//!
+//! Generates a model together with its migration, factory, seeder, controller and test,
+//! removing everything it wrote when one of them fails.
+
+use super::controller::{ControllerFramework, ControllerGenerator};
+use super::factory::FactoryGenerator;
+use super::migration::MigrationGenerator;
+use super::model::{ModelBase, ModelGenerator};
+use super::seeder::SeederGenerator;
+use super::test::TestGenerator;
+use crate::commands::make::prepare_model_migration_fields;
+use crate::config::TideConfig;
+use crate::utils::{pluralize_with_overrides, to_pascal_case, to_snake_case, validate_model_name};
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Parse a `--database` value into a `database.driver`
+pub fn parse_database(value: &str) -> Result<String, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "postgres" | "postgresql" => Ok("postgres".to_string()),
+        "mysql" => Ok("mysql".to_string()),
+        "sqlite" => Ok("sqlite".to_string()),
+        other => Err(format!("Unknown database '{}'; expected postgres, mysql or sqlite", other)),
+    }
+}
+
+/// A file of a resource and what it holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceFile {
+    pub kind: &'static str,
+    pub path: String,
+}
+
+/// Resource generator
+pub struct ResourceGenerator<'a> {
+    config: &'a TideConfig,
+    fields: Option<String>,
+    relations: Option<String>,
+    framework: ControllerFramework,
+}
+
+impl<'a> ResourceGenerator<'a> {
+    /// Create a new resource generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            fields: None,
+            relations: None,
+            framework: ControllerFramework::default(),
+        }
+    }
+
+    /// Model fields, as for `make model --fields`
+    pub fn fields(mut self, fields: Option<String>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Model relations, as for `make model --relations`
+    pub fn relations(mut self, relations: Option<String>) -> Self {
+        self.relations = relations;
+        self
+    }
+
+    /// Web framework of the controller
+    pub fn framework(mut self, framework: ControllerFramework) -> Self {
+        self.framework = framework;
+        self
+    }
+
+    /// Files `generate` would write, or an error naming the ones that already exist
+    ///
+    /// The migration's timestamp is only known once it is written, so its path shows a placeholder.
+    pub fn plan(&self, name: &str) -> Result<Vec<ResourceFile>, String> {
+        validate_model_name(name)?;
+
+        let paths = &self.config.paths;
+        let model_snake = to_snake_case(name);
+        let migration_name = format!("create_{}_table.rs", self.table_name(name));
+        let migration_file = if self.config.migration.timestamps {
+            format!("<timestamp>_{}", migration_name)
+        } else {
+            migration_name.clone()
+        };
+
+        let files = vec![
+            ResourceFile {
+                kind: "Model",
+                path: format!("{}/{}.rs", paths.models, model_snake),
+            },
+            ResourceFile {
+                kind: "Migration",
+                path: format!("{}/{}", paths.migrations, migration_file),
+            },
+            ResourceFile {
+                kind: "Factory",
+                path: format!("{}/{}_factory.rs", paths.factories, model_snake),
+            },
+            ResourceFile {
+                kind: "Seeder",
+                path: format!("{}/{}_seeder.rs", paths.seeders, model_snake),
+            },
+            ResourceFile {
+                kind: "Controller",
+                path: ControllerGenerator::file_path(name, &paths.controllers),
+            },
+            ResourceFile {
+                kind: "Test",
+                path: format!("{}/{}_test.rs", self.source_dir("tests"), model_snake),
+            },
+        ];
+
+        let mut conflicts: Vec<String> = files
+            .iter()
+            .filter(|file| Path::new(&file.path).exists())
+            .map(|file| file.path.clone())
+            .collect();
+        conflicts.extend(existing_migrations(&paths.migrations, &migration_name));
+
+        if !conflicts.is_empty() {
+            return Err(format!(
+                "Resource {} would overwrite existing files:\n  {}",
+                to_pascal_case(name),
+                conflicts.join("\n  ")
+            ));
+        }
+
+        Ok(files)
+    }
+
+    /// Write every file of the resource, or none of them
+    pub fn generate(&self, name: &str) -> Result<Vec<ResourceFile>, String> {
+        self.plan(name)?;
+
+        let paths = &self.config.paths;
+        let snapshot = OutputSnapshot::take(&[
+            &paths.models,
+            &paths.migrations,
+            &paths.factories,
+            &paths.seeders,
+            &paths.controllers,
+            &self.source_dir("tests"),
+        ]);
+
+        self.write_files(&to_pascal_case(name)).map_err(|error| match snapshot.restore() {
+            Ok(()) => format!("{}; no resource files were kept", error),
+            Err(restore_error) => format!("{}; undoing the written files also failed: {}", error, restore_error),
+        })
+    }
+
+    /// Run each generator in turn, the test last so it can use the factory and seeder
+    fn write_files(&self, name: &str) -> Result<Vec<ResourceFile>, String> {
+        let config = self.config;
+        let table = self.table_name(name);
+
+        let model = ModelGenerator::new(config)
+            .name(name)
+            .fields(self.fields.clone())
+            .relations(self.relations.clone())
+            .base(ModelBase::parse(&config.model.base_style)?)
+            .generate()?;
+
+        let migration_fields = prepare_model_migration_fields(
+            self.fields.clone(),
+            self.relations.as_deref(),
+            None,
+            None,
+            None,
+            &config.model.primary_key_type,
+        )?;
+        let migration = MigrationGenerator::new(config).generate(
+            &format!("create_{}_table", table),
+            Some(table),
+            None,
+            migration_fields,
+            config.model.timestamps,
+            config.model.soft_deletes,
+        )?;
+
+        let factory = FactoryGenerator::new(config).generate(&format!("{}Factory", name), Some(name.to_string()))?;
+        let seeder = SeederGenerator::new(config).generate(&format!("{}Seeder", name), Some(name.to_string()), 10)?;
+        let controller = ControllerGenerator::new(config)
+            .framework(self.framework)
+            .generate(name, &config.paths.controllers)?;
+        let test = TestGenerator::new(config).generate(name, &self.source_dir("tests"))?;
+
+        Ok([
+            ("Model", model),
+            ("Migration", migration),
+            ("Factory", factory),
+            ("Seeder", seeder),
+            ("Controller", controller),
+            ("Test", test),
+        ]
+        .into_iter()
+        .map(|(kind, path)| ResourceFile { kind, path })
+        .collect())
+    }
+
+    /// Directory next to the models one, e.g. `src/tests` for `src/models`
+    fn source_dir(&self, name: &str) -> String {
+        match Path::new(&self.config.paths.models).parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.join(name).to_string_lossy().into_owned(),
+            _ => name.to_string(),
+        }
+    }
+
+    fn table_name(&self, name: &str) -> String {
+        pluralize_with_overrides(&to_snake_case(name), &self.config.pluralization)
+    }
+}
+
+/// Migrations in `dir` that already create the table of `migration_name`, timestamped or not
+fn existing_migrations(dir: &str, migration_name: &str) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let timestamped = format!("_{}", migration_name);
+    let mut existing: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|file_name| file_name == migration_name || file_name.ends_with(&timestamped))
+        .map(|file_name| format!("{}/{}", dir, file_name))
+        .collect();
+    existing.sort();
+    existing
+}
+
+/// The output directories as they were before a resource was generated
+struct OutputSnapshot {
+    dirs: Vec<DirectoryState>,
+}
+
+enum DirectoryState {
+    /// Did not exist; `created` is its outermost ancestor that did not exist either
+    Missing { created: PathBuf },
+    /// Existed with these files, and this `mod.rs`, the only file generators change
+    Existing {
+        path: PathBuf,
+        files: HashSet<OsString>,
+        mod_rs: Option<Vec<u8>>,
+    },
+}
+
+impl OutputSnapshot {
+    fn take(dirs: &[&str]) -> Self {
+        let mut seen = HashSet::new();
+        let dirs = dirs
+            .iter()
+            .map(PathBuf::from)
+            .filter(|dir| seen.insert(dir.clone()))
+            .map(|path| {
+                if !path.exists() {
+                    let created = path
+                        .ancestors()
+                        .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+                        .last()
+                        .unwrap_or(&path)
+                        .to_path_buf();
+                    return DirectoryState::Missing { created };
+                }
+
+                let files = std::fs::read_dir(&path)
+                    .map(|entries| entries.filter_map(|entry| entry.ok()).map(|entry| entry.file_name()).collect())
+                    .unwrap_or_default();
+                let mod_rs = std::fs::read(path.join("mod.rs")).ok();
+                DirectoryState::Existing { path, files, mod_rs }
+            })
+            .collect();
+
+        Self { dirs }
+    }
+
+    /// Remove the files and directories written since the snapshot and restore each `mod.rs`
+    fn restore(&self) -> Result<(), String> {
+        for dir in &self.dirs {
+            match dir {
+                DirectoryState::Missing { created } => {
+                    if created.exists() {
+                        std::fs::remove_dir_all(created)
+                            .map_err(|e| format!("Failed to remove {}: {}", created.display(), e))?;
+                    }
+                }
+                DirectoryState::Existing { path, files, mod_rs } => {
+                    let Ok(entries) = std::fs::read_dir(path) else {
+                        continue;
+                    };
+                    for entry in entries.filter_map(|entry| entry.ok()) {
+                        if !files.contains(&entry.file_name()) {
+                            std::fs::remove_file(entry.path())
+                                .map_err(|e| format!("Failed to remove {}: {}", entry.path().display(), e))?;
+                        }
+                    }
+                    if let Some(mod_rs) = mod_rs {
+                        std::fs::write(path.join("mod.rs"), mod_rs)
+                            .map_err(|e| format!("Failed to restore {}/mod.rs: {}", path.display(), e))?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceGenerator;
+    use crate::config::TideConfig;
+    use std::path::Path;
+    use tempfile::{tempdir, TempDir};
+
+    /// A config whose paths live in `src` of a temporary directory
+    fn project() -> (TempDir, TideConfig) {
+        let dir = tempdir().unwrap();
+        let path = |name: &str| dir.path().join("src").join(name).to_string_lossy().into_owned();
+
+        let mut config = TideConfig::default();
+        config.paths.models = path("models");
+        config.paths.migrations = path("migrations");
+        config.paths.factories = path("factories");
+        config.paths.seeders = path("seeders");
+        config.paths.controllers = path("controllers");
+        (dir, config)
+    }
+
+    fn generator(config: &TideConfig) -> ResourceGenerator<'_> {
+        ResourceGenerator::new(config).fields(Some("title:string,body:text".to_string()))
+    }
+
+    #[test]
+    fn plan_lists_the_six_files_without_writing_them() {
+        let (dir, config) = project();
+        let files = generator(&config).plan("Post").unwrap();
+        let src = dir.path().join("src").to_string_lossy().into_owned();
+
+        let files: Vec<(&str, String)> = files
+            .iter()
+            .map(|file| (file.kind, file.path.replace(&src, "src")))
+            .collect();
+        assert_eq!(
+            files,
+            [
+                ("Model", "src/models/post.rs".to_string()),
+                ("Migration", "src/migrations/<timestamp>_create_posts_table.rs".to_string()),
+                ("Factory", "src/factories/post_factory.rs".to_string()),
+                ("Seeder", "src/seeders/post_seeder.rs".to_string()),
+                ("Controller", "src/controllers/post_controller.rs".to_string()),
+                ("Test", "src/tests/post_test.rs".to_string()),
+            ]
+        );
+        assert!(!dir.path().join("src").exists());
+    }
+
+    #[test]
+    fn generate_writes_every_file_and_registers_the_modules() {
+        let (dir, config) = project();
+        let files = generator(&config).generate("Post").unwrap();
+
+        assert_eq!(files.len(), 6);
+        assert!(files.iter().all(|file| Path::new(&file.path).exists()), "{:?}", files);
+        assert!(files[1].path.ends_with("_create_posts_table.rs"));
+        let test = std::fs::read_to_string(&files[5].path).unwrap();
+        assert!(test.contains("use crate::factories::post_factory::PostFactory;"), "{}", test);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("src/controllers/mod.rs")).unwrap(),
+            "pub mod post_controller;\n"
+        );
+    }
+
+    #[test]
+    fn generate_refuses_to_overwrite_existing_files() {
+        let (dir, config) = project();
+        std::fs::create_dir_all(dir.path().join("src/migrations")).unwrap();
+        std::fs::write(dir.path().join("src/migrations/20240101000000_create_posts_table.rs"), "").unwrap();
+
+        let error = generator(&config).generate("Post").unwrap_err();
+
+        assert!(error.starts_with("Resource Post would overwrite existing files:\n  "), "{}", error);
+        assert!(error.ends_with("src/migrations/20240101000000_create_posts_table.rs"), "{}", error);
+        assert!(!dir.path().join("src/models").exists());
+    }
+
+    #[test]
+    fn failed_generation_removes_the_files_already_written() {
+        let (dir, config) = project();
+        let models = dir.path().join("src/models");
+        std::fs::create_dir_all(&models).unwrap();
+        std::fs::write(models.join("mod.rs"), "pub mod user;\n").unwrap();
+        std::fs::write(models.join("user.rs"), "pub struct User;\n").unwrap();
+        // A file where the controllers directory should be makes the fifth generator fail
+        std::fs::write(dir.path().join("src/controllers"), "").unwrap();
+
+        let error = generator(&config).generate("Post").unwrap_err();
+
+        assert!(error.ends_with("; no resource files were kept"), "{}", error);
+        assert_eq!(std::fs::read_to_string(models.join("mod.rs")).unwrap(), "pub mod user;\n");
+        assert!(!models.join("post.rs").exists());
+        for created in ["migrations", "factories", "seeders", "tests"] {
+            assert!(!dir.path().join("src").join(created).exists(), "{}", created);
+        }
+    }
+}
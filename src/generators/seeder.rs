@@ -1,17 +1,136 @@
 //! Seeder generator for TideORM CLI
 
+use crate::commands::schema::{dependency_order, model_fields};
 use crate::config::TideConfig;
-use crate::utils::{ensure_directory, to_snake_case};
+use crate::utils::{
+    ensure_directory, parse_count, print_warning, singularize, to_snake_case, write_or_update_file, FieldDefinition,
+};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Columns maintained by TideORM that seeders leave at their defaults
+const MANAGED_COLUMNS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// How a model seeder fills in field values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SeedValues {
+    /// Values derived from the loop counter, such as "User 1"
+    #[default]
+    Sequential,
+    /// Random values from the `fake` crate
+    Faker,
+}
+
+/// Columns TideORM stamps on insert, which a snapshot can't restore
+const STAMPED_COLUMNS: [&str; 2] = ["created_at", "updated_at"];
+
+/// Rows captured from one table for a snapshot seeder
+pub struct TableSnapshot {
+    /// Table the rows were read from
+    pub table: String,
+    /// Rows as `(column, value)` pairs in column order
+    pub rows: Vec<Vec<(String, Value)>>,
+}
+
+/// Seeder running every seeder of a `make seeder-batch` definition file
+const BATCH_SEEDER: &str = "AllSeedersSeeder";
+
+/// One entry of a seed definition file, keyed by table name
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SeedDefinition {
+    /// Number of records to insert; accepts the same forms as `--count`
+    #[serde(deserialize_with = "deserialize_count")]
+    pub count: u32,
+    /// Values every record gets, in place of the generated ones
+    pub fields: BTreeMap<String, Value>,
+    /// Model to seed, when it isn't the singular of the table name
+    pub model: Option<String>,
+    /// Tables to seed first, besides the ones the model's `*_id` fields point at
+    pub depends_on: Vec<String>,
+}
+
+impl Default for SeedDefinition {
+    fn default() -> Self {
+        Self {
+            count: 10,
+            fields: BTreeMap::new(),
+            model: None,
+            depends_on: Vec::new(),
+        }
+    }
+}
+
+impl SeedDefinition {
+    /// Model seeded for `table`
+    pub fn model_name(&self, table: &str) -> String {
+        self.model
+            .clone()
+            .unwrap_or_else(|| to_pascal_case(&singularize(table)))
+    }
+}
+
+fn deserialize_count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u64),
+        Text(String),
+    }
+
+    let count = match Count::deserialize(deserializer)? {
+        Count::Number(number) => number.to_string(),
+        Count::Text(text) => text,
+    };
+    parse_count(&count).map_err(serde::de::Error::custom)
+}
+
+/// Read seed definitions from a TOML file, or YAML for `.yaml` and `.yml` files
+pub fn read_seed_definitions(path: &str) -> Result<BTreeMap<String, SeedDefinition>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read seed definition file {}: {}", path, e))?;
+
+    let definitions: BTreeMap<String, SeedDefinition> = if path.ends_with(".yaml") || path.ends_with(".yml") {
+        serde_yaml::from_str(&content).map_err(|e| format!("Invalid seed definition file {}: {}", path, e))?
+    } else {
+        toml::from_str(&content).map_err(|e| format!("Invalid seed definition file {}: {}", path, e))?
+    };
+
+    if definitions.is_empty() {
+        return Err(format!("No tables defined in {}", path));
+    }
+
+    Ok(definitions)
+}
 
 /// Seeder generator
 pub struct SeederGenerator<'a> {
     config: &'a TideConfig,
+    values: SeedValues,
+    truncate: bool,
 }
 
 impl<'a> SeederGenerator<'a> {
     /// Create a new seeder generator
     pub fn new(config: &'a TideConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            values: SeedValues::default(),
+            truncate: false,
+        }
+    }
+
+    /// Set how model seeders fill in field values
+    pub fn values(mut self, values: SeedValues) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Clear the model's table before seeding so re-runs don't duplicate rows
+    pub fn truncate(mut self, enabled: bool) -> Self {
+        self.truncate = enabled;
+        self
     }
 
     /// Generate a seeder file
@@ -23,17 +142,13 @@ impl<'a> SeederGenerator<'a> {
     ) -> Result<String, String> {
         ensure_directory(&self.config.paths.seeders)?;
 
-        let seeder_name = if name.ends_with("Seeder") {
-            to_pascal_case(name)
-        } else {
-            format!("{}Seeder", to_pascal_case(name))
-        };
-
+        let seeder_name = seeder_name(name);
         let file_name = format!("{}.rs", to_snake_case(&seeder_name));
         let file_path = format!("{}/{}", self.config.paths.seeders, file_name);
 
         let content = if let Some(model_name) = model {
-            self.generate_model_seeder(&seeder_name, &model_name, count)
+            let fields = self.model_fields(&model_name);
+            self.generate_model_seeder(&seeder_name, &model_name, count, &fields, &BTreeMap::new())?
         } else {
             self.generate_basic_seeder(&seeder_name)
         };
@@ -47,19 +162,254 @@ impl<'a> SeederGenerator<'a> {
         Ok(file_path)
     }
 
+    /// Generate a seeder that inserts the captured rows
+    ///
+    /// Returns the file path and the `table.column` names whose values could not be
+    /// written as Rust literals and were left out.
+    pub fn generate_snapshot(
+        &self,
+        name: &str,
+        snapshots: &[TableSnapshot],
+    ) -> Result<(String, Vec<String>), String> {
+        ensure_directory(&self.config.paths.seeders)?;
+
+        let seeder_name = seeder_name(name);
+        let file_path = format!("{}/{}.rs", self.config.paths.seeders, to_snake_case(&seeder_name));
+        let (content, skipped) = self.generate_snapshot_seeder(&seeder_name, snapshots);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write seeder file: {}", e))?;
+
+        self.update_mod_file(&seeder_name)?;
+
+        Ok((file_path, skipped))
+    }
+
+    /// Generate one model seeder per definition, plus an `AllSeedersSeeder` running them
+    ///
+    /// Seeders run parents first: a table comes after the tables in its `depends_on` and
+    /// the ones its model's `*_id` fields point at. Returns the paths of the seeders in
+    /// that order, the aggregate seeder last.
+    pub fn generate_batch(
+        &self,
+        source: &str,
+        definitions: &BTreeMap<String, SeedDefinition>,
+    ) -> Result<Vec<String>, String> {
+        ensure_directory(&self.config.paths.seeders)?;
+
+        let tables: Vec<String> = definitions.keys().cloned().collect();
+        let mut references: HashMap<String, Vec<String>> = HashMap::new();
+        let mut seeders = HashMap::new();
+
+        for (table, definition) in definitions {
+            let model_name = definition.model_name(table);
+            let fields = self.model_fields(&model_name);
+
+            for parent in &definition.depends_on {
+                if !definitions.contains_key(parent) {
+                    return Err(format!("{} depends on {}, which is not defined in {}", table, parent, source));
+                }
+            }
+            let parents = references.entry(table.clone()).or_default();
+            parents.extend(definition.depends_on.iter().cloned());
+            parents.extend(fields.iter().filter_map(|field| {
+                let parent_model = field.name.strip_suffix("_id")?;
+                definitions
+                    .iter()
+                    .find(|(other, other_definition)| {
+                        to_snake_case(&other_definition.model_name(other)) == parent_model
+                    })
+                    .map(|(other, _)| other.clone())
+            }));
+
+            let seeder_name = seeder_name(&model_name);
+            let content =
+                self.generate_model_seeder(&seeder_name, &model_name, definition.count, &fields, &definition.fields)?;
+            seeders.insert(table.clone(), (seeder_name, content));
+        }
+
+        let order = dependency_order(&tables, &references).unwrap_or_else(|| {
+            print_warning(&format!(
+                "Tables in {} depend on each other in a cycle; {} runs them alphabetically",
+                source, BATCH_SEEDER
+            ));
+            tables.clone()
+        });
+
+        let mut paths = Vec::new();
+        let mut seeder_names = Vec::new();
+        for table in &order {
+            let (seeder_name, content) = &seeders[table];
+            let file_path = format!("{}/{}.rs", self.config.paths.seeders, to_snake_case(seeder_name));
+            std::fs::write(&file_path, content)
+                .map_err(|e| format!("Failed to write seeder file: {}", e))?;
+            self.update_mod_file(seeder_name)?;
+
+            paths.push(file_path);
+            seeder_names.push(seeder_name.as_str());
+        }
+
+        let file_path = format!("{}/{}.rs", self.config.paths.seeders, to_snake_case(BATCH_SEEDER));
+        std::fs::write(&file_path, generate_batch_seeder(source, &seeder_names))
+            .map_err(|e| format!("Failed to write seeder file: {}", e))?;
+        self.update_mod_file(BATCH_SEEDER)?;
+        paths.push(file_path);
+
+        Ok(paths)
+    }
+
+    /// Fields of the model, or none when its file cannot be read
+    fn model_fields(&self, model_name: &str) -> Vec<FieldDefinition> {
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(model_name));
+
+        std::fs::read_to_string(model_path)
+            .map(|content| model_fields(&content, &to_pascal_case(model_name)))
+            .unwrap_or_default()
+    }
+
     /// Generate a seeder for a specific model
-    fn generate_model_seeder(&self, seeder_name: &str, model_name: &str, count: u32) -> String {
+    ///
+    /// `overrides` gives fields a fixed value in every record. Fields missing from the
+    /// model are typed from their values, which only works when the model can't be read.
+    fn generate_model_seeder(
+        &self,
+        seeder_name: &str,
+        model_name: &str,
+        count: u32,
+        fields: &[FieldDefinition],
+        overrides: &BTreeMap<String, Value>,
+    ) -> Result<String, String> {
         let model_snake = to_snake_case(model_name);
         let model_pascal = to_pascal_case(model_name);
 
-        format!(
+        let mut fixed = Vec::new();
+        for (name, value) in overrides {
+            let field = fields.iter().find(|field| &field.name == name);
+            if field.is_none() && !fields.is_empty() {
+                return Err(format!("{} has no field named {}", model_pascal, name));
+            }
+            let literal = snapshot_literal(field, value)
+                .ok_or_else(|| format!("Can't write {}.{} = {} as a Rust value", model_pascal, name, value))?;
+            fixed.push((name.as_str(), literal));
+        }
+
+        let generated: Vec<(&str, String)> = fields
+            .iter()
+            .filter(|field| {
+                !field.primary_key
+                    && !field.auto_increment
+                    && !MANAGED_COLUMNS.contains(&field.name.as_str())
+                    && !overrides.contains_key(&field.name)
+            })
+            .filter_map(|field| {
+                let value = match self.values {
+                    SeedValues::Sequential => sequential_value(field, &model_pascal, &model_snake),
+                    SeedValues::Faker => faker_value(field),
+                }?;
+                let value = if field.nullable {
+                    format!("Some({})", value)
+                } else {
+                    value
+                };
+                Some((field.name.as_str(), value))
+            })
+            .collect();
+        let assignments: Vec<(&str, String)> = fields
+            .iter()
+            .filter_map(|field| {
+                fixed
+                    .iter()
+                    .chain(&generated)
+                    .find(|(name, _)| *name == field.name)
+                    .cloned()
+            })
+            .chain(fixed.iter().filter(|(name, _)| fields.iter().all(|field| field.name != *name)).cloned())
+            .collect();
+
+        let (loop_var, field_lines) = if assignments.is_empty() {
+            (
+                "_i",
+                format!(
+                    r#"        // TODO: Fill in the model fields
+        // Example:
+        // name: format!("{model_pascal} {{}}", _i),
+        // email: format!("{model_snake}{{}}@example.com", _i),
+"#,
+                    model_pascal = model_pascal,
+                    model_snake = model_snake,
+                ),
+            )
+        } else {
+            let lines: String = assignments
+                .iter()
+                .map(|(name, value)| format!("        {}: {},\n", name, value))
+                .collect();
+            // Fixed values don't use the loop counter
+            let loop_var = if self.values == SeedValues::Faker || generated.is_empty() {
+                "_"
+            } else {
+                "i"
+            };
+            (loop_var, lines)
+        };
+
+        let faker_imports = if self.values == SeedValues::Faker && !generated.is_empty() {
+            faker_imports(&generated)
+        } else {
+            String::new()
+        };
+        let faker_note = if faker_imports.is_empty() {
+            ""
+        } else {
+            "//!\n//! Requires the `fake` crate: add `fake = \"4\"` to Cargo.toml.\n"
+        };
+
+        let insert_loop = format!(
+            r#"for {loop_var} in 1..={count} {{
+    let {model_snake} = {model_pascal} {{
+{field_lines}        ..Default::default()
+    }};
+
+    {model_snake}.save().await?;
+}}"#,
+            loop_var = loop_var,
+            count = count,
+            model_snake = model_snake,
+            model_pascal = model_pascal,
+            field_lines = field_lines,
+        );
+
+        // The delete and the inserts share a transaction, so a failed insert keeps the old rows
+        let (db_param, seed_body) = if self.truncate {
+            (
+                "db",
+                format!(
+                    r#"db.transaction(|_tx| {{
+            Box::pin(async move {{
+                {model_pascal}::delete_all().await?;
+
+{insert_loop}
+
+                Ok(())
+            }})
+        }})
+        .await?;"#,
+                    model_pascal = model_pascal,
+                    insert_loop = indent(&insert_loop, 16),
+                ),
+            )
+        } else {
+            ("_db", indent(&insert_loop, 8).trim_start().to_string())
+        };
+
+        Ok(format!(
             r#"//! {} Seeder
 //!
 //! Seeds the database with {} records.
-
+{faker_note}
 use tideorm::prelude::*;
 use crate::models::{model_snake}::{model_pascal};
-
+{faker_imports}
 /// {} seeder
 #[derive(Default)]
 pub struct {seeder_name};
@@ -70,20 +420,10 @@ impl Seed for {seeder_name} {{
         "{model_snake}_seeder"
     }}
 
-    async fn run(&self, _db: &Database) -> tideorm::Result<()> {{
+    async fn run(&self, {db_param}: &Database) -> tideorm::Result<()> {{
         println!("Seeding {model_snake}s...");
 
-        for _i in 1..={count} {{
-            let {model_snake} = {model_pascal} {{
-                // TODO: Fill in the model fields
-                // Example:
-                // name: format!("{model_pascal} {{}}", _i),
-                // email: format!("{model_snake}{{}}@example.com", _i),
-                ..Default::default()
-            }};
-
-            {model_snake}.save().await?;
-        }}
+        {seed_body}
 
         println!("Seeded {count} {model_snake}(s)");
         Ok(())
@@ -122,7 +462,105 @@ mod tests {{
             model_pascal = model_pascal,
             model_snake = model_snake,
             count = count,
-        )
+            db_param = db_param,
+            seed_body = seed_body,
+            faker_note = faker_note,
+            faker_imports = faker_imports,
+        ))
+    }
+
+    /// Generate a seeder with one `save` call per captured row
+    ///
+    /// Columns are typed from the table's model when its file exists, and from the
+    /// captured values otherwise. NULLs are left to the model's defaults.
+    fn generate_snapshot_seeder(&self, seeder_name: &str, snapshots: &[TableSnapshot]) -> (String, Vec<String>) {
+        let mut imports = String::new();
+        let mut inserts = Vec::new();
+        let mut skipped = Vec::new();
+
+        for snapshot in snapshots {
+            let model_pascal = to_pascal_case(&singularize(&snapshot.table));
+            let fields = self.model_fields(&model_pascal);
+            imports.push_str(&format!("use crate::models::{}::{};\n", to_snake_case(&model_pascal), model_pascal));
+            let mut saves = Vec::new();
+
+            for row in &snapshot.rows {
+                let mut lines = String::new();
+
+                for (column, value) in row {
+                    let field = fields.iter().find(|field| field.name == *column);
+                    let model_column = fields.is_empty() || field.is_some();
+                    let auto_increment = field.is_some_and(|field| field.primary_key && field.auto_increment);
+                    if !model_column || auto_increment || STAMPED_COLUMNS.contains(&column.as_str()) || value.is_null() {
+                        continue;
+                    }
+
+                    match snapshot_literal(field, value) {
+                        Some(literal) => lines.push_str(&format!("            {}: {},\n", column, literal)),
+                        None => {
+                            let name = format!("{}.{}", snapshot.table, column);
+                            if !skipped.contains(&name) {
+                                skipped.push(name);
+                            }
+                        }
+                    }
+                }
+
+                saves.push(format!(
+                    "        {} {{\n{}            ..Default::default()\n        }}\n        .save()\n        .await?;",
+                    model_pascal, lines
+                ));
+            }
+
+            inserts.push(format!(
+                "        // {}: {} row(s)\n{}",
+                snapshot.table,
+                snapshot.rows.len(),
+                saves.join("\n\n")
+            ));
+        }
+
+        let tables = snapshots
+            .iter()
+            .map(|snapshot| snapshot.table.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let content = format!(
+            r#"//! {seeder_name} Seeder
+//!
+//! Snapshot of the {tables} table(s), generated by `tideorm make seed-state`.
+
+use tideorm::prelude::*;
+{imports}
+/// {seeder_name}
+#[derive(Default)]
+pub struct {seeder_name};
+
+#[async_trait]
+impl Seed for {seeder_name} {{
+    fn name(&self) -> &str {{
+        "{seeder_snake}"
+    }}
+
+    async fn run(&self, _db: &Database) -> tideorm::Result<()> {{
+        println!("Running {seeder_name}...");
+
+{inserts}
+
+        println!("{seeder_name} completed!");
+        Ok(())
+    }}
+}}
+"#,
+            seeder_name = seeder_name,
+            seeder_snake = to_snake_case(seeder_name),
+            tables = tables,
+            imports = imports,
+            inserts = inserts.join("\n\n"),
+        );
+
+        (content, skipped)
     }
 
     /// Generate a basic seeder
@@ -187,41 +625,332 @@ mod tests {{
     /// Update mod.rs with new seeder
     fn update_mod_file(&self, seeder_name: &str) -> Result<(), String> {
         let mod_path = format!("{}/mod.rs", self.config.paths.seeders);
-        let module_name = to_snake_case(seeder_name);
+        let module_decl = format!("pub mod {};", to_snake_case(seeder_name));
 
-        let existing = std::fs::read_to_string(&mod_path).unwrap_or_default();
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)
+    }
+}
 
-        let module_decl = format!("pub mod {};", module_name);
-        if existing.contains(&module_decl) {
-            return Ok(());
-        }
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
 
-        let new_content = format!("{}{}\n", existing, module_decl);
+/// Seeder struct name, with the `Seeder` suffix added when missing
+fn seeder_name(name: &str) -> String {
+    if name.ends_with("Seeder") {
+        to_pascal_case(name)
+    } else {
+        format!("{}Seeder", to_pascal_case(name))
+    }
+}
 
-        std::fs::write(&mod_path, new_content)
-            .map_err(|e| format!("Failed to update mod.rs: {}", e))?;
+/// Seeder running the given seeders in order
+fn generate_batch_seeder(source: &str, seeder_names: &[&str]) -> String {
+    let imports: String = seeder_names
+        .iter()
+        .map(|seeder_name| format!("use super::{}::{};\n", to_snake_case(seeder_name), seeder_name))
+        .collect();
+    let calls: String = seeder_names
+        .iter()
+        .map(|seeder_name| format!("        {}::default().run(db).await?;\n", seeder_name))
+        .collect();
+
+    format!(
+        r#"//! {seeder_name}
+//!
+//! Runs the seeders generated from {source}, parents before the tables referencing them.
 
-        Ok(())
+use tideorm::prelude::*;
+{imports}
+/// Runs every seeder of {source}
+#[derive(Default)]
+pub struct {seeder_name};
+
+#[async_trait]
+impl Seed for {seeder_name} {{
+    fn name(&self) -> &str {{
+        "{seeder_snake}"
+    }}
+
+    async fn run(&self, db: &Database) -> tideorm::Result<()> {{
+{calls}        Ok(())
+    }}
+}}
+"#,
+        seeder_name = BATCH_SEEDER,
+        seeder_snake = to_snake_case(BATCH_SEEDER),
+        source = source,
+        imports = imports,
+        calls = calls,
+    )
+}
+
+/// Rust expression for a captured column value, or `None` when its type has no literal form
+///
+/// Without a model field the type is taken from the JSON value itself.
+fn snapshot_literal(field: Option<&FieldDefinition>, value: &Value) -> Option<String> {
+    let field_type = match (field, value) {
+        (Some(field), _) => field.field_type.as_str(),
+        (None, Value::String(_)) => "string",
+        (None, Value::Bool(_)) => "bool",
+        (None, Value::Number(number)) if number.is_f64() => "f64",
+        (None, Value::Number(_)) => "i64",
+        (None, _) => return None,
+    };
+
+    let literal = match (field_type, value) {
+        // Debug formatting escapes quotes, backslashes and control characters as Rust does
+        ("string", Value::String(text)) => format!("{:?}.to_string()", text),
+        ("string", Value::Number(number)) => format!("{:?}.to_string()", number.to_string()),
+        ("i8" | "i16" | "i32" | "i64", Value::Number(number)) if !number.is_f64() => number.to_string(),
+        ("f32" | "f64", Value::Number(number)) => format!("{:?}", number.as_f64()?),
+        ("bool", Value::Bool(flag)) => flag.to_string(),
+        // SQLite stores booleans as integers
+        ("bool", Value::Number(number)) => (number.as_i64()? != 0).to_string(),
+        ("datetime" | "date" | "time" | "uuid" | "decimal" | "json" | "jsonb", Value::String(text)) => {
+            format!("{:?}.parse().unwrap()", text)
+        }
+        ("json" | "jsonb", value) => format!("{:?}.parse().unwrap()", value.to_string()),
+        _ => return None,
+    };
+
+    Some(if field.is_some_and(|field| field.nullable) {
+        format!("Some({})", literal)
+    } else {
+        literal
+    })
+}
+
+/// Indent every non-empty line by `width` spaces
+fn indent(text: &str, width: usize) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", " ".repeat(width), line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Field value built from the loop counter `i`
+fn sequential_value(field: &FieldDefinition, model_pascal: &str, model_snake: &str) -> Option<String> {
+    match field.field_type.as_str() {
+        "string" if field.name.contains("email") => {
+            Some(format!("format!(\"{}{{}}@example.com\", i)", model_snake))
+        }
+        "string" if field.name == "name" => Some(format!("format!(\"{} {{}}\", i)", model_pascal)),
+        "string" => Some(format!("format!(\"{} {{}}\", i)", field.name)),
+        "i8" | "i16" | "i32" | "i64" | "f32" | "f64" => Some(format!("i as {}", field.field_type)),
+        _ => None,
     }
 }
 
-/// Convert string to PascalCase
-fn to_pascal_case(s: &str) -> String {
-    heck::AsPascalCase(s).to_string()
+/// Field value generated with the `fake` crate
+fn faker_value(field: &FieldDefinition) -> Option<String> {
+    let value = match field.field_type.as_str() {
+        "string" if field.name.contains("email") => "SafeEmail().fake()",
+        "string" if field.name.contains("name") => "Name().fake()",
+        "string" => "Word().fake()",
+        "i8" | "i16" | "i32" | "i64" => "(1..100).fake()",
+        "f32" | "f64" => "(1.0..100.0).fake()",
+        "bool" => "Boolean(50).fake()",
+        _ => return None,
+    };
+
+    Some(value.to_string())
+}
+
+/// `use` lines for the fakers referenced by the field assignments
+fn faker_imports(assignments: &[(&str, String)]) -> String {
+    let mut imports = vec!["use fake::Fake;".to_string()];
+
+    for (faker, path) in [
+        ("Boolean(", "fake::faker::boolean::en::Boolean"),
+        ("SafeEmail(", "fake::faker::internet::en::SafeEmail"),
+        ("Word(", "fake::faker::lorem::en::Word"),
+        ("Name(", "fake::faker::name::en::Name"),
+    ] {
+        if assignments.iter().any(|(_, value)| value.contains(faker)) {
+            imports.push(format!("use {};", path));
+        }
+    }
+
+    imports.join("\n") + "\n"
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SeederGenerator;
+    use super::{read_seed_definitions, SeedValues, SeederGenerator, TableSnapshot};
     use crate::config::TideConfig;
+    use crate::utils::FieldDefinition;
+    use serde_json::{json, Value};
+    use std::collections::BTreeMap;
 
     #[test]
     fn model_seeder_uses_global_db_helper_without_double_reference() {
         let config = TideConfig::default();
         let generator = SeederGenerator::new(&config);
-        let content = generator.generate_model_seeder("UserSeeder", "User", 10);
+        let content = generator
+            .generate_model_seeder("UserSeeder", "User", 10, &[], &BTreeMap::new())
+            .unwrap();
 
         assert!(content.contains("Self::default().run(db()).await"));
         assert!(!content.contains("run(&db())"));
+        assert!(content.contains("for _i in 1..=10 {"));
+    }
+
+    fn field(name: &str, field_type: &str, nullable: bool) -> FieldDefinition {
+        FieldDefinition {
+            name: name.to_string(),
+            field_type: field_type.to_string(),
+            nullable,
+            unique: false,
+            indexed: false,
+            primary_key: name == "id",
+            auto_increment: false,
+            default: None,
+            enum_variants: Vec::new(),
+            decimal_precision: None,
+            decimal_scale: None,
+            check: None,
+            generated: None,
+            index_where: None,
+            is_array: false,
+        }
+    }
+
+    fn user_fields() -> Vec<FieldDefinition> {
+        vec![
+            field("id", "i64", false),
+            field("name", "string", false),
+            field("email", "string", false),
+            field("bio", "string", true),
+            field("age", "i32", false),
+            field("active", "bool", false),
+            field("created_at", "datetime", false),
+        ]
+    }
+
+    #[test]
+    fn sequential_seeder_uses_loop_counter_in_field_values() {
+        let config = TideConfig::default();
+        let generator = SeederGenerator::new(&config);
+        let content = generator
+            .generate_model_seeder("UserSeeder", "User", 5, &user_fields(), &BTreeMap::new())
+            .unwrap();
+
+        assert!(content.contains("for i in 1..=5 {"));
+        assert!(content.contains("                name: format!(\"User {}\", i),\n"));
+        assert!(content.contains("                email: format!(\"user{}@example.com\", i),\n"));
+        assert!(content.contains("                bio: Some(format!(\"bio {}\", i)),\n"));
+        assert!(content.contains("                age: i as i32,\n"));
+        assert!(!content.contains("id: "));
+        assert!(!content.contains("active: "));
+        assert!(!content.contains("created_at: "));
+        assert!(!content.contains("use fake"));
+    }
+
+    #[test]
+    fn truncating_seeder_deletes_rows_before_the_insert_loop_in_a_transaction() {
+        let config = TideConfig::default();
+        let generator = SeederGenerator::new(&config).truncate(true);
+        let content = generator
+            .generate_model_seeder("UserSeeder", "User", 5, &user_fields(), &BTreeMap::new())
+            .unwrap();
+
+        assert!(content.contains("async fn run(&self, db: &Database)"));
+        assert!(content.contains("        db.transaction(|_tx| {\n            Box::pin(async move {\n"));
+        let truncate = content.find("                User::delete_all().await?;\n").unwrap();
+        let insert_loop = content.find("                for i in 1..=5 {\n").unwrap();
+        assert!(truncate < insert_loop);
+        assert!(content.contains("                        name: format!(\"User {}\", i),\n"));
+        assert!(content.contains("                    user.save().await?;\n"));
+        assert!(content.contains("                Ok(())\n            })\n        })\n        .await?;"));
+    }
+
+    #[test]
+    fn append_only_seeder_does_not_delete_rows() {
+        let config = TideConfig::default();
+        let generator = SeederGenerator::new(&config).truncate(false);
+        let content = generator
+            .generate_model_seeder("UserSeeder", "User", 5, &user_fields(), &BTreeMap::new())
+            .unwrap();
+
+        assert!(content.contains("async fn run(&self, _db: &Database)"));
+        assert!(content.contains("        for i in 1..=5 {\n            let user = User {\n"));
+        assert!(!content.contains("delete_all"));
+        assert!(!content.contains("transaction"));
+    }
+
+    #[test]
+    fn faker_seeder_imports_only_the_fakers_it_uses() {
+        let config = TideConfig::default();
+        let generator = SeederGenerator::new(&config).values(SeedValues::Faker);
+        let content = generator
+            .generate_model_seeder("UserSeeder", "User", 5, &user_fields(), &BTreeMap::new())
+            .unwrap();
+
+        assert!(content.contains("for _ in 1..=5 {"));
+        assert!(content.contains("name: Name().fake(),"));
+        assert!(content.contains("email: SafeEmail().fake(),"));
+        assert!(content.contains("bio: Some(Word().fake()),"));
+        assert!(content.contains("age: (1..100).fake(),"));
+        assert!(content.contains("active: Boolean(50).fake(),"));
+        assert!(content.contains("use fake::Fake;\nuse fake::faker::boolean::en::Boolean;"));
+        assert!(content.contains("`fake = \"4\"`"));
+    }
+
+    #[test]
+    fn snapshot_without_a_model_types_fields_from_the_values() {
+        let config = TideConfig::default();
+        let generator = SeederGenerator::new(&config);
+        let row = |values: Vec<(&str, Value)>| {
+            values
+                .into_iter()
+                .map(|(column, value)| (column.to_string(), value))
+                .collect::<Vec<_>>()
+        };
+        let snapshot = TableSnapshot {
+            table: "categories".to_string(),
+            rows: vec![row(vec![
+                ("id", json!(4)),
+                ("title", json!("Tab\there")),
+                ("ratio", json!(0.5)),
+                ("parent_id", Value::Null),
+                ("tags", json!(["a"])),
+                ("created_at", json!("2024-01-01 00:00:00")),
+            ])],
+        };
+
+        let (content, skipped) = generator.generate_snapshot_seeder("CategorySeeder", &[snapshot]);
+
+        assert!(content.contains("use crate::models::category::Category;\n"));
+        assert!(content.contains(
+            "        Category {\n            id: 4,\n            title: \"Tab\\there\".to_string(),\n            ratio: 0.5,\n            ..Default::default()\n"
+        ));
+        assert_eq!(skipped, vec!["categories.tags"]);
+    }
+
+    #[test]
+    fn seed_definitions_default_the_count_and_reject_unknown_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seeds.toml");
+        let path = path.to_str().unwrap();
+
+        std::fs::write(path, "[users]\n\n[posts]\ncount = \"2_500\"\ndepends_on = [\"users\"]\n").unwrap();
+        let definitions = read_seed_definitions(path).unwrap();
+        assert_eq!(definitions["users"].count, 10);
+        assert_eq!(definitions["users"].model_name("users"), "User");
+        assert_eq!(definitions["posts"].count, 2_500);
+        assert_eq!(definitions["posts"].depends_on, ["users"]);
+
+        for invalid in ["[users]\ncount = 0\n", "[users]\ncuont = 5\n", ""] {
+            std::fs::write(path, invalid).unwrap();
+            assert!(read_seed_definitions(path).is_err(), "{:?}", invalid);
+        }
     }
 }
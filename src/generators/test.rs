@@ -0,0 +1,309 @@
+//! Model test generator for TideORM CLI
+
+use crate::commands::schema::model_fields;
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, to_snake_case, write_or_update_file, FieldDefinition};
+use std::path::Path;
+
+/// Model test generator
+pub struct TestGenerator<'a> {
+    config: &'a TideConfig,
+}
+
+impl<'a> TestGenerator<'a> {
+    /// Create a new test generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self { config }
+    }
+
+    /// Generate a test module for an existing model
+    pub fn generate(&self, model: &str, output: &str) -> Result<String, String> {
+        let model_pascal = to_pascal_case(model);
+        let model_snake = to_snake_case(model);
+
+        let model_path = format!("{}/{}.rs", self.config.paths.models, model_snake);
+        let model_content = std::fs::read_to_string(&model_path).map_err(|_| {
+            format!(
+                "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                model_path, model_pascal
+            )
+        })?;
+
+        ensure_directory(output)?;
+
+        let module_name = format!("{}_test", model_snake);
+        let file_path = format!("{}/{}.rs", output, module_name);
+
+        let fixtures = Fixtures {
+            factory: Path::new(&format!("{}/{}_factory.rs", self.config.paths.factories, model_snake))
+                .exists(),
+            seeder: Path::new(&format!("{}/{}_seeder.rs", self.config.paths.seeders, model_snake))
+                .exists(),
+        };
+
+        let fields = model_fields(&model_content, &model_pascal);
+        let content = self.generate_test(&model_pascal, &fields, fixtures);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write test file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &module_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate test module content
+    fn generate_test(&self, model_name: &str, fields: &[FieldDefinition], fixtures: Fixtures) -> String {
+        let model_pascal = to_pascal_case(model_name);
+        let model_snake = to_snake_case(model_name);
+
+        let mut imports = vec![format!("use crate::models::{model_snake}::{model_pascal};")];
+        if fixtures.factory {
+            imports.insert(
+                0,
+                format!("use crate::factories::{model_snake}_factory::{model_pascal}Factory;"),
+            );
+        }
+        if fixtures.seeder {
+            imports.push(format!("use crate::seeders::{model_snake}_seeder::{model_pascal}Seeder;"));
+        }
+
+        let fixture = if fixtures.factory {
+            format!("{model_pascal}Factory::create().await")
+        } else {
+            format!("{model_pascal}::default().save().await")
+        };
+
+        // The first plain string column gives the update test something to change
+        let updated_field = fields.iter().find(|field| {
+            field.field_type == "string" && !field.nullable && !field.primary_key
+        });
+        let (update_assignment, update_assertion) = match updated_field {
+            Some(field) => (
+                format!("    {model_snake}.{} = \"Updated\".to_string();\n", field.name),
+                format!("    assert_eq!(found.{}, \"Updated\");\n", field.name),
+            ),
+            None => (
+                "    // TODO: Change a field before saving\n".to_string(),
+                format!("    assert_eq!(found.primary_key(), {model_snake}.primary_key());\n"),
+            ),
+        };
+        let update_binding = if updated_field.is_some() { "let mut" } else { "let" };
+
+        let seeder_test = if fixtures.seeder {
+            format!(
+                r#"
+#[tokio::test]
+async fn test_{model_snake}_seeder() {{
+    let _dir = setup().await;
+
+    {model_pascal}Seeder::default().run(db()).await.expect("seeder should run");
+
+    let count = {model_pascal}::query().count().await.expect("{model_snake}s should be counted");
+    assert!(count > 0);
+}}
+"#
+            )
+        } else {
+            String::new()
+        };
+
+        format!(
+            r#"//! {model_pascal} model tests
+//!
+//! Each test runs against its own SQLite database in a temporary directory.
+
+{imports}
+use tempfile::TempDir;
+use tideorm::prelude::*;
+
+/// Connect to a fresh database with the {model_snake} table synced from the model
+///
+/// The database is removed when the returned directory is dropped.
+async fn setup() -> TempDir {{
+    let dir = TempDir::new().expect("temporary directory should be created");
+    let url = format!("sqlite://{{}}?mode=rwc", dir.path().join("test.db").display());
+
+    TideConfig::init()
+        .database_type(DatabaseType::SQLite)
+        .database(&url)
+        .max_connections(1)
+        .sync(true)
+        .models::<({model_pascal},)>()
+        .connect()
+        .await
+        .expect("test database should connect");
+
+    dir
+}}
+
+/// Create a saved {model_pascal} fixture
+async fn create_{model_snake}() -> {model_pascal} {{
+    {fixture}.expect("{model_snake} should be created")
+}}
+
+#[tokio::test]
+async fn test_create_{model_snake}() {{
+    let _dir = setup().await;
+
+    create_{model_snake}().await;
+
+    let count = {model_pascal}::query().count().await.expect("{model_snake}s should be counted");
+    assert_eq!(count, 1);
+}}
+
+#[tokio::test]
+async fn test_find_{model_snake}() {{
+    let _dir = setup().await;
+    let {model_snake} = create_{model_snake}().await;
+
+    let found = {model_pascal}::find({model_snake}.primary_key())
+        .await
+        .expect("{model_snake} should be queried");
+
+    assert!(found.is_some());
+}}
+
+#[tokio::test]
+async fn test_update_{model_snake}() {{
+    let _dir = setup().await;
+    {update_binding} {model_snake} = create_{model_snake}().await;
+
+{update_assignment}    let {model_snake} = {model_snake}.update().await.expect("{model_snake} should be updated");
+
+    let found = {model_pascal}::find({model_snake}.primary_key())
+        .await
+        .expect("{model_snake} should be queried")
+        .expect("{model_snake} should exist");
+{update_assertion}}}
+
+#[tokio::test]
+async fn test_delete_{model_snake}() {{
+    let _dir = setup().await;
+    let {model_snake} = create_{model_snake}().await;
+    let id = {model_snake}.primary_key();
+
+    let deleted = {model_snake}.delete().await.expect("{model_snake} should be deleted");
+
+    assert_eq!(deleted, 1);
+    assert!({model_pascal}::find(id).await.expect("{model_snake} should be queried").is_none());
+}}
+{seeder_test}"#,
+            imports = imports.join("\n"),
+            model_pascal = model_pascal,
+            model_snake = model_snake,
+            fixture = fixture,
+            update_binding = update_binding,
+            update_assignment = update_assignment,
+            update_assertion = update_assertion,
+            seeder_test = seeder_test,
+        )
+    }
+
+    /// Update mod.rs with new test module
+    fn update_mod_file(&self, output: &str, module_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_decl = format!("pub mod {};", module_name);
+
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)
+    }
+}
+
+/// Generated files the test module can build fixtures from
+#[derive(Debug, Clone, Copy, Default)]
+struct Fixtures {
+    factory: bool,
+    seeder: bool,
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fixtures, TestGenerator};
+    use crate::config::TideConfig;
+    use crate::utils::FieldDefinition;
+    use tempfile::tempdir;
+
+    #[test]
+    fn generated_test_covers_crud_with_factory_and_seeder_fixtures() {
+        let config = TideConfig::default();
+        let generator = TestGenerator::new(&config);
+        let fields = vec![
+            FieldDefinition::parse("id:i64").unwrap(),
+            FieldDefinition::parse("name:string").unwrap(),
+        ];
+        let content = generator.generate_test(
+            "User",
+            &fields,
+            Fixtures {
+                factory: true,
+                seeder: true,
+            },
+        );
+
+        assert!(content.contains("use crate::factories::user_factory::UserFactory;"));
+        assert!(content.contains("use crate::seeders::user_seeder::UserSeeder;"));
+        assert!(content.contains("use tempfile::TempDir;"));
+        assert!(content.contains(".models::<(User,)>()"));
+        assert!(content.contains("UserFactory::create().await.expect(\"user should be created\")"));
+        for test in ["test_create_user", "test_find_user", "test_update_user", "test_delete_user", "test_user_seeder"] {
+            assert!(content.contains(&format!("#[tokio::test]\nasync fn {}() {{", test)));
+        }
+        assert!(content.contains("    user.name = \"Updated\".to_string();"));
+        assert!(content.contains("    assert_eq!(found.name, \"Updated\");"));
+    }
+
+    #[test]
+    fn generated_test_saves_defaults_without_factory() {
+        let config = TideConfig::default();
+        let generator = TestGenerator::new(&config);
+        let content = generator.generate_test("Tag", &[], Fixtures::default());
+
+        assert!(content.contains("Tag::default().save().await"));
+        assert!(!content.contains("Factory"));
+        assert!(!content.contains("Seeder"));
+        assert!(content.contains("    let tag = create_tag().await;\n\n    // TODO: Change a field before saving"));
+    }
+
+    #[test]
+    fn generate_requires_model_and_registers_module() {
+        let dir = tempdir().unwrap();
+        let models_dir = dir.path().join("models");
+        let factories_dir = dir.path().join("factories");
+        let tests_dir = dir.path().join("tests");
+        let tests_path = tests_dir.to_string_lossy().into_owned();
+
+        let mut config = TideConfig::default();
+        config.paths.models = models_dir.to_string_lossy().into_owned();
+        config.paths.factories = factories_dir.to_string_lossy().into_owned();
+        config.paths.seeders = dir.path().join("seeders").to_string_lossy().into_owned();
+        let generator = TestGenerator::new(&config);
+
+        let error = generator.generate("User", &tests_path).unwrap_err();
+        assert!(error.contains("Model file not found"));
+        assert!(!tests_dir.exists());
+
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(models_dir.join("user.rs"), "pub struct User {\n    pub email: String,\n}\n").unwrap();
+        std::fs::create_dir_all(&factories_dir).unwrap();
+        std::fs::write(factories_dir.join("user_factory.rs"), "").unwrap();
+
+        let path = generator.generate("user", &tests_path).unwrap();
+        generator.generate("User", &tests_path).unwrap();
+
+        assert!(path.ends_with("user_test.rs"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("UserFactory::create()"));
+        assert!(content.contains("user.email = \"Updated\".to_string();"));
+        assert!(!content.contains("UserSeeder"));
+        assert_eq!(
+            std::fs::read_to_string(tests_dir.join("mod.rs")).unwrap(),
+            "pub mod user_test;\n"
+        );
+    }
+}
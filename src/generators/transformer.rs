@@ -0,0 +1,227 @@
+//! Transformer generator for TideORM CLI
+//!
+//! Emits a struct that maps a model into another type, one item or a slice at a time.
+
+use crate::config::TideConfig;
+use crate::utils::{ensure_directory, inject_after_marker, to_pascal_case, to_snake_case, write_or_update_file};
+
+/// Marker trait shared by the transformers of an output directory
+const TRANSFORMER_TRAIT: &str = r#"//! Transformer
+//!
+//! Marker trait implemented by the generated transformers.
+
+/// Marks a type that turns `I` values into `O` values
+pub trait Transformer<I, O> {}
+"#;
+
+/// Transformer generator
+pub struct TransformerGenerator<'a> {
+    config: &'a TideConfig,
+    batch_size: Option<u32>,
+}
+
+impl<'a> TransformerGenerator<'a> {
+    /// Create a new transformer generator
+    pub fn new(config: &'a TideConfig) -> Self {
+        Self {
+            config,
+            batch_size: None,
+        }
+    }
+
+    /// Also emit `transform_batches`, which transforms items in chunks of this size
+    pub fn batch_size(mut self, batch_size: Option<u32>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Generate a transformer file for an existing model
+    pub fn generate(&self, name: &str, from_model: &str, to_type: &str, output: &str) -> Result<String, String> {
+        let to_type = to_type.trim();
+        if to_type.is_empty() {
+            return Err("--to-type is required".to_string());
+        }
+
+        let model_pascal = to_pascal_case(from_model);
+        let model_path = format!("{}/{}.rs", self.config.paths.models, to_snake_case(from_model));
+        if !std::path::Path::new(&model_path).exists() {
+            return Err(format!(
+                "Model file not found: {}. Generate it first with 'tideorm make model {}'",
+                model_path, model_pascal
+            ));
+        }
+
+        let transformer_name = if name.ends_with("Transformer") {
+            to_pascal_case(name)
+        } else {
+            format!("{}Transformer", to_pascal_case(name))
+        };
+
+        ensure_directory(output)?;
+
+        let file_path = format!("{}/{}.rs", output, to_snake_case(&transformer_name));
+        let content = self.generate_transformer(&transformer_name, &model_pascal, to_type);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write transformer file: {}", e))?;
+
+        // The trait goes first so mod.rs declares it before the transformers using it
+        self.write_transformer_trait(output)?;
+        self.update_mod_file(output, &transformer_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate transformer content
+    fn generate_transformer(&self, transformer_name: &str, model_pascal: &str, to_type: &str) -> String {
+        let batches = match self.batch_size {
+            Some(batch_size) => format!(
+                r#"
+
+    /// Items per chunk of `transform_batches`
+    pub const BATCH_SIZE: usize = {batch_size};
+
+    /// Transform the items in chunks of `BATCH_SIZE`, one `Vec` per chunk
+    pub fn transform_batches(&self, items: &[{model_pascal}]) -> Vec<Vec<{to_type}>> {{
+        items
+            .chunks(Self::BATCH_SIZE)
+            .map(|chunk| self.transform_many(chunk))
+            .collect()
+    }}"#,
+                batch_size = batch_size,
+                model_pascal = model_pascal,
+                to_type = to_type,
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            r#"//! {transformer_name}
+//!
+//! Transforms `{model_pascal}` models into `{to_type}`.
+
+use super::Transformer;
+use crate::models::{model_snake}::{model_pascal};
+
+/// Transforms `{model_pascal}` models into `{to_type}`
+#[derive(Debug, Clone, Default)]
+pub struct {transformer_name};
+
+impl {transformer_name} {{
+    /// Transform one model
+    pub fn transform(&self, input: &{model_pascal}) -> {to_type} {{
+        let _ = input;
+        todo!("{transformer_name}::transform")
+    }}
+
+    /// Transform every item, keeping their order
+    pub fn transform_many(&self, items: &[{model_pascal}]) -> Vec<{to_type}> {{
+        items.iter().map(|item| self.transform(item)).collect()
+    }}{batches}
+}}
+
+impl Transformer<{model_pascal}, {to_type}> for {transformer_name} {{}}
+"#,
+            transformer_name = transformer_name,
+            model_pascal = model_pascal,
+            model_snake = to_snake_case(model_pascal),
+            to_type = to_type,
+            batches = batches,
+        )
+    }
+
+    /// Write the `Transformer` trait next to the transformers unless it is already there
+    fn write_transformer_trait(&self, output: &str) -> Result<(), String> {
+        let trait_path = format!("{}/transformer.rs", output);
+        if !std::path::Path::new(&trait_path).exists() {
+            std::fs::write(&trait_path, TRANSFORMER_TRAIT)
+                .map_err(|e| format!("Failed to write transformer trait: {}", e))?;
+        }
+
+        self.update_mod_file(output, "Transformer")
+    }
+
+    /// Update mod.rs with a module and re-export its type
+    fn update_mod_file(&self, output: &str, type_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(type_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{};", module_name, type_name),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransformerGenerator;
+    use crate::config::TideConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn transformer_implements_the_marker_trait() {
+        let config = TideConfig::default();
+        let content = TransformerGenerator::new(&config).generate_transformer(
+            "UserTransformer",
+            "User",
+            "crate::dtos::UserResponseDto",
+        );
+
+        assert!(content.contains("use super::Transformer;\nuse crate::models::user::User;"));
+        assert!(content.contains("#[derive(Debug, Clone, Default)]\npub struct UserTransformer;"));
+        assert!(content.contains(
+            "impl Transformer<User, crate::dtos::UserResponseDto> for UserTransformer {}"
+        ));
+        assert!(content.contains(
+            "pub fn transform(&self, input: &User) -> crate::dtos::UserResponseDto {\n        let _ = input;\n        todo!(\"UserTransformer::transform\")"
+        ));
+        assert!(content.contains(
+            "pub fn transform_many(&self, items: &[User]) -> Vec<crate::dtos::UserResponseDto> {\n        items.iter().map(|item| self.transform(item)).collect()"
+        ));
+        assert!(!content.contains("transform_batches"));
+    }
+
+    #[test]
+    fn batch_size_adds_chunked_transform() {
+        let config = TideConfig::default();
+        let content = TransformerGenerator::new(&config)
+            .batch_size(Some(250))
+            .generate_transformer("UserTransformer", "User", "String");
+
+        assert!(content.contains("pub const BATCH_SIZE: usize = 250;"));
+        assert!(content.contains("pub fn transform_batches(&self, items: &[User]) -> Vec<Vec<String>> {"));
+        assert!(content.contains(".chunks(Self::BATCH_SIZE)\n            .map(|chunk| self.transform_many(chunk))"));
+    }
+
+    #[test]
+    fn generate_writes_the_trait_once_and_needs_the_model() {
+        let dir = tempdir().unwrap();
+        let mut config = TideConfig::default();
+        config.paths.models = dir.path().join("models").to_string_lossy().into_owned();
+        let output = dir.path().join("transformers").to_string_lossy().into_owned();
+        let generator = TransformerGenerator::new(&config);
+
+        assert!(generator
+            .generate("User", "User", "String", &output)
+            .unwrap_err()
+            .starts_with("Model file not found"));
+
+        std::fs::create_dir_all(&config.paths.models).unwrap();
+        std::fs::write(format!("{}/user.rs", config.paths.models), "pub struct User;\n").unwrap();
+        let path = generator.generate("User", "User", "String", &output).unwrap();
+        generator.generate("UserSummaryTransformer", "User", "String", &output).unwrap();
+
+        assert!(path.ends_with("user_transformer.rs"));
+        assert!(std::fs::read_to_string(dir.path().join("transformers/transformer.rs"))
+            .unwrap()
+            .contains("pub trait Transformer<I, O> {}"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("transformers/mod.rs")).unwrap(),
+            "pub mod transformer;\npub use transformer::Transformer;\npub mod user_transformer;\npub use user_transformer::UserTransformer;\npub mod user_summary_transformer;\npub use user_summary_transformer::UserSummaryTransformer;\n"
+        );
+    }
+}
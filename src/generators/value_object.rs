@@ -0,0 +1,503 @@
+//! Value object generator for TideORM CLI
+
+use crate::utils::{ensure_directory, inject_after_marker, to_snake_case, write_or_update_file};
+
+/// One check run by the generated `try_new`
+#[derive(Debug, Clone, PartialEq)]
+enum Validation {
+    MinLen(usize),
+    MaxLen(usize),
+    Regex(String),
+    /// Bound kept as a Rust literal of the inner type
+    Min(String),
+    Max(String),
+}
+
+impl Validation {
+    /// Parse `min_len:3`, `max_len:255`, `regex:[a-z]+`, `min:0` or `max:100` for `inner_type`
+    fn parse(spec: &str, inner_type: &str) -> Result<Self, String> {
+        let (kind, value) = spec.split_once(':').ok_or_else(|| {
+            format!("Invalid validation '{}'. Expected format: kind:value", spec)
+        })?;
+        let kind = kind.trim();
+
+        let validation = match kind {
+            "min_len" | "max_len" | "regex" if !is_string(inner_type) => {
+                return Err(format!("{} only applies to String value objects, not {}", kind, inner_type));
+            }
+            "min" | "max" if !is_number(inner_type) => {
+                return Err(format!("{} only applies to numeric value objects, not {}", kind, inner_type));
+            }
+            "min_len" => Self::MinLen(parse_length(kind, value)?),
+            "max_len" => Self::MaxLen(parse_length(kind, value)?),
+            "regex" => {
+                regex::Regex::new(value).map_err(|e| format!("Invalid regex '{}': {}", value, e))?;
+                Self::Regex(value.to_string())
+            }
+            "min" => Self::Min(number_literal(kind, value, inner_type)?),
+            "max" => Self::Max(number_literal(kind, value, inner_type)?),
+            other => {
+                return Err(format!(
+                    "Unknown validation: {}. Expected one of: min_len, max_len, regex, min, max",
+                    other
+                ));
+            }
+        };
+
+        Ok(validation)
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::MinLen(_) => "min_len",
+            Self::MaxLen(_) => "max_len",
+            Self::Regex(_) => "regex",
+            Self::Min(_) => "min",
+            Self::Max(_) => "max",
+        }
+    }
+}
+
+/// Generator for an immutable newtype validated on construction
+#[derive(Debug)]
+pub struct ValueObjectGenerator {
+    inner_type: String,
+    validations: Vec<Validation>,
+}
+
+impl ValueObjectGenerator {
+    /// Create a value object generator wrapping `inner_type`
+    pub fn new(inner_type: &str) -> Result<Self, String> {
+        let inner_type = inner_type.trim();
+
+        if inner_type.is_empty() {
+            return Err("--inner-type is required".to_string());
+        }
+
+        if inner_type.starts_with('&') {
+            return Err(format!("--inner-type must be an owned type, not {}", inner_type));
+        }
+
+        Ok(Self {
+            inner_type: inner_type.to_string(),
+            validations: Vec::new(),
+        })
+    }
+
+    /// Add validations from a comma-separated list, e.g. `min_len:3,max_len:255,regex:[a-z]+`
+    pub fn validations(mut self, validations: Option<&str>) -> Result<Self, String> {
+        let Some(validations) = validations else {
+            return Ok(self);
+        };
+
+        for spec in split_validations(validations) {
+            let validation = Validation::parse(spec, &self.inner_type)?;
+            if self.validations.iter().any(|other| other.kind() == validation.kind()) {
+                return Err(format!("Duplicate validation: {}", validation.kind()));
+            }
+            self.validations.push(validation);
+        }
+
+        let bound = |kind: &str| {
+            self.validations.iter().find_map(|validation| match validation {
+                Validation::MinLen(len) if kind == "min_len" => Some(*len as f64),
+                Validation::MaxLen(len) if kind == "max_len" => Some(*len as f64),
+                Validation::Min(value) if kind == "min" => value.parse().ok(),
+                Validation::Max(value) if kind == "max" => value.parse().ok(),
+                _ => None,
+            })
+        };
+        for (lower, upper) in [("min_len", "max_len"), ("min", "max")] {
+            if let (Some(min), Some(max)) = (bound(lower), bound(upper))
+                && min > max
+            {
+                return Err(format!("{} is greater than {}", lower, upper));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Whether the generated code needs the `regex` crate
+    pub fn uses_regex(&self) -> bool {
+        self.validations.iter().any(|validation| matches!(validation, Validation::Regex(_)))
+    }
+
+    /// Generate a value object file
+    pub fn generate(&self, name: &str, output: &str) -> Result<String, String> {
+        ensure_directory(output)?;
+
+        let type_name = to_pascal_case(name);
+        let file_name = format!("{}.rs", to_snake_case(&type_name));
+        let file_path = format!("{}/{}", output, file_name);
+
+        let content = self.generate_value_object(&type_name);
+
+        std::fs::write(&file_path, content)
+            .map_err(|e| format!("Failed to write value object file: {}", e))?;
+
+        // Update mod.rs
+        self.update_mod_file(output, &type_name)?;
+
+        Ok(file_path)
+    }
+
+    /// Generate value object content
+    fn generate_value_object(&self, type_name: &str) -> String {
+        let inner_type = &self.inner_type;
+        let error_name = format!("{}Error", type_name);
+
+        // Floats have no Eq or Hash impls
+        let derives = if is_float(inner_type) {
+            "Debug, Clone, PartialEq"
+        } else {
+            "Debug, Clone, PartialEq, Eq, Hash"
+        };
+
+        let mut imports = vec!["use std::fmt;", "use std::ops::Deref;"];
+        let mut statics = String::new();
+        let mut variants = Vec::new();
+        let mut messages = Vec::new();
+        let mut checks = Vec::new();
+
+        if self
+            .validations
+            .iter()
+            .any(|validation| matches!(validation, Validation::MinLen(_) | Validation::MaxLen(_)))
+        {
+            checks.push("let len = val.chars().count();".to_string());
+        }
+
+        for validation in &self.validations {
+            match validation {
+                Validation::MinLen(min) => {
+                    variants.push("    /// Shorter than `min` characters\n    TooShort { min: usize, actual: usize },".to_string());
+                    messages.push(format!(
+                        "            Self::TooShort {{ min, actual }} => write!(f, \"{} must be at least {{}} characters, got {{}}\", min, actual),",
+                        type_name
+                    ));
+                    checks.push(format!(
+                        "if len < {min} {{\n            return Err({error_name}::TooShort {{ min: {min}, actual: len }});\n        }}",
+                        min = min,
+                        error_name = error_name
+                    ));
+                }
+                Validation::MaxLen(max) => {
+                    variants.push("    /// Longer than `max` characters\n    TooLong { max: usize, actual: usize },".to_string());
+                    messages.push(format!(
+                        "            Self::TooLong {{ max, actual }} => write!(f, \"{} must be at most {{}} characters, got {{}}\", max, actual),",
+                        type_name
+                    ));
+                    checks.push(format!(
+                        "if len > {max} {{\n            return Err({error_name}::TooLong {{ max: {max}, actual: len }});\n        }}",
+                        max = max,
+                        error_name = error_name
+                    ));
+                }
+                Validation::Regex(pattern) => {
+                    imports.push("use std::sync::LazyLock;");
+                    statics = format!(
+                        "\n/// Pattern {type_name} values must match in full\nstatic PATTERN: LazyLock<regex::Regex> =\n    LazyLock::new(|| regex::Regex::new({anchored:?}).expect(\"valid {type_name} pattern\"));\n",
+                        type_name = type_name,
+                        anchored = format!("^(?:{})$", pattern)
+                    );
+                    variants.push(format!("    /// Doesn't match `{}`\n    PatternMismatch,", pattern));
+                    messages.push(format!(
+                        "            Self::PatternMismatch => write!(f, \"{} must match {}\"),",
+                        type_name,
+                        pattern.replace('\\', "\\\\").replace('"', "\\\"").replace('{', "{{").replace('}', "}}")
+                    ));
+                    checks.push(format!(
+                        "if !PATTERN.is_match(&val) {{\n            return Err({}::PatternMismatch);\n        }}",
+                        error_name
+                    ));
+                }
+                Validation::Min(min) => {
+                    variants.push(format!(
+                        "    /// Less than `min`\n    TooSmall {{ min: {inner}, actual: {inner} }},",
+                        inner = inner_type
+                    ));
+                    messages.push(format!(
+                        "            Self::TooSmall {{ min, actual }} => write!(f, \"{} must be at least {{}}, got {{}}\", min, actual),",
+                        type_name
+                    ));
+                    checks.push(format!(
+                        "if val < {min} {{\n            return Err({error_name}::TooSmall {{ min: {min}, actual: val }});\n        }}",
+                        min = min,
+                        error_name = error_name
+                    ));
+                }
+                Validation::Max(max) => {
+                    variants.push(format!(
+                        "    /// Greater than `max`\n    TooLarge {{ max: {inner}, actual: {inner} }},",
+                        inner = inner_type
+                    ));
+                    messages.push(format!(
+                        "            Self::TooLarge {{ max, actual }} => write!(f, \"{} must be at most {{}}, got {{}}\", max, actual),",
+                        type_name
+                    ));
+                    checks.push(format!(
+                        "if val > {max} {{\n            return Err({error_name}::TooLarge {{ max: {max}, actual: val }});\n        }}",
+                        max = max,
+                        error_name = error_name
+                    ));
+                }
+            }
+        }
+
+        // An error with no variants can't be constructed, so its Display matches nothing
+        let (formatter, display_body) = if messages.is_empty() {
+            ("_", "        match *self {}".to_string())
+        } else {
+            ("f", format!("        match self {{\n{}\n        }}", messages.join("\n")))
+        };
+        let error_derives = if is_float(inner_type) {
+            "Debug, Clone, PartialEq"
+        } else {
+            "Debug, Clone, PartialEq, Eq"
+        };
+        let variants = if variants.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}\n}}", variants.join("\n"))
+        };
+        let checks = checks
+            .iter()
+            .map(|check| format!("        {}\n", check))
+            .collect::<String>();
+
+        format!(
+            r#"//! {type_name}
+//!
+//! Immutable value object wrapping a `{inner_type}`, validated on construction.
+
+{imports}
+{statics}
+/// A validated `{inner_type}`; build one with [`{type_name}::try_new`]
+#[derive({derives})]
+pub struct {type_name}({inner_type});
+
+/// Why a value isn't a valid {type_name}
+#[derive({error_derives})]
+pub enum {error_name} {variants}
+
+impl fmt::Display for {error_name} {{
+    fn fmt(&self, {formatter}: &mut fmt::Formatter<'_>) -> fmt::Result {{
+{display_body}
+    }}
+}}
+
+impl std::error::Error for {error_name} {{}}
+
+impl {type_name} {{
+    /// Validate a value and wrap it
+    pub fn try_new(val: {inner_type}) -> Result<Self, {error_name}> {{
+{checks}        Ok(Self(val))
+    }}
+
+    /// Unwrap the inner value
+    pub fn into_inner(self) -> {inner_type} {{
+        self.0
+    }}
+}}
+
+impl fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        fmt::Display::fmt(&self.0, f)
+    }}
+}}
+
+impl Deref for {type_name} {{
+    type Target = {inner_type};
+
+    fn deref(&self) -> &Self::Target {{
+        &self.0
+    }}
+}}
+
+impl TryFrom<{inner_type}> for {type_name} {{
+    type Error = {error_name};
+
+    fn try_from(val: {inner_type}) -> Result<Self, Self::Error> {{
+        Self::try_new(val)
+    }}
+}}
+"#,
+            type_name = type_name,
+            inner_type = inner_type,
+            imports = imports.join("\n"),
+            statics = statics,
+            derives = derives,
+            error_derives = error_derives,
+            error_name = error_name,
+            variants = variants,
+            formatter = formatter,
+            display_body = display_body,
+            checks = checks,
+        )
+    }
+
+    /// Update mod.rs with new value object
+    fn update_mod_file(&self, output: &str, type_name: &str) -> Result<(), String> {
+        let mod_path = format!("{}/mod.rs", output);
+        let module_name = to_snake_case(type_name);
+
+        let module_decl = format!("pub mod {};", module_name);
+        write_or_update_file(&mod_path, &format!("{}\n", module_decl), &module_decl)?;
+        inject_after_marker(
+            &mod_path,
+            &module_decl,
+            &format!("pub use {}::{{{}, {}Error}};", module_name, type_name, type_name),
+        )
+    }
+}
+
+/// Split a comma-separated `--validations` list, keeping commas inside brackets
+/// such as the regex `[a-z]{2,4}`
+fn split_validations(validations: &str) -> Vec<&str> {
+    let mut specs = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, character) in validations.char_indices() {
+        match character {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                specs.push(validations[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    specs.push(validations[start..].trim());
+    specs.retain(|spec| !spec.is_empty());
+    specs
+}
+
+fn parse_length(kind: &str, value: &str) -> Result<usize, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{} must be a non-negative integer, got '{}'", kind, value))
+}
+
+/// `value` as a literal of the numeric `inner_type`, e.g. `0` becomes `0.0` for floats
+fn number_literal(kind: &str, value: &str, inner_type: &str) -> Result<String, String> {
+    let value = value.trim();
+    let invalid = || format!("{} must be a {} value, got '{}'", kind, inner_type, value);
+
+    if is_float(inner_type) {
+        let number: f64 = value.parse().map_err(|_| invalid())?;
+        if !number.is_finite() {
+            return Err(invalid());
+        }
+        return Ok(format!("{:?}", number));
+    }
+
+    let number: i128 = value.parse().map_err(|_| invalid())?;
+    if inner_type.starts_with('u') && number < 0 {
+        return Err(invalid());
+    }
+    Ok(number.to_string())
+}
+
+fn is_string(rust_type: &str) -> bool {
+    matches!(rust_type, "String" | "std::string::String")
+}
+
+fn is_float(rust_type: &str) -> bool {
+    matches!(rust_type, "f32" | "f64")
+}
+
+fn is_number(rust_type: &str) -> bool {
+    is_float(rust_type)
+        || matches!(
+            rust_type,
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+        )
+}
+
+/// Convert string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    heck::AsPascalCase(s).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueObjectGenerator;
+    use tempfile::tempdir;
+
+    #[test]
+    fn string_value_objects_check_length_and_pattern() {
+        let content = ValueObjectGenerator::new("String")
+            .unwrap()
+            .validations(Some("min_len:3,max_len:255,regex:[a-z]{2,}"))
+            .unwrap()
+            .generate_value_object("Username");
+
+        assert!(content.contains("#[derive(Debug, Clone, PartialEq, Eq, Hash)]\npub struct Username(String);"));
+        assert!(content.contains("    pub fn try_new(val: String) -> Result<Self, UsernameError> {\n        let len = val.chars().count();"));
+        assert!(content.contains("if len < 3 {\n            return Err(UsernameError::TooShort { min: 3, actual: len });"));
+        assert!(content.contains("if len > 255 {\n            return Err(UsernameError::TooLong { max: 255, actual: len });"));
+        assert!(content.contains("regex::Regex::new(\"^(?:[a-z]{2,})$\")"));
+        assert!(content.contains("Self::PatternMismatch => write!(f, \"Username must match [a-z]{{2,}}\"),"));
+        assert!(content.contains("impl Deref for Username {\n    type Target = String;"));
+        assert!(content.contains("impl TryFrom<String> for Username {\n    type Error = UsernameError;"));
+    }
+
+    #[test]
+    fn numeric_value_objects_check_bounds() {
+        let content = ValueObjectGenerator::new("f64")
+            .unwrap()
+            .validations(Some("min:0,max:100"))
+            .unwrap()
+            .generate_value_object("Percentage");
+
+        assert!(content.contains("#[derive(Debug, Clone, PartialEq)]\npub struct Percentage(f64);"));
+        assert!(content.contains("TooSmall { min: f64, actual: f64 },"));
+        assert!(content.contains("if val < 0.0 {\n            return Err(PercentageError::TooSmall { min: 0.0, actual: val });"));
+        assert!(content.contains("if val > 100.0 {"));
+        assert!(!content.contains("LazyLock"));
+
+        let content = ValueObjectGenerator::new("uuid::Uuid").unwrap().generate_value_object("OrderId");
+        assert!(content.contains("pub enum OrderIdError {}"));
+        assert!(content.contains("    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {\n        match *self {}"));
+        assert!(content.contains("        Ok(Self(val))"));
+    }
+
+    #[test]
+    fn invalid_validations_are_rejected() {
+        let string = || ValueObjectGenerator::new("String").unwrap();
+        assert!(string().validations(Some("min_len")).unwrap_err().contains("kind:value"));
+        assert!(string().validations(Some("min_len:-1")).is_err());
+        assert!(string().validations(Some("regex:[a-z")).unwrap_err().contains("Invalid regex"));
+        assert!(string().validations(Some("min:1")).unwrap_err().contains("numeric"));
+        assert!(string().validations(Some("min_len:5,max_len:3")).unwrap_err().contains("min_len is greater"));
+        assert!(string().validations(Some("max_len:3,max_len:4")).unwrap_err().contains("Duplicate"));
+        assert!(string().validations(Some("email:true")).unwrap_err().contains("Unknown validation"));
+
+        let unsigned = || ValueObjectGenerator::new("u32").unwrap();
+        assert!(unsigned().validations(Some("min:-1")).is_err());
+        assert!(unsigned().validations(Some("max_len:3")).unwrap_err().contains("String"));
+        assert!(ValueObjectGenerator::new("&str").is_err());
+    }
+
+    #[test]
+    fn generate_registers_value_object_once() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("value_objects").to_string_lossy().into_owned();
+        let generator = ValueObjectGenerator::new("String").unwrap();
+
+        let path = generator.generate("email_address", &output).unwrap();
+        generator.generate("EmailAddress", &output).unwrap();
+
+        assert!(path.ends_with("email_address.rs"));
+        let mod_content = std::fs::read_to_string(dir.path().join("value_objects/mod.rs")).unwrap();
+        assert_eq!(
+            mod_content,
+            "pub mod email_address;\npub use email_address::{EmailAddress, EmailAddressError};\n"
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Logging setup for the `--log-level` and `--log-format` flags
+//!
+//! The `print_*` helpers in `utils` emit `tracing` events under `LOG_TARGET`.
+//! The pretty format renders them exactly like the CLI always has; `json` and
+//! `compact` hand them to the stock `tracing_subscriber` formatters.
+
+use crate::utils::LOG_TARGET;
+use colored::Colorize;
+use std::fmt;
+use std::io::{Stderr, Stdout};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::writer::EitherWriter;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Minimum level of events to print
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogLevel(Level);
+
+impl LogLevel {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "trace" => Ok(Self(Level::TRACE)),
+            "debug" => Ok(Self(Level::DEBUG)),
+            "info" => Ok(Self(Level::INFO)),
+            "warn" => Ok(Self(Level::WARN)),
+            "error" => Ok(Self(Level::ERROR)),
+            other => Err(format!(
+                "Unsupported log level: {}. Expected trace, debug, info, warn, or error",
+                other
+            )),
+        }
+    }
+}
+
+/// How log events are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+    Compact,
+}
+
+impl LogFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            "compact" => Ok(Self::Compact),
+            other => Err(format!(
+                "Unsupported log format: {}. Expected pretty, json, or compact",
+                other
+            )),
+        }
+    }
+}
+
+/// Set once a command's stdout is reserved for output meant to be piped
+static LOG_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Send log events to stderr from now on, leaving stdout to the command's own output
+pub fn log_to_stderr() {
+    LOG_TO_STDERR.store(true, Ordering::Relaxed);
+}
+
+fn log_writer() -> EitherWriter<Stdout, Stderr> {
+    if LOG_TO_STDERR.load(Ordering::Relaxed) {
+        EitherWriter::B(std::io::stderr())
+    } else {
+        EitherWriter::A(std::io::stdout())
+    }
+}
+
+/// Install the global subscriber; output goes to stdout like the rest of the CLI,
+/// unless a command has called [`log_to_stderr`]
+pub fn init(level: LogLevel, format: LogFormat) {
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(Level::TRACE)
+        .with_writer(log_writer);
+
+    // main installs the subscriber exactly once, so this cannot fail
+    let _ = match format {
+        LogFormat::Pretty => tracing::subscriber::set_global_default(
+            builder
+                .with_ansi(true)
+                .event_format(CliFormat)
+                .finish()
+                .with(filter(level)),
+        ),
+        LogFormat::Json => tracing::subscriber::set_global_default(
+            builder.json().finish().with(filter(level)),
+        ),
+        LogFormat::Compact => tracing::subscriber::set_global_default(
+            builder
+                .compact()
+                .with_ansi(colored::control::SHOULD_COLORIZE.should_colorize())
+                .finish()
+                .with(filter(level)),
+        ),
+    };
+}
+
+/// Show CLI events at `level`; dependencies such as sqlx only join in at debug and trace
+fn filter(LogLevel(level): LogLevel) -> Targets {
+    let dependencies = if level >= Level::DEBUG { level } else { Level::WARN };
+
+    Targets::new()
+        .with_target(LOG_TARGET, level)
+        .with_default(dependencies)
+}
+
+/// Renders events as `<symbol> <message>`, matching the CLI's historic output
+struct CliFormat;
+
+impl<S, N> FormatEvent<S, N> for CliFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut fields = EventFields::default();
+        event.record(&mut fields);
+
+        let symbol = match (*event.metadata().level(), fields.status.as_deref()) {
+            (_, Some("success")) => "✓".green(),
+            (Level::ERROR, _) => "✗".red(),
+            (Level::WARN, _) => "⚠".yellow(),
+            (Level::INFO, _) => "ℹ".blue(),
+            _ => "·".dimmed(),
+        };
+
+        if event.metadata().target() == LOG_TARGET {
+            writeln!(writer, "{} {}", symbol, fields.message)
+        } else {
+            writeln!(
+                writer,
+                "{} {}: {}",
+                symbol,
+                event.metadata().target().dimmed(),
+                fields.message
+            )
+        }
+    }
+}
+
+/// The fields the pretty format understands
+#[derive(Default)]
+struct EventFields {
+    message: String,
+    status: Option<String>,
+}
+
+impl Visit for EventFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            "status" => self.status = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter, CliFormat, LogFormat, LogLevel};
+    use crate::utils::{print_info, print_success, print_warning, LOG_TARGET};
+    use std::sync::{Arc, Mutex};
+    use tracing::Level;
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Writer that collects output for tests
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Captured {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn capture(level: &str, format: LogFormat, emit: impl FnOnce()) -> String {
+        let output = Captured::default();
+        let builder = tracing_subscriber::fmt()
+            .with_max_level(Level::TRACE)
+            .with_ansi(false)
+            .with_writer(output.clone());
+        let level = LogLevel::parse(level).unwrap();
+
+        match format {
+            LogFormat::Pretty => tracing::subscriber::with_default(
+                builder.event_format(CliFormat).finish().with(filter(level)),
+                emit,
+            ),
+            LogFormat::Json => tracing::subscriber::with_default(
+                builder.json().finish().with(filter(level)),
+                emit,
+            ),
+            LogFormat::Compact => tracing::subscriber::with_default(
+                builder.compact().finish().with(filter(level)),
+                emit,
+            ),
+        }
+
+        let bytes = output.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn parses_levels_and_formats() {
+        assert_eq!(LogLevel::parse("WARN").unwrap(), LogLevel(Level::WARN));
+        assert_eq!(LogLevel::parse("trace").unwrap(), LogLevel(Level::TRACE));
+        assert!(LogLevel::parse("verbose").is_err());
+        assert_eq!(LogFormat::parse("json").unwrap(), LogFormat::Json);
+        assert_eq!(LogFormat::parse(" Compact ").unwrap(), LogFormat::Compact);
+        assert!(LogFormat::parse("xml").is_err());
+    }
+
+    #[test]
+    fn pretty_format_keeps_cli_symbols() {
+        let output = capture("info", LogFormat::Pretty, || {
+            print_success("Created users table");
+            print_info("Nothing to migrate");
+            print_warning("No models found");
+            tracing::debug!(target: LOG_TARGET, "hidden");
+        });
+
+        assert_eq!(
+            output,
+            "✓ Created users table\nℹ Nothing to migrate\n⚠ No models found\n"
+        );
+    }
+
+    #[test]
+    fn level_filters_cli_and_dependency_events() {
+        let output = capture("warn", LogFormat::Pretty, || {
+            print_info("Nothing to migrate");
+            print_warning("No models found");
+            tracing::warn!(target: "sqlx::query", "slow statement");
+            tracing::info!(target: "sqlx::query", "SELECT 1");
+        });
+        assert_eq!(output, "⚠ No models found\n⚠ sqlx::query: slow statement\n");
+
+        let output = capture("debug", LogFormat::Pretty, || {
+            tracing::debug!(target: LOG_TARGET, "Loaded config");
+            tracing::debug!(target: "sqlx::query", "SELECT 1");
+            tracing::trace!(target: LOG_TARGET, "hidden");
+        });
+        assert_eq!(output, "· Loaded config\n· sqlx::query: SELECT 1\n");
+    }
+
+    #[test]
+    fn json_format_emits_structured_events() {
+        let output = capture("info", LogFormat::Json, || {
+            print_success("Created users table");
+        });
+
+        let event: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["target"], LOG_TARGET);
+        assert_eq!(event["fields"]["message"], "Created users table");
+        assert_eq!(event["fields"]["status"], "success");
+    }
+}
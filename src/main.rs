@@ -22,10 +22,17 @@
 mod commands;
 mod config;
 mod generators;
+mod logging;
+mod migration_lock;
 mod runtime_db;
+mod schema_types;
+mod secrets;
+// Compiled into build.rs; included here so its tests run
+#[cfg(test)]
+mod sqlx_version;
 mod utils;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use colored::Colorize;
 
 /// TideORM CLI - A powerful command-line interface for TideORM
@@ -44,6 +51,14 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Minimum level of log messages: trace, debug, info, warn, or error
+    #[arg(long, global = true, default_value = "info", value_parser = logging::LogLevel::parse)]
+    log_level: logging::LogLevel,
+
+    /// Log output format: pretty, json, or compact
+    #[arg(long, global = true, default_value = "pretty", value_parser = logging::LogFormat::parse)]
+    log_format: logging::LogFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -62,7 +77,7 @@ enum Commands {
     // =========================================================================
     /// Subcommands for generating files
     #[command(subcommand, name = "make")]
-    Make(MakeCommands),
+    Make(Box<MakeCommands>),
 
     // =========================================================================
     // DATABASE COMMANDS
@@ -80,22 +95,124 @@ enum Commands {
         #[arg(default_value = ".")]
         name: String,
 
-        /// Database type (postgres, mysql, sqlite)
-        #[arg(short, long, default_value = "postgres")]
-        database: String,
+        /// Database type (postgres, mysql, sqlite); defaults to the DATABASE_URL scheme, then postgres
+        #[arg(short, long)]
+        database: Option<String>,
+
+        /// Don't infer the database type from DATABASE_URL
+        #[arg(long)]
+        ignore_env: bool,
+
+        /// Do not add the project to a Cargo workspace in the current directory
+        #[arg(long)]
+        no_workspace: bool,
+
+        /// Create Cargo.toml with `cargo init` and `cargo add` when none exists
+        #[arg(long)]
+        cargo_init: bool,
+
+        /// With --cargo-init, create a library crate instead of a binary
+        #[arg(long, requires = "cargo_init")]
+        lib: bool,
+
+        /// Skip .env.example, the development defaults in .env, and the .gitignore entry
+        #[arg(long)]
+        no_env: bool,
     },
 
     /// Show TideORM configuration
-    Config,
+    Config {
+        /// Print shell lines exporting DATABASE_URL and TIDEORM_ENV instead
+        #[arg(long)]
+        export_env: bool,
+
+        /// Shell syntax for --export-env: bash, fish, or powershell
+        #[arg(long, default_value = "bash", requires = "export_env", value_parser = commands::config::ExportShell::parse)]
+        shell: commands::config::ExportShell,
+
+        /// Include the database password in exported values
+        #[arg(long, requires = "export_env")]
+        show_secrets: bool,
+    },
 
     /// List all models in the project
-    Models,
+    Models {
+        /// Only show models modified on or after this date (YYYY-MM-DD)
+        #[arg(long, value_parser = commands::models::parse_since)]
+        since: Option<chrono::NaiveDate>,
+
+        /// Order by name, file, modified (newest first), or fields (most first)
+        #[arg(long, default_value = "name", value_parser = commands::models::ModelSort::parse)]
+        sort_by: commands::models::ModelSort,
+    },
 
     /// Show schema information
+    #[command(args_conflicts_with_subcommands = true)]
     Schema {
         /// Table name to show schema for
         #[arg(short, long)]
         table: Option<String>,
+
+        /// List tables alphabetically instead of in foreign key dependency order
+        #[arg(long)]
+        alphabetical: bool,
+
+        /// Output format: table, or mermaid for an ER diagram of every table
+        #[arg(long, default_value = "table", value_parser = commands::schema::SchemaFormat::parse)]
+        format: commands::schema::SchemaFormat,
+
+        /// With --format=mermaid, write the diagram to this Markdown file in a fenced code block
+        #[arg(short, long)]
+        output: Option<String>,
+
+        #[command(subcommand)]
+        command: Option<SchemaCommands>,
+    },
+
+    /// Check model, migration, seeder, and factory files for common issues
+    Validate {
+        /// Treat warnings as errors
+        #[arg(long)]
+        strict: bool,
+
+        /// Also report files that rustfmt would reformat
+        #[arg(long)]
+        check_style: bool,
+
+        /// Reformat files with rustfmt in place
+        #[arg(long)]
+        fix_style: bool,
+    },
+
+    /// Write an OpenAPI 3.0 document describing the models and controller routes
+    // --version sets the API version here, not the CLI's
+    #[command(disable_version_flag = true)]
+    GenerateOpenapi {
+        /// File to write the document to
+        #[arg(short, long, default_value = "openapi.yaml")]
+        output: String,
+
+        /// API title for the info section
+        #[arg(long, default_value = "API")]
+        title: String,
+
+        /// API version for the info section
+        #[arg(long, default_value = "1.0.0")]
+        version: String,
+
+        /// Document format: yaml or json
+        #[arg(long, default_value = "yaml", value_parser = commands::openapi::OpenApiFormat::parse)]
+        format: commands::openapi::OpenApiFormat,
+    },
+
+    /// Print a shell completion script
+    Completions {
+        /// Shell to generate completions for: bash, zsh, fish, powershell, or elvish
+        shell: String,
+
+        /// Write the script to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
     },
 
     // =========================================================================
@@ -111,6 +228,14 @@ enum Commands {
         /// Port to run the server on
         #[arg(short, long, default_value = "8080")]
         port: u16,
+
+        /// Allow API calls from this origin (`*` for any), e.g. a frontend dev server
+        #[arg(long)]
+        cors_origin: Option<String>,
+
+        /// Seconds browsers may cache CORS preflight responses
+        #[arg(long, requires = "cors_origin")]
+        cors_max_age: Option<u64>,
     },
 }
 
@@ -118,14 +243,19 @@ enum Commands {
 enum MigrateCommands {
     /// Run all pending migrations
     Run {
-        /// Run migrations in a specific directory
-        #[arg(short, long)]
-        path: Option<String>,
+        /// Run migrations from this directory instead of paths.migrations; repeat it to
+        /// combine several directories, whose migrations run in file name order
+        #[arg(short = 'p', long = "path", value_name = "PATH", action = clap::ArgAction::Append)]
+        paths: Vec<String>,
 
-        /// Pretend mode - show SQL without executing
+        /// Pretend mode - print the SQL to stdout without executing it (messages go to stderr)
         #[arg(long)]
         pretend: bool,
 
+        /// Leave the `-- Migration:` comment headers out of the --pretend SQL
+        #[arg(long, requires = "pretend")]
+        pretend_no_comments: bool,
+
         /// Force run in production
         #[arg(long)]
         force: bool,
@@ -133,27 +263,28 @@ enum MigrateCommands {
         /// Run a specific migration step
         #[arg(long)]
         step: Option<u32>,
-    },
-
-    /// Generate a new migration file
-    #[command(name = "generate", alias = "gen")]
-    Generate {
-        /// Migration name (e.g., create_users_table)
-        name: String,
 
-        /// Create table migration
+        /// Keep watching the migrations directory and run new files as they appear
         #[arg(long)]
-        create: Option<String>,
+        watch: bool,
 
-        /// Alter table migration
-        #[arg(short = 'a', long)]
-        table: Option<String>,
+        /// Milliseconds to wait for a new migration file to finish writing
+        #[arg(long, default_value = "200", requires = "watch")]
+        watch_debounce_ms: u64,
 
-        /// Fields to add (format: name:type:modifiers)
-        #[arg(short, long)]
-        fields: Option<String>,
+        /// Skip the database lock that keeps concurrent runs from overlapping
+        #[arg(long)]
+        no_lock: bool,
+
+        /// Seconds to wait for another run to release the migration lock
+        #[arg(long, default_value = "60", conflicts_with = "no_lock")]
+        lock_timeout: u64,
     },
 
+    /// Generate a new migration file
+    #[command(name = "generate", alias = "gen")]
+    Generate(Box<MigrateGenerateArgs>),
+
     /// Run migration up
     Up {
         /// Number of migrations to run
@@ -171,14 +302,37 @@ enum MigrateCommands {
 
     /// Run migration down (rollback)
     Down {
-        /// Number of migrations to rollback
-        #[arg(long, default_value = "1")]
-        step: u32,
+        /// Directory the migrations were run from with `migrate run --path`; repeat it for several
+        #[arg(short = 'p', long = "path", value_name = "PATH", action = clap::ArgAction::Append)]
+        paths: Vec<String>,
+
+        /// Number of migrations to rollback [default: the latest batch]
+        #[arg(long)]
+        step: Option<u32>,
 
         /// Specific migration to rollback
         #[arg(long)]
         migration: Option<String>,
 
+        /// Roll back every migration that ran after this one, keeping it applied
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Pretend mode
+        #[arg(long)]
+        pretend: bool,
+    },
+
+    /// Roll back every migration that ran after the named one (same as `down --to`)
+    #[command(name = "down-to")]
+    DownTo {
+        /// Migration to roll back to; it stays applied
+        migration: String,
+
+        /// Directory the migrations were run from with `migrate run --path`; repeat it for several
+        #[arg(short = 'p', long = "path", value_name = "PATH", action = clap::ArgAction::Append)]
+        paths: Vec<String>,
+
         /// Pretend mode
         #[arg(long)]
         pretend: bool,
@@ -208,10 +362,18 @@ enum MigrateCommands {
         /// Force run in production
         #[arg(long)]
         force: bool,
+
+        /// Also drop the migrations tracking table instead of clearing its records
+        #[arg(long)]
+        drop_tracking_table: bool,
     },
 
     /// Reset all migrations (rollback all)
     Reset {
+        /// Directory the migrations were run from with `migrate run --path`; repeat it for several
+        #[arg(short = 'p', long = "path", value_name = "PATH", action = clap::ArgAction::Append)]
+        paths: Vec<String>,
+
         /// Force run in production
         #[arg(long)]
         force: bool,
@@ -237,16 +399,115 @@ enum MigrateCommands {
     },
 
     /// Show migration status
-    Status,
+    Status {
+        /// Directory the migrations were run from with `migrate run --path`; repeat it for several
+        #[arg(short = 'p', long = "path", value_name = "PATH", action = clap::ArgAction::Append)]
+        paths: Vec<String>,
+
+        /// Run the pending migrations that are older than migrations that already ran
+        #[arg(long)]
+        fix_gaps: bool,
+
+        /// Force running gap migrations in production
+        #[arg(long, requires = "fix_gaps")]
+        force: bool,
+    },
 
     /// Show migration history
     History {
         /// Number of migrations to show
         #[arg(short, long, default_value = "10")]
         limit: u32,
+
+        /// Highlight migrations that took longer than this many milliseconds
+        #[arg(long)]
+        slow_threshold: Option<i64>,
     },
 }
 
+/// Arguments of `migrate generate`
+#[derive(Args)]
+struct MigrateGenerateArgs {
+    /// Migration name (e.g., create_users_table)
+    name: String,
+
+    /// Create table migration
+    #[arg(long)]
+    create: Option<String>,
+
+    /// Alter table migration
+    #[arg(short = 'a', long)]
+    table: Option<String>,
+
+    /// Fields to add (format: name:type:modifiers)
+    #[arg(short, long)]
+    fields: Option<String>,
+
+    /// Add and drop the columns that differ between the models and the live database
+    #[arg(long, conflicts_with_all = ["create", "table", "fields"])]
+    from_diff: bool,
+
+    /// With --from-diff, keep columns that exist only in the database
+    #[arg(long, requires = "from_diff")]
+    no_drop_columns: bool,
+
+    /// With --from-diff, write the migration without asking for confirmation
+    #[arg(long, requires = "from_diff")]
+    force: bool,
+
+    /// Drop this table; down() recreates it from the live schema when the database is reachable
+    #[arg(long, conflicts_with_all = ["create", "table", "fields", "from_diff"])]
+    drop_table: Option<String>,
+
+    /// With --drop-table, leave down() empty so the drop cannot be rolled back
+    #[arg(long, requires = "drop_table")]
+    no_down: bool,
+
+    /// Run the SQL in this file in up(), as written
+    #[arg(long, conflicts_with_all = ["create", "table", "fields", "from_diff", "drop_table"])]
+    from_sql: Option<String>,
+
+    /// With --from-sql, run the SQL in this file in down()
+    #[arg(long, requires = "from_sql")]
+    from_down_sql: Option<String>,
+
+    /// Add an index on these --table columns (comma-separated)
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    add_index: Option<String>,
+
+    /// Drop the index on these --table columns (comma-separated)
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    remove_index: Option<String>,
+
+    /// Add a unique index on these --table columns (comma-separated)
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    add_unique_index: Option<String>,
+
+    /// Drop the unique index on these --table columns (comma-separated)
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    remove_unique_index: Option<String>,
+
+    /// Add a full-text search index on these --table columns (comma-separated column[:language])
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    add_full_text_index: Option<String>,
+
+    /// Postgres text search configuration for --add-full-text-index columns without one
+    #[arg(long, requires = "add_full_text_index", default_value = "english")]
+    language: String,
+
+    /// Build Postgres indexes inside the migration transaction instead of CONCURRENTLY
+    #[arg(long)]
+    no_concurrent: bool,
+
+    /// Change the type of --table columns (comma-separated column:new_type)
+    #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "from_diff"])]
+    modify_column: Option<String>,
+
+    /// Type down() restores with --modify-column, instead of the live column type
+    #[arg(long, requires = "modify_column")]
+    original_type: Option<String>,
+}
+
 #[derive(Subcommand)]
 enum MakeCommands {
     /// Generate a new model
@@ -255,13 +516,14 @@ enum MakeCommands {
         /// Model name (e.g., User, BlogPost)
         name: String,
 
-        /// Table name (defaults to snake_case plural of model name)
-        #[arg(short, long)]
+        /// Table name, used as-is without pluralization
+        /// Defaults to the snake_case plural of the model name, honoring [pluralization] overrides
+        #[arg(short, long, alias = "table-name")]
         table: Option<String>,
 
         /// Fields (format: name:type[:modifiers...], comma-separated)
-        /// Types: string, text, i32, i64, f32, f64, bool, datetime, date, time, uuid, json, jsonb, decimal, bytes, int_array, bigint_array, text_array, bool_array, float_array, json_array
-        /// Modifiers: nullable, unique, indexed, primary_key, auto_increment, default=value
+        /// Types: string, text, i32, i64, f32, f64, bool, datetime, date, time, uuid, json, jsonb, decimal, decimal(precision,scale), bytes, int_array, bigint_array, text_array, bool_array, float_array, json_array, or type[] for an array, e.g. string[]
+        /// Modifiers: nullable, unique, indexed, primary_key, auto_increment, default=value, where=condition (last, with indexed)
         /// Example: --fields="name:string,email:string:unique,age:i32:nullable"
         #[arg(short, long)]
         fields: Option<String>,
@@ -303,6 +565,40 @@ enum MakeCommands {
         #[arg(long, alias = "null")]
         nullable: Option<String>,
 
+        /// Sortable fields that get order_by_*_asc/desc helpers (comma-separated field names)
+        /// Indexed fields get these helpers automatically
+        /// Example: --sortable="name,created_at"
+        #[arg(long)]
+        sortable: Option<String>,
+
+        /// Fields matched by a generated search(query, fields) method (comma-separated field names)
+        /// Example: --searchable="name,email"
+        #[arg(long)]
+        searchable: Option<String>,
+
+        /// Don't generate the paginate(page, per_page) method
+        #[arg(long)]
+        no_paginate: bool,
+
+        /// CHECK constraints (format: field:expression, comma-separated)
+        /// Example: --check-constraints="age:age > 0,score:score BETWEEN 0 AND 100"
+        #[arg(long)]
+        check_constraints: Option<String>,
+
+        /// Columns computed by the database (format: name:type:expression, comma-separated)
+        /// Example: --generated-columns="full_name:string:CONCAT(first_name, ' ', last_name)"
+        #[arg(long)]
+        generated_columns: Option<String>,
+
+        /// Inherit the fields of an existing model in the output directory (e.g., Content)
+        #[arg(long)]
+        extends: Option<String>,
+
+        /// Declare the model with #[tideorm::model] (proc_macro) or with a TideModel impl (trait)
+        /// Defaults to model.base_style in tideorm.toml
+        #[arg(long, value_parser = generators::model::ModelBase::parse)]
+        base: Option<generators::model::ModelBase>,
+
         /// Enable soft deletes
         #[arg(long, alias = "soft-delete")]
         soft_deletes: bool,
@@ -321,6 +617,24 @@ enum MakeCommands {
         #[arg(long)]
         tokenize: bool,
 
+        /// Add a version column and an increment_version() helper for optimistic locking
+        #[arg(long)]
+        optimistic_locking: bool,
+
+        /// Extra derives added after Debug and Clone (comma-separated trait names)
+        /// Example: --derives="PartialEq,Hash"
+        /// With --relations only Default, Serialize and Deserialize can be added
+        #[arg(long)]
+        derives: Option<String>,
+
+        /// Don't derive Debug, for models with fields that aren't Debug
+        #[arg(long)]
+        no_debug: bool,
+
+        /// Don't derive Clone; TideORM requires Clone, so #[tideorm::model] generates it instead
+        #[arg(long)]
+        no_clone: bool,
+
         /// Output directory for the model file
         #[arg(short, long, default_value = "src/models")]
         output: String,
@@ -340,6 +654,14 @@ enum MakeCommands {
         /// Generate all (migration + seeder + factory)
         #[arg(short, long)]
         all: bool,
+
+        /// Also generate async-graphql object, input, and dataloader types
+        #[arg(long)]
+        generate_graphql: bool,
+
+        /// Output directory for GraphQL types (written to <path>/types)
+        #[arg(long, default_value = "src/graphql", requires = "generate_graphql")]
+        graphql_output: String,
     },
 
     /// Generate a new migration
@@ -360,6 +682,70 @@ enum MakeCommands {
         #[arg(short, long)]
         fields: Option<String>,
 
+        /// Rename a table (old_name:new_name); inferred from names like rename_users_table_to_accounts
+        #[arg(
+            long,
+            value_parser = generators::migration::TableRename::parse,
+            conflicts_with_all = ["create", "table", "fields"]
+        )]
+        rename_table: Option<generators::migration::TableRename>,
+
+        /// Also rename <table>_id foreign key columns that reference the renamed table
+        #[arg(long)]
+        update_fks: bool,
+
+        /// Drop this table; down() recreates it from the live schema when the database is reachable
+        #[arg(long, conflicts_with_all = ["create", "table", "fields", "rename_table"])]
+        drop_table: Option<String>,
+
+        /// With --drop-table, leave down() empty so the drop cannot be rolled back
+        #[arg(long, requires = "drop_table")]
+        no_down: bool,
+
+        /// Run the SQL in this file in up(), as written
+        #[arg(long, conflicts_with_all = ["create", "table", "fields", "rename_table", "drop_table"])]
+        from_sql: Option<String>,
+
+        /// With --from-sql, run the SQL in this file in down()
+        #[arg(long, requires = "from_sql")]
+        from_down_sql: Option<String>,
+
+        /// Add an index on these --table columns (comma-separated)
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        add_index: Option<String>,
+
+        /// Drop the index on these --table columns (comma-separated)
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        remove_index: Option<String>,
+
+        /// Add a unique index on these --table columns (comma-separated)
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        add_unique_index: Option<String>,
+
+        /// Drop the unique index on these --table columns (comma-separated)
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        remove_unique_index: Option<String>,
+
+        /// Add a full-text search index on these --table columns (comma-separated column[:language])
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        add_full_text_index: Option<String>,
+
+        /// Postgres text search configuration for --add-full-text-index columns without one
+        #[arg(long, requires = "add_full_text_index", default_value = "english")]
+        language: String,
+
+        /// Build Postgres indexes inside the migration transaction instead of CONCURRENTLY
+        #[arg(long)]
+        no_concurrent: bool,
+
+        /// Change the type of --table columns (comma-separated column:new_type)
+        #[arg(long, requires = "table", conflicts_with_all = ["create", "fields", "rename_table"])]
+        modify_column: Option<String>,
+
+        /// Type down() restores with --modify-column, instead of the live column type
+        #[arg(long, requires = "modify_column")]
+        original_type: Option<String>,
+
         /// Output directory
         #[arg(short, long, default_value = "src/migrations")]
         output: String,
@@ -375,13 +761,59 @@ enum MakeCommands {
         #[arg(short, long)]
         model: Option<String>,
 
-        /// Number of records to seed
-        #[arg(short = 'n', long, default_value = "10")]
+        /// Number of records to seed (accepts 10_000, 5k, 1M; max 10M)
+        #[arg(short = 'n', long, default_value = "10", value_parser = utils::parse_count)]
         count: u32,
 
         /// Output directory
         #[arg(short, long, default_value = "src/seeders")]
         output: String,
+
+        /// Fill model fields from the loop counter, e.g. "User 1" (default)
+        #[arg(long, conflicts_with = "faker")]
+        sequential: bool,
+
+        /// Fill model fields with random values from the `fake` crate
+        #[arg(long)]
+        faker: bool,
+
+        /// Delete the model's rows before seeding, in the same transaction as the inserts
+        #[arg(long, requires = "model", conflicts_with = "no_truncate")]
+        truncate: bool,
+
+        /// Append to the existing rows (default)
+        #[arg(long)]
+        no_truncate: bool,
+    },
+
+    /// Generate a seeder that recreates the current rows of database tables
+    #[command(name = "seed-state")]
+    SeedState {
+        /// Seeder name (e.g., DevDataSeeder)
+        name: String,
+
+        /// Tables to capture (comma-separated)
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        tables: Vec<String>,
+
+        /// Maximum number of rows to capture per table
+        #[arg(long)]
+        limit: Option<u64>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/seeders")]
+        output: String,
+    },
+
+    /// Generate one seeder per table of a TOML or YAML seed definition file
+    #[command(name = "seeder-batch")]
+    SeederBatch {
+        /// Seed definition file (e.g., seeds.toml or seeds.yaml)
+        file: String,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/seeders")]
+        output: String,
     },
 
     /// Generate a new factory
@@ -394,10 +826,328 @@ enum MakeCommands {
         #[arg(short, long)]
         model: Option<String>,
 
+        /// Named states to generate (format: state:field=value[,field=value], e.g. admin:role=admin)
+        #[arg(long, alias = "state")]
+        states: Option<String>,
+
         /// Output directory
         #[arg(short, long, default_value = "src/factories")]
         output: String,
     },
+
+    /// Generate a new model observer
+    #[command(name = "observer")]
+    Observer {
+        /// Observer name (e.g., UserObserver)
+        name: String,
+
+        /// Model to observe (its model file must already exist)
+        #[arg(short, long)]
+        model: String,
+
+        /// Lifecycle events to hook into (comma-separated)
+        /// Events: creating, created, updating, updated, deleting, deleted
+        #[arg(
+            short,
+            long,
+            default_value = "creating,created,updating,updated,deleting,deleted"
+        )]
+        events: String,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/observers")]
+        output: String,
+    },
+
+    /// Generate a repository wrapping a model's queries
+    #[command(name = "repository")]
+    Repository {
+        /// Repository name (e.g., UserRepository)
+        name: String,
+
+        /// Model to wrap (its model file must already exist)
+        #[arg(short, long)]
+        model: String,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/repositories")]
+        output: String,
+    },
+
+    /// Generate a new background job
+    #[command(name = "job")]
+    Job {
+        /// Job name (e.g., SendWelcomeEmailJob)
+        name: String,
+
+        /// Queue to dispatch the job to (defaults to "default")
+        #[arg(short, long)]
+        queue: Option<String>,
+
+        /// Emit a delay() method returning this many seconds
+        #[arg(long)]
+        delay_secs: Option<u64>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/jobs")]
+        output: String,
+    },
+
+    /// Generate a notification with per-channel delivery methods
+    #[command(name = "notification")]
+    Notification {
+        /// Notification name (e.g., InvoicePaidNotification)
+        name: String,
+
+        /// Comma-separated delivery channels: email, slack, database
+        #[arg(long, default_value = "email")]
+        channels: String,
+
+        /// Model the notification is about (its model file must already exist)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/notifications")]
+        output: String,
+    },
+
+    /// Generate a cast converting a stored field type to another type and back
+    #[command(name = "cast")]
+    Cast {
+        /// Cast name (e.g., UuidCast)
+        name: String,
+
+        /// Stored field type (e.g., String)
+        #[arg(long)]
+        from_type: String,
+
+        /// Type the field is cast to (e.g., uuid::Uuid)
+        #[arg(long)]
+        to_type: String,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/casts")]
+        output: String,
+    },
+
+    /// Generate a custom clap subcommand for the project
+    #[command(name = "command")]
+    Command {
+        /// Command name (e.g., SyncUsers)
+        name: String,
+
+        /// Help text for the command
+        #[arg(long, default_value = "")]
+        description: String,
+
+        /// Command arguments (e.g., "verbose:bool,path:string,limit:i32:default=10")
+        #[arg(long)]
+        args: Option<String>,
+
+        /// Also add the CLI's global --config and --verbose flags to the args struct
+        #[arg(long)]
+        global_flags: bool,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/commands")]
+        output: String,
+    },
+
+    /// Generate a transformer that maps a model into another type
+    #[command(name = "transformer")]
+    Transformer {
+        /// Transformer name (e.g., User becomes UserTransformer)
+        name: String,
+
+        /// Model to transform (its model file must already exist)
+        #[arg(long)]
+        from_model: String,
+
+        /// Type the model is transformed into (e.g., crate::dtos::UserResponseDto)
+        #[arg(long)]
+        to_type: String,
+
+        /// Also generate transform_batches, which transforms items in chunks of this size
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        batch_size: Option<u32>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/transformers")]
+        output: String,
+    },
+
+    /// Generate a model with its migration, factory, seeder, controller and test
+    #[command(name = "resource")]
+    Resource {
+        /// Model name (e.g., User, BlogPost)
+        name: String,
+
+        /// Fields (format: name:type[:modifiers...], comma-separated), as for make model
+        #[arg(short, long)]
+        fields: Option<String>,
+
+        /// Relations (format: name:type:Model[:foreign_key], comma-separated), as for make model
+        #[arg(short, long)]
+        relations: Option<String>,
+
+        /// Database the migration SQL is written for (postgres, mysql, sqlite); defaults to database.driver
+        #[arg(long, value_parser = generators::resource::parse_database)]
+        database: Option<String>,
+
+        /// Web framework of the controller (axum, actix)
+        #[arg(long, value_parser = generators::controller::ControllerFramework::parse)]
+        framework: Option<generators::controller::ControllerFramework>,
+
+        /// List the files that would be created without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Generate a guard that checks requests before they reach a route
+    #[command(name = "guard")]
+    Guard {
+        /// Guard name (e.g., Admin becomes AdminGuard)
+        name: String,
+
+        /// Model to load from the request in an extract_user helper (its model file must already exist)
+        #[arg(long)]
+        model: Option<String>,
+
+        /// Redirect rejected requests to this URL instead of returning an error
+        #[arg(long)]
+        redirect_to: Option<String>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/guards")]
+        output: String,
+    },
+
+    /// Generate a presenter that formats a model's fields for display
+    #[command(name = "presenter")]
+    Presenter {
+        /// Presenter name (e.g., User becomes UserPresenter)
+        name: String,
+
+        /// Model to wrap (its model file must already exist)
+        #[arg(short, long)]
+        model: String,
+
+        /// Only generate methods for these fields (e.g., "full_name,created_at,avatar_url")
+        #[arg(short, long)]
+        fields: Option<String>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/presenters")]
+        output: String,
+    },
+
+    /// Generate an async trait and a stub service that implements it
+    #[command(name = "contract")]
+    Contract {
+        /// Contract name (e.g., User becomes UserContract and UserService)
+        name: String,
+
+        /// Methods as name:arg_type->return_type (e.g., "find_by_id:i64->Option<T>,find_all:->Vec<T>")
+        #[arg(short, long)]
+        methods: String,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/contracts")]
+        output: String,
+    },
+
+    /// Generate request and response DTOs, optionally mirroring a model's fields
+    #[command(name = "dto")]
+    Dto {
+        /// DTO name (e.g., User becomes UserRequestDto and UserResponseDto)
+        name: String,
+
+        /// Model to mirror (its model file must already exist)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Which DTOs to generate: request, response, or both
+        #[arg(short, long, value_parser = generators::dto::DtoDirection::parse, default_value = "both")]
+        direction: generators::dto::DtoDirection,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/dtos")]
+        output: String,
+    },
+
+    /// Generate a settings struct loaded from environment variables
+    #[command(name = "config")]
+    Config {
+        /// Config name (e.g., App becomes AppConfig)
+        name: String,
+
+        /// Fields as name:type[:default|required] (e.g., "port:u16:8080,db_url:string:required");
+        /// fields without a default are Options
+        #[arg(short, long)]
+        fields: String,
+
+        /// Prefix for every environment variable name (e.g., APP_ reads port from APP_PORT)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/config")]
+        output: String,
+    },
+
+    /// Generate an immutable newtype that validates a primitive on construction
+    #[command(name = "value-object")]
+    ValueObject {
+        /// Value object name (e.g., EmailAddress)
+        name: String,
+
+        /// Wrapped type (e.g., String, i64, f64)
+        #[arg(long)]
+        inner_type: String,
+
+        /// Checks run by try_new: min_len, max_len, and regex for strings; min and max for numbers
+        /// (e.g., "min_len:3,max_len:255,regex:[a-z]+")
+        #[arg(long)]
+        validations: Option<String>,
+
+        /// Output directory
+        #[arg(short, long, default_value = "src/value_objects")]
+        output: String,
+    },
+
+    /// Generate a test module for an existing model
+    #[command(name = "test")]
+    Test {
+        /// Model to test (its model file must already exist)
+        model: String,
+
+        /// Output directory (declare it with `#[cfg(test)] mod tests;` in main.rs)
+        #[arg(short, long, default_value = "src/tests")]
+        output: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum SchemaCommands {
+    /// Generate a migration that brings the database schema in line with the models
+    Sync {
+        /// Migration name
+        #[arg(short, long, default_value = "sync_schema")]
+        name: String,
+
+        /// Drop columns that no model declares
+        #[arg(long)]
+        drop_orphans: bool,
+
+        /// Include column type changes that may lose data
+        #[arg(long)]
+        destructive: bool,
+
+        /// Allow generating a migration that drops or retypes columns
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -408,9 +1158,22 @@ enum DbCommands {
         #[arg(short, long, alias = "class")]
         seeder: Option<String>,
 
+        /// Run only these seeders, in the listed order (comma-separated)
+        /// Example: --only="DatabaseSeeder,UserSeeder"
+        #[arg(long, conflicts_with_all = ["seeder", "except"])]
+        only: Option<String>,
+
+        /// Run every seeder except these (comma-separated)
+        #[arg(long, conflicts_with = "seeder")]
+        except: Option<String>,
+
         /// Force run in production
         #[arg(long)]
         force: bool,
+
+        /// Print the rows that would be inserted without writing them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Drop all tables and re-seed
@@ -444,11 +1207,11 @@ enum DbCommands {
         force: bool,
     },
 
-    /// Wipe all tables (truncate)
+    /// Empty all tables in foreign key order, keeping the migration history
     Wipe {
-        /// Also drop all types
+        /// Truncate with CASCADE on Postgres, also emptying tables that reference them
         #[arg(long)]
-        drop_types: bool,
+        cascade: bool,
 
         /// Force run in production
         #[arg(long)]
@@ -463,11 +1226,127 @@ enum DbCommands {
 
     /// List all tables
     Tables,
+
+    /// Measure round-trip latency to the database with repeated SELECT 1 queries
+    Ping {
+        /// Number of queries to send
+        #[arg(short = 'n', long, default_value = "5", value_parser = clap::value_parser!(u32).range(1..))]
+        count: u32,
+
+        /// Milliseconds to wait between queries
+        #[arg(short, long, default_value = "1000")]
+        interval_ms: u64,
+
+        /// Milliseconds to wait for each query before counting it as failed
+        #[arg(long, default_value = "5000")]
+        timeout: u64,
+    },
+
+    /// Refresh planner statistics and report table health
+    Analyze {
+        /// Tables to analyze (defaults to all tables)
+        tables: Vec<String>,
+
+        /// Run VACUUM ANALYZE instead of ANALYZE (PostgreSQL only)
+        #[arg(long)]
+        vacuum: bool,
+    },
+
+    /// Check that existing rows satisfy the tables' constraints; fails if any don't
+    CheckConstraints {
+        /// Tables to check (defaults to all tables)
+        tables: Vec<String>,
+    },
+
+    /// Check a migration's up() SQL against the database without applying it; fails if any statement is invalid
+    MigrateCheck {
+        /// Migration file to check
+        file: String,
+    },
+
+    /// Copy table data from another configured database into this one
+    Copy {
+        /// Config file of the source database (e.g., staging.toml)
+        #[arg(long)]
+        from_config: String,
+
+        /// Tables to copy (comma-separated, defaults to all tables)
+        #[arg(short, long, value_delimiter = ',')]
+        tables: Vec<String>,
+
+        /// Delete existing rows in the target tables before copying
+        #[arg(long)]
+        truncate_target: bool,
+
+        /// Allow copying when either database is in production
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Import a CSV or JSON file into an existing table
+    Import {
+        /// CSV file with a header line, or JSON array of objects
+        file: String,
+
+        /// Table to insert the rows into
+        #[arg(short, long)]
+        table: String,
+
+        /// File format (detected from the extension by default)
+        #[arg(long, value_parser = commands::import::ImportFormat::parse)]
+        format: Option<commands::import::ImportFormat>,
+
+        /// Delete existing rows in the table first
+        #[arg(long)]
+        truncate: bool,
+
+        /// Rows inserted per batch
+        #[arg(long, default_value = "500", value_parser = clap::value_parser!(u32).range(1..))]
+        batch_size: u32,
+
+        /// What to do with rows that conflict with existing ones: ignore, replace, or error
+        #[arg(long, default_value = "error", value_parser = runtime_db::OnConflict::parse)]
+        on_conflict: runtime_db::OnConflict,
+    },
+
+    /// Export a table to a CSV, TSV, or JSON file
+    Export {
+        /// Table to export
+        #[arg(short, long)]
+        table: String,
+
+        /// File to write (defaults to {table}_{timestamp}.{format})
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// File format: csv, json, or tsv
+        #[arg(long, default_value = "csv", value_parser = commands::export::ExportFormat::parse)]
+        format: commands::export::ExportFormat,
+
+        /// SQL condition rows must match, e.g. "active = 1"
+        #[arg(long = "where")]
+        where_clause: Option<String>,
+    },
+
+    /// Print the database server, driver, and CLI versions
+    Version {
+        /// Print the versions as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Open an interactive shell connected to the database
+    Shell {
+        /// Use the built-in shell instead of psql, mysql, or sqlite3
+        #[arg(long)]
+        no_system_client: bool,
+    },
 }
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    logging::init(cli.log_level, cli.log_format);
 
     // Print banner
     if cli.verbose {
@@ -480,25 +1359,88 @@ async fn main() {
             commands::migrate::handle_subcommand(&cli.config, cmd, cli.verbose).await
         }
         Commands::Make(cmd) => {
-            commands::make::handle(&cli.config, cmd, cli.verbose).await
+            commands::make::handle(&cli.config, *cmd, cli.verbose).await
         }
         Commands::Db(cmd) => {
             commands::db::handle(&cli.config, cmd, cli.verbose).await
         }
-        Commands::Init { name, database } => {
-            commands::init::run(&name, &database, cli.verbose).await
+        Commands::Init {
+            name,
+            database,
+            ignore_env,
+            no_workspace,
+            cargo_init,
+            lib,
+            no_env,
+        } => {
+            let database = commands::init::resolve_database(database.as_deref(), ignore_env);
+            let cargo_init = cargo_init.then_some(if lib {
+                commands::init::CargoInit::Lib
+            } else {
+                commands::init::CargoInit::Bin
+            });
+            commands::init::run(&name, &database, no_workspace, cargo_init, no_env, cli.verbose).await
         }
-        Commands::Config => {
+        Commands::Config {
+            export_env: true,
+            shell,
+            show_secrets,
+        } => commands::config::export_env(&cli.config, shell, show_secrets),
+        Commands::Config { .. } => {
             commands::config::show(&cli.config, cli.verbose).await
         }
-        Commands::Models => {
-            commands::models::list(&cli.config, cli.verbose).await
+        Commands::Models { since, sort_by } => {
+            commands::models::list(&cli.config, since, sort_by, cli.verbose).await
+        }
+        Commands::Schema {
+            table,
+            alphabetical,
+            format,
+            output,
+            command,
+        } => match command {
+            Some(SchemaCommands::Sync {
+                name,
+                drop_orphans,
+                destructive,
+                force,
+            }) => {
+                commands::schema::sync(&cli.config, &name, drop_orphans, destructive, force, cli.verbose)
+                    .await
+            }
+            None => {
+                commands::schema::show(&cli.config, table, alphabetical, format, output, cli.verbose).await
+            }
+        },
+        Commands::Validate {
+            strict,
+            check_style,
+            fix_style,
+        } => {
+            commands::validate::run(&cli.config, strict, check_style, fix_style, cli.verbose).await
         }
-        Commands::Schema { table } => {
-            commands::schema::show(&cli.config, table, cli.verbose).await
+        Commands::GenerateOpenapi {
+            output,
+            title,
+            version,
+            format,
+        } => commands::openapi::run(&cli.config, &output, &title, &version, format, cli.verbose),
+        Commands::Completions { shell, output } => {
+            commands::completions::run(Cli::command(), &shell, output.as_deref())
         }
-        Commands::Ui { host, port } => {
-            commands::ui::run(&host, port, cli.verbose).await
+        Commands::Ui {
+            host,
+            port,
+            cors_origin,
+            cors_max_age,
+        } => {
+            match cors_origin
+                .map(|origin| commands::ui::CorsOptions::new(origin, cors_max_age))
+                .transpose()
+            {
+                Ok(cors) => commands::ui::run(&host, port, cors, cli.verbose).await,
+                Err(error) => Err(error),
+            }
         }
     };
 
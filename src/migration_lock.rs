@@ -0,0 +1,246 @@
+//! Database-wide lock that keeps concurrent `migrate run` invocations from racing each other
+
+use crate::config::TideConfig;
+use crate::runtime_db;
+use std::time::{Duration, Instant};
+use tideorm::prelude::Database;
+
+/// Advisory lock key for PostgreSQL ("tideorm" in ASCII)
+const POSTGRES_LOCK_KEY: i64 = 0x0074_6964_656f_726d;
+
+/// Lock name for MySQL's GET_LOCK and the SQLite lock table
+const LOCK_NAME: &str = "tideorm_migrate";
+
+/// Table holding the SQLite lock row
+const SQLITE_LOCK_TABLE: &str = "_tideorm_lock";
+
+/// How often SQLite retries a lock held by another process
+const SQLITE_RETRY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A held migration lock, released by `release` or, failing that, on drop
+pub struct MigrationLock {
+    config: TideConfig,
+    db: Option<Database>,
+    owner: String,
+}
+
+impl MigrationLock {
+    /// Wait up to `timeout` for the lock
+    ///
+    /// PostgreSQL and MySQL hold a session lock on a dedicated connection, which the server
+    /// releases when that connection closes. SQLite has no such lock, so a row in
+    /// `_tideorm_lock` marks the owner instead.
+    pub async fn acquire(config: &TideConfig, timeout: Duration) -> Result<Self, String> {
+        let db = runtime_db::connect_single(config).await?;
+        let owner = format!(
+            "pid {} at {}",
+            std::process::id(),
+            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S%.6f UTC")
+        );
+
+        match config.database.driver.as_str() {
+            "postgres" | "postgresql" => acquire_postgres(&db, timeout).await?,
+            "mysql" => acquire_mysql(&db, timeout).await?,
+            "sqlite" => acquire_sqlite(&db, &owner, timeout).await?,
+            driver => return Err(format!("Unsupported database driver: {}", driver)),
+        }
+
+        Ok(Self {
+            config: config.clone(),
+            db: Some(db),
+            owner,
+        })
+    }
+
+    /// Release the lock and close its connection
+    pub async fn release(mut self) -> Result<(), String> {
+        let Some(db) = self.db.take() else {
+            return Ok(());
+        };
+
+        runtime_db::execute_on_db(&db, &release_sql(&self.config, &self.owner)).await?;
+        Ok(())
+    }
+}
+
+impl Drop for MigrationLock {
+    fn drop(&mut self) {
+        // Closing the connection is enough to release PostgreSQL and MySQL session locks
+        let Some(db) = self.db.take() else {
+            return;
+        };
+        drop(db);
+
+        if self.config.database.driver != "sqlite" {
+            return;
+        }
+
+        // The connection belongs to the runtime being unwound, so delete the row from a
+        // fresh connection on a thread of our own
+        let config = self.config.clone();
+        let sql = release_sql(&self.config, &self.owner);
+        let released = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|error| error.to_string())?;
+            runtime.block_on(runtime_db::execute(&config, &sql))
+        })
+        .join();
+
+        if !matches!(released, Ok(Ok(_))) {
+            eprintln!(
+                "Failed to release the migration lock; delete the '{}' row from {} before the next run",
+                LOCK_NAME, SQLITE_LOCK_TABLE
+            );
+        }
+    }
+}
+
+async fn acquire_postgres(db: &Database, timeout: Duration) -> Result<(), String> {
+    // lock_timeout also bounds advisory lock waits
+    runtime_db::execute_on_db(db, &format!("SET lock_timeout = {}", timeout.as_millis().max(1))).await?;
+    runtime_db::execute_on_db(db, &format!("SELECT pg_advisory_lock({})", POSTGRES_LOCK_KEY))
+        .await
+        .map_err(|error| {
+            if error.contains("lock timeout") {
+                held_elsewhere(timeout)
+            } else {
+                format!("Failed to acquire migration lock: {}", error)
+            }
+        })?;
+    runtime_db::execute_on_db(db, "RESET lock_timeout").await?;
+    Ok(())
+}
+
+async fn acquire_mysql(db: &Database, timeout: Duration) -> Result<(), String> {
+    let rows = runtime_db::query_json_on_db(
+        db,
+        &format!("SELECT GET_LOCK('{}', {}) AS acquired", LOCK_NAME, timeout.as_secs()),
+    )
+    .await?;
+
+    match rows.first().and_then(|row| row.get("acquired")).and_then(|value| value.as_i64()) {
+        Some(1) => Ok(()),
+        Some(_) => Err(held_elsewhere(timeout)),
+        None => Err("Failed to acquire migration lock: GET_LOCK returned NULL".to_string()),
+    }
+}
+
+async fn acquire_sqlite(db: &Database, owner: &str, timeout: Duration) -> Result<(), String> {
+    runtime_db::execute_on_db(
+        db,
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {} (name TEXT PRIMARY KEY, owner TEXT NOT NULL)",
+            SQLITE_LOCK_TABLE
+        ),
+    )
+    .await?;
+
+    let insert = format!(
+        "INSERT OR FAIL INTO {} (name, owner) VALUES ('{}', '{}')",
+        SQLITE_LOCK_TABLE, LOCK_NAME, owner
+    );
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match runtime_db::execute_on_db(db, &insert).await {
+            Ok(_) => return Ok(()),
+            Err(error) if error.contains("UNIQUE") => {
+                if Instant::now() >= deadline {
+                    let holder = sqlite_lock_owner(db).await.unwrap_or_default();
+                    return Err(format!(
+                        "{} (held by {}; if that process is gone, delete the '{}' row from {})",
+                        held_elsewhere(timeout),
+                        holder,
+                        LOCK_NAME,
+                        SQLITE_LOCK_TABLE
+                    ));
+                }
+                tokio::time::sleep(SQLITE_RETRY_INTERVAL.min(timeout)).await;
+            }
+            Err(error) => return Err(format!("Failed to acquire migration lock: {}", error)),
+        }
+    }
+}
+
+async fn sqlite_lock_owner(db: &Database) -> Option<String> {
+    let rows = runtime_db::query_json_on_db(
+        db,
+        &format!("SELECT owner FROM {} WHERE name = '{}'", SQLITE_LOCK_TABLE, LOCK_NAME),
+    )
+    .await
+    .ok()?;
+
+    rows.first()?.get("owner")?.as_str().map(str::to_string)
+}
+
+fn release_sql(config: &TideConfig, owner: &str) -> String {
+    match config.database.driver.as_str() {
+        "postgres" | "postgresql" => format!("SELECT pg_advisory_unlock({})", POSTGRES_LOCK_KEY),
+        "mysql" => format!("SELECT RELEASE_LOCK('{}')", LOCK_NAME),
+        _ => format!(
+            "DELETE FROM {} WHERE name = '{}' AND owner = '{}'",
+            SQLITE_LOCK_TABLE, LOCK_NAME, owner
+        ),
+    }
+}
+
+fn held_elsewhere(timeout: Duration) -> String {
+    format!(
+        "Another migration run still holds the lock after {}s; retry later or pass --no-lock",
+        timeout.as_secs_f64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MigrationLock;
+    use crate::config::TideConfig;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn sqlite_config(dir: &TempDir) -> TideConfig {
+        let mut config = TideConfig::default();
+        config.database.driver = "sqlite".to_string();
+        config.database.sqlite_path =
+            Some(dir.path().join("lock.sqlite3").to_string_lossy().replace('\\', "/"));
+        config
+    }
+
+    #[tokio::test]
+    async fn only_one_concurrent_acquisition_proceeds() {
+        let dir = TempDir::new().unwrap();
+        let config = sqlite_config(&dir);
+        let timeout = Duration::from_millis(300);
+
+        let (first, second) = tokio::join!(
+            MigrationLock::acquire(&config, timeout),
+            MigrationLock::acquire(&config, timeout)
+        );
+
+        let (held, error) = match (first, second) {
+            (Ok(lock), Err(error)) | (Err(error), Ok(lock)) => (lock, error),
+            (Ok(_), Ok(_)) => panic!("both acquisitions proceeded"),
+            (Err(first), Err(second)) => panic!("neither acquisition proceeded: {} / {}", first, second),
+        };
+        assert!(error.contains("still holds the lock"), "{}", error);
+        assert!(error.contains("--no-lock"), "{}", error);
+
+        held.release().await.unwrap();
+        MigrationLock::acquire(&config, timeout).await.unwrap().release().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn dropping_a_lock_releases_it() {
+        let dir = TempDir::new().unwrap();
+        let config = sqlite_config(&dir);
+        let timeout = Duration::from_millis(100);
+
+        let lock = MigrationLock::acquire(&config, timeout).await.unwrap();
+        assert!(MigrationLock::acquire(&config, timeout).await.is_err());
+
+        drop(lock);
+        MigrationLock::acquire(&config, timeout).await.unwrap().release().await.unwrap();
+    }
+}
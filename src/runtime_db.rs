@@ -1,13 +1,27 @@
-use crate::config::TideConfig;
+use crate::config::{url_credentials, TideConfig};
+use futures_util::StreamExt;
 use serde_json::Value;
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use tideorm::internal::{ConnectionTrait, Statement};
+use tideorm::sea_orm::sqlx::{self, Acquire, Executor};
+use tideorm::sea_orm::QueryResult;
 use tideorm::prelude::Database;
 
 pub const DEFAULT_SEEDERS_TABLE: &str = "_seeders";
 
+/// Layout version of the migrations table, bumped with each upgrade step in
+/// `upgrade_migration_table`: 1 is the original layout, 2 added `execution_ms`,
+/// 3 added `batch`
+pub const TIDEORM_SCHEMA_VERSION: i64 = 3;
+
+/// Table recording which layout version the migrations table has been upgraded to
+pub const SCHEMA_VERSION_TABLE: &str = "_tideorm_schema_version";
+
+/// Rows inserted per statement when copying between different drivers
+const COPY_BATCH_SIZE: usize = 500;
+
 #[derive(Debug, Clone)]
 pub struct ColumnDetails {
     pub name: String,
@@ -30,6 +44,28 @@ pub struct ForeignKeyDetails {
     pub column: String,
     pub references_table: String,
     pub references_column: String,
+    /// Constraint name; SQLite does not report one
+    pub constraint: Option<String>,
+}
+
+/// Result of validating one constraint against the rows of its table
+///
+/// SQLite reports a single `integrity_check` entry per table instead.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConstraintCheck {
+    pub table: String,
+    pub constraint: String,
+    /// Problems found; empty when every row satisfies the constraint
+    pub violations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableStats {
+    pub table: String,
+    pub row_estimate: Option<i64>,
+    pub dead_tuples: Option<i64>,
+    pub index_hit_ratio: Option<f64>,
+    pub messages: Vec<String>,
 }
 
 pub async fn connect(config: &TideConfig) -> Result<Database, String> {
@@ -38,7 +74,27 @@ pub async fn connect(config: &TideConfig) -> Result<Database, String> {
         create_database(config, sqlite_path).await?;
     }
 
-    Database::connect(&config.database.connection_url())
+    Database::connect(&config.database.resolve_url().await?)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Connect with a pool of exactly one connection, for session state such as advisory locks
+pub async fn connect_single(config: &TideConfig) -> Result<Database, String> {
+    if normalized_driver(config) == "sqlite" {
+        let sqlite_path = config.database.sqlite_path.as_deref().unwrap_or("database.db");
+        create_database(config, sqlite_path).await?;
+    }
+
+    // Keep the connection for the whole run; a recycled connection would drop its session locks
+    let day = std::time::Duration::from_secs(24 * 60 * 60);
+    Database::builder()
+        .url(config.database.resolve_url().await?)
+        .max_connections(1)
+        .min_connections(1)
+        .idle_timeout(day)
+        .max_lifetime(day)
+        .build()
         .await
         .map_err(|error| error.to_string())
 }
@@ -81,14 +137,82 @@ pub async fn query_json_on_db(db: &Database, sql: &str) -> Result<Vec<Value>, St
         .await
         .map_err(|error| error.to_string())?;
 
-    let mut values = Vec::new();
-    for row in rows {
-        let mut object = serde_json::Map::new();
+    Ok(rows
+        .iter()
+        .map(|row| Value::Object(row_values(row).into_iter().collect()))
+        .collect())
+}
+
+/// Stream the rows of `table_name`, optionally filtered by a SQL condition, to
+/// `on_row` as `(column, value)` pairs in column order. Returns the row count.
+pub async fn stream_table_rows<F>(
+    config: &TideConfig,
+    table_name: &str,
+    where_clause: Option<&str>,
+    mut on_row: F,
+) -> Result<u64, String>
+where
+    F: FnMut(Vec<(String, Value)>) -> Result<(), String>,
+{
+    use tideorm::sea_orm::StreamTrait;
+
+    let mut sql = format!("SELECT * FROM {}", quoted_identifier(config, table_name));
+    if let Some(condition) = where_clause.map(str::trim).filter(|condition| !condition.is_empty()) {
+        sql.push_str(&format!(" WHERE {}", condition));
+    }
+
+    let db = connect(config).await?;
+    let connection = db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+    let statement = Statement::from_string(connection.get_database_backend(), sql);
+    let mut rows = connection
+        .stream_raw(statement)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    let mut count = 0;
+    while let Some(row) = rows.next().await {
+        on_row(row_values(&row.map_err(|error| error.to_string())?))?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Read the rows of `table_name` as `(column, value)` pairs in column order
+pub async fn table_rows(
+    config: &TideConfig,
+    table_name: &str,
+    limit: Option<u64>,
+) -> Result<Vec<Vec<(String, Value)>>, String> {
+    let mut sql = format!("SELECT * FROM {}", quoted_identifier(config, table_name));
+    if let Some(limit) = limit {
+        sql.push_str(&format!(" LIMIT {}", limit));
+    }
+
+    let db = connect(config).await?;
+    let connection = db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+    let statement = Statement::from_string(connection.get_database_backend(), sql);
+    let rows = connection
+        .query_all_raw(statement)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    Ok(rows.iter().map(row_values).collect())
+}
 
-        for column_name in row.column_names() {
-            let json_value = if let Ok(value) = row.try_get::<Option<bool>>("", &column_name) {
+/// Decode every column of a row as the first JSON type that fits
+fn row_values(row: &QueryResult) -> Vec<(String, Value)> {
+    row.column_names()
+        .into_iter()
+        .map(|column_name| {
+            // Integers first: SQLite also decodes INTEGER columns as bool
+            let json_value = if let Ok(value) = row.try_get::<Option<i64>>("", &column_name) {
                 value.map(Value::from).unwrap_or(Value::Null)
-            } else if let Ok(value) = row.try_get::<Option<i64>>("", &column_name) {
+            } else if let Ok(value) = row.try_get::<Option<bool>>("", &column_name) {
                 value.map(Value::from).unwrap_or(Value::Null)
             } else if let Ok(value) = row.try_get::<Option<f64>>("", &column_name) {
                 value.map(Value::from).unwrap_or(Value::Null)
@@ -98,13 +222,9 @@ pub async fn query_json_on_db(db: &Database, sql: &str) -> Result<Vec<Value>, St
                 Value::Null
             };
 
-            object.insert(column_name.to_string(), json_value);
-        }
-
-        values.push(Value::Object(object));
-    }
-
-    Ok(values)
+            (column_name.to_string(), json_value)
+        })
+        .collect()
 }
 
 pub async fn list_tables(config: &TideConfig) -> Result<Vec<String>, String> {
@@ -177,7 +297,8 @@ pub async fn table_foreign_keys(
             quoted_identifier(config, table_name)
         ),
         "postgres" => format!(
-            "SELECT kcu.column_name, ccu.table_name AS references_table, ccu.column_name AS references_column \
+            "SELECT kcu.column_name, ccu.table_name AS references_table, ccu.column_name AS references_column, \
+             tc.constraint_name \
              FROM information_schema.table_constraints tc \
              JOIN information_schema.key_column_usage kcu \
                ON tc.constraint_name = kcu.constraint_name \
@@ -192,7 +313,8 @@ pub async fn table_foreign_keys(
             sql_string(table_name)
         ),
         "mysql" => format!(
-            "SELECT COLUMN_NAME AS column_name, REFERENCED_TABLE_NAME AS references_table, REFERENCED_COLUMN_NAME AS references_column \
+            "SELECT COLUMN_NAME AS column_name, REFERENCED_TABLE_NAME AS references_table, REFERENCED_COLUMN_NAME AS references_column, \
+             CONSTRAINT_NAME AS constraint_name \
              FROM information_schema.KEY_COLUMN_USAGE \
              WHERE TABLE_SCHEMA = DATABASE() \
                AND TABLE_NAME = {} \
@@ -211,72 +333,918 @@ pub async fn table_foreign_keys(
                 column: string_field(&row, &["column_name", "from"] )?,
                 references_table: string_field(&row, &["references_table", "table"] )?,
                 references_column: string_field(&row, &["references_column", "to"] )?,
+                constraint: string_field(&row, &["constraint_name"]),
             })
         })
         .collect())
 }
 
-pub async fn wipe_tables(config: &TideConfig, drop_types: bool) -> Result<(), String> {
+/// Check that the existing rows of `tables` satisfy their constraints
+///
+/// Postgres counts the rows failing each CHECK constraint. MySQL enforces its CHECK
+/// constraints, so only those marked NOT ENFORCED are counted. SQLite runs
+/// `PRAGMA integrity_check`, which covers CHECK and NOT NULL constraints.
+pub async fn check_constraints(config: &TideConfig, tables: &[String]) -> Result<Vec<ConstraintCheck>, String> {
     let db = connect(config).await?;
-    let tables = list_tables(config).await?;
+    let mut checks = Vec::new();
 
-    match normalized_driver(config) {
+    let constraints_sql = match normalized_driver(config) {
         "sqlite" => {
-            execute_on_db(&db, "PRAGMA foreign_keys = OFF;").await?;
             for table in tables {
-                execute_on_db(
+                let rows = query_json_on_db(
                     &db,
-                    &format!("DROP TABLE IF EXISTS {}", quoted_identifier(config, &table)),
+                    &format!("PRAGMA integrity_check({})", quoted_identifier(config, table)),
                 )
                 .await?;
+                let mut violations: Vec<(String, usize)> = Vec::new();
+                for message in rows.iter().filter_map(|row| string_field(row, &["integrity_check"])) {
+                    if message == "ok" {
+                        continue;
+                    }
+                    // Each failing row repeats the same message
+                    match violations.iter_mut().find(|(existing, _)| *existing == message) {
+                        Some((_, count)) => *count += 1,
+                        None => violations.push((message, 1)),
+                    }
+                }
+
+                checks.push(ConstraintCheck {
+                    table: table.clone(),
+                    constraint: "integrity_check".to_string(),
+                    violations: violations
+                        .into_iter()
+                        .map(|(message, count)| match count {
+                            1 => message,
+                            count => format!("{} ({} rows)", message, count),
+                        })
+                        .collect(),
+                });
             }
-            let _ = execute_on_db(&db, "DELETE FROM sqlite_sequence;").await;
-            execute_on_db(&db, "PRAGMA foreign_keys = ON;").await?;
+            return Ok(checks);
         }
-        "postgres" => {
-            if !tables.is_empty() {
-                let joined = tables
-                    .iter()
-                    .map(|table| quoted_identifier(config, table))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                execute_on_db(&db, &format!("DROP TABLE IF EXISTS {} CASCADE", joined)).await?;
+        "postgres" => "SELECT t.relname AS table_name, c.conname AS constraint_name, \
+                pg_get_constraintdef(c.oid) AS definition \
+             FROM pg_constraint c \
+             JOIN pg_class t ON t.oid = c.conrelid \
+             JOIN pg_namespace n ON n.oid = t.relnamespace \
+             WHERE c.contype = 'c' AND n.nspname = current_schema() \
+             ORDER BY t.relname, c.conname"
+            .to_string(),
+        "mysql" => "SELECT tc.TABLE_NAME AS table_name, tc.CONSTRAINT_NAME AS constraint_name, \
+                cc.CHECK_CLAUSE AS definition, tc.ENFORCED AS enforced \
+             FROM information_schema.TABLE_CONSTRAINTS tc \
+             JOIN information_schema.CHECK_CONSTRAINTS cc \
+               ON cc.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA AND cc.CONSTRAINT_NAME = tc.CONSTRAINT_NAME \
+             WHERE tc.CONSTRAINT_TYPE = 'CHECK' AND tc.TABLE_SCHEMA = DATABASE() \
+             ORDER BY tc.TABLE_NAME, tc.CONSTRAINT_NAME"
+            .to_string(),
+        driver => return Err(format!("Unsupported database driver: {}", driver)),
+    };
+
+    for row in query_json_on_db(&db, &constraints_sql).await? {
+        let (Some(table), Some(constraint), Some(definition)) = (
+            string_field(&row, &["table_name"]),
+            string_field(&row, &["constraint_name"]),
+            string_field(&row, &["definition"]),
+        ) else {
+            continue;
+        };
+        if !tables.contains(&table) {
+            continue;
+        }
+
+        let mut violations = Vec::new();
+        // The database already rejects rows failing an enforced constraint
+        if string_field(&row, &["enforced"]).as_deref() != Some("YES") {
+            let expression = check_expression(&definition);
+            let count_sql = format!(
+                "SELECT COUNT(*) AS violations FROM {} WHERE NOT ({})",
+                quoted_identifier(config, &table),
+                expression
+            );
+            let count = query_json_on_db(&db, &count_sql)
+                .await?
+                .first()
+                .and_then(|row| int_field(row, &["violations"]))
+                .unwrap_or_default();
+            if count > 0 {
+                violations.push(format!("{} row(s) fail {}", count, expression));
             }
+        }
 
-            if drop_types {
-                let types = query_json(
-                    config,
-                    "SELECT t.typname AS type_name FROM pg_type t JOIN pg_namespace n ON n.oid = t.typnamespace WHERE n.nspname = 'public' AND t.typtype = 'e' ORDER BY t.typname",
-                )
-                .await?;
+        checks.push(ConstraintCheck {
+            table,
+            constraint,
+            violations,
+        });
+    }
 
-                for row in types {
-                    if let Some(type_name) = string_field(&row, &["type_name"]) {
-                        execute_on_db(
-                            &db,
-                            &format!("DROP TYPE IF EXISTS {} CASCADE", quoted_identifier(config, &type_name)),
-                        )
-                        .await?;
-                    }
-                }
+    Ok(checks)
+}
+
+/// A statement the database rejected while checking migration SQL
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlError {
+    pub statement: String,
+    pub message: String,
+}
+
+/// Check `statements` against the database without keeping any change they make
+///
+/// Postgres and SQLite apply schema changes inside a transaction that is rolled back, so later
+/// statements can use the tables earlier ones create; data statements are only explained
+/// (Postgres) or prepared with `sqlite3_prepare_v2` (SQLite). MySQL can't roll back DDL, so every
+/// statement is only prepared there, and tables created by the same migration are unknown.
+pub async fn check_sql(config: &TideConfig, statements: &[&str]) -> Result<Vec<SqlError>, String> {
+    let db = connect(config).await?;
+    let connection = db.__internal_connection().map_err(|error| error.to_string())?;
+
+    match normalized_driver(config) {
+        "sqlite" => check_sqlite_sql(connection.get_sqlite_connection_pool(), statements).await,
+        "postgres" => check_postgres_sql(connection.get_postgres_connection_pool(), statements).await,
+        "mysql" => check_mysql_sql(connection.get_mysql_connection_pool(), statements).await,
+        driver => Err(format!("Unsupported database driver: {}", driver)),
+    }
+}
+
+async fn check_sqlite_sql(pool: &sqlx::SqlitePool, statements: &[&str]) -> Result<Vec<SqlError>, String> {
+    let mut transaction = pool.begin().await.map_err(|error| error.to_string())?;
+    let mut errors = Vec::new();
+
+    for statement in statements {
+        let result = match (&mut *transaction).prepare(statement).await {
+            Ok(_) if is_data_statement(statement) => Ok(()),
+            Ok(_) => apply_in_savepoint(&mut transaction, statement).await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            errors.push(sql_error(statement, error));
+        }
+    }
+
+    transaction.rollback().await.map_err(|error| error.to_string())?;
+    Ok(errors)
+}
+
+async fn check_postgres_sql(pool: &sqlx::PgPool, statements: &[&str]) -> Result<Vec<SqlError>, String> {
+    let mut transaction = pool.begin().await.map_err(|error| error.to_string())?;
+    let mut errors = Vec::new();
+
+    for statement in statements {
+        let result = if is_data_statement(statement) {
+            apply_in_savepoint(&mut transaction, &format!("EXPLAIN {}", statement)).await
+        } else {
+            apply_in_savepoint(&mut transaction, statement).await
+        };
+        if let Err(error) = result {
+            errors.push(sql_error(statement, error));
+        }
+    }
+
+    transaction.rollback().await.map_err(|error| error.to_string())?;
+    Ok(errors)
+}
+
+async fn check_mysql_sql(pool: &sqlx::MySqlPool, statements: &[&str]) -> Result<Vec<SqlError>, String> {
+    // PREPARE names live in the session, so both statements need the same connection
+    let mut connection = pool.acquire().await.map_err(|error| error.to_string())?;
+    let mut errors = Vec::new();
+
+    for statement in statements {
+        let prepare = format!(
+            "PREPARE tideorm_check FROM '{}'",
+            statement.replace('\\', "\\\\").replace('\'', "''")
+        );
+        match sqlx::raw_sql(&prepare).execute(&mut *connection).await {
+            Ok(_) => {
+                sqlx::raw_sql("DEALLOCATE PREPARE tideorm_check")
+                    .execute(&mut *connection)
+                    .await
+                    .map_err(|error| error.to_string())?;
             }
+            Err(error) => errors.push(sql_error(statement, error)),
         }
-        "mysql" => {
-            execute_on_db(&db, "SET FOREIGN_KEY_CHECKS = 0").await?;
-            if !tables.is_empty() {
-                let joined = tables
+    }
+
+    Ok(errors)
+}
+
+/// Run `sql`, undoing just its own changes if it fails so the transaction stays usable
+async fn apply_in_savepoint<DB>(transaction: &mut sqlx::Transaction<'_, DB>, sql: &str) -> Result<(), sqlx::Error>
+where
+    DB: sqlx::Database,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+{
+    let mut savepoint = transaction.begin().await?;
+    match sqlx::raw_sql(sql).execute(&mut *savepoint).await {
+        Ok(_) => savepoint.commit().await,
+        Err(error) => {
+            savepoint.rollback().await?;
+            Err(error)
+        }
+    }
+}
+
+/// Whether `statement` reads or writes rows rather than changing the schema
+fn is_data_statement(statement: &str) -> bool {
+    let normalized = statement.trim_start().to_ascii_uppercase();
+
+    ["SELECT", "WITH", "INSERT", "UPDATE", "DELETE", "VALUES"]
+        .iter()
+        .any(|keyword| normalized.starts_with(keyword))
+}
+
+/// The database's own message for a rejected statement
+fn sql_error(statement: &str, error: sqlx::Error) -> SqlError {
+    SqlError {
+        statement: statement.trim().to_string(),
+        message: match error.as_database_error() {
+            Some(error) => error.message().to_string(),
+            None => error.to_string(),
+        },
+    }
+}
+
+/// The boolean expression of a CHECK constraint definition such as `CHECK ((age > 0)) NOT VALID`
+fn check_expression(definition: &str) -> &str {
+    let definition = definition.trim();
+    let definition = definition.strip_suffix("NOT VALID").unwrap_or(definition).trim_end();
+    definition.strip_prefix("CHECK").unwrap_or(definition).trim()
+}
+
+pub async fn analyze_tables(
+    config: &TideConfig,
+    tables: &[String],
+    vacuum: bool,
+) -> Result<Vec<TableStats>, String> {
+    let db = connect(config).await?;
+    let driver = normalized_driver(config);
+
+    if driver == "sqlite" && !tables.is_empty() {
+        execute_on_db(&db, "ANALYZE").await?;
+    }
+
+    let mut stats = Vec::with_capacity(tables.len());
+
+    for table in tables {
+        let quoted = quoted_identifier(config, table);
+        let mut messages = Vec::new();
+
+        let stats_sql = match driver {
+            "postgres" => {
+                // VERBOSE output arrives as NOTICEs, which the connection doesn't surface
+                let command = if vacuum { "VACUUM ANALYZE" } else { "ANALYZE" };
+                execute_on_db(&db, &format!("{} {}", command, quoted)).await?;
+
+                format!(
+                    "SELECT s.n_live_tup AS row_estimate, s.n_dead_tup AS dead_tuples, \
+                     CAST(io.idx_blks_hit AS DOUBLE PRECISION) / NULLIF(io.idx_blks_hit + io.idx_blks_read, 0) AS index_hit_ratio \
+                     FROM pg_stat_user_tables s \
+                     JOIN pg_statio_user_tables io ON io.relid = s.relid \
+                     WHERE s.schemaname = 'public' AND s.relname = {}",
+                    sql_string(table)
+                )
+            }
+            "mysql" => {
+                let rows = query_json_on_db(&db, &format!("OPTIMIZE TABLE {}", quoted)).await?;
+                messages = rows
                     .iter()
-                    .map(|table| quoted_identifier(config, table))
+                    .filter_map(|row| {
+                        let kind = string_field(row, &["Msg_type", "msg_type"])?;
+                        let text = string_field(row, &["Msg_text", "msg_text"])?;
+                        Some(format!("{}: {}", kind, text))
+                    })
+                    .collect();
+
+                format!(
+                    "SELECT CAST(TABLE_ROWS AS SIGNED) AS row_estimate \
+                     FROM information_schema.TABLES \
+                     WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = {}",
+                    sql_string(table)
+                )
+            }
+            "sqlite" => format!(
+                "SELECT stat FROM sqlite_stat1 WHERE tbl = {} ORDER BY idx IS NOT NULL",
+                sql_string(table)
+            ),
+            driver => return Err(format!("Unsupported database driver: {}", driver)),
+        };
+
+        let rows = query_json_on_db(&db, &stats_sql).await?;
+        let row = rows.first();
+
+        let row_estimate = match driver {
+            "sqlite" => match row.and_then(|row| string_field(row, &["stat"])) {
+                Some(stat) => parse_sqlite_stat_rows(&stat),
+                // ANALYZE records nothing for empty tables
+                None => Some(0),
+            },
+            _ => row.and_then(|row| int_field(row, &["row_estimate"])),
+        };
+
+        stats.push(TableStats {
+            table: table.clone(),
+            row_estimate,
+            dead_tuples: row.and_then(|row| int_field(row, &["dead_tuples"])),
+            index_hit_ratio: row.and_then(|row| float_field(row, &["index_hit_ratio"])),
+            messages,
+        });
+    }
+
+    Ok(stats)
+}
+
+/// Empty the given tables, which must be ordered with referencing tables first
+pub async fn truncate_tables(config: &TideConfig, tables: &[String], cascade: bool) -> Result<(), String> {
+    let db = connect(config).await?;
+
+    for sql in truncate_statements(config, tables, cascade)? {
+        execute_on_db(&db, &sql).await?;
+    }
+
+    if normalized_driver(config) == "sqlite" && !tables.is_empty() {
+        // sqlite_sequence only exists once an AUTOINCREMENT table has been written to
+        let names = tables
+            .iter()
+            .map(|table| format!("'{}'", table.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = execute_on_db(&db, &format!("DELETE FROM sqlite_sequence WHERE name IN ({})", names)).await;
+    }
+
+    Ok(())
+}
+
+/// Build the statements that empty `tables` in order, resetting identity columns where supported
+///
+/// Postgres refuses to truncate a referenced table on its own, so without `cascade` every
+/// table goes into a single `TRUNCATE`.
+pub fn truncate_statements(config: &TideConfig, tables: &[String], cascade: bool) -> Result<Vec<String>, String> {
+    let quoted: Vec<String> = tables.iter().map(|table| quoted_identifier(config, table)).collect();
+
+    let statements = match normalized_driver(config) {
+        "postgres" if cascade => quoted
+            .iter()
+            .map(|table| format!("TRUNCATE TABLE {} RESTART IDENTITY CASCADE", table))
+            .collect(),
+        "postgres" if quoted.is_empty() => Vec::new(),
+        "postgres" => vec![format!("TRUNCATE TABLE {} RESTART IDENTITY", quoted.join(", "))],
+        "mysql" => quoted
+            .iter()
+            .map(|table| {
+                format!("SET FOREIGN_KEY_CHECKS = 0; TRUNCATE TABLE {}; SET FOREIGN_KEY_CHECKS = 1", table)
+            })
+            .collect(),
+        "sqlite" => quoted.iter().map(|table| format!("DELETE FROM {}", table)).collect(),
+        driver => return Err(format!("Unsupported database driver: {}", driver)),
+    };
+
+    Ok(statements)
+}
+
+/// Drop each table in the given order, collecting failures instead of stopping at the first
+pub async fn drop_tables(config: &TideConfig, tables: &[String]) -> Result<Vec<(String, String)>, String> {
+    let db = connect(config).await?;
+    let mut failures = Vec::new();
+
+    for table in tables {
+        let quoted = quoted_identifier(config, table);
+        let sql = match normalized_driver(config) {
+            "postgres" => format!("DROP TABLE IF EXISTS {} CASCADE", quoted),
+            "mysql" => format!(
+                "SET FOREIGN_KEY_CHECKS = 0; DROP TABLE IF EXISTS {}; SET FOREIGN_KEY_CHECKS = 1",
+                quoted
+            ),
+            "sqlite" => format!("DROP TABLE IF EXISTS {}", quoted),
+            driver => return Err(format!("Unsupported database driver: {}", driver)),
+        };
+
+        if let Err(error) = execute_on_db(&db, &sql).await {
+            failures.push((table.clone(), error));
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Drop the enum types in the public schema (PostgreSQL only)
+pub async fn drop_enum_types(config: &TideConfig) -> Result<(), String> {
+    if normalized_driver(config) != "postgres" {
+        return Ok(());
+    }
+
+    let db = connect(config).await?;
+    let types = query_json(
+        config,
+        "SELECT t.typname AS type_name FROM pg_type t JOIN pg_namespace n ON n.oid = t.typnamespace WHERE n.nspname = 'public' AND t.typtype = 'e' ORDER BY t.typname",
+    )
+    .await?;
+
+    for row in types {
+        if let Some(type_name) = string_field(&row, &["type_name"]) {
+            execute_on_db(
+                &db,
+                &format!("DROP TYPE IF EXISTS {} CASCADE", quoted_identifier(config, &type_name)),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn clear_table(config: &TideConfig, table_name: &str) -> Result<u64, String> {
+    execute(
+        config,
+        &format!("DELETE FROM {}", quoted_identifier(config, table_name)),
+    )
+    .await
+}
+
+/// Copy every row of a table from one database into the same table of another
+pub async fn copy_table(
+    source: &TideConfig,
+    target: &TideConfig,
+    table_name: &str,
+) -> Result<u64, String> {
+    let copied = match (normalized_driver(source), normalized_driver(target)) {
+        ("postgres", "postgres") => copy_postgres_table(source, target, table_name).await,
+        ("sqlite", "sqlite") => copy_sqlite_table(source, target, table_name).await,
+        _ => copy_table_rows(source, target, table_name).await,
+    }?;
+
+    // Rows keep their ids, so Postgres sequences must be moved past them before the next insert
+    if normalized_driver(target) == "postgres" {
+        reset_postgres_sequences(target, table_name).await?;
+    }
+
+    Ok(copied)
+}
+
+/// Set each serial or identity sequence of a table to the largest value in its column
+async fn reset_postgres_sequences(config: &TideConfig, table_name: &str) -> Result<(), String> {
+    let table = sql_string(&quoted_identifier(config, table_name));
+    let columns = query_json(
+        config,
+        &format!(
+            "SELECT CAST(column_name AS TEXT) AS column_name \
+             FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = {} \
+             AND pg_get_serial_sequence({}, CAST(column_name AS TEXT)) IS NOT NULL",
+            sql_string(table_name),
+            table
+        ),
+    )
+    .await?;
+
+    for column in columns.iter().filter_map(|row| row.get("column_name").and_then(Value::as_str)) {
+        execute(config, &sequence_reset_sql(config, table_name, column)).await?;
+    }
+
+    Ok(())
+}
+
+/// `setval` for a column's sequence; an empty column restarts it at 1
+fn sequence_reset_sql(config: &TideConfig, table_name: &str, column: &str) -> String {
+    let quoted = quoted_identifier(config, column);
+    format!(
+        "SELECT setval(pg_get_serial_sequence({}, {}), COALESCE(MAX({}), 1), MAX({}) IS NOT NULL) FROM {}",
+        sql_string(&quoted_identifier(config, table_name)),
+        sql_string(column),
+        quoted,
+        quoted,
+        quoted_identifier(config, table_name)
+    )
+}
+
+/// Copy a table by selecting rows as text and inserting them in batches
+pub async fn copy_table_rows(
+    source: &TideConfig,
+    target: &TideConfig,
+    table_name: &str,
+) -> Result<u64, String> {
+    let columns = source_column_names(source, table_name).await?;
+    let text_type = match normalized_driver(source) {
+        "mysql" => "CHAR",
+        _ => "TEXT",
+    };
+
+    let select_list = columns
+        .iter()
+        .map(|column| {
+            let quoted = quoted_identifier(source, column);
+            format!("CAST({} AS {}) AS {}", quoted, text_type, quoted)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rows = query_json(
+        source,
+        &format!("SELECT {} FROM {}", select_list, quoted_identifier(source, table_name)),
+    )
+    .await?;
+
+    let db = connect(target).await?;
+    let column_list = quoted_column_list(target, &columns);
+    let mut copied = 0;
+
+    for batch in rows.chunks(COPY_BATCH_SIZE) {
+        let values = batch
+            .iter()
+            .map(|row| {
+                let literals = columns
+                    .iter()
+                    .map(|column| match row.get(column) {
+                        None | Some(Value::Null) => "NULL".to_string(),
+                        Some(Value::String(value)) => sql_literal(target, value),
+                        Some(value) => sql_literal(target, &value.to_string()),
+                    })
                     .collect::<Vec<_>>()
                     .join(", ");
-                execute_on_db(&db, &format!("DROP TABLE IF EXISTS {}", joined)).await?;
+                format!("({})", literals)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        copied += execute_on_db(
+            &db,
+            &format!(
+                "INSERT INTO {} ({}) VALUES {}",
+                quoted_identifier(target, table_name),
+                column_list,
+                values
+            ),
+        )
+        .await?;
+    }
+
+    Ok(copied)
+}
+
+/// Insert a single JSON object as a row, returning the number of affected rows
+pub async fn insert_row(config: &TideConfig, table_name: &str, row: &Value) -> Result<u64, String> {
+    let Value::Object(fields) = row else {
+        return Err(format!("Row for {} must be a JSON object", table_name));
+    };
+
+    let columns: Vec<String> = fields.keys().cloned().collect();
+    let values = fields
+        .values()
+        .map(|value| match value {
+            Value::Null => "NULL".to_string(),
+            Value::Bool(value) => if *value { "TRUE" } else { "FALSE" }.to_string(),
+            Value::Number(value) => value.to_string(),
+            Value::String(value) => sql_literal(config, value),
+            value => sql_literal(config, &value.to_string()),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    execute(
+        config,
+        &format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quoted_identifier(config, table_name),
+            quoted_column_list(config, &columns),
+            values
+        ),
+    )
+    .await
+}
+
+/// What to do when an inserted row conflicts with an existing one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnConflict {
+    Ignore,
+    Replace,
+    Error,
+}
+
+impl OnConflict {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "replace" => Ok(Self::Replace),
+            "error" => Ok(Self::Error),
+            other => Err(format!(
+                "Unknown conflict strategy: {}. Expected one of: ignore, replace, error",
+                other
+            )),
+        }
+    }
+}
+
+/// Bulk inserts rows of JSON values into one table with bound parameters
+pub struct RowInserter {
+    db: Database,
+    driver: String,
+    prefix: String,
+    suffix: String,
+    /// Postgres column types, so text and integer parameters are cast to the column type
+    casts: Vec<Option<String>>,
+    max_rows: usize,
+}
+
+/// Bind one row of values into a `push_values` tuple
+macro_rules! push_row_values {
+    ($row_builder:expr, $row:expr, $casts:expr) => {
+        for (value, cast) in $row.iter().zip($casts) {
+            match value {
+                Value::Null => $row_builder.push_bind(None::<String>),
+                Value::Bool(value) => $row_builder.push_bind(*value),
+                Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+                    (Some(value), _) => $row_builder.push_bind(value),
+                    (None, Some(value)) => $row_builder.push_bind(value),
+                    (None, None) => $row_builder.push_bind(number.to_string()),
+                },
+                Value::String(value) => $row_builder.push_bind(value.clone()),
+                value => $row_builder.push_bind(value.to_string()),
+            };
+            if let Some(cast) = cast {
+                $row_builder.push_unseparated(format!("::{}", cast));
             }
-            execute_on_db(&db, "SET FOREIGN_KEY_CHECKS = 1").await?;
         }
-        driver => return Err(format!("Unsupported database driver: {}", driver)),
+    };
+}
+
+impl RowInserter {
+    /// Prepare inserts of `columns` into `table_name`
+    pub async fn new(
+        config: &TideConfig,
+        table_name: &str,
+        columns: &[String],
+        on_conflict: OnConflict,
+    ) -> Result<Self, String> {
+        let driver = normalized_driver(config).to_string();
+        let table = quoted_identifier(config, table_name);
+        let column_list = quoted_column_list(config, columns);
+
+        let verb = match (driver.as_str(), on_conflict) {
+            ("sqlite", OnConflict::Ignore) => "INSERT OR IGNORE INTO",
+            ("sqlite", OnConflict::Replace) => "INSERT OR REPLACE INTO",
+            ("mysql", OnConflict::Ignore) => "INSERT IGNORE INTO",
+            ("mysql", OnConflict::Replace) => "REPLACE INTO",
+            _ => "INSERT INTO",
+        };
+        let prefix = format!("{} {} ({}) ", verb, table, column_list);
+
+        let db = connect(config).await?;
+        let (suffix, casts) = if driver == "postgres" {
+            let suffix = match on_conflict {
+                OnConflict::Ignore => " ON CONFLICT DO NOTHING".to_string(),
+                OnConflict::Replace => postgres_upsert_clause(config, table_name, columns).await?,
+                OnConflict::Error => String::new(),
+            };
+            let types = postgres_column_types(&db, table_name).await?;
+            let casts = columns.iter().map(|column| types.get(column).cloned()).collect();
+            (suffix, casts)
+        } else {
+            (String::new(), vec![None; columns.len()])
+        };
+
+        // Stay under the bind parameter limit of a single statement
+        let max_parameters = if driver == "sqlite" { 32_766 } else { 65_535 };
+
+        Ok(Self {
+            db,
+            driver,
+            prefix,
+            suffix,
+            casts,
+            max_rows: (max_parameters / columns.len().max(1)).max(1),
+        })
     }
 
-    Ok(())
+    /// Insert rows, splitting them into statements as the parameter limit requires
+    pub async fn insert(&self, rows: &[Vec<Value>]) -> Result<u64, String> {
+        use tideorm::sea_orm::sqlx::{MySql, Postgres, QueryBuilder, Sqlite};
+
+        let connection = self
+            .db
+            .__internal_connection()
+            .map_err(|error| error.to_string())?;
+        let mut affected = 0;
+
+        for chunk in rows.chunks(self.max_rows) {
+            affected += match self.driver.as_str() {
+                "postgres" => {
+                    let mut builder = QueryBuilder::<Postgres>::new(&self.prefix);
+                    builder.push_values(chunk, |mut row_builder, row| {
+                        push_row_values!(row_builder, row, &self.casts)
+                    });
+                    builder.push(&self.suffix);
+                    builder
+                        .build()
+                        .execute(connection.get_postgres_connection_pool())
+                        .await
+                        .map(|result| result.rows_affected())
+                }
+                "mysql" => {
+                    let mut builder = QueryBuilder::<MySql>::new(&self.prefix);
+                    builder.push_values(chunk, |mut row_builder, row| {
+                        push_row_values!(row_builder, row, &self.casts)
+                    });
+                    builder
+                        .build()
+                        .execute(connection.get_mysql_connection_pool())
+                        .await
+                        .map(|result| result.rows_affected())
+                }
+                _ => {
+                    let mut builder = QueryBuilder::<Sqlite>::new(&self.prefix);
+                    builder.push_values(chunk, |mut row_builder, row| {
+                        push_row_values!(row_builder, row, &self.casts)
+                    });
+                    builder
+                        .build()
+                        .execute(connection.get_sqlite_connection_pool())
+                        .await
+                        .map(|result| result.rows_affected())
+                }
+            }
+            .map_err(|error| error.to_string())?;
+        }
+
+        Ok(affected)
+    }
+}
+
+/// `ON CONFLICT (pk) DO UPDATE` clause that overwrites the other imported columns
+async fn postgres_upsert_clause(
+    config: &TideConfig,
+    table_name: &str,
+    columns: &[String],
+) -> Result<String, String> {
+    let primary_key: Vec<String> = table_columns(config, table_name)
+        .await?
+        .into_iter()
+        .filter(|column| column.key.as_deref() == Some("PRI"))
+        .map(|column| column.name)
+        .collect();
+
+    if primary_key.is_empty() {
+        return Err(format!(
+            "Table {} has no primary key to detect conflicts with; use --on-conflict=ignore or error",
+            table_name
+        ));
+    }
+
+    let updates: Vec<String> = columns
+        .iter()
+        .filter(|column| !primary_key.contains(column))
+        .map(|column| {
+            let quoted = quoted_identifier(config, column);
+            format!("{} = EXCLUDED.{}", quoted, quoted)
+        })
+        .collect();
+
+    let target = quoted_column_list(config, &primary_key);
+    Ok(if updates.is_empty() {
+        format!(" ON CONFLICT ({}) DO NOTHING", target)
+    } else {
+        format!(" ON CONFLICT ({}) DO UPDATE SET {}", target, updates.join(", "))
+    })
+}
+
+/// Cast target for each column of a Postgres table, keyed by column name
+async fn postgres_column_types(db: &Database, table_name: &str) -> Result<BTreeMap<String, String>, String> {
+    let rows = query_json_on_db(
+        db,
+        &format!(
+            "SELECT column_name::text AS column_name, udt_name::text AS udt_name \
+             FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = {}",
+            sql_string(table_name)
+        ),
+    )
+    .await?;
+
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let column = string_field(row, &["column_name"])?;
+            let udt = string_field(row, &["udt_name"])?;
+            // Array types are named after their element type with a leading underscore
+            let cast = match udt.strip_prefix('_') {
+                Some(element) => format!("\"{}\"[]", element.replace('"', "\"\"")),
+                None => format!("\"{}\"", udt.replace('"', "\"\"")),
+            };
+            Some((column, cast))
+        })
+        .collect())
+}
+
+async fn copy_postgres_table(
+    source: &TideConfig,
+    target: &TideConfig,
+    table_name: &str,
+) -> Result<u64, String> {
+    use tideorm::sea_orm::sqlx::postgres::PgPoolCopyExt;
+
+    let columns = source_column_names(source, table_name).await?;
+    let table = quoted_identifier(source, table_name);
+    let column_list = quoted_column_list(source, &columns);
+
+    let source_db = connect(source).await?;
+    let target_db = connect(target).await?;
+    let source_connection = source_db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+    let target_connection = target_db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+
+    let mut rows = source_connection
+        .get_postgres_connection_pool()
+        .copy_out_raw(&format!("COPY {} ({}) TO STDOUT", table, column_list))
+        .await
+        .map_err(|error| error.to_string())?;
+    let mut copy_in = target_connection
+        .get_postgres_connection_pool()
+        .copy_in_raw(&format!("COPY {} ({}) FROM STDIN", table, column_list))
+        .await
+        .map_err(|error| error.to_string())?;
+
+    while let Some(chunk) = rows.next().await {
+        let sent = match chunk {
+            Ok(chunk) => copy_in.send(chunk).await.map(|_| ()),
+            Err(error) => Err(error),
+        };
+
+        if let Err(error) = sent {
+            let _ = copy_in.abort(error.to_string()).await;
+            return Err(error.to_string());
+        }
+    }
+
+    copy_in.finish().await.map_err(|error| error.to_string())
+}
+
+async fn copy_sqlite_table(
+    source: &TideConfig,
+    target: &TideConfig,
+    table_name: &str,
+) -> Result<u64, String> {
+    use tideorm::sea_orm::sqlx;
+
+    let columns = source_column_names(source, table_name).await?;
+    let column_list = quoted_column_list(target, &columns);
+    let table = quoted_identifier(target, table_name);
+    let source_path = source.database.sqlite_path.as_deref().unwrap_or("database.db");
+
+    let target_db = connect(target).await?;
+    let target_connection = target_db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+
+    // ATTACH only applies to one connection, so keep every statement on the same one
+    let mut connection = target_connection
+        .get_sqlite_connection_pool()
+        .acquire()
+        .await
+        .map_err(|error| error.to_string())?;
+
+    sqlx::query(&format!("ATTACH DATABASE {} AS copy_source", sql_string(source_path)))
+        .execute(&mut *connection)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    let copied = sqlx::query(&format!(
+        "INSERT INTO main.{} ({}) SELECT {} FROM copy_source.{}",
+        table, column_list, column_list, table
+    ))
+    .execute(&mut *connection)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|error| error.to_string());
+
+    sqlx::query("DETACH DATABASE copy_source")
+        .execute(&mut *connection)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    copied
+}
+
+async fn source_column_names(config: &TideConfig, table_name: &str) -> Result<Vec<String>, String> {
+    let columns: Vec<String> = table_columns(config, table_name)
+        .await?
+        .into_iter()
+        .map(|column| column.name)
+        .collect();
+
+    if columns.is_empty() {
+        return Err(format!("Table not found in source database: {}", table_name));
+    }
+
+    Ok(columns)
+}
+
+fn quoted_column_list(config: &TideConfig, columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| quoted_identifier(config, column))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 pub async fn create_database(config: &TideConfig, database_name: &str) -> Result<(), String> {
@@ -301,7 +1269,7 @@ pub async fn create_database(config: &TideConfig, database_name: &str) -> Result
             Ok(())
         }
         "postgres" | "mysql" => {
-            let admin_db = connect_with_url(&admin_connection_url(config)?).await?;
+            let admin_db = connect_with_url(&admin_connection_url(config).await?).await?;
             execute_on_db(
                 &admin_db,
                 &format!("CREATE DATABASE {}", quoted_identifier(config, database_name)),
@@ -344,9 +1312,99 @@ pub async fn ensure_migration_table_on_db(
     migration_table: &str,
 ) -> Result<(), String> {
     execute_on_db(db, &metadata_table_sql(config, migration_table, true)).await?;
+    upgrade_migration_table(db, config, migration_table).await?;
+    record_schema_version(db, config).await
+}
+
+/// Bring a migrations table created by an older CLI up to [`TIDEORM_SCHEMA_VERSION`]
+async fn upgrade_migration_table(
+    db: &Database,
+    config: &TideConfig,
+    migration_table: &str,
+) -> Result<(), String> {
+    let rows = query_json_on_db(db, &columns_sql(config, migration_table)?).await?;
+    let has_column = |name: &str| {
+        rows.iter()
+            .any(|row| column_from_row(config, row).name.eq_ignore_ascii_case(name))
+    };
+
+    // Version 2: tables created before execution times were tracked lack the column.
+    // Version 3: likewise for batches; rows that ran before it keep a NULL batch.
+    for (column, sql_type) in [("execution_ms", "BIGINT"), ("batch", "INTEGER")] {
+        if !has_column(column) {
+            execute_on_db(
+                db,
+                &format!(
+                    "ALTER TABLE {} ADD COLUMN {} {}",
+                    quoted_identifier(config, migration_table),
+                    quoted_identifier(config, column),
+                    sql_type
+                ),
+            )
+            .await?;
+        }
+    }
+
     Ok(())
 }
 
+async fn record_schema_version(db: &Database, config: &TideConfig) -> Result<(), String> {
+    let table = quoted_identifier(config, SCHEMA_VERSION_TABLE);
+    let version = quoted_identifier(config, "version");
+
+    execute_on_db(
+        db,
+        &format!("CREATE TABLE IF NOT EXISTS {} ({} INTEGER NOT NULL)", table, version),
+    )
+    .await?;
+    execute_on_db(db, &format!("DELETE FROM {}", table)).await?;
+    execute_on_db(
+        db,
+        &format!("INSERT INTO {} ({}) VALUES ({})", table, version, TIDEORM_SCHEMA_VERSION),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Layout version of an existing migrations table, or `None` when it does not exist yet
+///
+/// Tables from before the version was recorded count as version 1.
+pub async fn migration_table_schema_version(
+    config: &TideConfig,
+    migration_table: &str,
+) -> Result<Option<i64>, String> {
+    let tables = list_tables(config).await?;
+    if !tables.iter().any(|table| table == migration_table) {
+        return Ok(None);
+    }
+    if !tables.iter().any(|table| table == SCHEMA_VERSION_TABLE) {
+        return Ok(Some(1));
+    }
+
+    let db = connect(config).await?;
+    let connection = db
+        .__internal_connection()
+        .map_err(|error| error.to_string())?;
+    let statement = Statement::from_string(
+        connection.get_database_backend(),
+        format!(
+            "SELECT MAX({}) AS version FROM {}",
+            quoted_identifier(config, "version"),
+            quoted_identifier(config, SCHEMA_VERSION_TABLE)
+        ),
+    );
+    let row = connection
+        .query_one_raw(statement)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    Ok(Some(
+        row.and_then(|row| row.try_get::<Option<i64>>("", "version").ok().flatten())
+            .unwrap_or(1),
+    ))
+}
+
 pub async fn ensure_seeders_table_on_db(db: &Database, config: &TideConfig) -> Result<(), String> {
     execute_on_db(db, &metadata_table_sql(config, DEFAULT_SEEDERS_TABLE, false)).await?;
     Ok(())
@@ -366,7 +1424,7 @@ pub async fn drop_database(config: &TideConfig, database_name: &str) -> Result<(
             Ok(())
         }
         "postgres" => {
-            let admin_db = connect_with_url(&admin_connection_url(config)?).await?;
+            let admin_db = connect_with_url(&admin_connection_url(config).await?).await?;
             let terminate_sql = format!(
                 "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = {} AND pid <> pg_backend_pid()",
                 sql_string(database_name)
@@ -380,7 +1438,7 @@ pub async fn drop_database(config: &TideConfig, database_name: &str) -> Result<(
             .map(|_| ())
         }
         "mysql" => {
-            let admin_db = connect_with_url(&admin_connection_url(config)?).await?;
+            let admin_db = connect_with_url(&admin_connection_url(config).await?).await?;
             execute_on_db(
                 &admin_db,
                 &format!("DROP DATABASE IF EXISTS {}", quoted_identifier(config, database_name)),
@@ -411,18 +1469,18 @@ async fn connect_with_url(url: &str) -> Result<Database, String> {
         .map_err(|error| error.to_string())
 }
 
-fn admin_connection_url(config: &TideConfig) -> Result<String, String> {
+async fn admin_connection_url(config: &TideConfig) -> Result<String, String> {
     match normalized_driver(config) {
-        "postgres" => override_database_in_url(config, "postgres"),
-        "mysql" => override_database_in_url(config, "mysql"),
-        "sqlite" => Ok(config.database.connection_url()),
+        "postgres" => override_database_in_url(config, "postgres").await,
+        "mysql" => override_database_in_url(config, "mysql").await,
+        "sqlite" => config.database.resolve_url().await,
         driver => Err(format!("Unsupported database driver: {}", driver)),
     }
 }
 
-fn override_database_in_url(config: &TideConfig, database_name: &str) -> Result<String, String> {
+async fn override_database_in_url(config: &TideConfig, database_name: &str) -> Result<String, String> {
     if config.database.url.is_some() {
-        let url = config.database.connection_url();
+        let url = config.database.resolve_url().await?;
         let pattern = regex::Regex::new(r"^(?P<prefix>[a-zA-Z0-9+]+://[^/]+/)(?P<db>[^?]+)(?P<suffix>\?.*)?$")
             .map_err(|error| error.to_string())?;
 
@@ -442,11 +1500,13 @@ fn override_database_in_url(config: &TideConfig, database_name: &str) -> Result<
             let host = &config.database.host;
             let port = config.database.port.unwrap_or(5432);
 
-            if password.is_empty() {
-                Ok(format!("postgres://{}@{}:{}/{}", user, host, port, database_name))
-            } else {
-                Ok(format!("postgres://{}:{}@{}:{}/{}", user, password, host, port, database_name))
-            }
+            Ok(format!(
+                "postgres://{}@{}:{}/{}",
+                url_credentials(user, password),
+                host,
+                port,
+                database_name
+            ))
         }
         "mysql" => {
             let user = config.database.username.as_deref().unwrap_or("root");
@@ -454,11 +1514,13 @@ fn override_database_in_url(config: &TideConfig, database_name: &str) -> Result<
             let host = &config.database.host;
             let port = config.database.port.unwrap_or(3306);
 
-            if password.is_empty() {
-                Ok(format!("mysql://{}@{}:{}/{}", user, host, port, database_name))
-            } else {
-                Ok(format!("mysql://{}:{}@{}:{}/{}", user, password, host, port, database_name))
-            }
+            Ok(format!(
+                "mysql://{}@{}:{}/{}",
+                url_credentials(user, password),
+                host,
+                port,
+                database_name
+            ))
         }
         driver => Err(format!("Unsupported database driver: {}", driver)),
     }
@@ -506,7 +1568,7 @@ fn columns_sql(config: &TideConfig, table_name: &str) -> Result<String, String>
                ELSE NULL \
              END AS key_name, \
              c.column_default AS default_value, \
-             NULL::TEXT AS extra \
+             CASE WHEN c.is_generated = 'ALWAYS' THEN 'STORED GENERATED' END AS extra \
              FROM information_schema.columns c \
              WHERE c.table_schema = 'public' AND c.table_name = {} \
              ORDER BY c.ordinal_position",
@@ -671,33 +1733,45 @@ fn metadata_table_sql(config: &TideConfig, table_name: &str, include_version: bo
     } else {
         String::new()
     };
+    let execution_ms_column = if include_version {
+        format!(
+            ", {} BIGINT, {} INTEGER",
+            quoted_identifier(config, "execution_ms"),
+            quoted_identifier(config, "batch")
+        )
+    } else {
+        String::new()
+    };
 
     match normalized_driver(config) {
         "mysql" => format!(
-            "CREATE TABLE IF NOT EXISTS {} ({} INT AUTO_INCREMENT PRIMARY KEY{}, {} {} NOT NULL UNIQUE, {} TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+            "CREATE TABLE IF NOT EXISTS {} ({} INT AUTO_INCREMENT PRIMARY KEY{}, {} {} NOT NULL UNIQUE, {} TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP{})",
             table,
             id,
             version_column,
             name,
             metadata_text_type(config),
             applied_at,
+            execution_ms_column,
         ),
         "sqlite" => format!(
-            "CREATE TABLE IF NOT EXISTS {} ({} INTEGER PRIMARY KEY AUTOINCREMENT{}, {} TEXT NOT NULL UNIQUE, {} TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+            "CREATE TABLE IF NOT EXISTS {} ({} INTEGER PRIMARY KEY AUTOINCREMENT{}, {} TEXT NOT NULL UNIQUE, {} TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP{})",
             table,
             id,
             version_column,
             name,
             applied_at,
+            execution_ms_column,
         ),
         _ => format!(
-            "CREATE TABLE IF NOT EXISTS {} ({} SERIAL PRIMARY KEY{}, {} {} NOT NULL UNIQUE, {} TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+            "CREATE TABLE IF NOT EXISTS {} ({} SERIAL PRIMARY KEY{}, {} {} NOT NULL UNIQUE, {} TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP{})",
             table,
             id,
             version_column,
             name,
             metadata_text_type(config),
             applied_at,
+            execution_ms_column,
         ),
     }
 }
@@ -713,6 +1787,14 @@ fn sql_string(value: &str) -> String {
     format!("'{}'", value.replace('\'', "''"))
 }
 
+/// String literal for the given driver; MySQL also treats backslashes as escapes
+fn sql_literal(config: &TideConfig, value: &str) -> String {
+    match normalized_driver(config) {
+        "mysql" => sql_string(&value.replace('\\', "\\\\")),
+        _ => sql_string(value),
+    }
+}
+
 fn string_field(row: &Value, keys: &[&str]) -> Option<String> {
     keys.iter().find_map(|key| match row.get(*key) {
         Some(Value::String(value)) if !value.is_empty() => Some(value.clone()),
@@ -731,10 +1813,40 @@ fn bool_field(row: &Value, keys: &[&str]) -> bool {
     })
 }
 
+fn float_field(row: &Value, keys: &[&str]) -> Option<f64> {
+    keys.iter().find_map(|key| match row.get(*key) {
+        Some(Value::Number(value)) => value.as_f64(),
+        Some(Value::String(value)) => value.parse::<f64>().ok(),
+        _ => None,
+    })
+}
+
+/// The first number of a `sqlite_stat1.stat` value is the table's row count
+fn parse_sqlite_stat_rows(stat: &str) -> Option<i64> {
+    stat.split_whitespace().next()?.parse().ok()
+}
+
 fn int_field(row: &Value, keys: &[&str]) -> Option<i64> {
     keys.iter().find_map(|key| match row.get(*key) {
         Some(Value::Number(value)) => value.as_i64(),
         Some(Value::String(value)) => value.parse::<i64>().ok(),
         _ => None,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sequence_reset_sql;
+    use crate::config::TideConfig;
+
+    #[test]
+    fn sequence_reset_moves_the_sequence_past_the_largest_id() {
+        let mut config = TideConfig::default();
+        config.database.driver = "postgres".to_string();
+
+        assert_eq!(
+            sequence_reset_sql(&config, "blog_posts", "id"),
+            "SELECT setval(pg_get_serial_sequence('\"blog_posts\"', 'id'), COALESCE(MAX(\"id\"), 1), MAX(\"id\") IS NOT NULL) FROM \"blog_posts\""
+        );
+    }
+}
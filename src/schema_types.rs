@@ -0,0 +1,258 @@
+//! Schema snapshot types shared by the schema and migration commands
+
+use std::collections::HashMap;
+
+/// Column schema information
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSchema {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub key: Option<String>,
+    pub default: Option<String>,
+    pub extra: Option<String>,
+}
+
+impl ColumnSchema {
+    /// Whether the database computes the column (`GENERATED ALWAYS AS ...`)
+    pub fn is_generated(&self) -> bool {
+        self.extra
+            .as_deref()
+            .is_some_and(|extra| extra.contains("STORED GENERATED") || extra.contains("VIRTUAL GENERATED"))
+    }
+}
+
+/// Table schema information
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSchema {
+    pub name: String,
+    pub columns: Vec<ColumnSchema>,
+}
+
+impl TableSchema {
+    /// Find a column by name
+    pub fn column(&self, name: &str) -> Option<&ColumnSchema> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+
+/// A column belonging to a specific table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableColumn {
+    pub table: String,
+    pub column: ColumnSchema,
+}
+
+/// A column whose data type differs between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnTypeChange {
+    pub table: String,
+    pub column: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Differences between two schema snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub added_tables: Vec<String>,
+    pub dropped_tables: Vec<String>,
+    pub added_columns: Vec<TableColumn>,
+    pub dropped_columns: Vec<TableColumn>,
+    pub changed_columns: Vec<ColumnTypeChange>,
+}
+
+impl SchemaDiff {
+    /// Check whether the two snapshots were identical
+    pub fn is_empty(&self) -> bool {
+        self.added_tables.is_empty()
+            && self.dropped_tables.is_empty()
+            && self.added_columns.is_empty()
+            && self.dropped_columns.is_empty()
+            && self.changed_columns.is_empty()
+    }
+
+    /// Render the diff as one line per change
+    pub fn display(&self) -> String {
+        if self.is_empty() {
+            return "No schema changes".to_string();
+        }
+
+        let mut lines = Vec::new();
+
+        for table in &self.added_tables {
+            lines.push(format!("+ table {}", table));
+        }
+
+        for table in &self.dropped_tables {
+            lines.push(format!("- table {}", table));
+        }
+
+        for added in &self.added_columns {
+            lines.push(format!(
+                "+ column {}.{} ({})",
+                added.table, added.column.name, added.column.data_type
+            ));
+        }
+
+        for dropped in &self.dropped_columns {
+            lines.push(format!(
+                "- column {}.{} ({})",
+                dropped.table, dropped.column.name, dropped.column.data_type
+            ));
+        }
+
+        for changed in &self.changed_columns {
+            lines.push(format!(
+                "~ column {}.{}: {} -> {}",
+                changed.table, changed.column, changed.from, changed.to
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Compare two schema snapshots
+///
+/// Columns of added or dropped tables are not reported individually, and data
+/// types are compared case-insensitively.
+pub fn diff_schemas(before: &[TableSchema], after: &[TableSchema]) -> SchemaDiff {
+    diff_schemas_with(before, after, |from, to| from.eq_ignore_ascii_case(to))
+}
+
+/// Compare two schema snapshots, deciding type equality with `same_type`
+pub fn diff_schemas_with(
+    before: &[TableSchema],
+    after: &[TableSchema],
+    same_type: impl Fn(&str, &str) -> bool,
+) -> SchemaDiff {
+    let before_by_name: HashMap<_, _> = before.iter().map(|table| (table.name.as_str(), table)).collect();
+    let after_by_name: HashMap<_, _> = after.iter().map(|table| (table.name.as_str(), table)).collect();
+
+    let mut diff = SchemaDiff::default();
+
+    for table in after {
+        let Some(previous) = before_by_name.get(table.name.as_str()) else {
+            diff.added_tables.push(table.name.clone());
+            continue;
+        };
+
+        for column in &table.columns {
+            match previous.column(&column.name) {
+                None => diff.added_columns.push(TableColumn {
+                    table: table.name.clone(),
+                    column: column.clone(),
+                }),
+                Some(old) if !same_type(&old.data_type, &column.data_type) => {
+                    diff.changed_columns.push(ColumnTypeChange {
+                        table: table.name.clone(),
+                        column: column.name.clone(),
+                        from: old.data_type.clone(),
+                        to: column.data_type.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        for column in &previous.columns {
+            if table.column(&column.name).is_none() {
+                diff.dropped_columns.push(TableColumn {
+                    table: table.name.clone(),
+                    column: column.clone(),
+                });
+            }
+        }
+    }
+
+    for table in before {
+        if !after_by_name.contains_key(table.name.as_str()) {
+            diff.dropped_tables.push(table.name.clone());
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_schemas, ColumnSchema, TableSchema};
+
+    fn column(name: &str, data_type: &str) -> ColumnSchema {
+        ColumnSchema {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable: false,
+            key: None,
+            default: None,
+            extra: None,
+        }
+    }
+
+    fn table(name: &str, columns: Vec<ColumnSchema>) -> TableSchema {
+        TableSchema {
+            name: name.to_string(),
+            columns,
+        }
+    }
+
+    #[test]
+    fn diff_schemas_reports_table_and_column_changes() {
+        let before = vec![
+            table("users", vec![column("id", "BIGINT"), column("name", "VARCHAR(100)"), column("nickname", "TEXT")]),
+            table("legacy_logs", vec![column("id", "BIGINT")]),
+        ];
+        let after = vec![
+            table("users", vec![column("id", "bigint"), column("name", "TEXT"), column("age", "INTEGER")]),
+            table("posts", vec![column("id", "BIGINT"), column("title", "TEXT")]),
+        ];
+
+        let diff = diff_schemas(&before, &after);
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.added_tables, vec!["posts".to_string()]);
+        assert_eq!(diff.dropped_tables, vec!["legacy_logs".to_string()]);
+        assert_eq!(diff.added_columns.len(), 1);
+        assert_eq!(diff.added_columns[0].table, "users");
+        assert_eq!(diff.added_columns[0].column.name, "age");
+        assert_eq!(diff.dropped_columns.len(), 1);
+        assert_eq!(diff.dropped_columns[0].column.name, "nickname");
+        assert_eq!(diff.changed_columns.len(), 1);
+        assert_eq!(diff.changed_columns[0].column, "name");
+        assert_eq!(diff.changed_columns[0].from, "VARCHAR(100)");
+        assert_eq!(diff.changed_columns[0].to, "TEXT");
+
+        let rendered = diff.display();
+        assert!(rendered.contains("+ table posts"));
+        assert!(rendered.contains("- table legacy_logs"));
+        assert!(rendered.contains("+ column users.age (INTEGER)"));
+        assert!(rendered.contains("- column users.nickname (TEXT)"));
+        assert!(rendered.contains("~ column users.name: VARCHAR(100) -> TEXT"));
+    }
+
+    #[test]
+    fn diff_schemas_is_empty_for_identical_snapshots() {
+        let schema = vec![table("users", vec![column("id", "BIGINT"), column("email", "TEXT")])];
+
+        let diff = diff_schemas(&schema, &schema.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.display(), "No schema changes");
+    }
+
+    #[test]
+    fn generated_columns_are_recognized_from_extra() {
+        let with_extra = |extra: &str| ColumnSchema {
+            extra: Some(extra.to_string()),
+            ..column("full_name", "TEXT")
+        };
+
+        assert!(with_extra("STORED GENERATED").is_generated());
+        assert!(with_extra("VIRTUAL GENERATED").is_generated());
+        // MySQL marks columns with expression defaults, like CURRENT_TIMESTAMP, this way
+        assert!(!with_extra("DEFAULT_GENERATED").is_generated());
+        assert!(!column("full_name", "TEXT").is_generated());
+    }
+}
@@ -0,0 +1,236 @@
+//! Secret providers that resolve `database.url` from a secrets manager
+//!
+//! `asm://secret-name/key` reads AWS Secrets Manager and `vault://path/key` reads HashiCorp
+//! Vault. Both go through the vendor's CLI, so the usual credential chain (profiles, instance
+//! roles, `VAULT_TOKEN`) applies and the config file holds no secrets.
+
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use tokio::process::Command;
+
+/// Resolves secret URIs of one scheme into their value
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// URI scheme handled by this provider, without `://`
+    fn scheme(&self) -> &str;
+
+    /// Value of the secret `uri` points at
+    async fn resolve(&self, uri: &str) -> Result<String, String>;
+}
+
+/// `asm://secret-name/key`: the `key` field of a JSON secret, or `asm://secret-name` for the
+/// whole secret string
+///
+/// The last segment is the key, so `asm://prod/db/url` reads `url` from the secret `prod/db`.
+pub struct AwsSecretsManagerProvider;
+
+#[async_trait]
+impl SecretProvider for AwsSecretsManagerProvider {
+    fn scheme(&self) -> &str {
+        "asm"
+    }
+
+    async fn resolve(&self, uri: &str) -> Result<String, String> {
+        let location = strip_scheme(uri, self.scheme())?;
+        // A bare secret name has no key; its value is the whole secret string
+        let (secret_id, key) = match location.rsplit_once('/') {
+            Some((secret_id, key)) if !secret_id.is_empty() && !key.is_empty() => (secret_id, Some(key)),
+            _ => (location, None),
+        };
+
+        let secret = run_cli(
+            "aws",
+            &[
+                "secretsmanager",
+                "get-secret-value",
+                "--secret-id",
+                secret_id,
+                "--query",
+                "SecretString",
+                "--output",
+                "text",
+            ],
+        )
+        .await?;
+
+        match key {
+            Some(key) => secret_field(&secret, key).map_err(|error| format!("{}: {}", uri, error)),
+            None => Ok(secret),
+        }
+    }
+}
+
+/// `vault://path/key`: the `key` field of the KV secret at `path`
+pub struct VaultProvider;
+
+#[async_trait]
+impl SecretProvider for VaultProvider {
+    fn scheme(&self) -> &str {
+        "vault"
+    }
+
+    async fn resolve(&self, uri: &str) -> Result<String, String> {
+        let (path, key) = strip_scheme(uri, self.scheme())?
+            .rsplit_once('/')
+            .filter(|(path, key)| !path.is_empty() && !key.is_empty())
+            .ok_or_else(|| format!("{}: expected vault://path/key", uri))?;
+
+        run_cli("vault", &["kv", "get", &format!("-field={}", key), path]).await
+    }
+}
+
+/// Providers `DatabaseConfig::resolve_url` uses
+pub fn default_providers() -> Vec<Box<dyn SecretProvider>> {
+    vec![Box::new(AwsSecretsManagerProvider), Box::new(VaultProvider)]
+}
+
+/// Whether `value` is a URI one of the default providers resolves
+pub fn is_secret_uri(value: &str) -> bool {
+    default_providers()
+        .iter()
+        .any(|provider| provider_matches(provider.as_ref(), value))
+}
+
+/// Resolve `value` with the first provider handling its scheme, or return it unchanged
+pub async fn resolve(value: &str, providers: &[Box<dyn SecretProvider>]) -> Result<String, String> {
+    match providers
+        .iter()
+        .find(|provider| provider_matches(provider.as_ref(), value))
+    {
+        Some(provider) => provider.resolve(value).await,
+        None => Ok(value.to_string()),
+    }
+}
+
+fn provider_matches(provider: &dyn SecretProvider, value: &str) -> bool {
+    value
+        .strip_prefix(provider.scheme())
+        .is_some_and(|rest| rest.starts_with("://"))
+}
+
+/// `uri` without its `scheme://` prefix
+fn strip_scheme<'a>(uri: &'a str, scheme: &str) -> Result<&'a str, String> {
+    uri.strip_prefix(scheme)
+        .and_then(|rest| rest.strip_prefix("://"))
+        .filter(|location| !location.is_empty())
+        .ok_or_else(|| format!("{}: expected {}://...", uri, scheme))
+}
+
+/// Field `key` of a JSON object secret
+fn secret_field(secret: &str, key: &str) -> Result<String, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(secret).map_err(|_| format!("secret is not a JSON object, so it has no key {}", key))?;
+
+    match value.get(key) {
+        Some(serde_json::Value::String(field)) => Ok(field.clone()),
+        Some(serde_json::Value::Number(field)) => Ok(field.to_string()),
+        Some(_) => Err(format!("key {} is not a string", key)),
+        None => Err(format!("secret has no key {}", key)),
+    }
+}
+
+/// Trimmed stdout of a secrets CLI, with its stderr as the error when it fails
+async fn run_cli(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = match Command::new(program).args(args).output().await {
+        Ok(output) => output,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            return Err(format!("{} was not found on PATH; it is needed to resolve secret URLs", program));
+        }
+        Err(error) => return Err(format!("Failed to run {}: {}", program, error)),
+    };
+
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{is_secret_uri, resolve, secret_field, strip_scheme, SecretProvider};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    /// Provider serving fixed secrets, failing for any other URI of its scheme
+    pub(crate) struct MockProvider {
+        scheme: &'static str,
+        secrets: HashMap<&'static str, &'static str>,
+    }
+
+    impl MockProvider {
+        pub(crate) fn new(scheme: &'static str, secrets: &[(&'static str, &'static str)]) -> Self {
+            Self {
+                scheme,
+                secrets: secrets.iter().copied().collect(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SecretProvider for MockProvider {
+        fn scheme(&self) -> &str {
+            self.scheme
+        }
+
+        async fn resolve(&self, uri: &str) -> Result<String, String> {
+            self.secrets
+                .get(uri)
+                .map(|value| value.to_string())
+                .ok_or_else(|| format!("{}: secret not found", uri))
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_uses_the_provider_of_the_scheme_and_keeps_other_values() {
+        let providers: Vec<Box<dyn SecretProvider>> = vec![
+            Box::new(MockProvider::new("asm", &[("asm://prod/db/url", "postgres://app:s3cret@db:5432/app")])),
+            Box::new(MockProvider::new("vault", &[("vault://secret/app/url", "mysql://app@db/app")])),
+        ];
+
+        assert_eq!(
+            resolve("asm://prod/db/url", &providers).await.unwrap(),
+            "postgres://app:s3cret@db:5432/app"
+        );
+        assert_eq!(resolve("vault://secret/app/url", &providers).await.unwrap(), "mysql://app@db/app");
+        assert_eq!(
+            resolve("asm://prod/db/missing", &providers).await.unwrap_err(),
+            "asm://prod/db/missing: secret not found"
+        );
+        assert_eq!(
+            resolve("postgres://localhost/app", &providers).await.unwrap(),
+            "postgres://localhost/app"
+        );
+        // Only a full `scheme://` prefix selects a provider
+        assert_eq!(resolve("asmx://prod/db", &providers).await.unwrap(), "asmx://prod/db");
+    }
+
+    #[test]
+    fn secret_uris_are_recognized_by_scheme() {
+        assert!(is_secret_uri("asm://prod/db/url"));
+        assert!(is_secret_uri("vault://secret/app/url"));
+        assert!(!is_secret_uri("postgres://localhost/app"));
+        assert!(!is_secret_uri("sqlite://app.db"));
+
+        assert_eq!(strip_scheme("vault://secret/app/url", "vault"), Ok("secret/app/url"));
+        assert_eq!(strip_scheme("vault://", "vault"), Err("vault://: expected vault://...".to_string()));
+    }
+
+    #[test]
+    fn secret_field_reads_a_key_of_a_json_secret() {
+        let secret = r#"{"url": "postgres://db/app", "port": 5432, "tags": []}"#;
+
+        assert_eq!(secret_field(secret, "url"), Ok("postgres://db/app".to_string()));
+        assert_eq!(secret_field(secret, "port"), Ok("5432".to_string()));
+        assert_eq!(secret_field(secret, "tags"), Err("key tags is not a string".to_string()));
+        assert_eq!(secret_field(secret, "password"), Err("secret has no key password".to_string()));
+        assert_eq!(
+            secret_field("hunter2", "password"),
+            Err("secret is not a JSON object, so it has no key password".to_string())
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Locating the sqlx version the CLI was built with
+//!
+//! Shared with build.rs, which runs `cargo metadata` and exposes the result as
+//! `TIDEORM_SQLX_VERSION` for `db version`.
+
+/// Reported when the version can't be determined, e.g. when no lockfile is available
+pub const UNKNOWN: &str = "unknown";
+
+/// Version of the `sqlx` package in `cargo metadata --format-version 1` output
+pub fn from_metadata(metadata: &str) -> Option<String> {
+    let metadata: serde_json::Value = serde_json::from_str(metadata).ok()?;
+
+    metadata["packages"]
+        .as_array()?
+        .iter()
+        .find(|package| package["name"] == "sqlx")?["version"]
+        .as_str()
+        .map(str::to_string)
+}
+
+/// An explicit `TIDEORM_SQLX_VERSION` override, else the version found in the metadata, else `unknown`
+pub fn resolve(override_version: Option<String>, metadata: Option<&str>) -> String {
+    override_version
+        .filter(|version| !version.trim().is_empty())
+        .or_else(|| metadata.and_then(from_metadata))
+        .unwrap_or_else(|| UNKNOWN.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_metadata, resolve, UNKNOWN};
+
+    const METADATA: &str = r#"{"packages": [
+        {"name": "sqlx-core", "version": "0.8.6"},
+        {"name": "sqlx", "version": "0.8.6"}
+    ]}"#;
+
+    #[test]
+    fn version_is_read_from_cargo_metadata() {
+        assert_eq!(from_metadata(METADATA).as_deref(), Some("0.8.6"));
+        assert_eq!(resolve(None, Some(METADATA)), "0.8.6");
+    }
+
+    #[test]
+    fn missing_metadata_falls_back_to_the_override_or_unknown() {
+        assert_eq!(resolve(None, None), UNKNOWN);
+        assert_eq!(resolve(None, Some("error: no Cargo.lock")), UNKNOWN);
+        assert_eq!(resolve(None, Some(r#"{"packages": []}"#)), UNKNOWN);
+        assert_eq!(resolve(Some("0.8.2".to_string()), None), "0.8.2");
+        assert_eq!(resolve(Some(" ".to_string()), Some(METADATA)), "0.8.6");
+    }
+}
@@ -7,6 +7,7 @@
 let configExists = true;
 let pendingAction = null;
 let pendingActionType = null;
+let migrationPoller = null;
 
 // Initialize the application
 document.addEventListener('DOMContentLoaded', function() {
@@ -24,6 +25,8 @@ async function checkConfig() {
         if (!configExists) {
             showConfigWarning();
             disableCliFeatures();
+        } else {
+            startMigrationPolling();
         }
     } catch (error) {
         console.error('Failed to check config:', error);
@@ -123,6 +126,120 @@ async function runCommand(command, displayName) {
     }
 }
 
+// Migration status polling
+function startMigrationPolling() {
+    refreshMigrationStatus();
+    if (!migrationPoller) {
+        migrationPoller = setInterval(refreshMigrationStatus, 5000);
+    }
+}
+
+async function refreshMigrationStatus() {
+    const tbody = document.getElementById('migration-status');
+    if (!tbody) return;
+
+    try {
+        const response = await fetch('/api/migrations');
+        const data = await response.json();
+
+        if (!response.ok) {
+            renderMigrationMessage(tbody, data.error || 'Failed to load migration status.');
+            return;
+        }
+
+        if (data.length === 0) {
+            renderMigrationMessage(tbody, 'No migrations found.');
+            return;
+        }
+
+        tbody.replaceChildren(...data.map(migration => {
+            const row = document.createElement('tr');
+            const status = document.createElement('span');
+            status.className = migration.status === 'ran' ? 'badge badge-success' : 'badge badge-warning';
+            status.textContent = migration.status === 'ran' ? 'Ran' : 'Pending';
+
+            [migration.name, status, migration.batch ?? '—', migration.ran_at ?? '—'].forEach(value => {
+                const cell = document.createElement('td');
+                cell.append(value);
+                row.appendChild(cell);
+            });
+            return row;
+        }));
+    } catch (error) {
+        renderMigrationMessage(tbody, `Connection Error: ${error.message}`);
+    }
+}
+
+function renderMigrationMessage(tbody, message) {
+    const row = document.createElement('tr');
+    const cell = document.createElement('td');
+    cell.colSpan = 4;
+    cell.textContent = message;
+    row.appendChild(cell);
+    tbody.replaceChildren(row);
+}
+
+// Run or roll back migrations, streaming the output as it arrives
+async function streamMigrations(action, payload = {}) {
+    if (!configExists) {
+        showToast('warning', 'Configuration Required', 'Run "tideorm init" to create tideorm.toml first.');
+        return;
+    }
+
+    const outputEl = document.getElementById('migration-output');
+    const label = action === 'run' ? 'Migration run' : 'Rollback';
+    outputEl.textContent = '';
+
+    try {
+        const response = await fetch(`/api/migrations/${action}`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify(payload)
+        });
+
+        if (!(response.headers.get('Content-Type') || '').startsWith('text/event-stream')) {
+            const data = await response.json();
+            outputEl.textContent = data.error || 'Command failed.';
+            showToast('error', 'Error', data.error || 'Command failed.');
+            return;
+        }
+
+        const reader = response.body.getReader();
+        const decoder = new TextDecoder();
+        let buffer = '';
+        let success = false;
+
+        while (true) {
+            const { value, done } = await reader.read();
+            if (done) break;
+
+            buffer += decoder.decode(value, { stream: true });
+            const events = buffer.split('\n\n');
+            buffer = events.pop();
+
+            for (const event of events) {
+                const data = event.replace(/^(event: .*\n)?data: /, '');
+                if (event.startsWith('event: done')) {
+                    success = JSON.parse(data).success;
+                } else {
+                    outputEl.textContent += data + '\n';
+                }
+            }
+        }
+
+        if (success) {
+            showToast('success', 'Success', `${label} completed successfully.`);
+        } else {
+            showToast('error', 'Error', `${label} failed.`);
+        }
+    } catch (error) {
+        outputEl.textContent += `Error: ${error.message}`;
+        showToast('error', 'Connection Error', 'Failed to communicate with the server.');
+    }
+
+    refreshMigrationStatus();
+}
+
 // Get the appropriate output element ID based on command
 function getOutputIdForCommand(command) {
     const commandText = Array.isArray(command) ? command.join(' ') : command;
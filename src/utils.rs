@@ -1,33 +1,44 @@
 //! Utility functions for TideORM CLI
 
-use colored::Colorize;
 use minijinja::{AutoEscape, Environment};
 use serde::Serialize;
-use std::path::Path;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::thread::LocalKey;
+
+/// Target of the log events emitted by the CLI itself
+pub const LOG_TARGET: &str = "tideorm_cli";
 
 /// Print a success message
 pub fn print_success(message: &str) {
-    println!("{} {}", "✓".green(), message);
+    tracing::info!(target: LOG_TARGET, status = "success", "{}", message);
 }
 
 /// Print an info message
 pub fn print_info(message: &str) {
-    println!("{} {}", "ℹ".blue(), message);
+    tracing::info!(target: LOG_TARGET, "{}", message);
 }
 
 /// Print a warning message
 pub fn print_warning(message: &str) {
-    println!("{} {}", "⚠".yellow(), message);
+    tracing::warn!(target: LOG_TARGET, "{}", message);
 }
 
-/// Create a directory if it doesn't exist
-pub fn ensure_directory(path: &str) -> Result<(), String> {
+/// Create a directory if it doesn't exist, returning its canonical path
+///
+/// Lets callers build file paths with `ensure_directory("src/models")?.join("user.rs")`.
+pub fn ensure_directory(path: &str) -> Result<PathBuf, String> {
     let path = Path::new(path);
     if !path.exists() {
         std::fs::create_dir_all(path)
             .map_err(|e| format!("Failed to create directory '{}': {}", path.display(), e))?;
+    } else if !path.is_dir() {
+        return Err(format!("'{}' exists but is not a directory", path.display()));
     }
-    Ok(())
+
+    path.canonicalize()
+        .map_err(|e| format!("Failed to resolve directory '{}': {}", path.display(), e))
 }
 
 /// Check if a file exists
@@ -35,6 +46,59 @@ pub fn file_exists(path: &str) -> bool {
     Path::new(path).exists()
 }
 
+/// Append `content` to a file, creating it if needed, unless `marker` already appears in it
+pub fn write_or_update_file(path: &str, content: &str, marker: &str) -> Result<(), String> {
+    // Only a missing file starts out empty; any other read error must not clobber the file
+    let existing = match std::fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read {}: {}", file_label(path), e)),
+    };
+    if existing.contains(marker) {
+        return Ok(());
+    }
+
+    let separator = if existing.is_empty() || existing.ends_with('\n') { "" } else { "\n" };
+    std::fs::write(path, format!("{}{}{}", existing, separator, content))
+        .map_err(|e| format!("Failed to update {}: {}", file_label(path), e))
+}
+
+/// Insert `content` on the line after the first line containing `marker`,
+/// unless the file already contains `content`
+pub fn inject_after_marker(path: &str, marker: &str, content: &str) -> Result<(), String> {
+    let existing = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", file_label(path), e))?;
+    if existing.contains(content.trim_end()) {
+        return Ok(());
+    }
+
+    let marker_start = existing
+        .find(marker)
+        .ok_or_else(|| format!("'{}' not found in {}", marker, file_label(path)))?;
+    let line_end = existing[marker_start..]
+        .find('\n')
+        .map_or(existing.len(), |offset| marker_start + offset + 1);
+
+    let mut inserted = content.to_string();
+    if !inserted.ends_with('\n') {
+        inserted.push('\n');
+    }
+    if !existing[..line_end].ends_with('\n') {
+        inserted.insert(0, '\n');
+    }
+
+    let mut updated = existing;
+    updated.insert_str(line_end, &inserted);
+    std::fs::write(path, updated).map_err(|e| format!("Failed to update {}: {}", file_label(path), e))
+}
+
+/// File name used in read and write errors
+fn file_label(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map_or_else(|| path.to_string(), |name| name.to_string_lossy().into_owned())
+}
+
 /// Render generator output from a MiniJinja template.
 pub fn render_template<T: Serialize>(
     template_name: &str,
@@ -64,23 +128,97 @@ pub fn migration_timestamp() -> String {
     chrono::Utc::now().format("%Y%m%d%H%M%S").to_string()
 }
 
+/// Whether `name` is a plain ASCII Rust identifier
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// Convert a string to snake_case
 pub fn to_snake_case(s: &str) -> String {
-    heck::AsSnakeCase(s).to_string()
+    thread_local! {
+        static CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    }
+
+    cached_case(&CACHE, s, |s| heck::AsSnakeCase(s).to_string())
 }
 
 /// Convert a string to PascalCase
 pub fn to_pascal_case(s: &str) -> String {
-    heck::AsPascalCase(s).to_string()
+    thread_local! {
+        static CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    }
+
+    cached_case(&CACHE, s, |s| heck::AsPascalCase(s).to_string())
 }
 
-/// Pluralize a word
-pub fn pluralize(word: &str) -> String {
+/// Entries kept per case cache before it is cleared, so the long-running UI server stays bounded
+const CASE_CACHE_LIMIT: usize = 1024;
+
+/// Look up a case conversion in a thread-local cache, converting and storing it on a miss
+fn cached_case(
+    cache: &'static LocalKey<RefCell<HashMap<String, String>>>,
+    s: &str,
+    convert: fn(&str) -> String,
+) -> String {
+    cache.with(|cache| {
+        if let Some(converted) = cache.borrow().get(s) {
+            return converted.clone();
+        }
+
+        let converted = convert(s);
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CASE_CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(s.to_string(), converted.clone());
+        converted
+    })
+}
+
+/// Plurals the `pluralizer` crate gets wrong for common table names
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("medium", "media"),
+    ("buffalo", "buffaloes"),
+    ("criterion", "criteria"),
+    ("datum", "data"),
+];
+
+/// Pluralize a word, checking project-level overrides first
+///
+/// Overrides match either the whole word or the last `_`-separated segment,
+/// so an override for `medium` also turns `user_medium` into `user_media`.
+pub fn pluralize_with_overrides(word: &str, overrides: &HashMap<String, String>) -> String {
+    if let Some(plural) = overrides.get(word) {
+        return plural.clone();
+    }
+
+    if let Some((prefix, last)) = word.rsplit_once('_')
+        && let Some(plural) = overrides.get(last)
+    {
+        return format!("{}_{}", prefix, plural);
+    }
+
+    let (prefix, last) = match word.rsplit_once('_') {
+        Some((prefix, last)) => (Some(prefix), last),
+        None => (None, word),
+    };
+
+    if let Some((_, plural)) = IRREGULAR_PLURALS
+        .iter()
+        .find(|(singular, _)| singular.eq_ignore_ascii_case(last))
+    {
+        return match prefix {
+            Some(prefix) => format!("{}_{}", prefix, plural),
+            None => plural.to_string(),
+        };
+    }
+
     pluralizer::pluralize(word, 2, false)
 }
 
 /// Singularize a word
-#[cfg(test)]
 pub fn singularize(word: &str) -> String {
     pluralizer::pluralize(word, 1, false)
 }
@@ -97,6 +235,20 @@ pub struct FieldDefinition {
     pub primary_key: bool,
     pub auto_increment: bool,
     pub default: Option<String>,
+    /// Allowed values of an `enum(a|b|c)` field
+    pub enum_variants: Vec<String>,
+    /// Total digits of a `decimal(precision,scale)` field
+    pub decimal_precision: Option<u8>,
+    /// Digits after the decimal point of a `decimal(precision,scale)` field
+    pub decimal_scale: Option<u8>,
+    /// SQL `CHECK` expression from `--check-constraints`
+    pub check: Option<String>,
+    /// Expression of a `--generated-columns` column, computed by the database
+    pub generated: Option<String>,
+    /// Condition of a partial index, from the `where=` modifier
+    pub index_where: Option<String>,
+    /// Whether a `type[]` field holds an array of `field_type` values
+    pub is_array: bool,
 }
 
 impl FieldDefinition {
@@ -111,16 +263,81 @@ impl FieldDefinition {
         }
 
         let name = parts[0].trim().to_string();
-        let field_type = parts[1].trim().to_string();
+        let mut field_type = parts[1].trim().to_string();
+        let mut enum_variants = Vec::new();
+
+        let is_array = match field_type.strip_suffix("[]") {
+            Some(element_type) => {
+                field_type = element_type.trim().to_string();
+                true
+            }
+            None => false,
+        };
+
+        if is_array {
+            let element_type = field_type.to_lowercase();
+            if element_type.is_empty() || element_type.ends_with("[]") || element_type.ends_with("_array") {
+                return Err(format!(
+                    "Field '{}' has an invalid array type '{}'. Expected an element type, e.g. {}:string[]",
+                    name,
+                    parts[1].trim(),
+                    name
+                ));
+            }
+            if element_type.starts_with("enum(") {
+                return Err(format!("Field '{}' can't be an array of enum values; use {}:string[]", name, name));
+            }
+        }
+
+        if field_type.to_lowercase().starts_with("enum(") && field_type.ends_with(')') {
+            enum_variants = field_type[5..field_type.len() - 1]
+                .split('|')
+                .map(str::trim)
+                .filter(|variant| !variant.is_empty())
+                .map(str::to_string)
+                .collect();
+
+            if enum_variants.is_empty() {
+                return Err(format!(
+                    "Enum field '{}' needs at least one variant, e.g. {}:enum(active|inactive)",
+                    name, name
+                ));
+            }
+
+            field_type = "enum".to_string();
+        }
+
+        let mut decimal_precision = None;
+        let mut decimal_scale = None;
+
+        if let Some(params) = field_type
+            .to_lowercase()
+            .strip_prefix("decimal(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let (precision, scale) = parse_decimal_params(&name, params)?;
+            decimal_precision = Some(precision);
+            decimal_scale = Some(scale);
+            field_type = "decimal".to_string();
+        }
+
         let mut nullable = false;
         let mut unique = false;
         let mut indexed = false;
         let mut primary_key = false;
         let mut auto_increment = false;
         let mut default = None;
+        let mut index_where = None;
 
         // Parse modifiers
-        for part in parts.iter().skip(2) {
+        for (position, part) in parts.iter().enumerate().skip(2) {
+            // The condition keeps its case and may contain colons, so it takes the rest
+            if part.trim_start().to_lowercase().starts_with("where=") {
+                let condition = parts[position..].join(":");
+                index_where = Some(condition.trim_start()["where=".len()..].trim().to_string());
+                break;
+            }
+
             let part = part.trim().to_lowercase();
             match part.as_str() {
                 "nullable" | "null" => nullable = true,
@@ -137,6 +354,19 @@ impl FieldDefinition {
             }
         }
 
+        match &index_where {
+            Some(condition) if condition.is_empty() => {
+                return Err(format!("Field '{}' has where= without a condition", name));
+            }
+            Some(_) if !indexed || unique => {
+                return Err(format!(
+                    "Field '{}' has where=, which needs indexed and can't be combined with unique",
+                    name
+                ));
+            }
+            _ => {}
+        }
+
         Ok(Self {
             name,
             field_type,
@@ -146,13 +376,31 @@ impl FieldDefinition {
             primary_key,
             auto_increment,
             default,
+            enum_variants,
+            decimal_precision,
+            decimal_scale,
+            check: None,
+            generated: None,
+            index_where,
+            is_array,
         })
     }
 
     /// Convert field type string to Rust type
     pub fn rust_type(&self) -> String {
+        let base_type = self.base_rust_type();
+
+        if self.nullable {
+            format!("Option<{}>", base_type)
+        } else {
+            base_type
+        }
+    }
+
+    /// Rust type of the field without the `Option` wrapper for nullable fields
+    pub fn base_rust_type(&self) -> String {
         let base_type = match self.field_type.to_lowercase().as_str() {
-            "string" | "varchar" | "text" => "String",
+            "string" | "varchar" | "text" | "enum" => "String",
             "i8" | "tinyint" => "i8",
             "i16" | "smallint" => "i16",
             "i32" | "int" | "integer" => "i32",
@@ -170,24 +418,60 @@ impl FieldDefinition {
             "bytes" | "blob" | "binary" => "Vec<u8>",
             "int_array" | "integer_array" => "IntArray",
             "bigint_array" => "BigIntArray",
-            "text_array" | "string_array" => "TextArray",
+            "text_array" | "string_array" => "Vec<String>",
             "bool_array" | "boolean_array" => "BoolArray",
             "float_array" => "FloatArray",
-            "json_array" => "JsonArray",
+            "json_array" => "Vec<serde_json::Value>",
             _ => &self.field_type,
         };
 
-        if self.nullable {
-            format!("Option<{}>", base_type)
+        if self.is_array {
+            format!("Vec<{}>", base_type)
         } else {
             base_type.to_string()
         }
     }
 
+    /// Reject types the driver has no column type for
+    pub fn check_driver(&self, driver: &str) -> Result<(), String> {
+        match self.field_type.to_lowercase().as_str() {
+            "text_array" | "string_array" if driver != "postgres" => Err(format!(
+                "Field '{}' uses {}, which needs PostgreSQL (TEXT[]); use json_array on {}",
+                self.name, self.field_type, driver
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Convert to SQL type
+    ///
+    /// Arrays are native on PostgreSQL; MySQL and SQLite store them as JSON, see `sql_comment`.
     pub fn sql_type(&self, driver: &str) -> String {
+        match (self.is_array, driver) {
+            (false, _) => self.element_sql_type(driver),
+            (true, "postgres") => format!("{}[]", self.element_sql_type(driver)),
+            (true, "mysql") => "JSON".to_string(),
+            (true, _) => "TEXT".to_string(),
+        }
+    }
+
+    /// Note for the migration on how the column stores the field, if it isn't obvious from its type
+    pub fn sql_comment(&self, driver: &str) -> Option<&'static str> {
+        (self.is_array && driver != "postgres").then_some("Stored as JSON array")
+    }
+
+    /// SQL type of one value of the field
+    fn element_sql_type(&self, driver: &str) -> String {
         match (self.field_type.to_lowercase().as_str(), driver) {
             ("string" | "varchar", _) => "VARCHAR(255)".to_string(),
+            ("enum", "postgres") => format!(
+                "{name}_enum /* TODO: create the type first: CREATE TYPE {name}_enum AS ENUM ({variants}) */",
+                name = self.name,
+                variants = self.enum_variant_list()
+            ),
+            ("enum", "mysql") => format!("ENUM({})", self.enum_variant_list()),
+            ("enum", "sqlite") => format!("TEXT CHECK ({} IN ({}))", self.name, self.enum_variant_list()),
+            ("enum", _) => "VARCHAR(255)".to_string(),
             ("text", _) => "TEXT".to_string(),
             ("i8" | "tinyint", "mysql") => "TINYINT".to_string(),
             ("i8" | "tinyint", _) => "SMALLINT".to_string(),
@@ -207,7 +491,10 @@ impl FieldDefinition {
             ("json", "postgres") => "JSON".to_string(),
             ("jsonb", "postgres") => "JSONB".to_string(),
             ("json" | "jsonb", _) => "TEXT".to_string(),
-            ("decimal", _) => "DECIMAL(19, 4)".to_string(),
+            ("decimal", _) => match (self.decimal_precision, self.decimal_scale) {
+                (Some(precision), Some(scale)) => format!("DECIMAL({}, {})", precision, scale),
+                _ => "DECIMAL(19, 4)".to_string(),
+            },
             ("bytes" | "blob" | "binary", "postgres") => "BYTEA".to_string(),
             ("bytes" | "blob" | "binary", _) => "BLOB".to_string(),
             ("int_array" | "integer_array", "postgres") => "INTEGER[]".to_string(),
@@ -215,7 +502,8 @@ impl FieldDefinition {
             ("text_array" | "string_array", "postgres") => "TEXT[]".to_string(),
             ("bool_array" | "boolean_array", "postgres") => "BOOLEAN[]".to_string(),
             ("float_array", "postgres") => "DOUBLE PRECISION[]".to_string(),
-            ("json_array", "postgres") => "JSONB[]".to_string(),
+            ("json_array", "postgres") => "JSONB".to_string(),
+            ("json_array", "mysql") => "JSON".to_string(),
             (
                 "int_array"
                 | "integer_array"
@@ -231,6 +519,162 @@ impl FieldDefinition {
             _ => self.field_type.to_uppercase(),
         }
     }
+
+    /// Quoted, comma-separated enum variants for SQL
+    fn enum_variant_list(&self) -> String {
+        self.enum_variants
+            .iter()
+            .map(|variant| format!("'{}'", variant.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Largest DECIMAL precision accepted by PostgreSQL and MySQL's default
+const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// Parse the `precision,scale` of a `decimal(precision,scale)` field type
+fn parse_decimal_params(name: &str, params: &str) -> Result<(u8, u8), String> {
+    let invalid = || {
+        format!(
+            "Invalid decimal type for field '{}'. Expected decimal(precision,scale), e.g. {}:decimal(10,2)",
+            name, name
+        )
+    };
+
+    let (precision, scale) = params.split_once(',').ok_or_else(invalid)?;
+    let precision: u8 = precision.trim().parse().map_err(|_| invalid())?;
+    let scale: u8 = scale.trim().parse().map_err(|_| invalid())?;
+
+    if precision == 0 || precision > MAX_DECIMAL_PRECISION {
+        return Err(format!(
+            "Decimal precision for field '{}' must be between 1 and {}, got {}",
+            name, MAX_DECIMAL_PRECISION, precision
+        ));
+    }
+
+    if scale > precision {
+        return Err(format!(
+            "Decimal scale for field '{}' ({}) cannot exceed its precision ({})",
+            name, scale, precision
+        ));
+    }
+
+    Ok((precision, scale))
+}
+
+/// Split a comma-separated `--fields` list, keeping commas inside parentheses
+/// such as `price:decimal(10,2)`
+pub fn split_field_definitions(fields: &str) -> Vec<&str> {
+    let mut definitions = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, character) in fields.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                definitions.push(fields[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    definitions.push(fields[start..].trim());
+    definitions.retain(|definition| !definition.is_empty());
+    definitions
+}
+
+/// Attach `field:expression` check constraints, comma-separated, to their fields
+pub fn apply_check_constraints(fields: &mut [FieldDefinition], checks: &str) -> Result<(), String> {
+    for check in split_field_definitions(checks) {
+        let (name, expression) = check.split_once(':').ok_or_else(|| {
+            format!(
+                "Invalid check constraint '{}'. Expected format: field:expression",
+                check
+            )
+        })?;
+        let (name, expression) = (name.trim(), expression.trim());
+
+        validate_sql_expression("Check constraint", expression)?;
+
+        let field = fields
+            .iter_mut()
+            .find(|field| field.name == name)
+            .ok_or_else(|| format!("Check constraint references unknown field: {}", name))?;
+        field.check = Some(expression.to_string());
+    }
+
+    Ok(())
+}
+
+/// Parse `name:type:expression` generated columns, comma-separated
+///
+/// The columns are nullable since the database fills them in.
+pub fn parse_generated_columns(columns: &str) -> Result<Vec<FieldDefinition>, String> {
+    split_field_definitions(columns)
+        .into_iter()
+        .map(|column| {
+            let mut parts = column.splitn(3, ':');
+            let (Some(name), Some(field_type), Some(expression)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(format!(
+                    "Invalid generated column '{}'. Expected format: name:type:expression",
+                    column
+                ));
+            };
+            let expression = expression.trim();
+            validate_sql_expression("Generated column", expression)?;
+
+            let mut field = FieldDefinition::parse(&format!("{}:{}", name.trim(), field_type.trim()))?;
+            field.nullable = true;
+            field.generated = Some(expression.to_string());
+            Ok(field)
+        })
+        .collect()
+}
+
+/// Reject expressions that could end the statement or escape the generated code
+///
+/// `kind` names the expression in errors, e.g. "Check constraint".
+fn validate_sql_expression(kind: &str, expression: &str) -> Result<(), String> {
+    if expression.is_empty() {
+        return Err(format!("{} expression cannot be empty", kind));
+    }
+
+    if let Some(token) = [";", "--", "/*", "*/", "\"#"]
+        .into_iter()
+        .find(|token| expression.contains(token))
+    {
+        return Err(format!(
+            "{} '{}' must not contain '{}'",
+            kind, expression, token
+        ));
+    }
+
+    let mut depth = 0usize;
+    for character in expression.chars() {
+        match character {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    format!("{} '{}' has unbalanced parentheses", kind, expression)
+                })?
+            }
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err(format!(
+            "{} '{}' has unbalanced parentheses",
+            kind, expression
+        ));
+    }
+
+    Ok(())
 }
 
 /// Parse relation definition string
@@ -280,6 +724,98 @@ impl RelationDefinition {
     }
 }
 
+/// Largest record count accepted by `parse_count`
+const MAX_COUNT: u32 = 10_000_000;
+
+/// Parse a record count such as `500`, `10_000`, `5k`, or `1M`
+pub fn parse_count(s: &str) -> Result<u32, String> {
+    let cleaned = s.trim().replace('_', "");
+
+    let (digits, multiplier) = match cleaned.chars().last() {
+        Some('k' | 'K') => (&cleaned[..cleaned.len() - 1], 1_000u64),
+        Some('m' | 'M') => (&cleaned[..cleaned.len() - 1], 1_000_000u64),
+        _ => (cleaned.as_str(), 1u64),
+    };
+
+    let value = digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|value| value.checked_mul(multiplier))
+        .ok_or_else(|| format!("Invalid count '{}': expected a number like 500, 10_000, 5k, or 1M", s))?;
+
+    if value == 0 {
+        return Err("Count must be at least 1".to_string());
+    }
+
+    if value > u64::from(MAX_COUNT) {
+        return Err(format!("Count {} exceeds the maximum of 10_000_000", value));
+    }
+
+    Ok(value as u32)
+}
+
+/// Rust keywords, including ones reserved for future use
+///
+/// A model named after one of these produces a module such as `pub mod type;`.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Standard library types and traits a generated model struct would shadow
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Self", "String", "Option", "Some", "None", "Result", "Ok", "Err", "Vec", "Box", "Arc", "Rc",
+    "Cell", "RefCell", "Mutex", "RwLock", "Cow", "HashMap", "HashSet", "BTreeMap", "BTreeSet",
+    "Path", "PathBuf", "Duration", "Clone", "Copy", "Default", "Drop", "Eq", "PartialEq", "Ord",
+    "PartialOrd", "Fn", "FnMut", "FnOnce", "Iterator", "IntoIterator", "Send", "Sync", "Sized",
+    "ToOwned", "ToString",
+];
+
+/// Reject model names that would collide with Rust keywords or standard types
+///
+/// Extra names can be reserved with a comma-separated `TIDEORM_RESERVED_NAMES`.
+pub fn validate_model_name(name: &str) -> Result<(), String> {
+    let extra = std::env::var("TIDEORM_RESERVED_NAMES").unwrap_or_default();
+    let extra: Vec<&str> = extra
+        .split(',')
+        .map(str::trim)
+        .filter(|reserved| !reserved.is_empty())
+        .collect();
+
+    check_model_name(name, &extra)
+}
+
+fn check_model_name(name: &str, extra: &[&str]) -> Result<(), String> {
+    let struct_name = to_pascal_case(name);
+    let module_name = to_snake_case(name);
+
+    if RESERVED_TYPE_NAMES.contains(&struct_name.as_str()) {
+        return Err(format!(
+            "'{}' is a reserved Rust type name. Please choose a different model name.",
+            struct_name
+        ));
+    }
+
+    if RUST_KEYWORDS.contains(&module_name.as_str()) {
+        return Err(format!(
+            "'{}' is a reserved Rust keyword. Please choose a different model name.",
+            module_name
+        ));
+    }
+
+    if extra.iter().any(|reserved| to_pascal_case(reserved) == struct_name) {
+        return Err(format!(
+            "'{}' is reserved by TIDEORM_RESERVED_NAMES. Please choose a different model name.",
+            struct_name
+        ));
+    }
+
+    Ok(())
+}
+
 /// Confirm an action with the user
 pub fn confirm(message: &str) -> bool {
     use dialoguer::Confirm;
@@ -295,6 +831,36 @@ pub fn confirm(message: &str) -> bool {
 mod tests {
     use super::*;
 
+    fn pluralize(word: &str) -> String {
+        pluralize_with_overrides(word, &HashMap::new())
+    }
+
+    #[test]
+    fn cached_case_conversions_match_heck() {
+        let inputs = [
+            "User", "user", "BlogPost", "blog_post", "blogPost", "blog-post", "Blog Post",
+            "HTTPRequest", "HttpRequest", "http_request", "XMLHttpRequest", "IOError", "UserID",
+            "user_id", "userId", "APIKey", "api_key", "OAuth2Token", "oauth2_token", "Version2",
+            "v2_api", "V2Api", "ABC", "a", "A", "", "  padded  ", "__leading", "trailing__",
+            "double__underscore", "kebab-case-name", "SCREAMING_SNAKE", "Mixed_Case-Name",
+            "with.dots", "with spaces too", "Ünïcödé", "straße", "Café_Menu", "person", "people",
+            "OrderItem", "order_items", "CreateUsersTable", "create_users_table", "TideORM",
+            "tideorm_cli", "Seeder1", "123numbers", "numbers123Here", "x_y_z",
+        ];
+        assert_eq!(inputs.len(), 50);
+
+        for input in inputs {
+            let snake = heck::AsSnakeCase(input).to_string();
+            let pascal = heck::AsPascalCase(input).to_string();
+
+            // The second call is served from the cache
+            assert_eq!(to_snake_case(input), snake, "snake_case of {:?}", input);
+            assert_eq!(to_snake_case(input), snake, "cached snake_case of {:?}", input);
+            assert_eq!(to_pascal_case(input), pascal, "PascalCase of {:?}", input);
+            assert_eq!(to_pascal_case(input), pascal, "cached PascalCase of {:?}", input);
+        }
+    }
+
     #[test]
     fn test_pluralize() {
         assert_eq!(pluralize("user"), "users");
@@ -304,6 +870,56 @@ mod tests {
         assert_eq!(pluralize("leaf"), "leaves");
     }
 
+    #[test]
+    fn test_pluralize_uncountable_and_irregular_nouns() {
+        let cases = [
+            ("staff", "staff"),
+            ("media", "media"),
+            ("data", "data"),
+            ("series", "series"),
+            ("species", "species"),
+            ("medium", "media"),
+            ("datum", "data"),
+            ("criterion", "criteria"),
+            ("buffalo", "buffaloes"),
+            ("user_medium", "user_media"),
+        ];
+
+        for (word, expected) in cases {
+            assert_eq!(pluralize(word), expected, "pluralizing {}", word);
+        }
+    }
+
+    #[test]
+    fn test_pluralize_with_overrides() {
+        let overrides = HashMap::from([
+            ("person".to_string(), "persons".to_string()),
+            ("cactus".to_string(), "cactuses".to_string()),
+            ("staff".to_string(), "staff_members".to_string()),
+        ]);
+
+        assert_eq!(pluralize_with_overrides("person", &overrides), "persons");
+        assert_eq!(pluralize_with_overrides("cactus", &overrides), "cactuses");
+        assert_eq!(pluralize_with_overrides("staff", &overrides), "staff_members");
+        assert_eq!(pluralize_with_overrides("support_staff", &overrides), "support_staff_members");
+        assert_eq!(pluralize_with_overrides("user", &overrides), "users");
+    }
+
+    #[test]
+    fn test_parse_count() {
+        assert_eq!(parse_count("10"), Ok(10));
+        assert_eq!(parse_count("10_000"), Ok(10_000));
+        assert_eq!(parse_count("5k"), Ok(5_000));
+        assert_eq!(parse_count("1M"), Ok(1_000_000));
+        assert_eq!(parse_count("10_000_000"), Ok(10_000_000));
+
+        assert!(parse_count("0").is_err());
+        assert!(parse_count("20M").is_err());
+        assert!(parse_count("ten").is_err());
+        assert!(parse_count("k").is_err());
+        assert!(parse_count("-5").is_err());
+    }
+
     #[test]
     fn test_singularize() {
         assert_eq!(singularize("users"), "user");
@@ -333,6 +949,304 @@ mod tests {
         assert!(field.auto_increment);
     }
 
+    #[test]
+    fn test_where_modifier_keeps_the_partial_index_condition() {
+        let field = FieldDefinition::parse("status:string:indexed:where=status='Active'").unwrap();
+        assert!(field.indexed);
+        assert_eq!(field.index_where.as_deref(), Some("status='Active'"));
+
+        // Colons belong to the condition, so it can cast
+        let field = FieldDefinition::parse("state:string:nullable:indexed:WHERE=state::text <> 'done'").unwrap();
+        assert!(field.nullable);
+        assert_eq!(field.index_where.as_deref(), Some("state::text <> 'done'"));
+
+        assert_eq!(FieldDefinition::parse("status:string").unwrap().index_where, None);
+        assert_eq!(
+            FieldDefinition::parse("status:string:indexed:where=").unwrap_err(),
+            "Field 'status' has where= without a condition"
+        );
+        assert_eq!(
+            FieldDefinition::parse("status:string:where=status='active'").unwrap_err(),
+            "Field 'status' has where=, which needs indexed and can't be combined with unique"
+        );
+        assert!(FieldDefinition::parse("email:string:unique:indexed:where=active").is_err());
+    }
+
+    #[test]
+    fn test_decimal_field_parse_precision_and_scale() {
+        let field = FieldDefinition::parse("price:decimal(10,2)").unwrap();
+        assert_eq!(field.field_type, "decimal");
+        assert_eq!(field.decimal_precision, Some(10));
+        assert_eq!(field.decimal_scale, Some(2));
+        assert_eq!(field.rust_type(), "rust_decimal::Decimal");
+        assert_eq!(field.sql_type("postgres"), "DECIMAL(10, 2)");
+
+        let field = FieldDefinition::parse("quantity:decimal(5, 0):nullable").unwrap();
+        assert_eq!((field.decimal_precision, field.decimal_scale), (Some(5), Some(0)));
+        assert_eq!(field.sql_type("mysql"), "DECIMAL(5, 0)");
+        assert_eq!(field.rust_type(), "Option<rust_decimal::Decimal>");
+
+        let field = FieldDefinition::parse("amount:decimal").unwrap();
+        assert_eq!(field.decimal_precision, None);
+        assert_eq!(field.sql_type("sqlite"), "DECIMAL(19, 4)");
+
+        let error = FieldDefinition::parse("price:decimal(2,5)").unwrap_err();
+        assert!(error.contains("cannot exceed its precision"), "{}", error);
+        assert!(FieldDefinition::parse("price:decimal(39,2)").is_err());
+        assert!(FieldDefinition::parse("price:decimal(0,0)").is_err());
+        assert!(FieldDefinition::parse("price:decimal(10)").is_err());
+    }
+
+    #[test]
+    fn test_write_or_update_file_creates_appends_and_skips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mod.rs");
+        let path_str = path.to_string_lossy().into_owned();
+
+        write_or_update_file(&path_str, "pub mod user;\n", "pub mod user;").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "pub mod user;\n");
+
+        std::fs::write(&path, "//! Database models").unwrap();
+        write_or_update_file(&path_str, "pub mod user;\n", "pub mod user;").unwrap();
+        write_or_update_file(&path_str, "pub mod post;\n", "pub mod post;").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "//! Database models\npub mod user;\npub mod post;\n"
+        );
+
+        write_or_update_file(&path_str, "pub mod user;\n", "pub mod user;").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "//! Database models\npub mod user;\npub mod post;\n"
+        );
+    }
+
+    #[test]
+    fn test_write_or_update_file_keeps_files_it_cannot_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mod.rs");
+        let path_str = path.to_string_lossy().into_owned();
+        std::fs::write(&path, b"pub mod user;\n// \xff\n").unwrap();
+
+        let error = write_or_update_file(&path_str, "pub mod post;\n", "pub mod post;").unwrap_err();
+        assert!(error.starts_with("Failed to read mod.rs"), "{}", error);
+        assert_eq!(std::fs::read(&path).unwrap(), b"pub mod user;\n// \xff\n");
+    }
+
+    #[test]
+    fn test_ensure_directory_returns_canonical_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let nested = dir.path().join("src").join("models");
+        let nested_str = nested.to_string_lossy().into_owned();
+
+        let created = ensure_directory(&nested_str).unwrap();
+        assert!(created.is_dir());
+        assert_eq!(created, root.join("src").join("models"));
+        assert_eq!(created.join("user.rs"), root.join("src").join("models").join("user.rs"));
+
+        // Existing directories, including ones given with `/` or `..`, resolve to the same path
+        assert_eq!(ensure_directory(&nested_str).unwrap(), created);
+        let slashed = format!("{}/src/../src/models", dir.path().to_string_lossy());
+        assert_eq!(ensure_directory(&slashed).unwrap(), created);
+
+        let file = created.join("user.rs");
+        std::fs::write(&file, "").unwrap();
+        let error = ensure_directory(&file.to_string_lossy()).unwrap_err();
+        assert!(error.contains("is not a directory"), "{}", error);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_ensure_directory_accepts_backslash_separators() {
+        let dir = tempfile::tempdir().unwrap();
+        let backslashed = format!("{}\\src\\models", dir.path().to_string_lossy());
+        let slashed = format!("{}/src/models", dir.path().to_string_lossy());
+
+        let created = ensure_directory(&backslashed).unwrap();
+        assert_eq!(created, dir.path().canonicalize().unwrap().join("src").join("models"));
+        assert_eq!(ensure_directory(&slashed).unwrap(), created);
+    }
+
+    #[test]
+    fn test_inject_after_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mod.rs");
+        let path_str = path.to_string_lossy().into_owned();
+
+        assert!(inject_after_marker(&path_str, "pub mod user;", "pub use user::User;").is_err());
+
+        std::fs::write(&path, "pub mod user;\npub mod post;").unwrap();
+        inject_after_marker(&path_str, "pub mod user;", "pub use user::User;").unwrap();
+        inject_after_marker(&path_str, "pub mod post;", "pub use post::Post;\n").unwrap();
+        inject_after_marker(&path_str, "pub mod user;", "pub use user::User;").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "pub mod user;\npub use user::User;\npub mod post;\npub use post::Post;\n"
+        );
+
+        let error = inject_after_marker(&path_str, "pub mod tag;", "pub use tag::Tag;").unwrap_err();
+        assert_eq!(error, "'pub mod tag;' not found in mod.rs");
+    }
+
+    #[test]
+    fn test_apply_check_constraints() {
+        let mut fields = vec![
+            FieldDefinition::parse("age:i32").unwrap(),
+            FieldDefinition::parse("status:string").unwrap(),
+        ];
+        apply_check_constraints(&mut fields, "age:age > 0, status:status IN ('draft', 'live')").unwrap();
+        assert_eq!(fields[0].check.as_deref(), Some("age > 0"));
+        assert_eq!(fields[1].check.as_deref(), Some("status IN ('draft', 'live')"));
+
+        for checks in [
+            "age",
+            "age:",
+            "score:score > 0",
+            "age:age > 0; DROP TABLE users",
+            "age:age > 0 -- note",
+            "age:age /* note */ > 0",
+            "age:age > 0\"#",
+            "age:age > 0) OR (1 = 1",
+            "age:(age > 0",
+        ] {
+            assert!(apply_check_constraints(&mut fields, checks).is_err(), "{}", checks);
+        }
+    }
+
+    #[test]
+    fn test_parse_generated_columns() {
+        let columns = parse_generated_columns(
+            "full_name:string:CONCAT(first_name, ' ', last_name), total:decimal(10,2):price * quantity, slug:text:lower(title)::text",
+        )
+        .unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].name, "full_name");
+        assert_eq!(columns[0].field_type, "string");
+        assert!(columns[0].nullable);
+        assert_eq!(columns[0].rust_type(), "Option<String>");
+        assert_eq!(columns[0].generated.as_deref(), Some("CONCAT(first_name, ' ', last_name)"));
+        assert_eq!(columns[1].decimal_precision, Some(10));
+        assert_eq!(columns[1].generated.as_deref(), Some("price * quantity"));
+        assert_eq!(columns[2].generated.as_deref(), Some("lower(title)::text"));
+
+        for columns in [
+            "full_name",
+            "full_name:string",
+            "full_name:string:",
+            "full_name:string:a; DROP TABLE users",
+            "full_name:string:(a",
+        ] {
+            assert!(parse_generated_columns(columns).is_err(), "{}", columns);
+        }
+    }
+
+    #[test]
+    fn test_json_array_field_types() {
+        let field = FieldDefinition::parse("tags:json_array").unwrap();
+        assert_eq!(field.rust_type(), "Vec<serde_json::Value>");
+        assert_eq!(field.sql_type("postgres"), "JSONB");
+        assert_eq!(field.sql_type("mysql"), "JSON");
+        assert_eq!(field.sql_type("sqlite"), "TEXT");
+        for driver in ["postgres", "mysql", "sqlite"] {
+            assert!(field.check_driver(driver).is_ok());
+        }
+
+        let field = FieldDefinition::parse("tags:json_array:nullable").unwrap();
+        assert_eq!(field.rust_type(), "Option<Vec<serde_json::Value>>");
+        assert_eq!(field.base_rust_type(), "Vec<serde_json::Value>");
+    }
+
+    #[test]
+    fn test_text_array_field_types() {
+        let field = FieldDefinition::parse("labels:text_array").unwrap();
+        assert_eq!(field.rust_type(), "Vec<String>");
+        assert_eq!(field.sql_type("postgres"), "TEXT[]");
+        assert!(field.check_driver("postgres").is_ok());
+
+        for driver in ["mysql", "sqlite"] {
+            let error = field.check_driver(driver).unwrap_err();
+            assert!(error.contains("'labels'") && error.contains("PostgreSQL"), "{}", error);
+        }
+
+        let field = FieldDefinition::parse("labels:text_array:nullable").unwrap();
+        assert_eq!(field.rust_type(), "Option<Vec<String>>");
+    }
+
+    #[test]
+    fn test_array_field_types_for_each_driver() {
+        for (definition, rust_type, postgres) in [
+            ("tags:string[]", "Vec<String>", "VARCHAR(255)[]"),
+            ("scores:i32[]", "Vec<i32>", "INTEGER[]"),
+            ("owner_ids:uuid[]", "Vec<uuid::Uuid>", "UUID[]"),
+        ] {
+            let field = FieldDefinition::parse(definition).unwrap();
+            assert!(field.is_array, "{}", definition);
+            assert_eq!(field.rust_type(), rust_type);
+            assert_eq!(field.sql_type("postgres"), postgres);
+            assert_eq!(field.sql_type("mysql"), "JSON");
+            assert_eq!(field.sql_type("sqlite"), "TEXT");
+            assert_eq!(field.sql_comment("postgres"), None);
+            for driver in ["mysql", "sqlite"] {
+                assert_eq!(field.sql_comment(driver), Some("Stored as JSON array"));
+                assert!(field.check_driver(driver).is_ok());
+            }
+        }
+
+        let field = FieldDefinition::parse("tags:string[]:nullable").unwrap();
+        assert_eq!(field.field_type, "string");
+        assert_eq!(field.rust_type(), "Option<Vec<String>>");
+        assert!(!FieldDefinition::parse("tags:string").unwrap().is_array);
+
+        for definition in ["tags:[]", "tags:string[][]", "tags:text_array[]", "tags:enum(a|b)[]"] {
+            let error = FieldDefinition::parse(definition).unwrap_err();
+            assert!(error.starts_with("Field 'tags'"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn split_field_definitions_keeps_parenthesized_commas() {
+        assert_eq!(
+            split_field_definitions("name:string, price:decimal(10,2):nullable,,status:enum(a|b)"),
+            vec!["name:string", "price:decimal(10,2):nullable", "status:enum(a|b)"]
+        );
+        assert!(split_field_definitions(" ").is_empty());
+    }
+
+    #[test]
+    fn test_enum_field_parse_and_rust_type() {
+        let field = FieldDefinition::parse("status:enum(active|inactive|deleted):default=active").unwrap();
+        assert_eq!(field.field_type, "enum");
+        assert_eq!(field.enum_variants, vec!["active", "inactive", "deleted"]);
+        assert_eq!(field.default.as_deref(), Some("active"));
+        assert_eq!(field.rust_type(), "String");
+
+        let field = FieldDefinition::parse("status:enum(active|inactive):nullable").unwrap();
+        assert_eq!(field.rust_type(), "Option<String>");
+
+        assert!(FieldDefinition::parse("status:enum()").is_err());
+        assert!(FieldDefinition::parse("status:enum(|)").is_err());
+    }
+
+    #[test]
+    fn test_enum_field_sql_type_per_driver() {
+        let field = FieldDefinition::parse("status:enum(active|inactive|deleted)").unwrap();
+
+        assert_eq!(field.sql_type("mysql"), "ENUM('active','inactive','deleted')");
+        assert_eq!(
+            field.sql_type("sqlite"),
+            "TEXT CHECK (status IN ('active','inactive','deleted'))"
+        );
+
+        let postgres = field.sql_type("postgres");
+        assert!(postgres.starts_with("status_enum /* TODO"));
+        assert!(postgres.contains("CREATE TYPE status_enum AS ENUM ('active','inactive','deleted')"));
+
+        let field = FieldDefinition::parse("mood:enum(it's fine|ok)").unwrap();
+        assert_eq!(field.sql_type("mysql"), "ENUM('it''s fine','ok')");
+    }
+
     #[test]
     fn test_relation_definition_parse() {
         let rel = RelationDefinition::parse("posts:has_many:Post").unwrap();
@@ -345,4 +1259,44 @@ mod tests {
         assert_eq!(rel.relation_type, RelationType::BelongsTo);
         assert_eq!(rel.foreign_key, Some("user_id".to_string()));
     }
+
+    #[test]
+    fn test_check_model_name_rejects_standard_types() {
+        for name in ["String", "Option", "Result", "Vec", "Box", "Arc", "HashMap"] {
+            assert_eq!(
+                check_model_name(name, &[]).unwrap_err(),
+                format!("'{}' is a reserved Rust type name. Please choose a different model name.", name)
+            );
+        }
+
+        assert!(check_model_name("string", &[]).unwrap_err().contains("'String'"));
+    }
+
+    #[test]
+    fn test_check_model_name_rejects_keywords() {
+        for name in ["type", "Match", "async", "yield"] {
+            let error = check_model_name(name, &[]).unwrap_err();
+            assert!(error.contains("is a reserved Rust keyword"), "{}", error);
+        }
+
+        assert!(check_model_name("self", &[]).unwrap_err().contains("reserved Rust type name"));
+    }
+
+    #[test]
+    fn test_check_model_name_rejects_extra_reserved_names() {
+        let extra = ["request", "Response"];
+
+        assert!(check_model_name("Request", &extra)
+            .unwrap_err()
+            .contains("'Request' is reserved by TIDEORM_RESERVED_NAMES"));
+        assert!(check_model_name("response", &extra).is_err());
+        assert!(check_model_name("Requests", &extra).is_ok());
+    }
+
+    #[test]
+    fn test_check_model_name_accepts_ordinary_names() {
+        for name in ["User", "BlogPost", "order_item", "Types", "StringValue"] {
+            assert!(check_model_name(name, &[]).is_ok(), "{}", name);
+        }
+    }
 }
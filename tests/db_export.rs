@@ -0,0 +1,108 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, SqlitePool};
+
+/// A SQLite database with a populated `users` table and a config pointing at it
+struct ExportProject {
+    dir: TempDir,
+}
+
+impl ExportProject {
+    async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = TempDir::new()?;
+        let db_path = dir.path().join("export.sqlite3").to_string_lossy().replace('\\', "/");
+        std::fs::write(
+            dir.path().join("tideorm.toml"),
+            format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+        )?;
+
+        let pool = SqlitePool::connect(&format!("sqlite://{}?mode=rwc", db_path)).await?;
+        sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT, score REAL)")
+            .execute(&pool)
+            .await?;
+        sqlx::query(
+            "INSERT INTO users (id, name, email, score) VALUES \
+             (1, 'Alice', 'alice@example.com', 4.5), (2, 'Bob, Jr.', NULL, NULL), (3, 'Carol', 'carol@example.com', 2.0)",
+        )
+        .execute(&pool)
+        .await?;
+        pool.close().await;
+
+        Ok(Self { dir })
+    }
+
+    fn export(&self, args: &[&str]) -> Command {
+        let mut command = Command::cargo_bin("tideorm").unwrap();
+        command
+            .current_dir(self.dir.path())
+            .args(["db", "export", "--table", "users"])
+            .args(args);
+        command
+    }
+
+    fn read(&self, file: &str) -> String {
+        std::fs::read_to_string(self.dir.path().join(file)).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn csv_and_tsv_exports_write_a_header_and_empty_nulls() -> Result<(), Box<dyn std::error::Error>> {
+    let project = ExportProject::new().await?;
+
+    let output = project.export(&["--output", "users.csv"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Exported 3 rows from users to users.csv ("), "{}", stdout);
+    assert_eq!(
+        project.read("users.csv"),
+        "id,name,email,score\n1,Alice,alice@example.com,4.5\n2,\"Bob, Jr.\",,\n3,Carol,carol@example.com,2.0\n"
+    );
+
+    project
+        .export(&["--output", "users.tsv", "--format", "tsv", "--where", "score IS NULL"])
+        .assert()
+        .success();
+    assert_eq!(project.read("users.tsv"), "id\tname\temail\tscore\n2\tBob, Jr.\t\t\n");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_export_writes_an_array_with_nulls() -> Result<(), Box<dyn std::error::Error>> {
+    let project = ExportProject::new().await?;
+
+    let output = project.export(&["--format", "json", "--where", "id > 1"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+
+    let file = std::fs::read_dir(project.dir.path())?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .find(|name| name.starts_with("users_") && name.ends_with(".json"))
+        .expect("default output file");
+    let rows: serde_json::Value = serde_json::from_str(&project.read(&file))?;
+    assert_eq!(
+        rows,
+        serde_json::json!([
+            {"id": 2, "name": "Bob, Jr.", "email": null, "score": null},
+            {"id": 3, "name": "Carol", "email": "carol@example.com", "score": 2.0},
+        ])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exporting_a_missing_table_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let project = ExportProject::new().await?;
+
+    let output = Command::cargo_bin("tideorm")?
+        .current_dir(project.dir.path())
+        .args(["db", "export", "--table", "missing"])
+        .output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Table not found: missing"));
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use assert_cmd::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, Row, SqlitePool};
+
+/// A SQLite database with an empty `users` table and a config pointing at it
+///
+/// `role` defaults to `member` and is left out of `rows()`; see `roles()`.
+struct ImportProject {
+    dir: TempDir,
+    database_url: String,
+}
+
+impl ImportProject {
+    async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = TempDir::new()?;
+        let db_path = dir.path().join("import.sqlite3").to_string_lossy().replace('\\', "/");
+        std::fs::write(
+            dir.path().join("tideorm.toml"),
+            format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+        )?;
+
+        let database_url = format!("sqlite://{}?mode=rwc", db_path);
+        let pool = SqlitePool::connect(&database_url).await?;
+        sqlx::query(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT, \
+             age INTEGER, score REAL, active BOOLEAN, tags TEXT, role TEXT NOT NULL DEFAULT 'member')",
+        )
+        .execute(&pool)
+        .await?;
+        pool.close().await;
+
+        Ok(Self { dir, database_url })
+    }
+
+    fn import(&self, fixture: &str, args: &[&str]) -> Command {
+        let mut command = Command::cargo_bin("tideorm").unwrap();
+        command
+            .current_dir(self.dir.path())
+            .args(["db", "import"])
+            .arg(fixture_path(fixture))
+            .args(["--table", "users"])
+            .args(args);
+        command
+    }
+
+    /// `id|name|email|typeof(age)|score|active|tags` for every row, ordered by id
+    async fn rows(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let pool = SqlitePool::connect(&self.database_url).await?;
+        let rows = sqlx::query(
+            "SELECT id || '|' || name || '|' || COALESCE(email, 'NULL') || '|' || typeof(age) || '|' \
+             || COALESCE(score, 'NULL') || '|' || COALESCE(active, 'NULL') || '|' || COALESCE(tags, 'NULL') \
+             AS line FROM users ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await?;
+        pool.close().await;
+
+        Ok(rows.iter().map(|row| row.get::<String, _>("line")).collect())
+    }
+
+    /// `role` for every row, ordered by id
+    async fn roles(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let pool = SqlitePool::connect(&self.database_url).await?;
+        let rows = sqlx::query("SELECT role FROM users ORDER BY id").fetch_all(&pool).await?;
+        pool.close().await;
+
+        Ok(rows.iter().map(|row| row.get::<String, _>("role")).collect())
+    }
+}
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/import").join(name)
+}
+
+#[tokio::test]
+async fn csv_import_batches_rows_stores_nulls_and_reports_skipped_lines(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let project = ImportProject::new().await?;
+
+    let output = project.import("users.csv", &["--batch-size", "2"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Imported 4 rows into users"), "{}", stdout);
+    assert!(stdout.contains("1 rows skipped:"), "{}", stdout);
+    assert!(stdout.contains("line 4: expected 6 fields, found 3"), "{}", stdout);
+
+    assert_eq!(
+        project.rows().await?,
+        vec![
+            "1|Alice|alice@example.com|integer|4.5|1|NULL",
+            "2|Bob|NULL|integer|NULL|0|NULL",
+            "4|Dan|dan@example.com|integer|3.25|1|NULL",
+            "5|Erin|erin@example.com|integer|5.0|0|NULL",
+        ]
+    );
+
+    // --truncate replaces the rows instead of colliding with them
+    project.import("users.csv", &["--truncate"]).assert().success();
+    assert_eq!(project.rows().await?.len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_import_applies_the_conflict_strategy() -> Result<(), Box<dyn std::error::Error>> {
+    let project = ImportProject::new().await?;
+
+    let output = project.import("users.json", &[]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Imported 3 rows into users"), "{}", stdout);
+    assert!(stdout.contains("item 3: expected an object, found a string"), "{}", stdout);
+    assert_eq!(
+        project.rows().await?,
+        vec![
+            r#"1|Alice|alice@example.com|integer|NULL|1|["admin","staff"]"#,
+            "2|Bob|NULL|integer|NULL|0|NULL",
+            "3|Carol|carol@example.com|null|NULL|NULL|NULL",
+        ]
+    );
+    // Keys an object leaves out fall back to the column default instead of NULL
+    assert_eq!(project.roles().await?, vec!["admin", "member", "member"]);
+
+    let output = project.import("users_update.json", &[]).output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("UNIQUE constraint failed"));
+    assert_eq!(project.rows().await?.len(), 3);
+
+    let output = project.import("users_update.json", &["--on-conflict", "ignore"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Imported 1 rows into users"), "{}", stdout);
+    assert!(stdout.contains("1 rows skipped because they conflict with existing rows"), "{}", stdout);
+    assert!(project.rows().await?[0].starts_with("1|Alice|"));
+
+    project
+        .import("users_update.json", &["--on-conflict", "replace"])
+        .assert()
+        .success();
+    let rows = project.rows().await?;
+    assert_eq!(rows[0], "1|Alicia|alicia@example.com|null|NULL|NULL|NULL");
+    assert_eq!(rows.len(), 4);
+
+    Ok(())
+}
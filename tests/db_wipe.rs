@@ -0,0 +1,59 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, Row, SqlitePool};
+
+/// Count the rows of `table`
+async fn count(pool: &SqlitePool, table: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let row = sqlx::query(&format!("SELECT COUNT(*) AS count FROM {}", table)).fetch_one(pool).await?;
+    Ok(row.get("count"))
+}
+
+#[tokio::test]
+async fn wipe_empties_related_tables_and_keeps_migration_history() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let db_path = dir.path().join("app.sqlite3").to_string_lossy().replace('\\', "/");
+    std::fs::write(
+        dir.path().join("tideorm.toml"),
+        format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+    )?;
+
+    // Alphabetical order would empty accounts before the orders that reference it
+    let pool = SqlitePool::connect(&format!("sqlite://{}?mode=rwc", db_path)).await?;
+    sqlx::raw_sql(
+        "PRAGMA foreign_keys = ON;
+         CREATE TABLE _migrations (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+         CREATE TABLE accounts (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL);
+         CREATE TABLE orders (id INTEGER PRIMARY KEY AUTOINCREMENT, account_id INTEGER NOT NULL REFERENCES accounts (id));
+         CREATE TABLE order_items (id INTEGER PRIMARY KEY AUTOINCREMENT, order_id INTEGER NOT NULL REFERENCES orders (id));
+         INSERT INTO _migrations (name) VALUES ('20240101000000_create_accounts');
+         INSERT INTO accounts (name) VALUES ('Acme'), ('Globex');
+         INSERT INTO orders (account_id) VALUES (1), (2);
+         INSERT INTO order_items (order_id) VALUES (1), (1), (2);",
+    )
+    .execute(&pool)
+    .await?;
+    pool.close().await;
+
+    let output = Command::cargo_bin("tideorm")?
+        .current_dir(dir.path())
+        .args(["--verbose", "db", "wipe", "--force"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("order_items, orders, accounts"), "{}", stdout);
+    assert!(stdout.contains("Wiped 3 table(s)"), "{}", stdout);
+
+    let pool = SqlitePool::connect(&format!("sqlite://{}", db_path)).await?;
+    for table in ["accounts", "orders", "order_items"] {
+        assert_eq!(count(&pool, table).await?, 0, "{} should be empty", table);
+    }
+    assert_eq!(count(&pool, "_migrations").await?, 1);
+
+    sqlx::query("INSERT INTO accounts (name) VALUES ('Initech')").execute(&pool).await?;
+    let id: i64 = sqlx::query("SELECT id FROM accounts").fetch_one(&pool).await?.get("id");
+    assert_eq!(id, 1, "AUTOINCREMENT counters should restart");
+    pool.close().await;
+
+    Ok(())
+}
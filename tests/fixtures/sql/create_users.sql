@@ -0,0 +1,15 @@
+﻿-- Schema from the DBA (request "#42"); statements end with semicolons
+CREATE TABLE users (
+    id BIGSERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL, -- login; unique below
+    bio TEXT DEFAULT 'Says "hi"; nothing else'
+);
+
+CREATE UNIQUE INDEX users_email_idx ON users (email);
+
+CREATE FUNCTION touch_users() RETURNS trigger AS $$
+BEGIN
+    NEW.bio := COALESCE(NEW.bio, '');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,2 @@
+DROP FUNCTION IF EXISTS touch_users();
+DROP TABLE IF EXISTS users;
@@ -58,6 +58,162 @@ fn generated_project_builds_after_model_generation() -> Result<(), Box<dyn std::
     Ok(())
 }
 
+#[test]
+fn generated_seeders_compile_without_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("seeder_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "Product",
+            "--fields",
+            "name:string,sku:string:unique,price:float,stock:integer,notes:text:nullable,active:boolean",
+        ])
+        .assert()
+        .success();
+
+    for args in [
+        ["make", "seeder", "ProductSeeder", "--model", "Product", "--sequential"],
+        ["make", "seeder", "RandomProductSeeder", "--model", "Product", "--faker"],
+    ] {
+        Command::cargo_bin("tideorm")?
+            .current_dir(&project_dir)
+            .args(args)
+            .assert()
+            .success();
+    }
+
+    let sequential = std::fs::read_to_string(project_dir.join("src/seeders/product_seeder.rs"))?;
+    assert!(sequential.contains("for i in 1..=10 {"));
+    assert!(sequential.contains("stock: i as i32,"));
+
+    let manifest_path = project_dir.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&manifest_path)?;
+    std::fs::write(
+        &manifest_path,
+        manifest.replace("[dependencies]\n", "[dependencies]\nfake = \"4\"\n"),
+    )?;
+
+    let output = Command::new("cargo")
+        .args(["check", "--offline"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated seeders failed to build\n{}",
+        format_output(&output)
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated seeders produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generated_optimistic_locking_model_compiles_without_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("locking_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "Account",
+            "--fields",
+            "name:string,email:string",
+            "--optimistic-locking",
+            "--migration",
+        ])
+        .assert()
+        .success();
+
+    let output = Command::new("cargo")
+        .args(["check", "--offline"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated project failed to build\n{}",
+        format_output(&output)
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated project produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generated_trait_base_models_compile_without_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("trait_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    // One model needs nothing from the prelude, the other names its Json type
+    for (model, fields) in [("Thing", "name:string,count:integer"), ("Gadget", "name:string,settings:json")] {
+        Command::cargo_bin("tideorm")?
+            .current_dir(&project_dir)
+            .args(["make", "model", model, "--fields", fields, "--base", "trait"])
+            .assert()
+            .success();
+    }
+
+    let output = Command::new("cargo")
+        .args(["check", "--offline"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated project failed to build\n{}",
+        format_output(&output)
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated project produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn interactive_init_accepts_scripted_postgres_answers() -> Result<(), Box<dyn std::error::Error>> {
     let temp_dir = TempDir::new()?;
@@ -182,4 +338,433 @@ fn generated_sqlite_project_tracks_migrations_across_repeated_runs(
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn generated_repository_round_trips_records_against_sqlite(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("repository_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "User",
+            "--fields",
+            "name:string,email:string:unique",
+            "--migration",
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["make", "repository", "User", "--model", "User"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["migrate", "run"])
+        .assert()
+        .success();
+
+    std::fs::write(
+        project_dir.join("src/main.rs"),
+        r#"pub mod models;
+pub mod repositories;
+
+use models::user::User;
+use repositories::UserRepository;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let db = tideorm::Database::connect("sqlite://database.db").await?;
+    let repository = UserRepository::new(Arc::new(db));
+
+    let now = chrono::Utc::now();
+    let user = repository
+        .create(User {
+            id: 0,
+            name: "Ada".into(),
+            email: "ada@example.com".into(),
+            created_at: now,
+            updated_at: now,
+        })
+        .await?;
+
+    let mut found = repository.find_by_id(user.id).await?.expect("created user");
+    assert_eq!(found.email, "ada@example.com");
+    assert_eq!(repository.find_by("email", "ada@example.com").await?.len(), 1);
+
+    found.name = "Ada Lovelace".into();
+    repository.update(found).await?;
+    let reloaded = repository.find_by_id(user.id).await?.expect("updated user");
+    assert_eq!(reloaded.name, "Ada Lovelace");
+    assert_eq!(repository.count().await?, 1);
+    assert_eq!(repository.find_all().await?.len(), 1);
+
+    assert_eq!(repository.delete(reloaded).await?, 1);
+    assert!(repository.find_by_id(user.id).await?.is_none());
+    assert_eq!(repository.count().await?, 0);
+
+    println!("repository round trip ok");
+    Ok(())
+}
+"#,
+    )?;
+
+    let output = Command::new("cargo")
+        .args(["run", "--offline", "--quiet"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated repository program failed\n{}",
+        format_output(&output)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("repository round trip ok"));
+
+    Ok(())
+}
+
+#[test]
+fn generated_observer_runs_on_save_against_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("observer_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "User",
+            "--fields",
+            "name:string,email:string:unique",
+            "--migration",
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["make", "observer", "User", "--model", "User"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["migrate", "run"])
+        .assert()
+        .success();
+
+    // Fill in the creating hook so the test can see it ran
+    let observer_path = project_dir.join("src/observers/user_observer.rs");
+    let observer = std::fs::read_to_string(&observer_path)?.replacen(
+        "        Ok(())",
+        "        _user.email = _user.email.to_lowercase();\n        Ok(())",
+        1,
+    );
+    std::fs::write(&observer_path, observer)?;
+
+    std::fs::write(
+        project_dir.join("src/main.rs"),
+        r#"pub mod models;
+pub mod observers;
+
+use models::user::User;
+use tideorm::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tideorm::Database::init("sqlite://database.db").await?;
+
+    let now = chrono::Utc::now();
+    let user = User {
+        id: 0,
+        name: "Ada".into(),
+        email: "ADA@Example.com".into(),
+        created_at: now,
+        updated_at: now,
+    }
+    .save()
+    .await?;
+
+    let reloaded = User::find(user.id).await?.expect("saved user");
+    assert_eq!(reloaded.email, "ada@example.com");
+
+    println!("observer ran");
+    Ok(())
+}
+"#,
+    )?;
+
+    let output = Command::new("cargo")
+        .args(["run", "--offline", "--quiet"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated observer program failed\n{}",
+        format_output(&output)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("observer ran"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated project produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generated_model_tests_pass_against_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("model_test_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "User",
+            "--fields",
+            "name:string,email:string:unique",
+            "--factory",
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["make", "test", "User"])
+        .assert()
+        .success();
+
+    let manifest = std::fs::read_to_string(project_dir.join("Cargo.toml"))?;
+    assert!(manifest.contains("[dev-dependencies]\ntempfile = \"3\""));
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("src/tests/mod.rs"))?,
+        "pub mod user_test;\n"
+    );
+
+    let main_path = project_dir.join("src/main.rs");
+    let main = std::fs::read_to_string(&main_path)?;
+    std::fs::write(&main_path, format!("#[cfg(test)]\nmod tests;\n{}", main))?;
+
+    let output = Command::new("cargo")
+        .args(["test", "--offline", "tests::user_test"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated model tests failed\n{}",
+        format_output(&output)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("test result: ok. 4 passed"), "{}", format_output(&output));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated model tests produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generated_uuid_cast_round_trips_values() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("cast_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["make", "cast", "Uuid", "--from-type=String", "--to-type=uuid::Uuid"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("src/casts/mod.rs"))?,
+        "pub mod uuid_cast;\npub use uuid_cast::UuidCast;\n"
+    );
+
+    assert!(std::fs::read_to_string(project_dir.join("Cargo.toml"))?.contains("uuid = \"1\""));
+
+    std::fs::write(
+        project_dir.join("src/main.rs"),
+        r#"pub mod casts;
+
+use casts::UuidCast;
+
+fn main() {
+    let stored = "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string();
+    let uuid = UuidCast::to(&stored).expect("valid uuid");
+    assert_eq!(uuid.get_version_num(), 4);
+    assert_eq!(UuidCast::from(&uuid), stored);
+    assert_eq!(UuidCast::to(&format!(" {} ", stored)).unwrap(), uuid);
+
+    let error = UuidCast::to(&"not-a-uuid".to_string()).unwrap_err();
+    assert!(error.to_string().contains("invalid uuid::Uuid \"not-a-uuid\""), "{}", error);
+    assert!(UuidCast::to(&"  ".to_string()).is_err());
+
+    println!("cast round trip ok");
+}
+"#,
+    )?;
+
+    let output = Command::new("cargo")
+        .args(["run", "--offline", "--quiet"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated cast program failed\n{}",
+        format_output(&output)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("cast round trip ok"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("warning:"),
+        "generated cast produced warnings\n{}",
+        format_output(&output)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generated_presenter_formats_model_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let project_dir = temp_dir.path().join("presenter_app");
+    let project_dir_arg = project_dir.to_string_lossy().into_owned();
+
+    Command::cargo_bin("tideorm")?
+        .env("TIDEORM_NONINTERACTIVE", "1")
+        .args(["init", &project_dir_arg, "--database", "sqlite"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "model",
+            "Person",
+            "--fields",
+            "first_name:string,last_name:string,avatar_url:string:nullable,active:bool",
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args(["make", "presenter", "Person", "--model", "Person"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("tideorm")?
+        .current_dir(&project_dir)
+        .args([
+            "make",
+            "presenter",
+            "PersonCard",
+            "--model",
+            "Person",
+            "--fields",
+            "full_name,created_at",
+        ])
+        .assert()
+        .success();
+
+    std::fs::write(
+        project_dir.join("src/main.rs"),
+        r#"pub mod models;
+pub mod presenters;
+
+use chrono::TimeZone;
+use models::person::Person;
+use presenters::{PersonCardPresenter, PersonPresenter};
+
+fn main() {
+    let created_at = chrono::Utc.with_ymd_and_hms(2026, 3, 7, 9, 5, 0).unwrap();
+    let person = Person {
+        id: 1,
+        first_name: "Ada".into(),
+        last_name: "Lovelace".into(),
+        avatar_url: None,
+        active: true,
+        created_at,
+        updated_at: created_at,
+    };
+
+    let presenter = PersonPresenter::from(person.clone());
+    assert_eq!(presenter.full_name(), "Ada Lovelace");
+    assert_eq!(presenter.avatar_url(), "");
+    assert_eq!(presenter.active(), "Yes");
+    assert_eq!(presenter.created_at_human(), "Mar 7, 2026 09:05");
+    assert_eq!(presenter.model().id, 1);
+
+    let card: PersonCardPresenter = person.into();
+    assert_eq!(card.full_name(), "Ada Lovelace");
+    assert_eq!(card.into_inner().first_name, "Ada");
+
+    println!("presenter ok");
+}
+"#,
+    )?;
+
+    let output = Command::new("cargo")
+        .args(["run", "--offline", "--quiet"])
+        .current_dir(&project_dir)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "generated presenter program failed\n{}",
+        format_output(&output)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("presenter ok"));
+
+    Ok(())
+}
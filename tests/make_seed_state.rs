@@ -0,0 +1,97 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, SqlitePool};
+
+const USER_MODEL: &str = r#"use tideorm::prelude::*;
+
+#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+    pub score: f64,
+    pub active: bool,
+}
+"#;
+
+/// A SQLite project whose users table holds three rows
+async fn project() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let db_path = dir.path().join("app.sqlite3").to_string_lossy().replace('\\', "/");
+    std::fs::write(
+        dir.path().join("tideorm.toml"),
+        format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+    )?;
+    std::fs::create_dir_all(dir.path().join("src/models"))?;
+    std::fs::write(dir.path().join("src/models/user.rs"), USER_MODEL)?;
+
+    let pool = SqlitePool::connect(&format!("sqlite://{}?mode=rwc", db_path)).await?;
+    sqlx::raw_sql(
+        r#"CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, bio TEXT, score REAL NOT NULL, active BOOLEAN NOT NULL, avatar BLOB);
+           INSERT INTO users (name, bio, score, active, avatar) VALUES
+               ('Alice', 'Says "hi"', 9.5, 1, x'89504e47'),
+               ('Bob', NULL, 3, 0, NULL),
+               ('Chloé', 'Line one' || char(10) || 'C:\temp', 7.25, 1, NULL);"#,
+    )
+    .execute(&pool)
+    .await?;
+    pool.close().await;
+
+    Ok(dir)
+}
+
+fn seed_state(dir: &TempDir, args: &[&str]) -> Result<(String, String), Box<dyn std::error::Error>> {
+    let output = Command::cargo_bin("tideorm")?
+        .current_dir(dir.path())
+        .args(["make", "seed-state", "DevData", "--tables=users"])
+        .args(args)
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+
+    let seeder = std::fs::read_to_string(dir.path().join("src/seeders/dev_data_seeder.rs"))?;
+    Ok((stdout, seeder))
+}
+
+#[tokio::test]
+async fn seed_state_writes_a_save_call_per_row_with_escaped_literals() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = project().await?;
+
+    let (stdout, seeder) = seed_state(&dir, &[])?;
+
+    assert!(stdout.contains("Skipping binary column users.avatar"), "{}", stdout);
+    assert!(stdout.contains("(3 row(s))"), "{}", stdout);
+    assert!(seeder.contains("use crate::models::user::User;\n"), "{}", seeder);
+    assert!(seeder.contains("pub struct DevDataSeeder;"), "{}", seeder);
+    assert!(seeder.contains("        // users: 3 row(s)\n"), "{}", seeder);
+    assert_eq!(seeder.matches(".save()\n        .await?;").count(), 3, "{}", seeder);
+    assert!(
+        seeder.contains(
+            "        User {\n            name: \"Alice\".to_string(),\n            bio: Some(\"Says \\\"hi\\\"\".to_string()),\n            score: 9.5,\n            active: true,\n            ..Default::default()\n        }\n"
+        ),
+        "{}",
+        seeder
+    );
+    assert!(
+        seeder.contains("            name: \"Bob\".to_string(),\n            score: 3.0,\n            active: false,\n"),
+        "{}",
+        seeder
+    );
+    assert!(seeder.contains("            bio: Some(\"Line one\\nC:\\\\temp\".to_string()),\n"), "{}", seeder);
+    assert!(seeder.contains("\"Chloé\".to_string()"), "{}", seeder);
+    for unwanted in ["id:", "avatar"] {
+        assert!(!seeder.contains(unwanted), "seeder contains {:?}:\n{}", unwanted, seeder);
+    }
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("src/seeders/mod.rs"))?,
+        "pub mod dev_data_seeder;\n"
+    );
+
+    let (_, seeder) = seed_state(&dir, &["--limit=2"])?;
+    assert!(seeder.contains("// users: 2 row(s)"), "{}", seeder);
+    assert!(!seeder.contains("Chloé"), "{}", seeder);
+
+    Ok(())
+}
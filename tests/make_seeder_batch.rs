@@ -0,0 +1,155 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+
+const MODELS: [(&str, &str); 3] = [
+    (
+        "user",
+        r#"#[tideorm::model(table = "users")]
+pub struct User {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub name: String,
+    pub role: String,
+}
+"#,
+    ),
+    (
+        "post",
+        r#"#[tideorm::model(table = "posts")]
+pub struct Post {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub user_id: i64,
+    pub title: String,
+}
+"#,
+    ),
+    (
+        "comment",
+        r#"#[tideorm::model(table = "comments")]
+pub struct Comment {
+    #[tideorm(primary_key, auto_increment)]
+    pub id: i64,
+    pub post_id: i64,
+    pub body: String,
+}
+"#,
+    ),
+];
+
+/// A project with User, Post and Comment models, where comments reference posts and posts users
+fn project(definition_file: &str, definition: &str) -> Result<TempDir, Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    std::fs::create_dir_all(dir.path().join("src/models"))?;
+    for (name, model) in MODELS {
+        std::fs::write(dir.path().join(format!("src/models/{}.rs", name)), model)?;
+    }
+    std::fs::write(dir.path().join(definition_file), definition)?;
+    Ok(dir)
+}
+
+fn seeder_batch(dir: &TempDir, file: &str) -> Command {
+    let mut command = Command::cargo_bin("tideorm").unwrap();
+    command.current_dir(dir.path()).args(["make", "seeder-batch", file]);
+    command
+}
+
+fn seeder_files(dir: &TempDir) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut files: Vec<String> = std::fs::read_dir(dir.path().join("src/seeders"))?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<_, _>>()?;
+    files.sort();
+    Ok(files)
+}
+
+#[test]
+fn seeder_batch_writes_a_seeder_per_table_and_runs_them_parents_first() -> Result<(), Box<dyn std::error::Error>> {
+    // Listed children first, to show the order comes from the models' foreign keys
+    let dir = project(
+        "seeds.toml",
+        r#"
+[comments]
+count = 300
+
+[posts]
+count = 200
+
+[users]
+count = "1k"
+fields = { role = "admin" }
+"#,
+    )?;
+
+    seeder_batch(&dir, "seeds.toml").assert().success();
+
+    assert_eq!(
+        seeder_files(&dir)?,
+        [
+            "all_seeders_seeder.rs",
+            "comment_seeder.rs",
+            "mod.rs",
+            "post_seeder.rs",
+            "user_seeder.rs"
+        ]
+    );
+
+    let aggregate = std::fs::read_to_string(dir.path().join("src/seeders/all_seeders_seeder.rs"))?;
+    let calls: Vec<&str> = aggregate
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.ends_with(".run(db).await?;"))
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            "UserSeeder::default().run(db).await?;",
+            "PostSeeder::default().run(db).await?;",
+            "CommentSeeder::default().run(db).await?;"
+        ]
+    );
+
+    let users = std::fs::read_to_string(dir.path().join("src/seeders/user_seeder.rs"))?;
+    assert!(users.contains("for i in 1..=1000 {"), "{}", users);
+    assert!(users.contains("name: format!(\"User {}\", i),\n                role: \"admin\".to_string(),\n"), "{}", users);
+    let posts = std::fs::read_to_string(dir.path().join("src/seeders/post_seeder.rs"))?;
+    assert!(posts.contains("for i in 1..=200 {"), "{}", posts);
+
+    Ok(())
+}
+
+#[test]
+fn seeder_batch_reads_yaml_definitions() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = project(
+        "seeds.yml",
+        "users:\n  count: 50\n  fields:\n    role: admin\nposts:\n  count: 200\n",
+    )?;
+
+    seeder_batch(&dir, "seeds.yml").assert().success();
+
+    assert_eq!(
+        seeder_files(&dir)?,
+        ["all_seeders_seeder.rs", "mod.rs", "post_seeder.rs", "user_seeder.rs"]
+    );
+    let users = std::fs::read_to_string(dir.path().join("src/seeders/user_seeder.rs"))?;
+    assert!(users.contains("for i in 1..=50 {"), "{}", users);
+    assert!(users.contains("role: \"admin\".to_string(),"), "{}", users);
+
+    Ok(())
+}
+
+#[test]
+fn seeder_batch_rejects_unknown_fields_and_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = project("seeds.toml", "[users]\nfields = { nickname = \"ada\" }\n")?;
+    let output = seeder_batch(&dir, "seeds.toml").output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("User has no field named nickname"));
+
+    let dir = project("seeds.toml", "[posts]\ndepends_on = [\"users\"]\n")?;
+    let output = seeder_batch(&dir, "seeds.toml").output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("posts depends on users, which is not defined in seeds.toml"));
+
+    assert!(!dir.path().join("src/seeders/all_seeders_seeder.rs").exists());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, Row, SqlitePool};
+
+fn tideorm(dir: &TempDir, args: &[&str]) -> Command {
+    let mut command = Command::cargo_bin("tideorm").unwrap();
+    command.current_dir(dir.path()).args(args);
+    command
+}
+
+/// Declared type of each column of `users`, plus the rows and indexes that must survive rebuilds
+async fn users_state(pool: &SqlitePool) -> Result<(Vec<String>, Vec<String>, Vec<String>), Box<dyn std::error::Error>> {
+    let types = sqlx::query("PRAGMA table_info(users)")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| format!("{} {}", row.get::<String, _>("name"), row.get::<String, _>("type")))
+        .collect();
+    let rows = sqlx::query("SELECT email, age FROM users ORDER BY id")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| format!("{} {}", row.get::<String, _>("email"), row.get::<i64, _>("age")))
+        .collect();
+    let indexes = sqlx::query("SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = 'users' ORDER BY name")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| row.get::<String, _>("name"))
+        .collect();
+    Ok((types, rows, indexes))
+}
+
+#[tokio::test]
+async fn modify_column_rebuilds_sqlite_tables_and_rolls_back() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let db_path = dir.path().join("app.sqlite3").to_string_lossy().replace('\\', "/");
+    std::fs::write(
+        dir.path().join("tideorm.toml"),
+        format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+    )?;
+
+    let pool = SqlitePool::connect(&format!("sqlite://{}?mode=rwc", db_path)).await?;
+    sqlx::raw_sql(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL UNIQUE, age BIGINT);
+         CREATE INDEX users_age_idx ON users (age);
+         INSERT INTO users (email, age) VALUES ('ada@example.com', 36), ('alan@example.com', 41);",
+    )
+    .execute(&pool)
+    .await?;
+
+    let output = tideorm(&dir, &["make", "migration", "shrink_users", "--table=users", "--modify-column=email:varchar(10),age:int"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("Changing users.email from TEXT to varchar(10) may truncate existing data"), "{}", stdout);
+    assert!(stdout.contains("Changing users.age from BIGINT to int may truncate existing data"), "{}", stdout);
+
+    let rows = vec!["ada@example.com 36".to_string(), "alan@example.com 41".to_string()];
+    let indexes = vec!["users_age_idx".to_string(), "users_email_key".to_string()];
+
+    tideorm(&dir, &["migrate", "run"]).assert().success();
+    assert_eq!(
+        users_state(&pool).await?,
+        (vec!["id INTEGER".into(), "email varchar(10)".into(), "age INT".into()], rows.clone(), indexes.clone())
+    );
+
+    tideorm(&dir, &["migrate", "down"]).assert().success();
+    assert_eq!(
+        users_state(&pool).await?,
+        (vec!["id INTEGER".into(), "email TEXT".into(), "age BIGINT".into()], rows, indexes)
+    );
+
+    pool.close().await;
+    Ok(())
+}
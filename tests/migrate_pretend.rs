@@ -0,0 +1,82 @@
+use assert_cmd::prelude::*;
+use std::process::Command;
+use tempfile::TempDir;
+use tideorm::sea_orm::sqlx::{self, Row, SqlitePool};
+
+/// A SQLite project with two pending migrations
+struct PretendProject {
+    dir: TempDir,
+}
+
+impl PretendProject {
+    fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = TempDir::new()?;
+        let db_path = dir.path().join("app.sqlite3").to_string_lossy().replace('\\', "/");
+        std::fs::write(
+            dir.path().join("tideorm.toml"),
+            format!("[database]\ndriver = \"sqlite\"\nsqlite_path = \"{}\"\n", db_path),
+        )?;
+
+        let project = Self { dir };
+        project
+            .tideorm(&["make", "migration", "create_users_table", "--create=users", "--fields=name:string,email:string"])
+            .assert()
+            .success();
+        // Migration versions are second-resolution timestamps
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        project
+            .tideorm(&["make", "migration", "index_users_email", "--table=users", "--add-index=email"])
+            .assert()
+            .success();
+
+        Ok(project)
+    }
+
+    fn tideorm(&self, args: &[&str]) -> Command {
+        let mut command = Command::cargo_bin("tideorm").unwrap();
+        command.current_dir(self.dir.path()).args(args);
+        command
+    }
+
+    fn pretend(&self, args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+        let output = self.tideorm(&["migrate", "run", "--pretend"]).args(args).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        assert!(output.status.success(), "{}", stderr);
+        assert!(stderr.contains("Running in pretend mode"), "{}", stderr);
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+/// Run `sql` against a fresh SQLite database and list the tables and indexes it created
+async fn apply(sql: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let pool = SqlitePool::connect("sqlite::memory:").await?;
+    sqlx::raw_sql(sql).execute(&pool).await?;
+    let names = sqlx::query("SELECT name FROM sqlite_master WHERE name NOT LIKE 'sqlite_%' ORDER BY name")
+        .fetch_all(&pool)
+        .await?
+        .iter()
+        .map(|row| row.get::<String, _>("name"))
+        .collect();
+    pool.close().await;
+    Ok(names)
+}
+
+#[tokio::test]
+async fn pretend_prints_only_sql_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let project = PretendProject::new()?;
+
+    let stdout = project.pretend(&[])?;
+    for noise in ["ℹ", "⚠", "Pretend mode", "Migrations to run", "schema.", "r#\"", ".await", "async fn"] {
+        assert!(!stdout.contains(noise), "stdout contains {:?}:\n{}", noise, stdout);
+    }
+    assert_eq!(stdout.matches("-- Migration: ").count(), 2, "{}", stdout);
+    assert!(stdout.contains("_create_users_table\nCREATE TABLE"), "{}", stdout);
+    assert_eq!(apply(&stdout).await?, vec!["users", "users_email_idx"]);
+
+    let stdout = project.pretend(&["--pretend-no-comments"])?;
+    assert!(!stdout.contains("--"), "{}", stdout);
+    assert!(stdout.trim_end().ends_with(';'), "{}", stdout);
+    assert_eq!(apply(&stdout).await?, vec!["users", "users_email_idx"]);
+
+    Ok(())
+}